@@ -0,0 +1,23 @@
+//! Parses arbitrary rules, which must fail cleanly or format back into an equivalent rule.
+
+#![no_main]
+
+use cldr_pluralrules_parser::{ParseOptions, parse, parse_with};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|source: &str| {
+    for options in [ParseOptions::default(), ParseOptions::lenient()] {
+        if let Ok(mut rule) = parse_with(source, &options) {
+            // Formatting canonicalizes the syntax, such as `is` into `=`, so compare normalized rules.
+            let formatted = rule.to_string();
+            let mut reparsed = parse(&formatted).expect("A formatted rule parses");
+            rule.normalize();
+            reparsed.normalize();
+            assert_eq!(
+                rule, reparsed,
+                "{:?} was formatted as {:?}",
+                source, formatted
+            );
+        }
+    }
+});
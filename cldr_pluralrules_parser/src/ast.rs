@@ -1,31 +1,75 @@
-#[derive(Debug, Clone, PartialEq)]
+use crate::error::UnknownOperand;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rule {
     pub condition: Condition,
     pub samples: Option<Samples>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+impl Rule {
+    /// Returns the rule of `condition`, without samples, for building rules without the parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::ast::*;
+    /// use cldr_pluralrules_parser::parse;
+    ///
+    /// let rule = Rule::from_condition(Relation::equals(Operand::N, 1).into());
+    /// assert_eq!(Ok(rule), parse("n = 1"));
+    /// ```
+    pub fn from_condition(condition: Condition) -> Rule {
+        Rule {
+            condition,
+            samples: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Samples {
     pub integer: Option<SampleList>,
     pub decimal: Option<SampleList>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SampleList {
     pub sample_ranges: Vec<SampleRange>,
     pub ellipsis: bool,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SampleRange {
     pub lower_val: DecimalValue,
     pub upper_val: Option<DecimalValue>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+/// A sample value, such as `1`, `1.05`, the compact `1.1c6` or the negative `-1.0`.
+///
+/// The digits are kept as they are written rather than as integers, as the samples of compact numbers can
+/// exceed `u64`. Conversions of values too large for them saturate: `to_f64` to infinity, while ranges
+/// with such bounds, or with more than 1000 values, are not stepped through, and `SampleRange::expand` and
+/// `values` only yield their bounds.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DecimalValue {
-    pub integer: Value,
-    pub decimal: Option<Value>,
+    /// Whether the value is written with a leading `-`. As in CLDR, it selects the category of its magnitude.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub negative: bool,
+    /// The integer digits, kept verbatim.
+    pub integer: String,
+    /// The fraction digits, kept verbatim so that leading and trailing zeros are preserved.
+    pub decimal: Option<String>,
+    /// The power of ten of the compact notation, written after `c` (or the deprecated `e`).
+    pub exponent: Option<Value>,
 }
 
 /// A complete (and the only complete) AST representation of a plural rule. Comprises a vector of AndConditions.
@@ -85,9 +129,17 @@ pub struct DecimalValue {
 ///          .condition
 /// )
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Condition(pub Vec<AndCondition>);
 
+/// The condition of a single relation, such as `n = 1`.
+impl From<Relation> for Condition {
+    fn from(relation: Relation) -> Condition {
+        Condition(vec![AndCondition(vec![relation])])
+    }
+}
+
 /// An incomplete AST representation of a plural rule. Comprises a vector of Relations.
 ///
 /// # Examples
@@ -123,7 +175,8 @@ pub struct Condition(pub Vec<AndCondition>);
 /// ]);
 ///
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AndCondition(pub Vec<Relation>);
 
 /// An incomplete AST representation of a plural rule. Comprises an Expression, an Operator, and a RangeList.
@@ -151,13 +204,65 @@ pub struct AndCondition(pub Vec<Relation>);
 /// };
 ///
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Relation {
     pub expression: Expression,
     pub operator: Operator,
     pub range_list: RangeList,
 }
 
+impl Relation {
+    /// Returns the relation of `operand`, taken modulo `modulus` if any, with `range_list`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::ast::*;
+    ///
+    /// let relation = Relation::new(
+    ///     Operand::N,
+    ///     Some(100),
+    ///     Operator::NotEQ,
+    ///     RangeList(vec![RangeListItem::Range(Range { lower_val: Value(11), upper_val: Value(14) })]),
+    /// );
+    /// assert_eq!(relation.to_string(), "n % 100 != 11..14");
+    /// ```
+    pub fn new(
+        operand: Operand,
+        modulus: Option<u64>,
+        operator: Operator,
+        range_list: RangeList,
+    ) -> Relation {
+        Relation {
+            expression: Expression {
+                operand,
+                modulus: modulus.map(|m| Modulo(Value(m))),
+            },
+            operator,
+            range_list,
+        }
+    }
+
+    /// Returns the relation holding when `operand` equals `value`, such as `n = 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::ast::*;
+    ///
+    /// assert_eq!(Relation::equals(Operand::V, 0).to_string(), "v = 0");
+    /// ```
+    pub fn equals(operand: Operand, value: u64) -> Relation {
+        Relation::new(
+            operand,
+            None,
+            Operator::EQ,
+            RangeList(vec![RangeListItem::Value(Value(value))]),
+        )
+    }
+}
+
 /// An enum of Relation operators for plural rules.
 ///
 /// Each Operator enumeration belongs to the corresponding symbolic operators:
@@ -173,7 +278,8 @@ pub struct Relation {
 /// | EQ | "=" |
 /// | NotEq | "!=" |
 ///
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Operator {
     In,
     NotIn,
@@ -185,6 +291,33 @@ pub enum Operator {
     NotEQ,
 }
 
+impl Operator {
+    /// Returns the operator selecting the other numbers, such as `NotEQ` for `EQ`.
+    ///
+    /// Negating an operator twice returns it unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::ast::Operator;
+    ///
+    /// assert_eq!(Operator::In.negate(), Operator::NotIn);
+    /// assert_eq!(Operator::NotWithin.negate(), Operator::Within);
+    /// ```
+    pub fn negate(self) -> Operator {
+        match self {
+            Operator::In => Operator::NotIn,
+            Operator::NotIn => Operator::In,
+            Operator::Within => Operator::NotWithin,
+            Operator::NotWithin => Operator::Within,
+            Operator::Is => Operator::IsNot,
+            Operator::IsNot => Operator::Is,
+            Operator::EQ => Operator::NotEQ,
+            Operator::NotEQ => Operator::EQ,
+        }
+    }
+}
+
 /// An incomplete AST representation of a plural rule. Comprises an Operand and an optional Modulo.
 ///
 /// # Examples
@@ -206,13 +339,14 @@ pub enum Operator {
 /// };
 ///
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Expression {
     pub operand: Operand,
     pub modulus: Option<Modulo>,
 }
 
-/// An incomplete AST representation of a plural rule. Comprises a Value but is later expressed as `% usize`.
+/// An incomplete AST representation of a plural rule. Comprises a Value but is later expressed as `% u64`.
 ///
 /// # Examples
 ///
@@ -229,7 +363,8 @@ pub struct Expression {
 ///
 /// Modulo(Value(100));
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Modulo(pub Value);
 
 /// An incomplete AST representation of a plural rule. Comprises a char.
@@ -249,7 +384,8 @@ pub struct Modulo(pub Value);
 ///
 /// Operand::I;
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Operand {
     N, // Absolute value of input
     I, // Integer value of input
@@ -257,6 +393,96 @@ pub enum Operand {
     W, // Number of visible fraction digits without trailing zeros
     F, // Visible fraction digits with trailing zeros
     T, // Visible fraction digits without trailing zeros
+    C, // Exponent of the power of 10 used in compact decimal formatting
+    E, // Synonym of C
+}
+
+impl Operand {
+    /// Returns the operand this one is an alias of, or itself.
+    ///
+    /// CLDR defines `e` as a synonym of `c`, so both evaluate the same way. Parsing keeps the
+    /// spelling of the source, and consumers should use the canonical operand when generating code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::ast::Operand;
+    ///
+    /// assert_eq!(Operand::E.canonical(), Operand::C);
+    /// assert_eq!(Operand::I.canonical(), Operand::I);
+    /// ```
+    pub fn canonical(&self) -> Operand {
+        match self {
+            Operand::E => Operand::C,
+            operand => operand.clone(),
+        }
+    }
+
+    /// Returns the letter of the operand in the rule syntax, such as `'i'`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::ast::Operand;
+    ///
+    /// assert_eq!(Operand::I.as_char(), 'i');
+    /// assert_eq!(Operand::E.as_char(), 'e');
+    /// ```
+    pub fn as_char(&self) -> char {
+        match self {
+            Operand::N => 'n',
+            Operand::I => 'i',
+            Operand::V => 'v',
+            Operand::W => 'w',
+            Operand::F => 'f',
+            Operand::T => 't',
+            Operand::C => 'c',
+            Operand::E => 'e',
+        }
+    }
+}
+
+/// Converts the letter of an operand, the inverse of `Operand::as_char`.
+impl TryFrom<char> for Operand {
+    type Error = UnknownOperand;
+
+    fn try_from(letter: char) -> Result<Operand, UnknownOperand> {
+        Ok(match letter {
+            'n' => Operand::N,
+            'i' => Operand::I,
+            'v' => Operand::V,
+            'w' => Operand::W,
+            'f' => Operand::F,
+            't' => Operand::T,
+            'c' => Operand::C,
+            'e' => Operand::E,
+            _ => return Err(UnknownOperand(letter.to_string())),
+        })
+    }
+}
+
+/// Parses a string holding the letter of an operand alone, such as `"v"`.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::ast::Operand;
+///
+/// assert_eq!("v".parse(), Ok(Operand::V));
+/// assert!("vw".parse::<Operand>().is_err());
+/// ```
+impl FromStr for Operand {
+    type Err = UnknownOperand;
+
+    fn from_str(s: &str) -> Result<Operand, UnknownOperand> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(letter), None) => {
+                Operand::try_from(letter).map_err(|_| UnknownOperand(s.to_owned()))
+            }
+            _ => Err(UnknownOperand(s.to_owned())),
+        }
+    }
 }
 
 /// An incomplete AST representation of a plural rule. Comprises a vector of RangeListItems.
@@ -280,14 +506,62 @@ pub enum Operand {
 ///     RangeListItem::Value(Value(9)),
 /// ]);
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RangeList(pub Vec<RangeListItem>);
 
+impl RangeList {
+    /// Returns whether `value` equals one of the values of the list or lies within one of its ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::ast::*;
+    ///
+    /// let range_list = RangeList(vec![
+    ///     RangeListItem::Value(Value(1)),
+    ///     RangeListItem::Range(Range { lower_val: Value(3), upper_val: Value(5) }),
+    /// ]);
+    /// assert!(range_list.contains(1) && range_list.contains(4));
+    /// assert!(!range_list.contains(2));
+    /// ```
+    pub fn contains(&self, value: u64) -> bool {
+        self.0.iter().any(|item| match item {
+            RangeListItem::Value(Value(v)) => *v == value,
+            RangeListItem::Range(range) => range.contains(value),
+        })
+    }
+
+    /// Returns an iterator over every value of the list, expanding its ranges, in list order.
+    ///
+    /// The iterator is lazy, so a huge range such as `0..18446744073709551615` only costs what is taken
+    /// from it. A range whose lower bound exceeds its upper bound yields no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::ast::*;
+    ///
+    /// let range_list = RangeList(vec![
+    ///     RangeListItem::Value(Value(1)),
+    ///     RangeListItem::Range(Range { lower_val: Value(3), upper_val: Value(5) }),
+    /// ]);
+    /// assert_eq!(range_list.iter_values().collect::<Vec<_>>(), vec![1, 3, 4, 5]);
+    /// ```
+    pub fn iter_values(&self) -> impl Iterator<Item = u64> + '_ {
+        self.0.iter().flat_map(|item| match item {
+            RangeListItem::Value(Value(v)) => *v..=*v,
+            RangeListItem::Range(range) => range.lower_val.0..=range.upper_val.0,
+        })
+    }
+}
+
 /// An enum of items that appear in a RangeList: Range or a Value.
 ///
 /// See Range and Value for additional details.
 ///
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RangeListItem {
     Range(Range),
     Value(Value),
@@ -313,12 +587,46 @@ pub enum RangeListItem {
 ///     upper_val: Value(15),
 /// });
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Range {
     pub lower_val: Value,
     pub upper_val: Value,
 }
 
+impl Range {
+    /// Returns whether the lower bound does not exceed the upper bound.
+    ///
+    /// Equal bounds are valid, the range then matches a single value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::ast::*;
+    ///
+    /// assert!(Range { lower_val: Value(3), upper_val: Value(3) }.is_valid());
+    /// assert!(!Range { lower_val: Value(5), upper_val: Value(2) }.is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.lower_val.0 <= self.upper_val.0
+    }
+
+    /// Returns whether `value` lies within the inclusive bounds of the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::ast::*;
+    ///
+    /// let range = Range { lower_val: Value(2), upper_val: Value(4) };
+    /// assert!(range.contains(2) && range.contains(4));
+    /// assert!(!range.contains(5));
+    /// ```
+    pub fn contains(&self, value: u64) -> bool {
+        self.lower_val.0 <= value && value <= self.upper_val.0
+    }
+}
+
 /// An incomplete AST representation of a plural rule, representing one integer.
 ///
 /// # Examples
@@ -336,5 +644,421 @@ pub struct Range {
 ///
 /// RangeListItem::Value(Value(99));
 /// ```
-#[derive(Debug, Clone, PartialEq)]
-pub struct Value(pub usize);
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Value(pub u64);
+
+/// Writes `items` separated by `separator`.
+fn write_joined<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    items: &[T],
+    separator: &str,
+) -> fmt::Result {
+    for (idx, item) in items.iter().enumerate() {
+        if idx > 0 {
+            f.write_str(separator)?;
+        }
+        write!(f, "{}", item)?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Rule {
+    /// Writes the rule in canonical CLDR syntax, which parses back into an equivalent rule.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.condition)?;
+        if let Some(samples) = &self.samples {
+            if !self.condition.0.is_empty() {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", samples)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_joined(f, &self.0, " or ")
+    }
+}
+
+impl fmt::Display for AndCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_joined(f, &self.0, " and ")
+    }
+}
+
+impl fmt::Display for Relation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.expression, self.operator, self.range_list
+        )
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.operand)?;
+        if let Some(modulus) = &self.modulus {
+            write!(f, " {}", modulus)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for RangeList {
+    /// Writes the items separated by commas without spaces, as CLDR does.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_joined(f, &self.0, ",")
+    }
+}
+
+impl fmt::Display for RangeListItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeListItem::Range(range) => write!(f, "{}", range),
+            RangeListItem::Value(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.lower_val, self.upper_val)
+    }
+}
+
+impl fmt::Display for Operator {
+    /// Writes the canonical CLDR spelling of the operator, `=` and `!=` over their keyword forms.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Operator::In | Operator::Is | Operator::EQ => "=",
+            Operator::NotIn | Operator::IsNot | Operator::NotEQ => "!=",
+            Operator::Within => "within",
+            Operator::NotWithin => "not within",
+        })
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+impl fmt::Display for Modulo {
+    /// Writes the canonical CLDR spelling of the modulo, `% N` over `mod N`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "% {}", self.0)
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl DecimalValue {
+    fn fraction_digits(&self) -> usize {
+        self.decimal.as_ref().map_or(0, String::len)
+    }
+
+    /// Returns the value without its decimal point, scaled to `digits` fraction digits,
+    /// or `None` if it doesn't fit.
+    fn mantissa(&self, digits: usize) -> Option<u64> {
+        let fraction = self.decimal.as_deref().unwrap_or("");
+        let padded = format!(
+            "{}{}{:0<width$}",
+            self.integer,
+            fraction,
+            "",
+            width = digits - fraction.len()
+        );
+        padded.parse().ok()
+    }
+
+    /// Returns the numeric value, with the compact exponent applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::ast::*;
+    ///
+    /// let value = DecimalValue {
+    ///     negative: false,
+    ///     integer: "1".to_string(),
+    ///     decimal: Some("1".to_string()),
+    ///     exponent: Some(Value(6)),
+    /// };
+    /// assert_eq!(value.to_f64(), 1100000.0);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        let fraction = self.decimal.as_deref().unwrap_or("0");
+        let exponent = self.exponent.as_ref().map_or(0, |Value(e)| *e);
+        // The digits always form a valid float literal, which saturates to infinity when too large.
+        let magnitude = format!("{}.{}e{}", self.integer, fraction, exponent)
+            .parse()
+            .unwrap_or(f64::INFINITY);
+        if self.negative { -magnitude } else { magnitude }
+    }
+}
+
+/// The most values a sample range is stepped through. Longer ranges only expand to their bounds, so that
+/// samples such as `0~18446744073709551615` can't exhaust the memory.
+const MAX_RANGE_VALUES: u64 = 1000;
+
+/// The largest compact exponent a sample value is written out with by `SampleRange::values`, as a string
+/// of its digits. Values with a larger exponent, such as `1c4000000000`, are returned as written instead.
+const MAX_WRITTEN_EXPONENT: u64 = 100;
+
+fn scale(mantissa: u64, digits: usize, exponent: Option<&Value>) -> f64 {
+    // An exponent beyond `i32` saturates, as does the scaled value, to infinity.
+    let exponent = exponent.map_or(0, |Value(e)| i32::try_from(*e).unwrap_or(i32::MAX));
+    let power = exponent.saturating_sub(i32::try_from(digits).unwrap_or(i32::MAX));
+    if power >= 0 {
+        mantissa as f64 * 10_f64.powi(power)
+    } else {
+        mantissa as f64 / 10_f64.powi(-power)
+    }
+}
+
+impl SampleRange {
+    /// Returns the numeric values in the range, stepping by the precision of its most precise bound.
+    ///
+    /// The exponent of the lower bound applies to the whole range. Ranges whose bounds have too
+    /// many digits to be stepped through, or spanning more than 1000 values, only expand to their bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::parse_samples;
+    ///
+    /// let samples = parse_samples("@decimal 1.0c6~1.2c6").unwrap().unwrap();
+    /// assert_eq!(
+    ///     samples.decimal.unwrap().sample_ranges[0].expand(),
+    ///     vec![1000000.0, 1100000.0, 1200000.0]
+    /// );
+    /// ```
+    pub fn expand(&self) -> Vec<f64> {
+        let Some(upper_val) = &self.upper_val else {
+            return vec![self.lower_val.to_f64()];
+        };
+        let exponent = self.lower_val.exponent.as_ref();
+        match self.steps(upper_val) {
+            Some((magnitudes, digits, false)) => magnitudes
+                .map(|mantissa| scale(mantissa, digits, exponent))
+                .collect(),
+            Some((magnitudes, digits, true)) => magnitudes
+                .rev()
+                .map(|mantissa| -scale(mantissa, digits, exponent))
+                .collect(),
+            None => vec![self.lower_val.to_f64(), upper_val.to_f64()],
+        }
+    }
+
+    /// Returns the mantissas of the magnitudes between the bounds, at the precision of the most precise bound,
+    /// along with that precision and whether the bounds are negative.
+    ///
+    /// Returns `None` if the bounds have too many digits, opposite signs, or too many values between them to be
+    /// stepped through.
+    fn steps(&self, upper_val: &DecimalValue) -> Option<(RangeInclusive<u64>, usize, bool)> {
+        let digits = self
+            .lower_val
+            .fraction_digits()
+            .max(upper_val.fraction_digits());
+        let lower = self.lower_val.mantissa(digits)?;
+        let upper = upper_val.mantissa(digits)?;
+        let (magnitudes, negative) = match (self.lower_val.negative, upper_val.negative) {
+            (false, false) => (lower..=upper, false),
+            // The magnitudes of negative bounds decrease from the lower bound to the upper one.
+            (true, true) => (upper..=lower, true),
+            _ => return None,
+        };
+        if magnitudes.end().saturating_sub(*magnitudes.start()) >= MAX_RANGE_VALUES {
+            return None;
+        }
+        Some((magnitudes, digits, negative))
+    }
+}
+
+/// Formats `mantissa * 10^(exponent - digits)` as a decimal string, keeping the visible fraction digits.
+fn format_scaled(mantissa: u64, digits: usize, exponent: Option<&Value>) -> String {
+    let exponent = exponent.map_or(0, |Value(e)| *e as usize);
+    let mantissa = mantissa.to_string();
+    if exponent >= digits {
+        return format!("{}{}", mantissa, "0".repeat(exponent - digits));
+    }
+    let fraction_digits = digits - exponent;
+    let mantissa = format!("{:0>width$}", mantissa, width = fraction_digits + 1);
+    let (integer, fraction) = mantissa.split_at(mantissa.len() - fraction_digits);
+    format!("{}.{}", integer, fraction)
+}
+
+impl SampleRange {
+    /// Returns the values in the range as decimal strings, keeping their visible fraction digits.
+    ///
+    /// Unlike [`SampleRange::expand`], this keeps trailing zeros, which matter to the plural operands.
+    /// Ranges whose bounds have too many digits to be stepped through, or spanning more than 1000 values,
+    /// only return their bounds, as do values with a compact exponent above 100, which are kept as written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::parse_samples;
+    ///
+    /// let samples = parse_samples("@decimal 0.0~0.2, 1.5c3").unwrap().unwrap();
+    /// let decimal = samples.decimal.unwrap();
+    /// assert_eq!(decimal.sample_ranges[0].values(), vec!["0.0", "0.1", "0.2"]);
+    /// assert_eq!(decimal.sample_ranges[1].values(), vec!["1500"]);
+    /// ```
+    pub fn values(&self) -> Vec<String> {
+        let upper_val = self.upper_val.as_ref().unwrap_or(&self.lower_val);
+        let exponent = self.lower_val.exponent.as_ref();
+        if exponent.is_some_and(|Value(e)| *e > MAX_WRITTEN_EXPONENT) {
+            return self.endpoints();
+        }
+        match self.steps(upper_val) {
+            Some((magnitudes, digits, false)) => magnitudes
+                .map(|mantissa| format_scaled(mantissa, digits, exponent))
+                .collect(),
+            Some((magnitudes, digits, true)) => magnitudes
+                .rev()
+                .map(|mantissa| format!("-{}", format_scaled(mantissa, digits, exponent)))
+                .collect(),
+            None => self.endpoints(),
+        }
+    }
+
+    /// Returns the bounds of the range as decimal strings, as they are written in the samples.
+    ///
+    /// A single value returns only itself. Unlike [`SampleRange::values`], the values between the
+    /// bounds are not stepped through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::parse_samples;
+    ///
+    /// let samples = parse_samples("@decimal 0.0~1.5, 2.5").unwrap().unwrap();
+    /// let decimal = samples.decimal.unwrap();
+    /// assert_eq!(decimal.sample_ranges[0].endpoints(), vec!["0.0", "1.5"]);
+    /// assert_eq!(decimal.sample_ranges[1].endpoints(), vec!["2.5"]);
+    /// ```
+    pub fn endpoints(&self) -> Vec<String> {
+        let mut endpoints = vec![self.lower_val.to_string()];
+        if let Some(upper_val) = &self.upper_val {
+            endpoints.push(upper_val.to_string());
+        }
+        endpoints
+    }
+}
+
+impl fmt::Display for DecimalValue {
+    /// Writes the value as it appears in CLDR samples, using `c` for the compact exponent.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        write!(f, "{}", self.integer)?;
+        if let Some(decimal) = &self.decimal {
+            write!(f, ".{}", decimal)?;
+        }
+        if let Some(exponent) = &self.exponent {
+            write!(f, "c{}", exponent)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for SampleRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.lower_val)?;
+        if let Some(upper_val) = &self.upper_val {
+            write!(f, "~{}", upper_val)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for SampleList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_joined(f, &self.sample_ranges, ", ")?;
+        if self.ellipsis {
+            f.write_str(", …")?;
+        }
+        Ok(())
+    }
+}
+
+impl Samples {
+    /// Returns every integer and decimal sample value as a decimal string, expanding the ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::parse_samples;
+    ///
+    /// let samples = parse_samples("@integer 2~4, 22, … @decimal 2.0").unwrap().unwrap();
+    ///
+    /// assert_eq!(samples.values(), vec!["2", "3", "4", "22", "2.0"]);
+    /// ```
+    pub fn values(&self) -> Vec<String> {
+        self.integer
+            .iter()
+            .chain(self.decimal.iter())
+            .flat_map(|sample_list| sample_list.sample_ranges.iter())
+            .flat_map(SampleRange::values)
+            .collect()
+    }
+
+    /// Returns representative sample values as decimal strings.
+    ///
+    /// Integer ranges are expanded to every integer between their bounds, as they are short in CLDR.
+    /// Decimal ranges only yield their two endpoints, as stepping through them at the precision of
+    /// their bounds can take many values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::parse_samples;
+    ///
+    /// let samples = parse_samples("@integer 2~4, 22 @decimal 0.0~1.5").unwrap().unwrap();
+    ///
+    /// assert_eq!(
+    ///     samples.representative_values(),
+    ///     vec!["2", "3", "4", "22", "0.0", "1.5"]
+    /// );
+    /// ```
+    pub fn representative_values(&self) -> Vec<String> {
+        let integer = self
+            .integer
+            .iter()
+            .flat_map(|sample_list| sample_list.sample_ranges.iter())
+            .flat_map(SampleRange::values);
+        let decimal = self
+            .decimal
+            .iter()
+            .flat_map(|sample_list| sample_list.sample_ranges.iter())
+            .flat_map(SampleRange::endpoints);
+        integer.chain(decimal).collect()
+    }
+}
+
+impl fmt::Display for Samples {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(integer) = &self.integer {
+            write!(f, "@integer {}", integer)?;
+        }
+        if let Some(decimal) = &self.decimal {
+            if self.integer.is_some() {
+                f.write_str(" ")?;
+            }
+            write!(f, "@decimal {}", decimal)?;
+        }
+        Ok(())
+    }
+}
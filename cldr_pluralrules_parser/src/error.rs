@@ -0,0 +1,243 @@
+//! Errors reported when a plural rule can not be parsed.
+
+use crate::ast::{Operator, Range};
+use crate::options::ParseLimit;
+use crate::parser::{ParserError, ParserErrorKind};
+use nom::error::ErrorKind;
+use std::fmt;
+
+/// A structured error describing why a plural rule could not be parsed.
+///
+/// Every variant carries the byte offset into the source at which parsing stopped.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::{parse, PluralRuleParseError};
+///
+/// assert_eq!(
+///     parse("n % i = 0"),
+///     Err(PluralRuleParseError::NonLiteralModulus {
+///         operand: 'i',
+///         offset: 4,
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluralRuleParseError {
+    /// The divisor of a `mod`/`%` expression is an operand instead of an integer literal.
+    NonLiteralModulus { operand: char, offset: usize },
+    /// The divisor of a `mod`/`%` expression is `0`.
+    ZeroModulus { offset: usize },
+    /// An integer of several digits starts with `0`, such as `07`.
+    LeadingZero { offset: usize },
+    /// The relation operator is not allowed by the [`ParseOptions`](crate::ParseOptions) in use.
+    DisallowedOperator { operator: Operator, offset: usize },
+    /// A relation has no values to compare against.
+    EmptyRangeList { offset: usize },
+    /// A relation compares with `==`, where CLDR writes `=`.
+    DoubleEquals { offset: usize },
+    /// The lower bound of a range exceeds its upper bound, so the range never matches.
+    InvalidRange { range: Range, offset: usize },
+    /// A relation starts with a letter that is not a plural operand.
+    UnexpectedOperand { operand: char, offset: usize },
+    /// An `and` or `or` keyword is not followed by a relation.
+    EmptyRelation { offset: usize },
+    /// A parenthesized condition is not closed by `)`.
+    UnclosedParenthesis { offset: usize },
+    /// The rule exceeds one of the [`ParseLimits`](crate::ParseLimits) of the options in use, whose value is `max`.
+    LimitExceeded {
+        limit: ParseLimit,
+        max: usize,
+        offset: usize,
+    },
+    /// The input continues after a complete rule.
+    TrailingInput { offset: usize },
+    /// The input does not follow the plural rule syntax.
+    InvalidSyntax { offset: usize },
+}
+
+impl fmt::Display for PluralRuleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonLiteralModulus { operand, offset } => write!(
+                f,
+                "modulo divisor must be an integer literal, found operand '{}' at offset {}",
+                operand, offset
+            ),
+            Self::ZeroModulus { offset } => {
+                write!(f, "modulo divisor must not be zero at offset {}", offset)
+            }
+            Self::LeadingZero { offset } => {
+                write!(f, "integer has a leading zero at offset {}", offset)
+            }
+            Self::DisallowedOperator { operator, offset } => write!(
+                f,
+                "operator '{}' is not allowed at offset {}, {}",
+                keyword(operator),
+                offset,
+                migration_hint(operator)
+            ),
+            Self::EmptyRangeList { offset } => {
+                write!(f, "relation has an empty range list at offset {}", offset)
+            }
+            Self::DoubleEquals { offset } => {
+                write!(f, "expected '=' instead of '==' at offset {}", offset)
+            }
+            Self::InvalidRange { range, offset } => write!(
+                f,
+                "range '{}' at offset {} has a lower bound exceeding its upper bound",
+                range, offset
+            ),
+            Self::UnexpectedOperand { operand, offset } => {
+                write!(f, "unexpected operand '{}' at offset {}", operand, offset)
+            }
+            Self::EmptyRelation { offset } => {
+                write!(f, "expected a relation at offset {}", offset)
+            }
+            Self::UnclosedParenthesis { offset } => write!(f, "expected ')' at offset {}", offset),
+            Self::LimitExceeded { limit, max, offset } => write!(
+                f,
+                "rule exceeds the limit of {} {} at offset {}",
+                max,
+                limit_unit(limit),
+                offset
+            ),
+            Self::TrailingInput { offset } => {
+                write!(f, "unexpected input after the rule at offset {}", offset)
+            }
+            Self::InvalidSyntax { offset } => write!(f, "invalid plural rule at offset {}", offset),
+        }
+    }
+}
+
+/// The error composes with `Box<dyn Error>` and the `?` operator.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::parse;
+/// use std::error::Error;
+///
+/// fn condition(source: &str) -> Result<String, Box<dyn Error>> {
+///     Ok(parse(source)?.condition.to_string())
+/// }
+///
+/// assert_eq!(condition("n = 1").unwrap(), "n = 1");
+/// assert_eq!(
+///     condition("n % 0 = 1").unwrap_err().to_string(),
+///     "modulo divisor must not be zero at offset 4"
+/// );
+/// ```
+impl std::error::Error for PluralRuleParseError {}
+
+impl PluralRuleParseError {
+    /// Returns the byte offset into the source at which parsing stopped.
+    pub fn offset(&self) -> usize {
+        match self {
+            Self::NonLiteralModulus { offset, .. }
+            | Self::ZeroModulus { offset }
+            | Self::LeadingZero { offset }
+            | Self::DisallowedOperator { offset, .. }
+            | Self::EmptyRangeList { offset }
+            | Self::DoubleEquals { offset }
+            | Self::InvalidRange { offset, .. }
+            | Self::UnexpectedOperand { offset, .. }
+            | Self::EmptyRelation { offset }
+            | Self::UnclosedParenthesis { offset }
+            | Self::LimitExceeded { offset, .. }
+            | Self::TrailingInput { offset }
+            | Self::InvalidSyntax { offset } => *offset,
+        }
+    }
+
+    /// Converts an error returned by the parser into a structured error relative to `source`.
+    pub(crate) fn from_parser_error(source: &str, err: nom::Err<ParserError>) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                let offset = source.len() - e.input.len();
+                match e.kind {
+                    ParserErrorKind::NonLiteralModulus(operand) => {
+                        Self::NonLiteralModulus { operand, offset }
+                    }
+                    ParserErrorKind::ZeroModulus => Self::ZeroModulus { offset },
+                    ParserErrorKind::LeadingZero => Self::LeadingZero { offset },
+                    ParserErrorKind::DisallowedOperator(operator) => {
+                        Self::DisallowedOperator { operator, offset }
+                    }
+                    ParserErrorKind::EmptyRangeList => Self::EmptyRangeList { offset },
+                    ParserErrorKind::DoubleEquals => Self::DoubleEquals { offset },
+                    ParserErrorKind::InvalidRange(range) => Self::InvalidRange { range, offset },
+                    ParserErrorKind::UnexpectedOperand(operand) => {
+                        Self::UnexpectedOperand { operand, offset }
+                    }
+                    ParserErrorKind::EmptyRelation => Self::EmptyRelation { offset },
+                    ParserErrorKind::UnclosedParenthesis => Self::UnclosedParenthesis { offset },
+                    ParserErrorKind::LimitExceeded(limit, max) => {
+                        Self::LimitExceeded { limit, max, offset }
+                    }
+                    ParserErrorKind::Nom(ErrorKind::Eof) => Self::TrailingInput { offset },
+                    ParserErrorKind::Nom(_) => Self::InvalidSyntax { offset },
+                }
+            }
+            nom::Err::Incomplete(_) => Self::InvalidSyntax {
+                offset: source.len(),
+            },
+        }
+    }
+}
+
+fn keyword(operator: &Operator) -> &'static str {
+    match operator {
+        Operator::In => "in",
+        Operator::NotIn => "not in",
+        Operator::Within => "within",
+        Operator::NotWithin => "not within",
+        Operator::Is => "is",
+        Operator::IsNot => "is not",
+        Operator::EQ => "=",
+        Operator::NotEQ => "!=",
+    }
+}
+
+fn limit_unit(limit: &ParseLimit) -> &'static str {
+    match limit {
+        ParseLimit::Len => "bytes",
+        ParseLimit::AndConditions => "'or' conditions",
+        ParseLimit::Relations => "relations per 'and' condition",
+        ParseLimit::RangeListItems => "range list items",
+        ParseLimit::Depth => "levels of nested parentheses",
+    }
+}
+
+fn migration_hint(operator: &Operator) -> &'static str {
+    match operator {
+        Operator::In | Operator::Is => "use '=' instead",
+        Operator::NotIn | Operator::IsNot => "use '!=' instead",
+        Operator::Within | Operator::NotWithin => {
+            "rewrite the relation with '=' or '!=' over the integer operand 'i'"
+        }
+        Operator::EQ | Operator::NotEQ => "allow it in the parse options",
+    }
+}
+
+/// The error of converting a string or character which is not the letter of an operand into an `Operand`.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::ast::Operand;
+/// use cldr_pluralrules_parser::UnknownOperand;
+///
+/// assert_eq!("x".parse::<Operand>(), Err(UnknownOperand("x".to_owned())));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownOperand(pub String);
+
+impl fmt::Display for UnknownOperand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown operand `{}`", self.0)
+    }
+}
+
+impl std::error::Error for UnknownOperand {}
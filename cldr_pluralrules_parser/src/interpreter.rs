@@ -0,0 +1,266 @@
+//! Evaluation of parsed plural rules against plural operands.
+
+use crate::ast::*;
+use intl_pluralrules::PluralCategory;
+use intl_pluralrules::operands::PluralOperands;
+use std::convert::TryFrom;
+
+/// Returns the value of `operand` for the given plural operands and compact exponent.
+fn operand_value(operand: &Operand, po: &PluralOperands, exponent: u64) -> f64 {
+    match operand {
+        Operand::N => po.n,
+        Operand::I => po.i as f64,
+        Operand::V => po.v as f64,
+        Operand::W => po.w as f64,
+        Operand::F => po.f as f64,
+        Operand::T => po.t as f64,
+        // Plural operands have no compact exponent, so it is passed along with them.
+        Operand::C | Operand::E => exponent as f64,
+    }
+}
+
+impl Expression {
+    /// Returns the value of the expression for the given plural operands, with its modulus applied.
+    ///
+    /// The value is a float, as the `n` operand may have a fraction: `n % 10` of `21.5` is `1.5`.
+    /// The parser rejects a modulus of `0`, which an AST built by hand must not use either. Plural
+    /// operands have no compact exponent, so `c` and `e` are `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::parse;
+    /// use intl_pluralrules::operands::PluralOperands;
+    ///
+    /// let rule = parse("i mod 10 = 3").unwrap();
+    /// let expression = &rule.condition.0[0].0[0].expression;
+    /// assert_eq!(expression.apply(&PluralOperands::from(123_u64)), 3.0);
+    /// ```
+    pub fn apply(&self, po: &PluralOperands) -> f64 {
+        self.apply_with_exponent(po, 0)
+    }
+
+    fn apply_with_exponent(&self, po: &PluralOperands, exponent: u64) -> f64 {
+        let value = operand_value(&self.operand, po, exponent);
+        match self.modulus {
+            Some(Modulo(Value(m))) => value % m as f64,
+            None => value,
+        }
+    }
+}
+
+fn range_list_contains(range_list: &RangeList, value: f64, integer_only: bool) -> bool {
+    if value.fract() == 0.0 {
+        return range_list.contains(value as u64);
+    }
+    // Only `within` matches fractions, which fall between the bounds of a range.
+    !integer_only
+        && range_list.0.iter().any(|item| match item {
+            RangeListItem::Value(_) => false,
+            RangeListItem::Range(Range {
+                lower_val: Value(lower),
+                upper_val: Value(upper),
+            }) => *lower as f64 <= value && value <= *upper as f64,
+        })
+}
+
+fn relation_matches(relation: &Relation, po: &PluralOperands, exponent: u64) -> bool {
+    let value = relation.expression.apply_with_exponent(po, exponent);
+    match relation.operator {
+        Operator::In | Operator::Is | Operator::EQ => {
+            range_list_contains(&relation.range_list, value, true)
+        }
+        Operator::NotIn | Operator::IsNot | Operator::NotEQ => {
+            !range_list_contains(&relation.range_list, value, true)
+        }
+        Operator::Within => range_list_contains(&relation.range_list, value, false),
+        Operator::NotWithin => !range_list_contains(&relation.range_list, value, false),
+    }
+}
+
+/// Returns whether `condition` holds for the given plural operands and compact exponent.
+///
+/// An empty condition, as used by the `other` category, always holds.
+fn condition_matches(condition: &Condition, po: &PluralOperands, exponent: u64) -> bool {
+    condition.0.is_empty()
+        || condition.0.iter().any(|and_condition| {
+            and_condition
+                .0
+                .iter()
+                .all(|r| relation_matches(r, po, exponent))
+        })
+}
+
+impl Rule {
+    /// Returns whether the condition of the rule holds for the given plural operands.
+    ///
+    /// Relations using `within` also hold for fractional values between the bounds of a range,
+    /// while `in`, `is` and `=` only hold for integers. The `c` and `e` operands are `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::parse;
+    /// use intl_pluralrules::operands::PluralOperands;
+    /// use std::convert::TryFrom;
+    ///
+    /// let rule = parse("n % 10 = 1 and n % 100 != 11").unwrap();
+    ///
+    /// assert!(rule.matches(&PluralOperands::from(21_u64)));
+    /// assert!(!rule.matches(&PluralOperands::from(11_u64)));
+    /// assert!(!rule.matches(&PluralOperands::try_from("1.5").unwrap()));
+    /// ```
+    pub fn matches(&self, po: &PluralOperands) -> bool {
+        condition_matches(&self.condition, po, 0)
+    }
+}
+
+/// Selects the category of the first rule whose condition holds for the given plural operands,
+/// falling back to `other` if none does.
+///
+/// The `c` and `e` operands are `0`, as plural operands have no compact exponent.
+pub fn select(rules: &[(PluralCategory, Rule)], po: &PluralOperands) -> PluralCategory {
+    select_with_exponent(rules, po, 0)
+}
+
+fn select_with_exponent(
+    rules: &[(PluralCategory, Rule)],
+    po: &PluralOperands,
+    exponent: u64,
+) -> PluralCategory {
+    rules
+        .iter()
+        .find(|(_, rule)| condition_matches(&rule.condition, po, exponent))
+        .map_or(PluralCategory::OTHER, |(category, _)| *category)
+}
+
+/// Selects the category of every integer in the inclusive range `lo..=hi`.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::{evaluate_range, parse};
+/// use intl_pluralrules::PluralCategory;
+///
+/// let rules = vec![(PluralCategory::ONE, parse("i = 1 and v = 0").unwrap())];
+///
+/// assert_eq!(
+///     evaluate_range(&rules, 0, 2),
+///     vec![
+///         (0, PluralCategory::OTHER),
+///         (1, PluralCategory::ONE),
+///         (2, PluralCategory::OTHER),
+///     ]
+/// );
+/// ```
+pub fn evaluate_range(
+    rules: &[(PluralCategory, Rule)],
+    lo: usize,
+    hi: usize,
+) -> Vec<(usize, PluralCategory)> {
+    (lo..=hi)
+        .map(|value| (value, select(rules, &PluralOperands::from(value))))
+        .collect()
+}
+
+/// Selects the category of every integer in `0..period`, revealing the repeating pattern of locales
+/// whose rules use `% period`.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::{category_cycle, parse};
+/// use intl_pluralrules::PluralCategory;
+///
+/// let rules = vec![(PluralCategory::ONE, parse("n % 10 = 1").unwrap())];
+///
+/// assert_eq!(category_cycle(&rules, 3)[1], PluralCategory::ONE);
+/// ```
+pub fn category_cycle(rules: &[(PluralCategory, Rule)], period: usize) -> Vec<PluralCategory> {
+    (0..period)
+        .map(|value| select(rules, &PluralOperands::from(value)))
+        .collect()
+}
+
+/// A sample value which doesn't select the category it is listed for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SampleError {
+    /// The sample value selects another category.
+    Mismatch {
+        /// The sample value, as a decimal string.
+        sample: String,
+        /// The category the sample is listed for.
+        expected: PluralCategory,
+        /// The category the rules select for the sample.
+        actual: PluralCategory,
+    },
+    /// The sample value can't be converted to `PluralOperands`, such as an integer beyond `u64`, so
+    /// it can't be checked.
+    Unevaluable {
+        /// The sample value, as a decimal string.
+        sample: String,
+    },
+}
+
+impl SampleError {
+    /// The sample value, as a decimal string.
+    pub fn sample(&self) -> &str {
+        match self {
+            Self::Mismatch { sample, .. } | Self::Unevaluable { sample } => sample,
+        }
+    }
+}
+
+impl Samples {
+    /// Checks that every sample value selects `expected` with the full rule set of a locale.
+    ///
+    /// Returns the first sample value selecting another category, or which can't be evaluated. A
+    /// compact sample such as `1.1c6` is evaluated as `1100000` with `c` and `e` set to its exponent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::{parse, parse_samples};
+    /// use intl_pluralrules::PluralCategory;
+    ///
+    /// let rules = vec![(PluralCategory::ONE, parse("i = 1 and v = 0").unwrap())];
+    /// let samples = parse_samples("@integer 0, 2~16 @decimal 1.0").unwrap().unwrap();
+    ///
+    /// assert!(samples.validate_against(&rules, PluralCategory::OTHER).is_ok());
+    /// assert_eq!(
+    ///     samples
+    ///         .validate_against(&rules, PluralCategory::ONE)
+    ///         .unwrap_err()
+    ///         .sample(),
+    ///     "0"
+    /// );
+    /// ```
+    pub fn validate_against(
+        &self,
+        rules: &[(PluralCategory, Rule)],
+        expected: PluralCategory,
+    ) -> Result<(), SampleError> {
+        let ranges = self
+            .integer
+            .iter()
+            .chain(self.decimal.iter())
+            .flat_map(|sample_list| sample_list.sample_ranges.iter());
+        for range in ranges {
+            let exponent = range.lower_val.exponent.as_ref().map_or(0, |Value(e)| *e);
+            for sample in range.values() {
+                let Ok(po) = PluralOperands::try_from(sample.as_str()) else {
+                    return Err(SampleError::Unevaluable { sample });
+                };
+                let actual = select_with_exponent(rules, &po, exponent);
+                if actual != expected {
+                    return Err(SampleError::Mismatch {
+                        sample,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,289 @@
+//! Parsing of whole CLDR plural rules JSON files, such as `plurals.json` and `ordinals.json`.
+
+use crate::PluralRuleParseError;
+use crate::ast::Rule;
+use intl_pluralrules::operands::PluralOperands;
+use intl_pluralrules::{PluralCategory, PluralRuleType};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use unic_langid::LanguageIdentifier;
+
+/// An error preventing a CLDR plural rules JSON file from being parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PluralsJsonError {
+    /// The input is not valid JSON.
+    InvalidJson(String),
+    /// The input has no `supplemental.plurals-type-<name>` section.
+    MissingRules,
+    /// The input has several `supplemental.plurals-type-<name>` sections.
+    SeveralRuleTypes,
+    /// The name of the `plurals-type-<name>` section is not that of a [`PluralRuleType`].
+    UnknownPluralType(String),
+    /// The rules of the section, or of one of its locales, are not a JSON object of strings.
+    InvalidShape(String),
+    /// A locale is not a valid language identifier.
+    InvalidLocale(String),
+    /// A rule name is not `pluralRule-count-<category>` for a CLDR plural category.
+    UnknownCategory { locale: String, name: String },
+    /// A rule of a locale can not be parsed.
+    InvalidRule {
+        locale: String,
+        category: PluralCategory,
+        error: PluralRuleParseError,
+    },
+}
+
+impl fmt::Display for PluralsJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidJson(error) => write!(f, "invalid JSON: {}", error),
+            Self::MissingRules => write!(f, "no plurals-type section found"),
+            Self::SeveralRuleTypes => write!(f, "several plurals-type sections found"),
+            Self::UnknownPluralType(name) => write!(f, "unknown plural rule type '{}'", name),
+            Self::InvalidShape(key) => write!(f, "'{}' is not an object of rules", key),
+            Self::InvalidLocale(locale) => write!(f, "invalid locale '{}'", locale),
+            Self::UnknownCategory { locale, name } => {
+                write!(f, "unknown rule name '{}' of locale '{}'", name, locale)
+            }
+            Self::InvalidRule {
+                locale,
+                category,
+                error,
+            } => write!(
+                f,
+                "invalid {} rule of locale '{}': {}",
+                category, locale, error
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PluralsJsonError {}
+
+/// Parses the plural rules of a CLDR JSON file, such as `plurals.json` or `ordinals.json`,
+/// keyed by locale.
+///
+/// The file must have a single `supplemental.plurals-type-<name>` section, as both cardinal and
+/// ordinal files do. The rules of each locale are listed in CLDR category order. The `root` locale,
+/// which CLDR lists besides the languages, is skipped.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::parse_plurals_json;
+/// use intl_pluralrules::PluralCategory;
+///
+/// let json = r#"{
+///     "supplemental": {
+///         "plurals-type-ordinal": {
+///             "xx": {
+///                 "pluralRule-count-other": " @integer 0~15",
+///                 "pluralRule-count-one": "n = 1 @integer 1"
+///             }
+///         }
+///     }
+/// }"#;
+/// let locales = parse_plurals_json(json).expect("Parsing succeeded");
+/// let categories: Vec<_> = locales[&"xx".parse().unwrap()]
+///     .iter()
+///     .map(|(category, _)| *category)
+///     .collect();
+/// assert_eq!(categories, [PluralCategory::ONE, PluralCategory::OTHER]);
+/// ```
+pub fn parse_plurals_json(
+    json: &str,
+) -> Result<BTreeMap<LanguageIdentifier, Vec<(PluralCategory, Rule)>>, PluralsJsonError> {
+    let value: Value =
+        serde_json::from_str(json).map_err(|err| PluralsJsonError::InvalidJson(err.to_string()))?;
+    parse_plurals_json_value(&value)
+}
+
+/// Like [`parse_plurals_json`], but takes a CLDR JSON file already parsed into a `serde_json::Value`.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::parse_plurals_json_value;
+/// use serde_json::json;
+///
+/// let value = json!({
+///     "supplemental": {
+///         "plurals-type-cardinal": {
+///             "xx": { "pluralRule-count-one": "n = 1", "pluralRule-count-other": "" }
+///         }
+///     }
+/// });
+/// let locales = parse_plurals_json_value(&value).expect("Parsing succeeded");
+/// assert_eq!(locales[&"xx".parse().unwrap()].len(), 2);
+/// ```
+pub fn parse_plurals_json_value(
+    value: &Value,
+) -> Result<BTreeMap<LanguageIdentifier, Vec<(PluralCategory, Rule)>>, PluralsJsonError> {
+    let mut sections = value
+        .get("supplemental")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+        .filter(|(key, _)| key.starts_with("plurals-type-"));
+    let (key, section) = sections.next().ok_or(PluralsJsonError::MissingRules)?;
+    if sections.next().is_some() {
+        return Err(PluralsJsonError::SeveralRuleTypes);
+    }
+    parse_section(key, section).map(|(_, locales)| locales)
+}
+
+/// The rules of each locale, in CLDR category order.
+type LocaleRules = BTreeMap<LanguageIdentifier, Vec<(PluralCategory, Rule)>>;
+
+/// Parses a `plurals-type-<name>` section, named `key`, into its plural rule type and the rules of its locales.
+fn parse_section(
+    key: &str,
+    section: &Value,
+) -> Result<(PluralRuleType, LocaleRules), PluralsJsonError> {
+    let name = &key["plurals-type-".len()..];
+    let prt = name
+        .parse::<PluralRuleType>()
+        .map_err(|_| PluralsJsonError::UnknownPluralType(name.to_owned()))?;
+    let section = section
+        .as_object()
+        .ok_or_else(|| PluralsJsonError::InvalidShape(key.to_owned()))?;
+
+    let mut locales = BTreeMap::new();
+    for (locale, rules) in section {
+        if locale == "root" {
+            continue;
+        }
+        let langid: LanguageIdentifier = locale
+            .parse()
+            .map_err(|_| PluralsJsonError::InvalidLocale(locale.clone()))?;
+        let rules = rules
+            .as_object()
+            .ok_or_else(|| PluralsJsonError::InvalidShape(locale.clone()))?;
+
+        let mut parsed = Vec::with_capacity(rules.len());
+        for (name, source) in rules {
+            let category = name
+                .strip_prefix("pluralRule-count-")
+                .and_then(|keyword| keyword.parse().ok())
+                .ok_or_else(|| PluralsJsonError::UnknownCategory {
+                    locale: locale.clone(),
+                    name: name.clone(),
+                })?;
+            let source = source
+                .as_str()
+                .ok_or_else(|| PluralsJsonError::InvalidShape(locale.clone()))?;
+            let rule = crate::parse(source).map_err(|error| PluralsJsonError::InvalidRule {
+                locale: locale.clone(),
+                category,
+                error,
+            })?;
+            parsed.push((category, rule));
+        }
+        parsed.sort_by_key(|(category, _)| *category);
+        locales.insert(langid, parsed);
+    }
+    Ok((prt, locales))
+}
+
+/// Lists every rule of a CLDR JSON file, one item per locale, plural rule type and category, for bulk
+/// processing such as building test matrices.
+///
+/// Unlike [`parse_plurals_json`], the file may have several `supplemental.plurals-type-<name>` sections, such
+/// as both `cardinal` and `ordinal`. The file is parsed, and all its rules checked, once, before the first item:
+/// the items are then in the order of the sections, then of the locales, then of the categories, and `root` is
+/// skipped.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::all_rules;
+/// use intl_pluralrules::{PluralCategory, PluralRuleType};
+///
+/// let json = r#"{
+///     "supplemental": {
+///         "plurals-type-cardinal": {
+///             "xx": { "pluralRule-count-one": "n = 1", "pluralRule-count-other": "" }
+///         },
+///         "plurals-type-ordinal": {
+///             "xx": { "pluralRule-count-other": "" }
+///         }
+///     }
+/// }"#;
+/// let rules: Vec<_> = all_rules(json)
+///     .expect("Parsing succeeded")
+///     .map(|(lang, prt, category, _)| (lang.to_string(), prt, category))
+///     .collect();
+/// assert_eq!(rules[0], ("xx".to_owned(), PluralRuleType::CARDINAL, PluralCategory::ONE));
+/// assert_eq!(rules.len(), 3);
+/// ```
+pub fn all_rules(
+    json: &str,
+) -> Result<
+    impl Iterator<Item = (LanguageIdentifier, PluralRuleType, PluralCategory, Rule)>,
+    PluralsJsonError,
+> {
+    let value: Value =
+        serde_json::from_str(json).map_err(|err| PluralsJsonError::InvalidJson(err.to_string()))?;
+    let sections = value
+        .get("supplemental")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+        .filter(|(key, _)| key.starts_with("plurals-type-"))
+        .map(|(key, section)| parse_section(key, section))
+        .collect::<Result<Vec<_>, _>>()?;
+    if sections.is_empty() {
+        return Err(PluralsJsonError::MissingRules);
+    }
+    Ok(sections.into_iter().flat_map(|(prt, locales)| {
+        locales.into_iter().flat_map(move |(lang, rules)| {
+            rules
+                .into_iter()
+                .map(move |(category, rule)| (lang.clone(), prt, category, rule))
+        })
+    }))
+}
+
+/// Selects the category of the operands for `lang`, with rules loaded at runtime, such as by `parse_plurals_json`,
+/// rather than generated, so that the locale data can change without recompiling.
+///
+/// The rules are evaluated with [`select`](crate::select). As in the generated code, a locale without rules
+/// falls back to the rules of its language alone, such as `en` for `en-US`, and otherwise selects `OTHER`.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::{parse_plurals_json, select_dynamic};
+/// use intl_pluralrules::PluralCategory;
+/// use intl_pluralrules::operands::PluralOperands;
+///
+/// let json = r#"{
+///     "supplemental": {
+///         "plurals-type-cardinal": {
+///             "en": {
+///                 "pluralRule-count-one": "i = 1 and v = 0 @integer 1",
+///                 "pluralRule-count-other": " @integer 0, 2~16"
+///             }
+///         }
+///     }
+/// }"#;
+/// let locales = parse_plurals_json(json).expect("Parsing succeeded");
+/// let po = PluralOperands::from(1_u64);
+/// assert_eq!(select_dynamic(&locales, &"en-US".parse().unwrap(), &po), PluralCategory::ONE);
+/// ```
+pub fn select_dynamic(
+    locales: &BTreeMap<LanguageIdentifier, Vec<(PluralCategory, Rule)>>,
+    lang: &LanguageIdentifier,
+    po: &PluralOperands,
+) -> PluralCategory {
+    let rules = locales.get(lang).or_else(|| {
+        locales.get(&LanguageIdentifier::from_parts(
+            lang.language,
+            None,
+            None,
+            &[],
+        ))
+    });
+    rules.map_or(PluralCategory::OTHER, |rules| crate::select(rules, po))
+}
@@ -39,8 +39,138 @@
 
 /// A public AST module for plural rule representations.
 pub mod ast;
+/// A private module for parser errors.
+mod error;
+/// A private module for evaluating plural rules.
+#[cfg(feature = "interpreter")]
+mod interpreter;
+/// A private module for parsing CLDR plural rules JSON files.
+#[cfg(feature = "json")]
+mod json;
+/// A private module for normalizing rules.
+mod normalize;
+/// A private module for parser options.
+mod options;
 /// A private parsing module for plural rules.
 mod parser;
+/// A private module for rendering rules as trees.
+mod pretty;
+/// A public module for the source spans of parsed rules.
+#[cfg(feature = "spans")]
+pub mod spans;
+/// A public module for traversing the AST.
+pub mod visit;
+
+pub use crate::error::{PluralRuleParseError, UnknownOperand};
+#[cfg(feature = "interpreter")]
+pub use crate::interpreter::{SampleError, category_cycle, evaluate_range, select};
+#[cfg(feature = "json")]
+pub use crate::json::{
+    PluralsJsonError, all_rules, parse_plurals_json, parse_plurals_json_value, select_dynamic,
+};
+pub use crate::normalize::{equivalence_classes, rules_equal};
+pub use crate::options::{ParseLimit, ParseLimits, ParseOptions};
+
+/// Given a string reference of a plural rule, will return the AST representation of that rule
+/// or a structured error describing where and why parsing failed.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::{parse, PluralRuleParseError};
+///
+/// assert!(parse("i = 1 and v = 0 @integer 1").is_ok());
+/// assert_eq!(
+///     parse("n % i = 0").unwrap_err().to_string(),
+///     "modulo divisor must be an integer literal, found operand 'i' at offset 4"
+/// );
+/// ```
+pub fn parse<S: AsRef<str>>(source: S) -> Result<ast::Rule, PluralRuleParseError> {
+    parse_with(source, &ParseOptions::default())
+}
+
+/// Like [`parse`], but only accepts the syntax allowed by `options`.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::{parse_with, ParseOptions};
+///
+/// let options = ParseOptions::modern();
+///
+/// assert!(parse_with("i = 1 and v = 0", &options).is_ok());
+/// assert_eq!(
+///     parse_with("i is 1", &options).unwrap_err().to_string(),
+///     "operator 'is' is not allowed at offset 2, use '=' instead"
+/// );
+/// ```
+pub fn parse_with<S: AsRef<str>>(
+    source: S,
+    options: &ParseOptions,
+) -> Result<ast::Rule, PluralRuleParseError> {
+    let source = source.as_ref();
+    match parser::parse_rule_with(source, options) {
+        Ok(("", rule)) => Ok(rule),
+        Ok((left, _)) => Err(PluralRuleParseError::TrailingInput {
+            offset: source.len() - left.len(),
+        }),
+        Err(err) => Err(PluralRuleParseError::from_parser_error(source, err)),
+    }
+}
+
+/// Like [`parse_with`], but also returns the byte ranges of `source` each relation was parsed from.
+///
+/// A relation distributed out of a parenthesized condition has the same span in each `and` condition.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::{parse_with_spans, ParseOptions};
+///
+/// let source = "v = 0 and i % 10 = 1 or f % 10 = 1";
+/// let (rule, spans) = parse_with_spans(source, &ParseOptions::default()).unwrap();
+/// let span = spans.relation(0, 1).unwrap();
+/// assert_eq!(&source[span.relation.clone()], "i % 10 = 1");
+/// assert_eq!(&source[span.operator.clone()], "=");
+/// assert_eq!(rule.condition.0[0].0.len(), 2);
+/// ```
+#[cfg(feature = "spans")]
+pub fn parse_with_spans<S: AsRef<str>>(
+    source: S,
+    options: &ParseOptions,
+) -> Result<(ast::Rule, spans::RuleSpans), PluralRuleParseError> {
+    let source = source.as_ref();
+    match parser::parse_rule_spanned(source, options) {
+        Ok(("", spanned)) => Ok(spanned),
+        Ok((left, _)) => Err(PluralRuleParseError::TrailingInput {
+            offset: source.len() - left.len(),
+        }),
+        Err(err) => Err(PluralRuleParseError::from_parser_error(source, err)),
+    }
+}
+
+/// Like [`parse`], but also accepts trailing whitespace after the rule.
+///
+/// Any other trailing input is rejected, so a corrupted rule such as `n = 1 garbage` is not
+/// silently truncated.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::{parse_rule_complete, PluralRuleParseError};
+///
+/// assert!(parse_rule_complete("n = 1 @integer 1 ").is_ok());
+/// assert_eq!(
+///     parse_rule_complete("n = 1 garbage here"),
+///     Err(PluralRuleParseError::TrailingInput { offset: 6 })
+/// );
+/// ```
+pub fn parse_rule_complete<S: AsRef<str>>(source: S) -> Result<ast::Rule, PluralRuleParseError> {
+    let source = source.as_ref();
+    parser::parse_rule_complete(source)
+        .map(|(_, rule)| rule)
+        .map_err(|err| PluralRuleParseError::from_parser_error(source, err))
+}
 
 /// Given a string reference of a plural rule, will return the AST representation of that rule.
 ///
@@ -77,17 +207,50 @@ mod parser;
 /// )
 /// ```
 pub fn parse_plural_rule<S: AsRef<str>>(source: S) -> Result<ast::Rule, String> {
-    match parser::parse_rule(source.as_ref()) {
+    let source = source.as_ref();
+    match parser::parse_rule(source) {
         Ok(("", rule)) => Ok(rule),
         //Ok((_, rule)) => Ok(rule),
         Ok((left, _)) => Err(format!("Left string: {}", left)),
-        _ => Err("Parser failed".to_string()),
+        Err(err) => Err(PluralRuleParseError::from_parser_error(source, err).to_string()),
     }
 }
 
 pub fn parse_plural_condition<S: AsRef<str>>(source: S) -> Result<ast::Condition, String> {
-    match parser::parse_condition(source.as_ref()) {
+    let source = source.as_ref();
+    match parser::parse_condition(source) {
         Ok((_, rule)) => Ok(rule),
-        _ => Err("Parser failed".to_string()),
+        Err(err) => Err(PluralRuleParseError::from_parser_error(source, err).to_string()),
+    }
+}
+
+/// Given a string reference of the samples portion of a plural rule, will return the AST representation of those samples.
+///
+/// The samples portion starts with the `@integer` and/or `@decimal` keywords, in either order, each followed by a list of sample values.
+/// An empty source has no samples.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::parse_samples;
+/// use cldr_pluralrules_parser::ast::*;
+///
+/// let samples = parse_samples("@integer 2~4, 22 @decimal 2.0, …")
+///     .expect("Parsing succeeded")
+///     .expect("Samples are present");
+///
+/// assert_eq!(samples.integer.expect("Integer samples are present").sample_ranges.len(), 2);
+/// assert!(samples.decimal.expect("Decimal samples are present").ellipsis);
+/// ```
+pub fn parse_samples<S: AsRef<str>>(
+    source: S,
+) -> Result<Option<ast::Samples>, PluralRuleParseError> {
+    let source = source.as_ref();
+    match parser::parse_samples(source) {
+        Ok((left, samples)) if left.trim().is_empty() => Ok(samples),
+        Ok((left, _)) => Err(PluralRuleParseError::InvalidSyntax {
+            offset: source.len() - left.len(),
+        }),
+        Err(err) => Err(PluralRuleParseError::from_parser_error(source, err)),
     }
 }
@@ -0,0 +1,206 @@
+//! Canonicalization of equivalent conditions, so that differently written rules compare equal.
+
+use crate::ast::*;
+
+/// Whether two rules are written equivalently once normalized, ignoring their samples.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::{parse, rules_equal};
+///
+/// let rule = parse("n is 1 or n mod 10 in 5, 3 @integer 1, 3").unwrap();
+/// let other = parse("n % 10 = 3,5 or n = 1").unwrap();
+/// assert!(rules_equal(&rule, &other));
+/// assert!(!rules_equal(&rule, &parse("n = 1").unwrap()));
+/// ```
+pub fn rules_equal(a: &Rule, b: &Rule) -> bool {
+    normalized_condition(a) == normalized_condition(b)
+}
+
+/// Groups locales into classes whose rules are equal by [`rules_equal`] category by category.
+///
+/// The rules of a locale are keyed by their category, of any type, such as the names of the rules in CLDR.
+/// Each class lists its locales in the order they are given, and the classes are ordered by their first
+/// locale.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::{equivalence_classes, parse};
+///
+/// let rules = |one| vec![("one", parse(one).unwrap()), ("other", parse("").unwrap())];
+/// let locales = [
+///     ("de", rules("i = 1 and v = 0")),
+///     ("en", rules("v = 0 and i = 1 @integer 1")),
+///     ("fr", rules("i = 0,1")),
+/// ];
+/// let classes = equivalence_classes(locales.iter().map(|(locale, rules)| (*locale, rules.as_slice())));
+/// assert_eq!(classes, [vec!["de", "en"], vec!["fr"]]);
+/// ```
+pub fn equivalence_classes<'a, K, C: Ord + Clone + 'a>(
+    locales: impl IntoIterator<Item = (K, &'a [(C, Rule)])>,
+) -> Vec<Vec<K>> {
+    let mut keys: Vec<Vec<(C, Condition)>> = Vec::new();
+    let mut classes: Vec<Vec<K>> = Vec::new();
+    for (locale, rules) in locales {
+        let mut key: Vec<_> = rules
+            .iter()
+            .map(|(category, rule)| (category.clone(), normalized_condition(rule)))
+            .collect();
+        key.sort_by(|(a, _), (b, _)| a.cmp(b));
+        match keys.iter().position(|class_key| *class_key == key) {
+            Some(idx) => classes[idx].push(locale),
+            None => {
+                keys.push(key);
+                classes.push(vec![locale]);
+            }
+        }
+    }
+    classes
+}
+
+/// Returns the normalized condition of a rule, without cloning its samples.
+fn normalized_condition(rule: &Rule) -> Condition {
+    let mut rule = Rule {
+        condition: rule.condition.clone(),
+        samples: None,
+    };
+    rule.normalize();
+    rule.condition
+}
+
+impl Rule {
+    /// Rewrites the condition of the rule into a canonical form, keeping its meaning and its samples.
+    ///
+    /// - `is`, `in`, `is not` and `not in` become `=` and `!=`, as they select the same numbers;
+    /// - the `e` operand becomes its synonym `c`;
+    /// - ranges of a single value, such as `1..1`, become that value, and the items of a range list
+    ///   are sorted and deduplicated;
+    /// - the relations of an `and` condition, and the `and` conditions of a rule, are sorted by their
+    ///   canonical syntax and deduplicated, as both `and` and `or` are commutative.
+    ///
+    /// Normalizing a normalized rule leaves it unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::parse;
+    ///
+    /// let mut rule = parse("v = 0 and n is 1..1 or n mod 10 in 5, 3").unwrap();
+    /// let mut other = parse("n % 10 = 3,5 or n = 1 and v = 0").unwrap();
+    /// rule.normalize();
+    /// other.normalize();
+    /// assert_eq!(rule, other);
+    /// assert_eq!(rule.to_string(), "n % 10 = 3,5 or n = 1 and v = 0");
+    /// ```
+    pub fn normalize(&mut self) {
+        for and_condition in &mut self.condition.0 {
+            for relation in &mut and_condition.0 {
+                relation.normalize();
+            }
+            sort_canonically(&mut and_condition.0);
+        }
+        sort_canonically(&mut self.condition.0);
+    }
+}
+
+impl Relation {
+    fn normalize(&mut self) {
+        self.operator = match self.operator {
+            Operator::Is | Operator::In | Operator::EQ => Operator::EQ,
+            Operator::IsNot | Operator::NotIn | Operator::NotEQ => Operator::NotEQ,
+            Operator::Within => Operator::Within,
+            Operator::NotWithin => Operator::NotWithin,
+        };
+        self.expression.operand = self.expression.operand.canonical();
+
+        let items = &mut self.range_list.0;
+        for item in items.iter_mut() {
+            if let RangeListItem::Range(range) = item
+                && range.lower_val == range.upper_val
+            {
+                *item = RangeListItem::Value(range.lower_val.clone());
+            }
+        }
+        items.sort_by_key(|item| match item {
+            RangeListItem::Value(value) => (value.0, value.0),
+            RangeListItem::Range(range) => (range.lower_val.0, range.upper_val.0),
+        });
+        items.dedup();
+    }
+}
+
+impl Relation {
+    /// Returns the relation holding exactly when this one doesn't, rewriting `not (n = 1)` as `n != 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::ast::Operator;
+    /// use cldr_pluralrules_parser::parse_plural_condition;
+    ///
+    /// let condition = parse_plural_condition("n % 10 not in 2..4").unwrap();
+    /// let negated = condition.0[0].0[0].negate();
+    /// assert_eq!(negated.operator, Operator::In);
+    /// assert_eq!(negated.range_list, condition.0[0].0[0].range_list);
+    /// ```
+    pub fn negate(&self) -> Relation {
+        Relation {
+            expression: self.expression.clone(),
+            operator: self.operator.clone().negate(),
+            range_list: self.range_list.clone(),
+        }
+    }
+}
+
+impl Condition {
+    /// Returns the condition holding exactly when this one doesn't, or `None` if it always holds.
+    ///
+    /// By De Morgan's laws, the negation of `a and b or c` is `(not a or not b) and not c`, whose `and` is
+    /// then distributed over the `or` to keep the form of a rule: `not a and not c or not b and not c`.
+    /// The negation hence has as many `and` conditions as the product of the number of relations of each
+    /// `and` condition. An empty condition, as of `other`, always holds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::parse_plural_condition;
+    ///
+    /// let condition = parse_plural_condition("i = 1 and v = 0 or n = 0").unwrap();
+    /// assert_eq!(
+    ///     condition.negate().unwrap().to_string(),
+    ///     "i != 1 and n != 0 or v != 0 and n != 0"
+    /// );
+    /// assert_eq!(parse_plural_condition("").unwrap().negate(), None);
+    /// ```
+    pub fn negate(&self) -> Option<Condition> {
+        if self.0.is_empty() {
+            return None;
+        }
+        let mut negated = vec![AndCondition(vec![])];
+        for and_condition in &self.0 {
+            negated = negated
+                .iter()
+                .flat_map(|partial| {
+                    and_condition.0.iter().map(|relation| {
+                        let mut partial = partial.clone();
+                        partial.0.push(relation.negate());
+                        partial
+                    })
+                })
+                .collect();
+        }
+        // An empty `and` condition always holds, so its negation never does.
+        if negated.is_empty() {
+            return None;
+        }
+        Some(Condition(negated))
+    }
+}
+
+/// Sorts `nodes` by their canonical syntax, and drops duplicates.
+fn sort_canonically<T: ToString + PartialEq>(nodes: &mut Vec<T>) {
+    nodes.sort_by_cached_key(ToString::to_string);
+    nodes.dedup();
+}
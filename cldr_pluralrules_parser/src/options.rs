@@ -0,0 +1,114 @@
+//! Options controlling which plural rule syntax the parser accepts.
+
+use crate::ast::Operator;
+
+/// Options controlling which plural rule syntax the parser accepts.
+///
+/// The default options accept every operator supported by the parser.
+///
+/// # Examples
+///
+/// ```
+/// use cldr_pluralrules_parser::{parse_with, ParseOptions};
+///
+/// assert!(parse_with("n within 0..2", &ParseOptions::default()).is_ok());
+/// assert!(parse_with("n within 0..2", &ParseOptions::modern()).is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// The relation operators allowed in a rule, or `None` to allow all of them.
+    pub allowed_operators: Option<Vec<Operator>>,
+    /// Match the keywords (`and`, `or`, `mod`, `is`, `in`, `within` and `not`) regardless of their case,
+    /// accepting hand-authored rules such as `i = 1 AND v = 0`. CLDR only uses lowercase keywords.
+    pub case_insensitive_keywords: bool,
+    /// Limits on the size and complexity of a rule.
+    pub limits: ParseLimits,
+    /// Check the `@integer` and `@decimal` sample sections but discard them, so that parsed rules have no
+    /// samples. This saves the allocations of the sample lists when only the conditions are needed, as for
+    /// code generation.
+    pub skip_samples: bool,
+}
+
+/// Limits on the size and complexity of a rule, so that untrusted rules can't make the parser allocate
+/// without bound. A rule exceeding one fails with `PluralRuleParseError::LimitExceeded`.
+///
+/// The defaults are far beyond the needs of CLDR, whose rules have at most 3 `or` conditions of 4 relations,
+/// range lists of 9 items, and 285 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// The maximum length of a rule in bytes, including its samples.
+    pub max_len: usize,
+    /// The maximum number of `and` conditions joined by `or`.
+    pub max_and_conditions: usize,
+    /// The maximum number of relations joined by `and` in an `and` condition.
+    pub max_relations: usize,
+    /// The maximum number of values and ranges in a range list.
+    pub max_range_list_items: usize,
+    /// The maximum nesting depth of parenthesized conditions.
+    pub max_depth: usize,
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self {
+            max_len: 4096,
+            max_and_conditions: 64,
+            max_relations: 64,
+            max_range_list_items: 256,
+            max_depth: 16,
+        }
+    }
+}
+
+/// One of the `ParseLimits`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseLimit {
+    /// `ParseLimits::max_len`.
+    Len,
+    /// `ParseLimits::max_and_conditions`.
+    AndConditions,
+    /// `ParseLimits::max_relations`.
+    Relations,
+    /// `ParseLimits::max_range_list_items`.
+    RangeListItems,
+    /// `ParseLimits::max_depth`.
+    Depth,
+}
+
+impl ParseLimits {
+    /// Returns the value of `limit`.
+    pub fn get(&self, limit: ParseLimit) -> usize {
+        match limit {
+            ParseLimit::Len => self.max_len,
+            ParseLimit::AndConditions => self.max_and_conditions,
+            ParseLimit::Relations => self.max_relations,
+            ParseLimit::RangeListItems => self.max_range_list_items,
+            ParseLimit::Depth => self.max_depth,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// The strict CLDR-modern profile, which only allows the `=` and `!=` operators.
+    pub fn modern() -> Self {
+        Self {
+            allowed_operators: Some(vec![Operator::EQ, Operator::NotEQ]),
+            ..Default::default()
+        }
+    }
+
+    /// The lenient profile, which matches the keywords regardless of their case.
+    pub fn lenient() -> Self {
+        Self {
+            case_insensitive_keywords: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns whether `operator` is allowed by these options.
+    pub fn allows(&self, operator: &Operator) -> bool {
+        self.allowed_operators
+            .as_ref()
+            .is_none_or(|allowed| allowed.contains(operator))
+    }
+}
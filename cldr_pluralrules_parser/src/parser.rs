@@ -1,32 +1,142 @@
+//! The nom parser of the plural rule syntax.
+//!
+//! Precedence is fixed: a modulus binds to its operand first (`n % 10 = 1` compares `n % 10`), relations
+//! are then joined by `and`, and `and` conditions by `or`. `a = 1 and b = 2 or c = 3` hence reads as
+//! `(a = 1 and b = 2) or c = 3`.
+//!
+//! Beyond the CLDR grammar, a parenthesized condition may stand in for a relation, grouping an `or` within an
+//! `and`, as in `(n = 1 or n = 2) and i = 0`. As the AST is an `or` of `and` conditions, a group is distributed
+//! over the `and` condition it is part of, so that this rule parses as `n = 1 and i = 0 or n = 2 and i = 0`.
+//! The distributed conditions count towards the limits of the options.
+//!
+//! Relation operators are tried so that no operator is matched by a prefix of another: `!=` before `=`,
+//! and `is not` before `is`.
+
 use super::ast::*;
+use super::options::{ParseLimit, ParseOptions};
+#[cfg(feature = "spans")]
+use super::spans::{RelationSpan, RuleSpans};
 use nom::{
     IResult,
     Parser,
     branch::alt,
     //error::context,
-    bytes::complete::tag,
-    character::complete::{digit1, one_of, space0, space1},
-    combinator::{map, map_res, opt},
-    multi::{separated_list0, separated_list1},
-    sequence::{preceded, separated_pair},
+    bytes::complete::{tag, tag_no_case},
+    character::complete::{digit1, one_of},
+    combinator::{all_consuming, map, map_res, opt, verify},
+    error::{ErrorKind, FromExternalError, ParseError},
+    multi::{many0_count, separated_list1},
+    sequence::{preceded, separated_pair, terminated},
 };
 
-fn value(i: &str) -> IResult<&str, Value> {
-    map_res(digit1, |s: &str| s.parse::<usize>().map(Value)).parse(i)
+/// The reason the parser stopped, beyond nom's own error kinds.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParserErrorKind {
+    Nom(ErrorKind),
+    NonLiteralModulus(char),
+    ZeroModulus,
+    LeadingZero,
+    DisallowedOperator(Operator),
+    EmptyRangeList,
+    DoubleEquals,
+    InvalidRange(Range),
+    UnexpectedOperand(char),
+    EmptyRelation,
+    UnclosedParenthesis,
+    LimitExceeded(ParseLimit, usize),
 }
 
-fn range(i: &str) -> IResult<&str, Range> {
-    map(
-        separated_pair(value, tag(".."), value),
-        |(lower_val, upper_val)| Range {
-            lower_val,
-            upper_val,
-        },
-    )
-    .parse(i)
+/// The parser error, carrying the remaining input at the point of failure.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParserError<'a> {
+    pub input: &'a str,
+    pub kind: ParserErrorKind,
+}
+
+impl<'a> ParseError<&'a str> for ParserError<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        ParserError {
+            input,
+            kind: ParserErrorKind::Nom(kind),
+        }
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for ParserError<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _: E) -> Self {
+        Self::from_error_kind(input, kind)
+    }
+}
+
+type ParserResult<'a, O> = IResult<&'a str, O, ParserError<'a>>;
+
+/// Whether `c` separates tokens: any Unicode whitespace within a line, such as a tab, a no-break space
+/// or a thin space, but no line break.
+fn is_space(c: char) -> bool {
+    c.is_whitespace()
+        && !matches!(
+            c,
+            '\n' | '\u{0B}' | '\u{0C}' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}'
+        )
 }
 
-fn range_list_item(i: &str) -> IResult<&str, RangeListItem> {
+/// Like nom's `space0`, but accepting all whitespace of `is_space`.
+fn space0(i: &str) -> ParserResult<'_, &str> {
+    let end = i.find(|c| !is_space(c)).unwrap_or(i.len());
+    Ok((&i[end..], &i[..end]))
+}
+
+/// Like nom's `space1`, but accepting all whitespace of `is_space`.
+fn space1(i: &str) -> ParserResult<'_, &str> {
+    match space0(i)? {
+        (_, "") => Err(nom::Err::Error(ParserError::from_error_kind(
+            i,
+            ErrorKind::Space,
+        ))),
+        spaces => Ok(spaces),
+    }
+}
+
+/// Matches the digits of an integer, of any size.
+fn integer_digits(i: &str) -> ParserResult<'_, &str> {
+    let (rest, digits) = digit1(i)?;
+    // CLDR never writes integers with leading zeros, so `007` is a sign of corrupt data
+    // rather than a spelling of `7`.
+    if digits.len() > 1 && digits.starts_with('0') {
+        return Err(nom::Err::Failure(ParserError {
+            input: i,
+            kind: ParserErrorKind::LeadingZero,
+        }));
+    }
+    Ok((rest, digits))
+}
+
+fn value(i: &str) -> ParserResult<'_, Value> {
+    map_res(integer_digits, |s: &str| s.parse::<u64>().map(Value)).parse(i)
+}
+
+fn range(i: &str) -> ParserResult<'_, Range> {
+    let (rest, (lower_val, upper_val)) = separated_pair(value, tag(".."), value).parse(i)?;
+    let range = Range {
+        lower_val,
+        upper_val,
+    };
+    // A range whose bounds are swapped never matches, which is almost always a typo,
+    // so report it instead of generating dead code.
+    if !range.is_valid() {
+        return Err(nom::Err::Failure(ParserError {
+            input: i,
+            kind: ParserErrorKind::InvalidRange(range),
+        }));
+    }
+    Ok((rest, range))
+}
+
+fn range_list_item(i: &str) -> ParserResult<'_, RangeListItem> {
     alt((
         map(range, RangeListItem::Range),
         map(value, RangeListItem::Value),
@@ -34,74 +144,195 @@ fn range_list_item(i: &str) -> IResult<&str, RangeListItem> {
     .parse(i)
 }
 
-fn range_list(i: &str) -> IResult<&str, RangeList> {
-    map(
-        separated_list0((space0, tag(","), space0), range_list_item),
-        RangeList,
-    )
-    .parse(i)
+fn range_list<'a>(i: &'a str, options: &ParseOptions) -> ParserResult<'a, RangeList> {
+    // Like `separated_list0`, but failing as soon as the list exceeds its limit.
+    let max = options.limits.max_range_list_items;
+    let mut items = Vec::new();
+    let mut rest = i;
+    loop {
+        let item_start = if items.is_empty() {
+            rest
+        } else {
+            match (space0, tag(","), space0).parse(rest) {
+                Ok((item_start, _)) => item_start,
+                Err(nom::Err::Error(_)) => break,
+                Err(err) => return Err(err),
+            }
+        };
+        match range_list_item(item_start) {
+            Ok(_) if items.len() == max => {
+                return Err(nom::Err::Failure(ParserError {
+                    input: item_start,
+                    kind: ParserErrorKind::LimitExceeded(ParseLimit::RangeListItems, max),
+                }));
+            }
+            Ok((after_item, item)) => {
+                items.push(item);
+                rest = after_item;
+            }
+            Err(nom::Err::Error(_)) => break,
+            Err(err) => return Err(err),
+        }
+    }
+    // A membership test against no values is meaningless, so report it explicitly
+    // instead of accepting it.
+    if items.is_empty() {
+        return Err(nom::Err::Failure(ParserError {
+            input: i,
+            kind: ParserErrorKind::EmptyRangeList,
+        }));
+    }
+    Ok((rest, RangeList(items)))
 }
 
-fn operand(i: &str) -> IResult<&str, Operand> {
-    map(one_of("nivwft"), |c| match c {
-        'n' => Operand::N,
-        'i' => Operand::I,
-        'v' => Operand::V,
-        'w' => Operand::W,
-        'f' => Operand::F,
-        't' => Operand::T,
-        _ => unreachable!(),
-    })
+fn operand(i: &str) -> ParserResult<'_, Operand> {
+    // Each operand is matched by its own parser, so that no letter is left unhandled.
+    alt((
+        map(tag("n"), |_| Operand::N),
+        map(tag("i"), |_| Operand::I),
+        map(tag("v"), |_| Operand::V),
+        map(tag("w"), |_| Operand::W),
+        map(tag("f"), |_| Operand::F),
+        map(tag("t"), |_| Operand::T),
+        map(tag("c"), |_| Operand::C),
+        map(tag("e"), |_| Operand::E),
+    ))
     .parse(i)
 }
 
-fn mod_expression(i: &str) -> IResult<&str, Option<Modulo>> {
+fn modulus(i: &str) -> ParserResult<'_, Value> {
+    // Operand-to-operand modulo is a common authoring mistake, so report it explicitly
+    // instead of failing with a generic error.
+    match (operand(i), i.chars().next()) {
+        (Ok(_), Some(c)) => Err(nom::Err::Failure(ParserError {
+            input: i,
+            kind: ParserErrorKind::NonLiteralModulus(c),
+        })),
+        // A modulo by zero is undefined, and would panic in generated code.
+        _ => match value(i)? {
+            (_, Value(0)) => Err(nom::Err::Failure(ParserError {
+                input: i,
+                kind: ParserErrorKind::ZeroModulus,
+            })),
+            parsed => Ok(parsed),
+        },
+    }
+}
+
+/// Matches the keyword `kw`, regardless of its case if the options allow it.
+fn keyword<'a>(
+    kw: &'static str,
+    options: &ParseOptions,
+) -> impl FnMut(&'a str) -> ParserResult<'a, &'a str> {
+    let case_insensitive = options.case_insensitive_keywords;
+    move |i| {
+        if case_insensitive {
+            tag_no_case(kw).parse(i)
+        } else {
+            tag(kw).parse(i)
+        }
+    }
+}
+
+fn mod_expression<'a>(i: &'a str, options: &ParseOptions) -> ParserResult<'a, Option<Modulo>> {
     opt(map(
-        preceded((space0, alt((tag("mod"), tag("%"))), space1), value),
+        preceded(
+            (
+                space0,
+                alt(((keyword("mod", options), space1), (tag("%"), space0))),
+            ),
+            modulus,
+        ),
         Modulo,
     ))
     .parse(i)
 }
 
-fn expression(i: &str) -> IResult<&str, Expression> {
-    map((operand, mod_expression), |(operand, modulus)| Expression {
-        operand,
-        modulus,
-    })
+fn expression<'a>(i: &'a str, options: &ParseOptions) -> ParserResult<'a, Expression> {
+    // A relation always starts with an operand, so any other letter is a misspelled operand.
+    if let Some(c) = i.chars().next().filter(|c| c.is_alphabetic())
+        && operand(i).is_err()
+    {
+        return Err(nom::Err::Failure(ParserError {
+            input: i,
+            kind: ParserErrorKind::UnexpectedOperand(c),
+        }));
+    }
+    map(
+        (operand, |i| mod_expression(i, options)),
+        |(operand, modulus)| Expression { operand, modulus },
+    )
     .parse(i)
 }
 
-fn relation_operator(i: &str) -> IResult<&str, Operator> {
+/// Parses a relation operator. The negations follow the grammar of UTS #35:
+///
+/// ```text
+/// is_relation     = expr 'is' ('not')? value
+/// in_relation     = expr (('not')? 'in' | '=' | '!=') range_list
+/// within_relation = expr ('not')? 'within' range_list
+/// ```
+///
+/// `not` thus follows `is`, but precedes `in` and `within`, and must be separated from them by whitespace.
+/// The `not` branch commits to a negated operator, so `not` alone, or before `is`, fails.
+fn relation_operator<'a>(i: &'a str, options: &ParseOptions) -> ParserResult<'a, Operator> {
+    // `==` is not CLDR syntax, and `=` alone would leave the second `=` to fail as a missing value.
+    if i.starts_with("==") {
+        return Err(nom::Err::Failure(ParserError {
+            input: i,
+            kind: ParserErrorKind::DoubleEquals,
+        }));
+    }
     alt((
-        map(tag("="), |_| Operator::EQ),
         map(tag("!="), |_| Operator::NotEQ),
-        map((tag("is"), space1, opt(tag("not"))), |(_, _, n)| {
-            if n.is_some() {
-                Operator::IsNot
-            } else {
-                Operator::Is
-            }
-        }),
-        map(tag("in"), |_| Operator::In),
+        map(tag("="), |_| Operator::EQ),
+        map(
+            (keyword("is", options), space1, opt(keyword("not", options))),
+            |(_, _, n)| {
+                if n.is_some() {
+                    Operator::IsNot
+                } else {
+                    Operator::Is
+                }
+            },
+        ),
+        map(keyword("in", options), |_| Operator::In),
         map(
             (
-                tag("not"),
+                keyword("not", options),
                 space1,
                 alt((
-                    map(tag("in"), |_| Operator::NotIn),
-                    map(tag("within"), |_| Operator::NotWithin),
+                    map(keyword("in", options), |_| Operator::NotIn),
+                    map(keyword("within", options), |_| Operator::NotWithin),
                 )),
             ),
             |(_, _, v)| v,
         ),
-        map(tag("within"), |_| Operator::Within),
+        map(keyword("within", options), |_| Operator::Within),
     ))
     .parse(i)
 }
 
-fn relation(i: &str) -> IResult<&str, Relation> {
+fn allowed_relation_operator<'a>(i: &'a str, options: &ParseOptions) -> ParserResult<'a, Operator> {
+    let (rest, operator) = relation_operator(i, options)?;
+    if !options.allows(&operator) {
+        return Err(nom::Err::Failure(ParserError {
+            input: i,
+            kind: ParserErrorKind::DisallowedOperator(operator),
+        }));
+    }
+    Ok((rest, operator))
+}
+
+fn relation<'a>(i: &'a str, options: &ParseOptions) -> ParserResult<'a, Relation> {
     map(
-        (expression, space0, relation_operator, space0, range_list),
+        (
+            |i| expression(i, options),
+            space0,
+            |i| allowed_relation_operator(i, options),
+            space0,
+            |i| range_list(i, options),
+        ),
         |(expression, _, operator, _, range_list)| Relation {
             expression,
             operator,
@@ -111,23 +342,196 @@ fn relation(i: &str) -> IResult<&str, Relation> {
     .parse(i)
 }
 
-fn and_condition(i: &str) -> IResult<&str, AndCondition> {
-    map(
-        separated_list1((space1, tag("and"), space1), relation),
-        AndCondition,
-    )
-    .parse(i)
+/// Like `relation`, but also returns the byte ranges of `source`, which `i` is a suffix of, it was parsed from.
+#[cfg(feature = "spans")]
+fn spanned_relation<'a>(
+    i: &'a str,
+    source: &str,
+    options: &ParseOptions,
+) -> ParserResult<'a, (Relation, RelationSpan)> {
+    let offset = |rest: &str| source.len() - rest.len();
+    let (after_expression, expression) = expression(i, options)?;
+    let (operator_start, _) = space0(after_expression)?;
+    let (after_operator, operator) = allowed_relation_operator(operator_start, options)?;
+    let (range_list_start, _) = space0(after_operator)?;
+    let (rest, range_list) = range_list(range_list_start, options)?;
+    let span = RelationSpan {
+        relation: offset(i)..offset(rest),
+        expression: offset(i)..offset(after_expression),
+        operator: offset(operator_start)..offset(after_operator),
+        range_list: offset(range_list_start)..offset(rest),
+    };
+    let relation = Relation {
+        expression,
+        operator,
+        range_list,
+    };
+    Ok((rest, (relation, span)))
+}
+
+/// Parses a list of at most as many `element`s as the `limit` of the options allows, separated by the keyword `kw`.
+///
+/// Unlike `separated_list1`, a keyword not followed by an element is an error rather than trailing input.
+fn keyword_separated<'a, O>(
+    i: &'a str,
+    kw: &'static str,
+    limit: ParseLimit,
+    options: &ParseOptions,
+    mut element: impl FnMut(&'a str) -> ParserResult<'a, O>,
+) -> ParserResult<'a, Vec<O>> {
+    let max = options.limits.get(limit);
+    let (mut i, first) = element(i)?;
+    let mut elements = vec![first];
+    while let Ok((rest, _)) = (space1, keyword(kw, options)).parse(i) {
+        if elements.len() == max {
+            return Err(nom::Err::Failure(ParserError {
+                input: rest.trim_start_matches(is_space),
+                kind: ParserErrorKind::LimitExceeded(limit, max),
+            }));
+        }
+        match preceded(space1, &mut element).parse(rest) {
+            Ok((rest, next)) => {
+                elements.push(next);
+                i = rest;
+            }
+            Err(nom::Err::Error(_)) => {
+                return Err(nom::Err::Failure(ParserError {
+                    input: rest.trim_start_matches(is_space),
+                    kind: ParserErrorKind::EmptyRelation,
+                }));
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    Ok((i, elements))
+}
+
+/// Parses `and` conditions joined by `or`, within `depth` parentheses, with `relation` parsing each relation.
+fn or_condition<'a, T: Clone>(
+    i: &'a str,
+    depth: usize,
+    options: &ParseOptions,
+    relation: &dyn Fn(&'a str) -> ParserResult<'a, T>,
+) -> ParserResult<'a, Vec<Vec<T>>> {
+    let (rest, groups) = keyword_separated(i, "or", ParseLimit::AndConditions, options, |i| {
+        and_condition(i, depth, options, relation)
+    })?;
+    let and_conditions: Vec<_> = groups.into_iter().flatten().collect();
+    check_count(
+        rest,
+        and_conditions.len(),
+        ParseLimit::AndConditions,
+        options,
+    )?;
+    Ok((rest, and_conditions))
+}
+
+/// Parses relations and parenthesized conditions joined by `and`, distributing the `or` of each condition
+/// over the others into a list of `and` conditions.
+fn and_condition<'a, T: Clone>(
+    i: &'a str,
+    depth: usize,
+    options: &ParseOptions,
+    relation: &dyn Fn(&'a str) -> ParserResult<'a, T>,
+) -> ParserResult<'a, Vec<Vec<T>>> {
+    let (rest, operands) = keyword_separated(i, "and", ParseLimit::Relations, options, |i| {
+        and_operand(i, depth, options, relation)
+    })?;
+    let mut and_conditions = vec![vec![]];
+    for operand in operands {
+        and_conditions = and_conditions
+            .iter()
+            .flat_map(|prefix| {
+                operand
+                    .iter()
+                    .map(move |suffix| [&prefix[..], suffix].concat())
+            })
+            .collect();
+        check_count(
+            rest,
+            and_conditions.len(),
+            ParseLimit::AndConditions,
+            options,
+        )?;
+        let relations = and_conditions.iter().map(Vec::len).max().unwrap_or(0);
+        check_count(rest, relations, ParseLimit::Relations, options)?;
+    }
+    Ok((rest, and_conditions))
 }
 
-fn decimal_value(i: &str) -> IResult<&str, DecimalValue> {
+/// Parses a relation, as a single `and` condition, or a parenthesized condition.
+fn and_operand<'a, T: Clone>(
+    i: &'a str,
+    depth: usize,
+    options: &ParseOptions,
+    relation: &dyn Fn(&'a str) -> ParserResult<'a, T>,
+) -> ParserResult<'a, Vec<Vec<T>>> {
+    let Ok((inner, _)) = (tag("("), space0).parse(i) else {
+        let (rest, relation) = relation(i)?;
+        return Ok((rest, vec![vec![relation]]));
+    };
+    let max = options.limits.max_depth;
+    if depth == max {
+        return Err(nom::Err::Failure(ParserError {
+            input: i,
+            kind: ParserErrorKind::LimitExceeded(ParseLimit::Depth, max),
+        }));
+    }
+    let (rest, and_conditions) =
+        or_condition(inner, depth + 1, options, relation).map_err(|err| match err {
+            nom::Err::Error(_) => nom::Err::Failure(ParserError {
+                input: inner,
+                kind: ParserErrorKind::EmptyRelation,
+            }),
+            err => err,
+        })?;
+    let (rest, _) = (space0, tag(")"))
+        .parse(rest)
+        .map_err(|_: nom::Err<ParserError>| {
+            nom::Err::Failure(ParserError {
+                input: rest.trim_start_matches(is_space),
+                kind: ParserErrorKind::UnclosedParenthesis,
+            })
+        })?;
+    Ok((rest, and_conditions))
+}
+
+/// Fails at `i` if `count` exceeds the `limit` of the options.
+fn check_count<'a>(
+    i: &'a str,
+    count: usize,
+    limit: ParseLimit,
+    options: &ParseOptions,
+) -> Result<(), nom::Err<ParserError<'a>>> {
+    let max = options.limits.get(limit);
+    if count <= max {
+        return Ok(());
+    }
+    Err(nom::Err::Failure(ParserError {
+        input: i,
+        kind: ParserErrorKind::LimitExceeded(limit, max),
+    }))
+}
+
+fn decimal_value(i: &str) -> ParserResult<'_, DecimalValue> {
     map(
-        (value, opt(preceded(tag("."), value))),
-        |(integer, decimal)| DecimalValue { integer, decimal },
+        (
+            opt(tag("-")),
+            integer_digits,
+            opt(preceded(tag("."), digit1)),
+            opt(preceded(one_of("ce"), value)),
+        ),
+        |(sign, integer, decimal, exponent): (_, &str, Option<&str>, _)| DecimalValue {
+            negative: sign.is_some(),
+            integer: integer.to_owned(),
+            decimal: decimal.map(str::to_owned),
+            exponent,
+        },
     )
     .parse(i)
 }
 
-fn sample_range(i: &str) -> IResult<&str, SampleRange> {
+fn sample_range(i: &str) -> ParserResult<'_, SampleRange> {
     map(
         (
             decimal_value,
@@ -141,60 +545,223 @@ fn sample_range(i: &str) -> IResult<&str, SampleRange> {
     .parse(i)
 }
 
-fn sample_list(i: &str) -> IResult<&str, SampleList> {
+/// Matches the end of a sample list, an ellipsis with or without a comma before it, or a dangling comma,
+/// returning whether there was an ellipsis.
+fn sample_list_end(i: &str) -> ParserResult<'_, bool> {
+    map(
+        (
+            opt((space0, tag(","))),
+            opt(preceded(space0, alt((tag("..."), tag("…"))))),
+        ),
+        |(_, ellipsis)| ellipsis.is_some(),
+    )
+    .parse(i)
+}
+
+fn sample_list(i: &str) -> ParserResult<'_, SampleList> {
     map(
         (
             separated_list1((space0, tag(","), space0), sample_range),
-            opt(preceded(
-                (space0, tag(","), space0),
-                alt((tag("..."), tag("…"))),
-            )),
+            sample_list_end,
         ),
         |(l, ellipsis)| SampleList {
             sample_ranges: l,
-            ellipsis: ellipsis.is_some(),
+            ellipsis,
         },
     )
     .parse(i)
 }
 
-fn samples(i: &str) -> IResult<&str, Option<Samples>> {
+/// Matches a sample value like `decimal_value`, without allocating its fraction digits.
+fn skipped_decimal_value(i: &str) -> ParserResult<'_, ()> {
     map(
         (
-            opt(preceded((space1, tag("@integer"), space1), sample_list)),
-            opt(preceded((space1, tag("@decimal"), space1), sample_list)),
+            opt(tag("-")),
+            integer_digits,
+            opt(preceded(tag("."), digit1)),
+            opt(preceded(one_of("ce"), value)),
         ),
-        |(integer, decimal)| {
-            if integer.is_some() || decimal.is_some() {
-                Some(Samples { integer, decimal })
-            } else {
-                None
-            }
-        },
+        |_| (),
     )
     .parse(i)
 }
 
-pub fn parse_rule(i: &str) -> IResult<&str, Rule> {
-    map((parse_condition, samples), |(condition, samples)| Rule {
-        condition,
-        samples,
-    })
+fn skipped_sample_range(i: &str) -> ParserResult<'_, ()> {
+    map(
+        (
+            skipped_decimal_value,
+            opt(preceded((space0, tag("~"), space0), skipped_decimal_value)),
+        ),
+        |_| (),
+    )
     .parse(i)
 }
 
-pub fn parse_condition(i: &str) -> IResult<&str, Condition> {
+/// Matches a sample list like `sample_list`, without collecting its ranges.
+fn skipped_sample_list(i: &str) -> ParserResult<'_, ()> {
+    map(
+        (
+            skipped_sample_range,
+            many0_count(preceded((space0, tag(","), space0), skipped_sample_range)),
+            sample_list_end,
+        ),
+        |_| (),
+    )
+    .parse(i)
+}
+
+/// A sample section keyword, `@integer` or `@decimal`, along with its list.
+type Section<'a, O> = (&'a str, O);
+
+/// Parses an `@integer` or `@decimal` sample section with `list`, returning its keyword along with its list.
+fn sample_section<'a, O>(
+    i: &'a str,
+    list: fn(&'a str) -> ParserResult<'a, O>,
+) -> ParserResult<'a, Section<'a, O>> {
+    (
+        alt((tag("@integer"), tag("@decimal"))),
+        preceded(space1, list),
+    )
+        .parse(i)
+}
+
+/// Parses the `@integer` and/or `@decimal` sample sections with `list`, in either order.
+///
+/// CLDR lists `@integer` first, but hand-written rules may not. A section can't be repeated.
+fn sample_section_pair<'a, O>(
+    i: &'a str,
+    list: fn(&'a str) -> ParserResult<'a, O>,
+) -> ParserResult<'a, (Section<'a, O>, Option<Section<'a, O>>)> {
+    let (rest, first) = sample_section(i, list)?;
+    let (rest, second) = opt(preceded(
+        space1,
+        verify(
+            |i| sample_section(i, list),
+            |(keyword, _): &Section<O>| *keyword != first.0,
+        ),
+    ))
+    .parse(rest)?;
+    Ok((rest, (first, second)))
+}
+
+fn sample_sections(i: &str) -> ParserResult<'_, Samples> {
+    let (rest, (first, second)) = sample_section_pair(i, sample_list)?;
+
+    let mut samples = Samples {
+        integer: None,
+        decimal: None,
+    };
+    for (keyword, list) in std::iter::once(first).chain(second) {
+        if keyword == "@integer" {
+            samples.integer = Some(list);
+        } else {
+            samples.decimal = Some(list);
+        }
+    }
+    Ok((rest, samples))
+}
+
+pub fn parse_samples(i: &str) -> ParserResult<'_, Option<Samples>> {
+    // A standalone samples portion doesn't follow a condition, so leading whitespace is optional.
+    opt(preceded(space0, sample_sections)).parse(i)
+}
+
+/// Advances past the samples following a condition, which are only kept if the options allow it.
+fn rule_samples<'a>(
+    i: &'a str,
+    condition: &Condition,
+    options: &ParseOptions,
+) -> ParserResult<'a, Option<Samples>> {
+    // Without a condition, as for `other`, the samples don't need to be preceded by whitespace.
+    let separator = if condition.0.is_empty() {
+        space0
+    } else {
+        space1
+    };
+    if options.skip_samples {
+        let skipped = |i| sample_section_pair(i, skipped_sample_list);
+        map(opt(preceded(separator, skipped)), |_| None).parse(i)
+    } else {
+        opt(preceded(separator, sample_sections)).parse(i)
+    }
+}
+
+pub fn parse_rule(i: &str) -> ParserResult<'_, Rule> {
+    parse_rule_with(i, &ParseOptions::default())
+}
+
+pub fn parse_rule_with<'a>(i: &'a str, options: &ParseOptions) -> ParserResult<'a, Rule> {
+    let (rest, condition) = parse_condition_with(i, options)?;
+    let (rest, samples) = rule_samples(rest, &condition, options)?;
+    Ok((rest, Rule { condition, samples }))
+}
+
+/// Parses a rule followed by nothing but whitespace.
+pub fn parse_rule_complete(i: &str) -> ParserResult<'_, Rule> {
+    all_consuming(terminated(parse_rule, space0)).parse(i)
+}
+
+/// Like `parse_rule_with`, but also returns the spans of the relations in `i`.
+#[cfg(feature = "spans")]
+pub fn parse_rule_spanned<'a>(
+    i: &'a str,
+    options: &ParseOptions,
+) -> ParserResult<'a, (Rule, RuleSpans)> {
+    check_len(i, options)?;
+    let source = i;
+    // Empty conditions are handled as in `parse_condition_with`.
+    let (rest, spanned) = if i.trim().is_empty() {
+        ("", vec![])
+    } else if i.trim().starts_with("@") {
+        (i, vec![])
+    } else {
+        or_condition(i, 0, options, &|i| spanned_relation(i, source, options))?
+    };
+    let (relations, spans) = spanned
+        .into_iter()
+        .map(|and_condition| and_condition.into_iter().unzip())
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+    let condition = Condition(relations.into_iter().map(AndCondition).collect());
+    let (rest, samples) = rule_samples(rest, &condition, options)?;
+    Ok((
+        rest,
+        (Rule { condition, samples }, RuleSpans { relations: spans }),
+    ))
+}
+
+/// Fails if `i` is longer than the limit of the options, at the last character boundary within the limit.
+fn check_len<'a>(i: &'a str, options: &ParseOptions) -> Result<(), nom::Err<ParserError<'a>>> {
+    let max = options.limits.max_len;
+    if i.len() <= max {
+        return Ok(());
+    }
+    let end = (0..=max)
+        .rev()
+        .find(|&end| i.is_char_boundary(end))
+        .unwrap_or(0);
+    Err(nom::Err::Failure(ParserError {
+        input: &i[end..],
+        kind: ParserErrorKind::LimitExceeded(ParseLimit::Len, max),
+    }))
+}
+
+pub fn parse_condition(i: &str) -> ParserResult<'_, Condition> {
+    parse_condition_with(i, &ParseOptions::default())
+}
+
+pub fn parse_condition_with<'a>(i: &'a str, options: &ParseOptions) -> ParserResult<'a, Condition> {
+    check_len(i, options)?;
     // We need to handle empty input and/or input that is empty until sample.
     if i.trim().is_empty() {
-        return IResult::Ok(("", Condition(vec![])));
+        return Ok(("", Condition(vec![])));
     }
 
     if i.trim().starts_with("@") {
-        return IResult::Ok(("", Condition(vec![])));
+        return Ok((i, Condition(vec![])));
     }
     map(
-        separated_list1((space1, tag("or"), space1), and_condition),
-        Condition,
+        |i| or_condition(i, 0, options, &|i| relation(i, options)),
+        |and_conditions| Condition(and_conditions.into_iter().map(AndCondition).collect()),
     )
     .parse(i)
 }
@@ -0,0 +1,97 @@
+//! An indented tree view of rules, for debugging.
+
+use crate::ast::*;
+use std::fmt::Write;
+
+impl Rule {
+    /// Renders the AST of the rule as a tree, one node per line, indenting the children of a node
+    /// by two spaces.
+    ///
+    /// Unlike the canonical syntax of `Display`, the tree names every node of the AST, which makes
+    /// the grouping of the conditions and relations visible, and keeps the operators as written. A
+    /// rule without samples has no `Samples` node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::parse;
+    ///
+    /// let rule = parse("n % 10 = 2..4 @integer 2~4, …").unwrap();
+    /// assert_eq!(
+    ///     rule.pretty(),
+    ///     "Rule
+    ///   Condition
+    ///     AndCondition
+    ///       Relation
+    ///         Expression n % 10
+    ///         Operator EQ
+    ///         RangeList
+    ///           Range 2..4
+    ///   Samples
+    ///     Integer
+    ///       SampleRange 2~4
+    ///       Ellipsis
+    /// "
+    /// );
+    /// ```
+    pub fn pretty(&self) -> String {
+        let mut tree = Tree::default();
+        tree.node(0, "Rule");
+        tree.node(1, "Condition");
+        for and_condition in &self.condition.0 {
+            tree.node(2, "AndCondition");
+            for relation in &and_condition.0 {
+                tree.node(3, "Relation");
+                tree.leaf(4, "Expression", &relation.expression);
+                // The variant tells the operators `Display` writes alike apart, such as `in` and `=`.
+                tree.node(4, &format!("Operator {:?}", relation.operator));
+                tree.node(4, "RangeList");
+                for item in &relation.range_list.0 {
+                    match item {
+                        RangeListItem::Range(range) => tree.leaf(5, "Range", range),
+                        RangeListItem::Value(value) => tree.leaf(5, "Value", value),
+                    }
+                }
+            }
+        }
+        if let Some(samples) = &self.samples {
+            tree.node(1, "Samples");
+            for (name, list) in [("Integer", &samples.integer), ("Decimal", &samples.decimal)] {
+                let Some(list) = list else {
+                    continue;
+                };
+                tree.node(2, name);
+                for range in &list.sample_ranges {
+                    tree.leaf(3, "SampleRange", range);
+                }
+                if list.ellipsis {
+                    tree.node(3, "Ellipsis");
+                }
+            }
+        }
+        tree.0
+    }
+}
+
+/// The lines of a tree being rendered.
+#[derive(Default)]
+struct Tree(String);
+
+impl Tree {
+    fn node(&mut self, depth: usize, name: &str) {
+        writeln!(self.0, "{:indent$}{}", "", name, indent = depth * 2).unwrap();
+    }
+
+    /// Adds a node followed by the canonical syntax of its value.
+    fn leaf(&mut self, depth: usize, name: &str, value: &impl std::fmt::Display) {
+        writeln!(
+            self.0,
+            "{:indent$}{} {}",
+            "",
+            name,
+            value,
+            indent = depth * 2
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,34 @@
+//! Byte ranges of the source the nodes of a rule were parsed from, for diagnostics pointing into the rule.
+//!
+//! The spans are kept apart from the AST, which stays the same with or without them, and are returned by
+//! [`parse_with_spans`](crate::parse_with_spans).
+
+use std::ops::Range;
+
+/// The byte ranges of the source a relation and its parts were parsed from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RelationSpan {
+    /// The whole relation, such as `n % 10 = 1..4`.
+    pub relation: Range<usize>,
+    /// The expression, such as `n % 10`.
+    pub expression: Range<usize>,
+    /// The operator, such as `=` or `not in`.
+    pub operator: Range<usize>,
+    /// The range list, such as `1..4`.
+    pub range_list: Range<usize>,
+}
+
+/// The spans of the relations of a rule, in the shape of its condition.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RuleSpans {
+    /// The spans of the relations of each `and` condition, so `relations[i][j]` is the span of
+    /// `rule.condition.0[i].0[j]`.
+    pub relations: Vec<Vec<RelationSpan>>,
+}
+
+impl RuleSpans {
+    /// Returns the span of the relation at `relation` in the `and` condition at `and_condition`.
+    pub fn relation(&self, and_condition: usize, relation: usize) -> Option<&RelationSpan> {
+        self.relations.get(and_condition)?.get(relation)
+    }
+}
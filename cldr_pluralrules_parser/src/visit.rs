@@ -0,0 +1,187 @@
+//! Traversal of the AST of a plural rule.
+//!
+//! A [`Visit`] implementation overrides the `visit_*` methods of the nodes it cares about. Each default
+//! method visits the children of its node by calling the matching `walk_*` function, which an overriding
+//! method can call as well to keep descending.
+//!
+//! # Examples
+//!
+//! Collecting the operands referenced by a rule:
+//!
+//! ```
+//! use cldr_pluralrules_parser::ast::Operand;
+//! use cldr_pluralrules_parser::parse_plural_rule;
+//! use cldr_pluralrules_parser::visit::Visit;
+//! use std::collections::BTreeSet;
+//!
+//! #[derive(Default)]
+//! struct Operands(BTreeSet<Operand>);
+//!
+//! impl Visit for Operands {
+//!     fn visit_operand(&mut self, operand: &Operand) {
+//!         self.0.insert(operand.clone());
+//!     }
+//! }
+//!
+//! let rule = parse_plural_rule("v = 0 and i % 10 = 1 or f % 10 = 1").expect("Parsing succeeded");
+//! let mut operands = Operands::default();
+//! operands.visit_rule(&rule);
+//! assert_eq!(operands.0, BTreeSet::from([Operand::I, Operand::V, Operand::F]));
+//! ```
+
+use crate::ast::*;
+use std::collections::BTreeSet;
+
+/// A visitor of the AST nodes of a plural rule, in source order.
+///
+/// The samples of a rule are not visited.
+pub trait Visit {
+    fn visit_rule(&mut self, rule: &Rule) {
+        walk_rule(self, rule);
+    }
+
+    fn visit_condition(&mut self, condition: &Condition) {
+        walk_condition(self, condition);
+    }
+
+    fn visit_and_condition(&mut self, and_condition: &AndCondition) {
+        walk_and_condition(self, and_condition);
+    }
+
+    fn visit_relation(&mut self, relation: &Relation) {
+        walk_relation(self, relation);
+    }
+
+    fn visit_expression(&mut self, expression: &Expression) {
+        walk_expression(self, expression);
+    }
+
+    fn visit_operand(&mut self, _operand: &Operand) {}
+
+    fn visit_modulo(&mut self, modulo: &Modulo) {
+        walk_modulo(self, modulo);
+    }
+
+    fn visit_operator(&mut self, _operator: &Operator) {}
+
+    fn visit_range_list(&mut self, range_list: &RangeList) {
+        walk_range_list(self, range_list);
+    }
+
+    fn visit_range_list_item(&mut self, item: &RangeListItem) {
+        walk_range_list_item(self, item);
+    }
+
+    fn visit_range(&mut self, range: &Range) {
+        walk_range(self, range);
+    }
+
+    fn visit_value(&mut self, _value: &Value) {}
+}
+
+pub fn walk_rule<V: Visit + ?Sized>(visitor: &mut V, rule: &Rule) {
+    visitor.visit_condition(&rule.condition);
+}
+
+pub fn walk_condition<V: Visit + ?Sized>(visitor: &mut V, condition: &Condition) {
+    for and_condition in &condition.0 {
+        visitor.visit_and_condition(and_condition);
+    }
+}
+
+pub fn walk_and_condition<V: Visit + ?Sized>(visitor: &mut V, and_condition: &AndCondition) {
+    for relation in &and_condition.0 {
+        visitor.visit_relation(relation);
+    }
+}
+
+pub fn walk_relation<V: Visit + ?Sized>(visitor: &mut V, relation: &Relation) {
+    visitor.visit_expression(&relation.expression);
+    visitor.visit_operator(&relation.operator);
+    visitor.visit_range_list(&relation.range_list);
+}
+
+pub fn walk_expression<V: Visit + ?Sized>(visitor: &mut V, expression: &Expression) {
+    visitor.visit_operand(&expression.operand);
+    if let Some(modulus) = &expression.modulus {
+        visitor.visit_modulo(modulus);
+    }
+}
+
+pub fn walk_modulo<V: Visit + ?Sized>(visitor: &mut V, modulo: &Modulo) {
+    visitor.visit_value(&modulo.0);
+}
+
+pub fn walk_range_list<V: Visit + ?Sized>(visitor: &mut V, range_list: &RangeList) {
+    for item in &range_list.0 {
+        visitor.visit_range_list_item(item);
+    }
+}
+
+pub fn walk_range_list_item<V: Visit + ?Sized>(visitor: &mut V, item: &RangeListItem) {
+    match item {
+        RangeListItem::Range(range) => visitor.visit_range(range),
+        RangeListItem::Value(value) => visitor.visit_value(value),
+    }
+}
+
+pub fn walk_range<V: Visit + ?Sized>(visitor: &mut V, range: &Range) {
+    visitor.visit_value(&range.lower_val);
+    visitor.visit_value(&range.upper_val);
+}
+
+/// Collects the operands of the visited expressions.
+struct OperandsUsed(BTreeSet<Operand>);
+
+impl Visit for OperandsUsed {
+    fn visit_operand(&mut self, operand: &Operand) {
+        self.0.insert(operand.clone());
+    }
+}
+
+/// Collects the operators of the visited relations.
+struct OperatorsUsed(BTreeSet<Operator>);
+
+impl Visit for OperatorsUsed {
+    fn visit_operator(&mut self, operator: &Operator) {
+        self.0.insert(operator.clone());
+    }
+}
+
+impl Rule {
+    /// Returns the operands the condition of the rule refers to, such as the `i` and `v` of `i = 1 and v = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::ast::Operand;
+    /// use cldr_pluralrules_parser::parse;
+    /// use std::collections::BTreeSet;
+    ///
+    /// let rule = parse("i = 1 and v = 0 @integer 1").expect("Parsing succeeded");
+    /// assert_eq!(rule.operands_used(), BTreeSet::from([Operand::I, Operand::V]));
+    /// ```
+    pub fn operands_used(&self) -> BTreeSet<Operand> {
+        let mut operands = OperandsUsed(BTreeSet::new());
+        operands.visit_rule(self);
+        operands.0
+    }
+
+    /// Returns the operators of the relations of the rule, as written, so `=` and `in` are distinct.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cldr_pluralrules_parser::ast::Operator;
+    /// use cldr_pluralrules_parser::parse;
+    /// use std::collections::BTreeSet;
+    ///
+    /// let rule = parse("n % 10 = 1 and n % 100 != 11").expect("Parsing succeeded");
+    /// assert_eq!(rule.operators_used(), BTreeSet::from([Operator::EQ, Operator::NotEQ]));
+    /// ```
+    pub fn operators_used(&self) -> BTreeSet<Operator> {
+        let mut operators = OperatorsUsed(BTreeSet::new());
+        operators.visit_rule(self);
+        operators.0
+    }
+}
@@ -0,0 +1,16 @@
+/// Returns the rule names and rules of the CLDR data used to generate `intl_pluralrules`.
+pub fn cldr_rules() -> Vec<(String, String)> {
+    [
+        include_str!("../../../intl_pluralrules/cldr_data/cldr_pluralrules_cardinals.json"),
+        include_str!("../../../intl_pluralrules/cldr_data/cldr_pluralrules_ordinals.json"),
+    ]
+    .iter()
+    .flat_map(|json| json.lines())
+    .filter_map(|line| line.trim().strip_prefix("\"pluralRule-count-"))
+    .map(|entry| {
+        let (category, rule) = entry.split_once("\": \"").expect("The entry has a rule");
+        let rule = rule.trim_end_matches(',').trim_end_matches('"');
+        (category.to_owned(), rule.to_owned())
+    })
+    .collect()
+}
@@ -293,3 +293,375 @@ fn be_many_rule() {
             .condition
     );
 }
+
+#[test]
+fn lenient_keywords() {
+    let test = "i = 1 AND v = 0";
+
+    assert!(parse_plural_rule(test).is_err());
+    assert_eq!(
+        parse_plural_rule("i = 1 and v = 0").expect("Parsing succeeded"),
+        parse_with(test, &ParseOptions::lenient()).expect("Parsing succeeded")
+    );
+
+    let test = "n MOD 10 IS NOT 1 Or n Not Within 2..4";
+
+    assert_eq!(
+        parse_plural_rule("n mod 10 is not 1 or n not within 2..4").expect("Parsing succeeded"),
+        parse_with(test, &ParseOptions::lenient()).expect("Parsing succeeded")
+    );
+}
+
+#[test]
+fn lenient_keywords_and_spacing() {
+    let expected = parse_plural_rule("n = 1 or i = 0").expect("Parsing succeeded");
+    for test in [
+        "n = 1 OR i = 0",
+        "n = 1   Or \t i = 0",
+        "n  =  1  oR  i  =  0",
+    ] {
+        assert_eq!(
+            expected,
+            parse_with(test, &ParseOptions::lenient()).expect("Parsing succeeded"),
+            "{}",
+            test
+        );
+    }
+
+    // Spaces around operators are optional, except after the `mod` keyword.
+    let expected = parse_plural_rule("n % 10 = 1 and i != 2").expect("Parsing succeeded");
+    for test in [
+        "n%10=1 and i!=2",
+        "n %10 =1 and i!= 2",
+        "n mod 10=1 and i !=2",
+    ] {
+        assert_eq!(
+            expected,
+            parse_plural_rule(test).expect("Parsing succeeded"),
+            "{}",
+            test
+        );
+    }
+
+    // The operands stay case-sensitive, as `N` is not a CLDR operand.
+    assert_eq!(
+        parse_with("N = 1 OR i = 0", &ParseOptions::lenient())
+            .unwrap_err()
+            .to_string(),
+        "unexpected operand 'N' at offset 0"
+    );
+}
+
+#[test]
+fn compact_exponent_operands() {
+    let operands = |rule: &Rule| -> Vec<Operand> {
+        rule.condition
+            .0
+            .iter()
+            .flat_map(|and| and.0.iter().map(|rel| rel.expression.operand.clone()))
+            .collect()
+    };
+
+    let test = "i = 0 or n = 1 @integer 0, 1";
+    let rule = parse_plural_rule(test).expect("Parsing succeeded");
+    assert_eq!(operands(&rule), vec![Operand::I, Operand::N]);
+
+    let test =
+        "e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5 @integer 1000000, 1c6, 2c6";
+    let rule = parse_plural_rule(test).expect("Parsing succeeded");
+    assert!(rule.samples.is_some());
+    assert_eq!(
+        operands(&rule),
+        vec![Operand::E, Operand::I, Operand::I, Operand::V, Operand::E]
+    );
+
+    let compact = parse_plural_rule("c = 0 and i != 0").expect("Parsing succeeded");
+    let exponent = parse_plural_rule("e = 0 and i != 0").expect("Parsing succeeded");
+    assert_eq!(operands(&compact), vec![Operand::C, Operand::I]);
+    assert_eq!(operands(&exponent), vec![Operand::E, Operand::I]);
+    assert_eq!(Operand::E.to_string(), "e");
+    assert_eq!(
+        operands(&compact)[0].canonical(),
+        operands(&exponent)[0].canonical()
+    );
+}
+
+#[test]
+fn values_beyond_u32() {
+    // Values don't depend on the pointer width of the target.
+    let test = "n % 10000000000 = 4294967296..8589934592 @integer 4294967296";
+    let rule = parse_plural_rule(test).expect("Parsing succeeded");
+    let relation = &rule.condition.0[0].0[0];
+    assert_eq!(
+        relation.expression.modulus,
+        Some(Modulo(Value(10_000_000_000)))
+    );
+    assert_eq!(
+        relation.range_list,
+        RangeList(vec![RangeListItem::Range(Range {
+            lower_val: Value(u64::from(u32::MAX) + 1),
+            upper_val: Value(2 * (u64::from(u32::MAX) + 1)),
+        })])
+    );
+    assert_eq!(
+        rule.samples.expect("Samples are present").values(),
+        vec!["4294967296"]
+    );
+
+    let max = format!("i = {}", u64::MAX);
+    let rule = parse_plural_rule(&max).expect("Parsing succeeded");
+    assert_eq!(
+        rule.condition.0[0].0[0].range_list,
+        RangeList(vec![RangeListItem::Value(Value(u64::MAX))])
+    );
+}
+
+#[test]
+fn range_contains() {
+    let range = Range {
+        lower_val: Value(3),
+        upper_val: Value(5),
+    };
+    assert!(!range.contains(2));
+    assert!(range.contains(3));
+    assert!(range.contains(4));
+    assert!(range.contains(5));
+    assert!(!range.contains(6));
+
+    let single = Range {
+        lower_val: Value(7),
+        upper_val: Value(7),
+    };
+    assert!(single.contains(7));
+    assert!(!single.contains(6) && !single.contains(8));
+}
+
+/// Returns the range list of the relation `n = <source>`.
+fn range_list(source: &str) -> RangeList {
+    parse_plural_rule(format!("n = {}", source))
+        .expect("Parsing succeeded")
+        .condition
+        .0[0]
+        .0[0]
+        .range_list
+        .clone()
+}
+
+#[test]
+fn range_list_contains() {
+    let list = range_list("1,3..5,9,11..12");
+    for value in [1, 3, 4, 5, 9, 11, 12] {
+        assert!(list.contains(value), "{}", value);
+    }
+    for value in [0, 2, 6, 8, 10, 13] {
+        assert!(!list.contains(value), "{}", value);
+    }
+
+    let list = range_list("0");
+    assert!(list.contains(0));
+    assert!(!list.contains(1));
+}
+
+#[test]
+fn range_list_values() {
+    assert_eq!(
+        range_list("1,3..5").iter_values().collect::<Vec<_>>(),
+        vec![1, 3, 4, 5]
+    );
+    assert_eq!(
+        range_list("7,2..3,7").iter_values().collect::<Vec<_>>(),
+        vec![7, 2, 3, 7]
+    );
+
+    // Huge ranges are only expanded as far as they are consumed.
+    let huge = range_list(&format!("0..{}", u64::MAX));
+    assert_eq!(
+        huge.iter_values().take(3).collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+    assert_eq!(huge.iter_values().nth(1_000_000), Some(1_000_000));
+}
+
+#[test]
+fn unicode_whitespace() {
+    let expected = parse("n % 10 = 1 and n % 100 != 11 or n = 0").expect("Parsing succeeded");
+
+    // No-break, narrow no-break and thin spaces, and tabs separate tokens like ASCII spaces.
+    for separator in ["\u{A0}", "\u{202F}", "\u{2009}", "\t", " \u{A0}"] {
+        let source = [
+            "n", "%", "10", "=", "1", "and", "n", "%", "100", "!=", "11", "or", "n", "=", "0",
+        ]
+        .join(separator);
+        assert_eq!(parse(&source), Ok(expected.clone()), "{:?}", source);
+    }
+
+    assert_eq!(
+        parse("n = 1\u{A0}@integer 1")
+            .expect("Parsing succeeded")
+            .samples,
+        parse("n = 1 @integer 1")
+            .expect("Parsing succeeded")
+            .samples
+    );
+}
+
+#[test]
+fn equal_rules_hash_equally() {
+    let source = "n % 10 = 1 and n % 100 != 11 @integer 1, 21~31, … @decimal 1.0, 21.0";
+    let rules: std::collections::HashSet<_> = [
+        parse(source).expect("Parsing succeeded"),
+        parse(source).expect("Parsing succeeded"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(rules.len(), 1);
+
+    // Rules differing in their samples alone are distinct.
+    let distinct: std::collections::HashSet<_> = [
+        parse(source).expect("Parsing succeeded"),
+        parse("n % 10 = 1 and n % 100 != 11 @integer 1").expect("Parsing succeeded"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(distinct.len(), 2);
+}
+
+fn simple_relation(operand: Operand, operator: Operator, value: u64) -> Relation {
+    Relation {
+        expression: Expression {
+            operand,
+            modulus: None,
+        },
+        operator,
+        range_list: RangeList(vec![RangeListItem::Value(Value(value))]),
+    }
+}
+
+#[test]
+fn chained_not_equal_relations() {
+    let expected = Condition(vec![AndCondition(vec![
+        simple_relation(Operand::N, Operator::NotEQ, 0),
+        simple_relation(Operand::N, Operator::NotEQ, 1),
+    ])]);
+    for source in ["n != 0 and n != 1", "n!=0 and n!=1"] {
+        assert_eq!(
+            expected,
+            parse_plural_rule(source)
+                .expect("Parsing succeeded")
+                .condition,
+            "{}",
+            source
+        );
+    }
+}
+
+#[test]
+fn mixed_equal_and_not_equal_relations() {
+    assert_eq!(
+        Condition(vec![
+            AndCondition(vec![
+                simple_relation(Operand::I, Operator::EQ, 1),
+                simple_relation(Operand::V, Operator::NotEQ, 0),
+            ]),
+            AndCondition(vec![
+                simple_relation(Operand::F, Operator::NotEQ, 2),
+                simple_relation(Operand::T, Operator::EQ, 3),
+            ]),
+        ]),
+        parse_plural_rule("i = 1 and v != 0 or f!=2 and t=3")
+            .expect("Parsing succeeded")
+            .condition
+    );
+}
+
+#[test]
+fn negated_operators() {
+    let operator = |test: &str| {
+        parse_plural_condition(test).expect("Parsing succeeded").0[0].0[0]
+            .operator
+            .clone()
+    };
+
+    for (test, expected) in [
+        ("n = 1", Operator::EQ),
+        ("n != 1", Operator::NotEQ),
+        ("n is 1", Operator::Is),
+        ("n is not 1", Operator::IsNot),
+        ("n in 1", Operator::In),
+        ("n not in 1", Operator::NotIn),
+        ("n within 1", Operator::Within),
+        ("n not within 1", Operator::NotWithin),
+        ("n % 10 is not 1", Operator::IsNot),
+        ("n % 10 not in 1..2", Operator::NotIn),
+        ("n % 10 not within 1..2", Operator::NotWithin),
+    ] {
+        assert_eq!(expected, operator(test), "{}", test);
+    }
+
+    let test = "n is not 1 and n not in 2..4 or n not within 5..7 and n within 0..9";
+    let operators: Vec<Vec<Operator>> = parse_plural_condition(test)
+        .expect("Parsing succeeded")
+        .0
+        .into_iter()
+        .map(|and| and.0.into_iter().map(|rel| rel.operator).collect())
+        .collect();
+    assert_eq!(
+        vec![
+            vec![Operator::IsNot, Operator::NotIn],
+            vec![Operator::NotWithin, Operator::Within],
+        ],
+        operators
+    );
+
+    // `not` is a separate word, which only precedes the range list after `is`.
+    for test in [
+        "n isnot 1",
+        "n notin 1",
+        "n notwithin 1",
+        "n not 1",
+        "n is not in 1",
+        "n not is 1",
+        "n in not 1",
+    ] {
+        assert!(parse_plural_condition(test).is_err(), "{}", test);
+    }
+}
+
+#[test]
+fn grouped_conditions() {
+    for (grouped, flat) in [
+        (
+            "(n = 1 or n = 2) and i = 0",
+            "n = 1 and i = 0 or n = 2 and i = 0",
+        ),
+        (
+            "i = 0 and (n = 1 or n = 2)",
+            "i = 0 and n = 1 or i = 0 and n = 2",
+        ),
+        ("( n = 1 )", "n = 1"),
+        ("(n = 1 and v = 0) or n = 2", "n = 1 and v = 0 or n = 2"),
+        (
+            "(i = 1 or i = 2) and (v = 0 or f = 1)",
+            "i = 1 and v = 0 or i = 1 and f = 1 or i = 2 and v = 0 or i = 2 and f = 1",
+        ),
+        (
+            "((i = 1 or i = 2) and v = 0) or i = 5",
+            "i = 1 and v = 0 or i = 2 and v = 0 or i = 5",
+        ),
+    ] {
+        assert_eq!(
+            parse_plural_condition(grouped),
+            parse_plural_condition(flat),
+            "{}",
+            grouped
+        );
+    }
+
+    // Groups are distributed, so that a rule is still displayed as an `or` of `and` conditions.
+    assert_eq!(
+        parse("(n = 1 or n = 2) and i = 0 @integer 1, 2")
+            .expect("Parsing succeeded")
+            .to_string(),
+        "n = 1 and i = 0 or n = 2 and i = 0 @integer 1, 2"
+    );
+}
@@ -0,0 +1,195 @@
+use cldr_pluralrules_parser::ast::*;
+use cldr_pluralrules_parser::*;
+
+mod common;
+
+fn first_relation(source: &str) -> Relation {
+    parse(source).expect("Parsing succeeded").condition.0[0].0[0].clone()
+}
+
+#[test]
+fn canonical_operator() {
+    for (source, canonical) in [
+        ("i = 1", "="),
+        ("i is 1", "="),
+        ("i in 1..2", "="),
+        ("i != 1", "!="),
+        ("i is not 1", "!="),
+        ("i not in 1..2", "!="),
+        ("n within 1..2", "within"),
+        ("n not within 1..2", "not within"),
+    ] {
+        assert_eq!(canonical, first_relation(source).operator.to_string());
+    }
+}
+
+#[test]
+fn canonical_operand() {
+    for operand in ["n", "i", "v", "w", "f", "t"] {
+        let relation = first_relation(&format!("{} = 0", operand));
+        assert_eq!(operand, relation.expression.operand.to_string());
+    }
+}
+
+#[test]
+fn canonical_modulo() {
+    let relation = first_relation("n mod 10 is 1");
+
+    assert_eq!(
+        "% 10",
+        relation
+            .expression
+            .modulus
+            .expect("Modulus is present")
+            .to_string()
+    );
+    assert_eq!("=", relation.operator.to_string());
+
+    let relation = first_relation("n % 100 = 11");
+
+    assert_eq!(
+        "% 100",
+        relation
+            .expression
+            .modulus
+            .expect("Modulus is present")
+            .to_string()
+    );
+}
+
+#[test]
+fn round_trip_cldr_rules() {
+    let rules: Vec<String> = common::cldr_rules()
+        .into_iter()
+        .map(|(_, rule)| rule)
+        .collect();
+    assert!(rules.len() > 100);
+
+    for source in rules {
+        let rule = parse(&source).expect("Parsing succeeded");
+        let formatted = rule.to_string();
+        assert_eq!(
+            rule,
+            parse(&formatted).expect("Parsing the formatted rule succeeded"),
+            "{} was formatted as {}",
+            source,
+            formatted
+        );
+        // CLDR rules are already written in canonical syntax, up to stray spaces.
+        assert_eq!(
+            source.split_whitespace().collect::<Vec<_>>().join(" "),
+            formatted
+        );
+    }
+}
+
+#[test]
+fn canonical_rule() {
+    for (source, canonical) in [
+        ("n mod 10 is 1", "n % 10 = 1"),
+        ("n is not 2 and i in 3..5, 7", "n != 2 and i = 3..5,7"),
+        ("n!=0 and n!=1", "n != 0 and n != 1"),
+        ("i = 1 and v != 0 or f!=2", "i = 1 and v != 0 or f != 2"),
+        ("n not within 1..2 or v = 0", "n not within 1..2 or v = 0"),
+        ("i = 1 and v = 0 @integer 1", "i = 1 and v = 0 @integer 1"),
+        (" @decimal 0.0~1.5, 1c3, …", "@decimal 0.0~1.5, 1c3, …"),
+        ("@integer 0", "@integer 0"),
+        ("", ""),
+    ] {
+        assert_eq!(
+            canonical,
+            parse(source).expect("Parsing succeeded").to_string()
+        );
+    }
+}
+
+#[test]
+fn pretty_tree() {
+    let rule = parse(
+        "v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or v != 0 and f in 1, 5..9 \
+         @integer 2~4, 22~24, … @decimal 0.1, 1.5",
+    )
+    .expect("Parsing succeeded");
+
+    assert_eq!(
+        rule.pretty(),
+        "\
+Rule
+  Condition
+    AndCondition
+      Relation
+        Expression v
+        Operator EQ
+        RangeList
+          Value 0
+      Relation
+        Expression i % 10
+        Operator EQ
+        RangeList
+          Range 2..4
+      Relation
+        Expression i % 100
+        Operator NotEQ
+        RangeList
+          Range 12..14
+    AndCondition
+      Relation
+        Expression v
+        Operator NotEQ
+        RangeList
+          Value 0
+      Relation
+        Expression f
+        Operator In
+        RangeList
+          Value 1
+          Range 5..9
+  Samples
+    Integer
+      SampleRange 2~4
+      SampleRange 22~24
+      Ellipsis
+    Decimal
+      SampleRange 0.1
+      SampleRange 1.5
+"
+    );
+
+    assert_eq!(
+        parse("").expect("Parsing succeeded").pretty(),
+        "Rule\n  Condition\n"
+    );
+}
+
+#[test]
+fn operand_letters() {
+    use std::convert::TryFrom;
+
+    for (letter, operand) in [
+        ('n', Operand::N),
+        ('i', Operand::I),
+        ('v', Operand::V),
+        ('w', Operand::W),
+        ('f', Operand::F),
+        ('t', Operand::T),
+        ('c', Operand::C),
+        ('e', Operand::E),
+    ] {
+        assert_eq!(operand.as_char(), letter);
+        assert_eq!(Operand::try_from(letter), Ok(operand.clone()));
+        assert_eq!(letter.to_string().parse(), Ok(operand.clone()));
+        assert_eq!(operand.to_string(), letter.to_string());
+    }
+
+    assert_eq!(Operand::try_from('x'), Err(UnknownOperand("x".to_owned())));
+    for invalid in ["", "N", "nn", " n"] {
+        assert_eq!(
+            invalid.parse::<Operand>(),
+            Err(UnknownOperand(invalid.to_owned()))
+        );
+    }
+    assert_eq!(
+        UnknownOperand("x".to_owned()).to_string(),
+        "unknown operand `x`"
+    );
+}
@@ -0,0 +1,485 @@
+use cldr_pluralrules_parser::ast::*;
+use cldr_pluralrules_parser::*;
+
+#[test]
+fn non_literal_modulus() {
+    let test = "n % i = 0";
+
+    assert_eq!(
+        Err(PluralRuleParseError::NonLiteralModulus {
+            operand: 'i',
+            offset: 4,
+        }),
+        parse(test)
+    );
+
+    let test = "v = 0 and n mod t = 1";
+
+    assert_eq!(
+        Err(PluralRuleParseError::NonLiteralModulus {
+            operand: 't',
+            offset: 16,
+        }),
+        parse(test)
+    );
+
+    assert_eq!(
+        Err("modulo divisor must be an integer literal, found operand 'i' at offset 4".to_string()),
+        parse_plural_condition("n % i = 0")
+    );
+}
+
+#[test]
+fn leading_zero() {
+    assert!(parse("n = 0").is_ok());
+    assert!(parse("n = 10").is_ok());
+    assert!(parse("n % 100 = 0,10..19").is_ok());
+    assert_eq!(
+        Err(PluralRuleParseError::LeadingZero { offset: 4 }),
+        parse("n = 07")
+    );
+    assert_eq!(
+        Err(PluralRuleParseError::LeadingZero { offset: 7 }),
+        parse("n = 2..019")
+    );
+    assert_eq!(
+        Err(PluralRuleParseError::LeadingZero { offset: 4 }),
+        parse("n % 010 = 1")
+    );
+    assert_eq!(
+        Err("integer has a leading zero at offset 4".to_string()),
+        parse_plural_condition("n = 07")
+    );
+}
+
+#[test]
+fn zero_modulus() {
+    assert_eq!(
+        Err(PluralRuleParseError::ZeroModulus { offset: 4 }),
+        parse("n % 0 = 1")
+    );
+    assert_eq!(
+        Err(PluralRuleParseError::ZeroModulus { offset: 16 }),
+        parse("v = 0 and i mod 0 = 1")
+    );
+    assert_eq!(
+        Err("modulo divisor must not be zero at offset 4".to_string()),
+        parse_plural_condition("n % 0 = 1")
+    );
+    assert!(parse("n % 10 = 0").is_ok());
+}
+
+#[test]
+fn invalid_syntax() {
+    let test = "n ~ 1";
+
+    assert_eq!(
+        Err(PluralRuleParseError::InvalidSyntax { offset: 2 }),
+        parse(test)
+    );
+}
+
+#[test]
+fn trailing_input() {
+    let test = "n % 10 = 1 garbage";
+
+    assert_eq!(
+        Err(PluralRuleParseError::TrailingInput { offset: 10 }),
+        parse(test)
+    );
+}
+
+#[test]
+fn unexpected_operand() {
+    let test = "i = 1 and x = 0";
+
+    assert_eq!(
+        Err(PluralRuleParseError::UnexpectedOperand {
+            operand: 'x',
+            offset: 10,
+        }),
+        parse(test)
+    );
+
+    assert_eq!(
+        Err("unexpected operand 'z' at offset 0".to_string()),
+        parse_plural_condition("z = 1")
+    );
+}
+
+#[test]
+fn empty_relation() {
+    let test = "i = 1 and @integer 1";
+
+    assert_eq!(
+        Err(PluralRuleParseError::EmptyRelation { offset: 10 }),
+        parse(test)
+    );
+
+    let test = "i = 1 or";
+
+    assert_eq!(
+        Err(PluralRuleParseError::EmptyRelation { offset: 8 }),
+        parse(test)
+    );
+}
+
+#[test]
+fn unclosed_parenthesis() {
+    assert_eq!(
+        Err(PluralRuleParseError::UnclosedParenthesis { offset: 25 }),
+        parse("(n = 1 or n = 2 and i = 0")
+    );
+    assert_eq!(
+        Err(PluralRuleParseError::UnclosedParenthesis { offset: 7 }),
+        parse("(n = 1 @integer 1")
+    );
+    assert_eq!(
+        "expected ')' at offset 7",
+        parse("(n = 1 @integer 1").unwrap_err().to_string()
+    );
+
+    // A parenthesis not followed by a condition is missing a relation.
+    assert_eq!(
+        Err(PluralRuleParseError::EmptyRelation { offset: 11 }),
+        parse("i = 0 and ()")
+    );
+    assert_eq!(
+        Err(PluralRuleParseError::TrailingInput { offset: 5 }),
+        parse("n = 1) and i = 0")
+    );
+}
+
+#[test]
+fn disallowed_operator() {
+    let test = "n within 0..2";
+
+    assert!(parse(test).is_ok());
+    assert!(parse_with(test, &ParseOptions::default()).is_ok());
+    assert_eq!(
+        Err(PluralRuleParseError::DisallowedOperator {
+            operator: ast::Operator::Within,
+            offset: 2,
+        }),
+        parse_with(test, &ParseOptions::modern())
+    );
+
+    let test = "i = 1 and v not in 0..2";
+
+    assert_eq!(
+        Err(PluralRuleParseError::DisallowedOperator {
+            operator: ast::Operator::NotIn,
+            offset: 12,
+        }),
+        parse_with(test, &ParseOptions::modern())
+    );
+
+    let options = ParseOptions {
+        allowed_operators: Some(vec![ast::Operator::EQ]),
+        ..Default::default()
+    };
+
+    assert!(parse_with("n % 10 = 1", &options).is_ok());
+    assert_eq!(
+        "operator '!=' is not allowed at offset 7, allow it in the parse options",
+        parse_with("n % 10 != 1", &options).unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn empty_range_list() {
+    let test = "i = and v = 0";
+
+    assert_eq!(
+        Err(PluralRuleParseError::EmptyRangeList { offset: 4 }),
+        parse(test)
+    );
+
+    let test = "i = 1 and v =";
+
+    assert_eq!(
+        Err(PluralRuleParseError::EmptyRangeList { offset: 13 }),
+        parse(test)
+    );
+
+    assert_eq!(
+        Err("relation has an empty range list at offset 4".to_string()),
+        parse_plural_condition("i = and v = 0")
+    );
+}
+
+#[test]
+fn empty_range_list_for_every_operator() {
+    for (test, offset) in [
+        ("n = ", 4),
+        ("n != @integer 1", 5),
+        ("n is not ", 9),
+        ("n in", 4),
+        ("n not in , 1", 9),
+        ("n within", 8),
+        ("n % 10 not within @integer 1", 18),
+    ] {
+        assert_eq!(
+            Err(PluralRuleParseError::EmptyRangeList { offset }),
+            parse(test),
+            "{}",
+            test
+        );
+    }
+
+    // A single value, or a single range, is a complete range list.
+    for test in [
+        "n = 1",
+        "n is 1",
+        "n in 1 @integer 1",
+        "n within 0..2",
+        "i != 0",
+    ] {
+        let rule = parse(test).expect("Parsing succeeded");
+        assert_eq!(rule.condition.0[0].0[0].range_list.0.len(), 1, "{}", test);
+    }
+}
+
+#[test]
+fn double_equals() {
+    let expected = parse("n = 1").expect("Parsing succeeded");
+    assert_eq!(parse("n=1"), Ok(expected.clone()));
+    assert_eq!(parse("n  =  1"), Ok(expected));
+
+    for (test, offset) in [("n == 1", 2), ("n==1", 1), ("v = 0 and i % 10== 1", 16)] {
+        assert_eq!(
+            Err(PluralRuleParseError::DoubleEquals { offset }),
+            parse(test),
+            "{}",
+            test
+        );
+    }
+    assert_eq!(
+        Err("expected '=' instead of '==' at offset 2".to_string()),
+        parse_plural_condition("n == 1")
+    );
+}
+
+#[test]
+fn invalid_range() {
+    let test = "n % 10 = 5..2";
+
+    assert_eq!(
+        Err(PluralRuleParseError::InvalidRange {
+            range: Range {
+                lower_val: Value(5),
+                upper_val: Value(2),
+            },
+            offset: 9,
+        }),
+        parse(test)
+    );
+
+    assert_eq!(
+        Err("range '12..10' at offset 8 has a lower bound exceeding its upper bound".to_string()),
+        parse_plural_condition("i = 1,3,12..10")
+    );
+
+    let test = "i = 3..3";
+
+    assert_eq!(
+        Ok(Condition(vec![AndCondition(vec![Relation {
+            expression: Expression {
+                operand: Operand::I,
+                modulus: None,
+            },
+            operator: Operator::EQ,
+            range_list: RangeList(vec![RangeListItem::Range(Range {
+                lower_val: Value(3),
+                upper_val: Value(3),
+            })]),
+        }])])),
+        parse_plural_condition(test)
+    );
+}
+
+#[test]
+fn complete_rule() {
+    for test in [
+        "n = 1",
+        "n = 1 @integer 1",
+        "n = 1 @integer 1 @decimal 1.0  ",
+        " @integer 0, 2~16",
+        "",
+    ] {
+        assert_eq!(parse(test.trim_end()), parse_rule_complete(test));
+    }
+
+    assert_eq!(
+        Err(PluralRuleParseError::TrailingInput { offset: 6 }),
+        parse_rule_complete("n = 1 garbage here")
+    );
+    assert_eq!(
+        Err(PluralRuleParseError::TrailingInput { offset: 17 }),
+        parse_rule_complete("n = 1 @integer 1 ;")
+    );
+    // A dangling comma ends the sample list.
+    assert!(parse_rule_complete("n = 1 @integer 1 ,").is_ok());
+}
+
+#[test]
+fn line_break_separator() {
+    // Line breaks don't separate tokens within a rule.
+    let test = "n = 1\nand n = 2";
+
+    assert_eq!(
+        Err(PluralRuleParseError::TrailingInput { offset: 5 }),
+        parse(test)
+    );
+}
+
+#[test]
+fn huge_range_list() {
+    let values: Vec<String> = (0..100_000).map(|value| value.to_string()).collect();
+    let huge = format!("i = {}", values.join(","));
+    let limits = ParseLimits {
+        max_len: usize::MAX,
+        ..Default::default()
+    };
+    let options = ParseOptions {
+        limits,
+        ..Default::default()
+    };
+
+    // The list fails at its first item beyond the limit.
+    let offset = "i = ".len() + values[..256].join(",").len() + 1;
+    assert_eq!(
+        parse_with(&huge, &options),
+        Err(PluralRuleParseError::LimitExceeded {
+            limit: ParseLimit::RangeListItems,
+            max: 256,
+            offset,
+        })
+    );
+    // With the default limits, the length of the rule alone is beyond them.
+    assert_eq!(
+        parse(&huge),
+        Err(PluralRuleParseError::LimitExceeded {
+            limit: ParseLimit::Len,
+            max: 4096,
+            offset: 4096,
+        })
+    );
+    assert_eq!(
+        parse(&huge).unwrap_err().to_string(),
+        "rule exceeds the limit of 4096 bytes at offset 4096"
+    );
+}
+
+#[test]
+fn too_many_relations() {
+    let options = ParseOptions {
+        limits: ParseLimits {
+            max_and_conditions: 2,
+            max_relations: 3,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert!(parse_with("i = 1 and i = 2 and i = 3 or i = 4", &options).is_ok());
+    assert_eq!(
+        parse_with("i = 1 and i = 2 and i = 3 and i = 4", &options),
+        Err(PluralRuleParseError::LimitExceeded {
+            limit: ParseLimit::Relations,
+            max: 3,
+            offset: 30,
+        })
+    );
+    assert_eq!(
+        parse_with("i = 1 or i = 2 or i = 3", &options),
+        Err(PluralRuleParseError::LimitExceeded {
+            limit: ParseLimit::AndConditions,
+            max: 2,
+            offset: 18,
+        })
+    );
+    assert_eq!(
+        parse_with("i = 1 or i = 2 or i = 3", &options)
+            .unwrap_err()
+            .to_string(),
+        "rule exceeds the limit of 2 'or' conditions at offset 18"
+    );
+
+    // Distributed parenthesized conditions count towards the limits.
+    assert!(parse_with("(i = 1 or i = 2) and i = 3", &options).is_ok());
+    assert_eq!(
+        parse_with("(i = 1 or i = 2) and (i = 3 or i = 4)", &options),
+        Err(PluralRuleParseError::LimitExceeded {
+            limit: ParseLimit::AndConditions,
+            max: 2,
+            offset: 37,
+        })
+    );
+    assert_eq!(
+        parse_with("(i = 1 and i = 2) and (i = 3 and i = 4)", &options),
+        Err(PluralRuleParseError::LimitExceeded {
+            limit: ParseLimit::Relations,
+            max: 3,
+            offset: 39,
+        })
+    );
+
+    // A multi-byte character straddling the length limit is left out.
+    let options = ParseOptions {
+        limits: ParseLimits {
+            max_len: 6,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_eq!(
+        parse_with("i = 1\u{00A0}and v = 0", &options).map_err(|err| err.offset()),
+        Err(5)
+    );
+}
+
+#[test]
+fn too_deeply_nested() {
+    let nested = |depth: usize| format!("{}n = 1{}", "(".repeat(depth), ")".repeat(depth));
+
+    assert!(parse(nested(16)).is_ok());
+    assert_eq!(
+        parse(nested(17)),
+        Err(PluralRuleParseError::LimitExceeded {
+            limit: ParseLimit::Depth,
+            max: 16,
+            offset: 16,
+        })
+    );
+    assert_eq!(
+        parse(nested(17)).unwrap_err().to_string(),
+        "rule exceeds the limit of 16 levels of nested parentheses at offset 16"
+    );
+}
+
+#[test]
+fn boxed_errors() {
+    fn relations(source: &str) -> Result<usize, Box<dyn std::error::Error>> {
+        Ok(parse(source)?
+            .condition
+            .0
+            .iter()
+            .map(|and| and.0.len())
+            .sum())
+    }
+
+    assert_eq!(relations("i = 1 and v = 0 or n = 0").ok(), Some(3));
+    for (source, message) in [
+        ("x = 1", "unexpected operand 'x' at offset 0"),
+        (
+            "n = 4..2",
+            "range '4..2' at offset 4 has a lower bound exceeding its upper bound",
+        ),
+        ("n = 1 )", "unexpected input after the rule at offset 5"),
+    ] {
+        let error = relations(source).expect_err("The rule is invalid");
+        assert_eq!(error.to_string(), message);
+        assert!(error.downcast_ref::<PluralRuleParseError>().is_some());
+    }
+}
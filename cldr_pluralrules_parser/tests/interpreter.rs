@@ -0,0 +1,289 @@
+#![cfg(feature = "interpreter")]
+
+use cldr_pluralrules_parser::ast::*;
+use cldr_pluralrules_parser::*;
+use intl_pluralrules::PluralCategory;
+use intl_pluralrules::operands::PluralOperands;
+use intl_pluralrules::test_util::assert_selects;
+use std::convert::TryFrom;
+
+mod common;
+
+#[test]
+fn evaluate_range_welsh() {
+    let rules: Vec<_> = [
+        (
+            PluralCategory::ZERO,
+            "n = 0 @integer 0 @decimal 0.0, 0.00, 0.000, 0.0000",
+        ),
+        (
+            PluralCategory::ONE,
+            "n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000",
+        ),
+        (
+            PluralCategory::TWO,
+            "n = 2 @integer 2 @decimal 2.0, 2.00, 2.000, 2.0000",
+        ),
+        (
+            PluralCategory::FEW,
+            "n = 3 @integer 3 @decimal 3.0, 3.00, 3.000, 3.0000",
+        ),
+        (
+            PluralCategory::MANY,
+            "n = 6 @integer 6 @decimal 6.0, 6.00, 6.000, 6.0000",
+        ),
+    ]
+    .iter()
+    .map(|(category, source)| (*category, parse(source).expect("Parsing succeeded")))
+    .collect();
+
+    assert_eq!(
+        evaluate_range(&rules, 0, 10),
+        vec![
+            (0, PluralCategory::ZERO),
+            (1, PluralCategory::ONE),
+            (2, PluralCategory::TWO),
+            (3, PluralCategory::FEW),
+            (4, PluralCategory::OTHER),
+            (5, PluralCategory::OTHER),
+            (6, PluralCategory::MANY),
+            (7, PluralCategory::OTHER),
+            (8, PluralCategory::OTHER),
+            (9, PluralCategory::OTHER),
+            (10, PluralCategory::OTHER),
+        ]
+    );
+}
+
+#[test]
+fn select_english() {
+    let rules = vec![(
+        PluralCategory::ONE,
+        parse("i = 1 and v = 0 @integer 1").expect("Parsing succeeded"),
+    )];
+    let rule = |po: &PluralOperands| select(&rules, po);
+
+    assert_selects(rule, "1", PluralCategory::ONE);
+    assert_selects(rule, "1.0", PluralCategory::OTHER);
+    assert_selects(rule, "2.50", PluralCategory::OTHER);
+    assert_eq!(
+        select(&rules, &PluralOperands::integer(1)),
+        PluralCategory::ONE
+    );
+    assert_eq!(
+        select(&rules, &PluralOperands::decimal("1.00")),
+        PluralCategory::OTHER
+    );
+}
+
+#[test]
+fn category_cycle_polish() {
+    let rules: Vec<_> = [
+        (PluralCategory::ONE, "i = 1 and v = 0"),
+        (
+            PluralCategory::FEW,
+            "v = 0 and i % 10 = 2..4 and i % 100 != 12..14",
+        ),
+        (
+            PluralCategory::MANY,
+            "v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 12..14",
+        ),
+    ]
+    .iter()
+    .map(|(category, source)| (*category, parse(source).expect("Parsing succeeded")))
+    .collect();
+
+    use PluralCategory::{FEW, MANY, ONE};
+    assert_eq!(
+        category_cycle(&rules, 21),
+        vec![
+            MANY, ONE, FEW, FEW, FEW, MANY, MANY, MANY, MANY, MANY, MANY, MANY, MANY, MANY, MANY,
+            MANY, MANY, MANY, MANY, MANY, MANY,
+        ]
+    );
+    assert_eq!(category_cycle(&rules, 0), Vec::<PluralCategory>::new());
+}
+
+#[test]
+fn integer_n_and_i_agree() {
+    let rules = |source| {
+        vec![(
+            PluralCategory::ONE,
+            parse(source).expect("Parsing succeeded"),
+        )]
+    };
+    let n_rules = rules("n = 7");
+    let i_rules = rules("i = 7");
+
+    for po in [
+        PluralOperands::try_from("7").unwrap(),
+        PluralOperands::from(7_u64),
+        PluralOperands::from(7.0_f64),
+    ] {
+        assert_eq!(select(&n_rules, &po), PluralCategory::ONE);
+        assert_eq!(select(&i_rules, &po), PluralCategory::ONE);
+    }
+}
+
+#[test]
+fn expression_apply() {
+    let expression = |source| {
+        let rule = parse(source).expect("Parsing succeeded");
+        rule.condition.0[0].0[0].expression.clone()
+    };
+
+    let i_mod_10 = expression("i mod 10 = 3");
+    assert_eq!(i_mod_10.apply(&PluralOperands::from(123_u64)), 3.0);
+    assert_eq!(i_mod_10.apply(&PluralOperands::from(10_u64)), 0.0);
+    assert_eq!(
+        i_mod_10.apply(&PluralOperands::try_from("23.5").unwrap()),
+        3.0
+    );
+    assert_eq!(
+        expression("n % 10 = 1").apply(&PluralOperands::try_from("21.5").unwrap()),
+        1.5
+    );
+    assert_eq!(expression("v = 0").apply(&PluralOperands::from(5_u64)), 0.0);
+}
+
+/// Returns the operands of every sample value listed for `rule`.
+fn sample_operands(rule: &ast::Rule) -> Vec<(String, PluralOperands)> {
+    let samples = rule.samples.as_ref().expect("The rule has samples");
+    samples
+        .values()
+        .into_iter()
+        .filter_map(|sample| {
+            let po = PluralOperands::try_from(sample.as_str()).ok()?;
+            Some((sample, po))
+        })
+        .collect()
+}
+
+#[test]
+fn rules_match_their_samples() {
+    let mut checked = 0;
+    for (category, source) in common::cldr_rules() {
+        // `other` has no condition, and holds for any value.
+        if category == "other" {
+            continue;
+        }
+        let rule = parse(&source).expect("Parsing succeeded");
+        for (sample, po) in sample_operands(&rule) {
+            assert!(rule.matches(&po), "{} doesn't match {}", source, sample);
+            checked += 1;
+        }
+    }
+    assert!(checked > 1000);
+}
+
+#[test]
+fn within_matches_fractions() {
+    let within = parse("n within 1..2").expect("Parsing succeeded");
+    let is_in = parse("n = 1..2").expect("Parsing succeeded");
+    let fraction = PluralOperands::try_from("1.5").expect("Valid operands");
+    let integer = PluralOperands::from(2_u64);
+
+    assert!(within.matches(&fraction));
+    assert!(!is_in.matches(&fraction));
+    assert!(within.matches(&integer));
+    assert!(is_in.matches(&integer));
+
+    let not_within = parse("n not within 1..2").expect("Parsing succeeded");
+    let not_in = parse("n != 1..2").expect("Parsing succeeded");
+
+    assert!(!not_within.matches(&fraction));
+    assert!(not_in.matches(&fraction));
+    assert!(parse("").expect("Parsing succeeded").matches(&fraction));
+}
+
+#[test]
+fn within_range_list() {
+    let half = PluralOperands::try_from("2.5").expect("Valid operands");
+
+    assert!(
+        !parse("n in 0..5")
+            .expect("Parsing succeeded")
+            .matches(&half)
+    );
+    assert!(
+        parse("n within 0..5")
+            .expect("Parsing succeeded")
+            .matches(&half)
+    );
+    assert!(
+        parse("n not in 0..5")
+            .expect("Parsing succeeded")
+            .matches(&half)
+    );
+    assert!(
+        !parse("n not within 0..5")
+            .expect("Parsing succeeded")
+            .matches(&half)
+    );
+
+    // Every range of the list is checked, with the modulus keeping the fraction.
+    let within = parse("n % 10 within 0..1, 2..3").expect("Parsing succeeded");
+    assert!(within.matches(&PluralOperands::try_from("12.5").expect("Valid operands")));
+    assert!(!within.matches(&PluralOperands::try_from("11.5").expect("Valid operands")));
+}
+
+#[test]
+fn grouped_conditions_select_alike() {
+    for (grouped, flat) in [
+        (
+            "(n = 1 or n = 2) and i = 0",
+            "n = 1 and i = 0 or n = 2 and i = 0",
+        ),
+        (
+            "(i % 10 = 2..4 or i % 100 = 12..14) and v = 0",
+            "v = 0 and i % 10 = 2..4 or v = 0 and i % 100 = 12..14",
+        ),
+        (
+            "v = 0 and (i = 1 or (f = 3 and i != 4)) or n = 7",
+            "v = 0 and i = 1 or v = 0 and f = 3 and i != 4 or n = 7",
+        ),
+    ] {
+        let grouped = parse(grouped).expect("Parsing succeeded");
+        let flat = parse(flat).expect("Parsing succeeded");
+        let values = (0..200)
+            .map(|n| n.to_string())
+            .chain(["0.3", "1.0", "1.3", "2.5", "12.0", "7.00"].map(String::from));
+        for value in values {
+            let po = PluralOperands::try_from(value.as_str()).unwrap();
+            assert_eq!(grouped.matches(&po), flat.matches(&po), "{}", value);
+        }
+    }
+}
+
+#[test]
+fn programmatic_rule() {
+    let few = Relation::new(
+        Operand::N,
+        Some(10),
+        Operator::EQ,
+        RangeList(vec![RangeListItem::Range(Range {
+            lower_val: Value(2),
+            upper_val: Value(4),
+        })]),
+    );
+    let rule = Rule::from_condition(Condition(vec![
+        AndCondition(vec![
+            Relation::equals(Operand::I, 1),
+            Relation::equals(Operand::V, 0),
+        ]),
+        AndCondition(vec![few]),
+    ]));
+    let parsed = parse("i = 1 and v = 0 or n % 10 = 2..4").expect("Parsing succeeded");
+    assert_eq!(parsed, rule);
+
+    let built = vec![(PluralCategory::ONE, rule)];
+    let parsed = vec![(PluralCategory::ONE, parsed)];
+    assert_eq!(
+        evaluate_range(&built, 0, 100),
+        evaluate_range(&parsed, 0, 100)
+    );
+    for number in ["1.0", "2.5", "3", "14", "1"] {
+        let po = PluralOperands::try_from(number).expect("The number is valid");
+        assert_eq!(select(&built, &po), select(&parsed, &po), "{}", number);
+    }
+}
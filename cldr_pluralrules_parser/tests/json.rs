@@ -0,0 +1,197 @@
+#![cfg(feature = "json")]
+
+use cldr_pluralrules_parser::*;
+use intl_pluralrules::operands::PluralOperands;
+use intl_pluralrules::{PluralCategory, PluralRuleType};
+use unic_langid::LanguageIdentifier;
+
+fn langid(locale: &str) -> LanguageIdentifier {
+    locale.parse().expect("Parsing failed.")
+}
+
+#[test]
+fn parse_cardinal_json() {
+    let locales =
+        parse_plurals_json(include_str!("fixtures/plurals.json")).expect("Parsing succeeded");
+
+    // `root` is skipped.
+    assert_eq!(
+        locales.keys().collect::<Vec<_>>(),
+        [&langid("ar"), &langid("en"), &langid("pl")]
+    );
+
+    let pl = &locales[&langid("pl")];
+    assert_eq!(
+        pl.iter().map(|(category, _)| *category).collect::<Vec<_>>(),
+        [
+            PluralCategory::ONE,
+            PluralCategory::FEW,
+            PluralCategory::MANY,
+            PluralCategory::OTHER,
+        ]
+    );
+    assert_eq!(
+        pl[1].1.condition.to_string(),
+        "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"
+    );
+    assert!(pl[3].1.samples.is_some());
+    assert_eq!(
+        select(pl, &PluralOperands::from(22_u64)),
+        PluralCategory::FEW
+    );
+}
+
+#[test]
+fn select_dynamic_english() {
+    let locales =
+        parse_plurals_json(include_str!("fixtures/plurals.json")).expect("Parsing succeeded");
+
+    for (lang, number, category) in [
+        ("en", 1_u64, PluralCategory::ONE),
+        ("en", 2, PluralCategory::OTHER),
+        ("en", 5, PluralCategory::OTHER),
+        // Locales without rules fall back to their language, and otherwise select `other`.
+        ("en-GB", 1, PluralCategory::ONE),
+        ("xx", 1, PluralCategory::OTHER),
+    ] {
+        assert_eq!(
+            select_dynamic(&locales, &langid(lang), &PluralOperands::from(number)),
+            category,
+            "{} {}",
+            lang,
+            number
+        );
+    }
+    assert_eq!(
+        select_dynamic(&locales, &langid("pl"), &PluralOperands::from(5_u64)),
+        PluralCategory::MANY
+    );
+}
+
+#[test]
+fn parse_ordinal_json() {
+    let locales =
+        parse_plurals_json(include_str!("fixtures/ordinals.json")).expect("Parsing succeeded");
+
+    assert_eq!(locales.len(), 2);
+    assert_eq!(
+        locales[&langid("cy")]
+            .iter()
+            .map(|(category, _)| *category)
+            .collect::<Vec<_>>(),
+        PluralCategory::ALL
+    );
+    assert_eq!(
+        select(&locales[&langid("en")], &PluralOperands::from(23_u64)),
+        PluralCategory::FEW
+    );
+}
+
+#[test]
+fn all_ordinal_rules() {
+    let rules: Vec<_> = all_rules(include_str!("fixtures/ordinals.json"))
+        .expect("Parsing succeeded")
+        .collect();
+
+    // The six categories of `cy` and the four of `en`, without `root`.
+    assert_eq!(rules.len(), 10);
+    assert!(
+        rules
+            .iter()
+            .all(|(_, prt, _, _)| *prt == PluralRuleType::ORDINAL)
+    );
+    let (_, _, _, rule) = rules
+        .iter()
+        .find(|(lang, _, category, _)| *lang == langid("en") && *category == PluralCategory::TWO)
+        .expect("The rule is listed");
+    assert_eq!(rule.condition.to_string(), "n % 10 = 2 and n % 100 != 12");
+
+    assert_eq!(
+        all_rules(r#"{ "supplemental": {} }"#).err(),
+        Some(PluralsJsonError::MissingRules)
+    );
+}
+
+#[test]
+fn invalid_json() {
+    assert!(matches!(
+        parse_plurals_json("{"),
+        Err(PluralsJsonError::InvalidJson(_))
+    ));
+    assert_eq!(
+        parse_plurals_json(r#"{ "supplemental": { "currencyData": {} } }"#),
+        Err(PluralsJsonError::MissingRules)
+    );
+    assert_eq!(
+        parse_plurals_json(
+            r#"{ "supplemental": { "plurals-type-cardinal": {}, "plurals-type-ordinal": {} } }"#
+        ),
+        Err(PluralsJsonError::SeveralRuleTypes)
+    );
+    assert_eq!(
+        parse_plurals_json(r#"{ "supplemental": { "plurals-type-cardinals": {} } }"#),
+        Err(PluralsJsonError::UnknownPluralType("cardinals".to_owned()))
+    );
+    assert_eq!(
+        parse_plurals_json(
+            r#"{ "supplemental": { "plurals-type-cardinal": { "xx": { "pluralRule-count-several": "n = 1" } } } }"#
+        ),
+        Err(PluralsJsonError::UnknownCategory {
+            locale: "xx".to_owned(),
+            name: "pluralRule-count-several".to_owned(),
+        })
+    );
+    assert_eq!(
+        parse_plurals_json(
+            r#"{ "supplemental": { "plurals-type-cardinal": { "xx": { "pluralRule-count-one": "n ~ 1" } } } }"#
+        ),
+        Err(PluralsJsonError::InvalidRule {
+            locale: "xx".to_owned(),
+            category: PluralCategory::ONE,
+            error: PluralRuleParseError::InvalidSyntax { offset: 2 },
+        })
+    );
+}
+
+#[test]
+fn parse_json_value() {
+    let mut rules = serde_json::Map::new();
+    rules.insert(
+        "pluralRule-count-one".to_owned(),
+        "i = 1 and v = 0 @integer 1".into(),
+    );
+    rules.insert(
+        "pluralRule-count-other".to_owned(),
+        " @integer 0, 2~16".into(),
+    );
+    let mut section = serde_json::Map::new();
+    section.insert("en".to_owned(), rules.into());
+    section.insert("root".to_owned(), serde_json::json!({}));
+    let value = serde_json::json!({
+        "supplemental": {
+            "version": { "_cldrVersion": "33" },
+            "plurals-type-cardinal": section,
+        }
+    });
+
+    let locales = parse_plurals_json_value(&value).expect("Parsing succeeded");
+    assert_eq!(locales.keys().collect::<Vec<_>>(), [&langid("en")]);
+    let en = &locales[&langid("en")];
+    assert_eq!(en[0].0, PluralCategory::ONE);
+    assert_eq!(en[0].1.condition.to_string(), "i = 1 and v = 0");
+    assert_eq!(
+        select(en, &PluralOperands::from(1_u64)),
+        PluralCategory::ONE
+    );
+    assert_eq!(
+        select(en, &PluralOperands::from(2_u64)),
+        PluralCategory::OTHER
+    );
+
+    // The same data parses the same as a string.
+    assert_eq!(Ok(locales), parse_plurals_json(&value.to_string()));
+    assert_eq!(
+        parse_plurals_json_value(&serde_json::json!({ "supplemental": {} })),
+        Err(PluralsJsonError::MissingRules)
+    );
+}
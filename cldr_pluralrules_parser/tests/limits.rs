@@ -0,0 +1,30 @@
+use cldr_pluralrules_parser::*;
+
+mod common;
+
+#[test]
+fn default_limits_accept_cldr_rules() {
+    let rules = common::cldr_rules();
+    assert!(rules.len() > 100);
+
+    // Reaching a fraction of any limit would already be far beyond CLDR.
+    let limits = ParseLimits::default();
+    let tight = ParseOptions {
+        limits: ParseLimits {
+            max_len: limits.max_len / 8,
+            max_and_conditions: limits.max_and_conditions / 8,
+            max_relations: limits.max_relations / 8,
+            max_range_list_items: limits.max_range_list_items / 8,
+            max_depth: limits.max_depth / 8,
+        },
+        ..Default::default()
+    };
+    for (locale, source) in rules {
+        assert!(
+            parse_with(&source, &tight).is_ok(),
+            "{}: {}",
+            locale,
+            source
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use cldr_pluralrules_parser::*;
+
+mod common;
+
+fn normalized(source: &str) -> ast::Rule {
+    let mut rule = parse(source).expect("Parsing succeeded");
+    rule.normalize();
+    rule
+}
+
+#[test]
+fn equivalent_rules_normalize_equally() {
+    for (source, equivalent) in [
+        ("n is 1", "n = 1"),
+        ("n is not 1", "n != 1"),
+        ("n in 2..4", "n = 2..4"),
+        ("n not in 2..4", "n != 2..4"),
+        ("n mod 10 is 1", "n % 10 = 1"),
+        ("i = 1..1", "i = 1"),
+        ("i = 5,1,3", "i = 1,3,5"),
+        ("i = 1,1,2..3,2..3", "i = 1,2..3"),
+        ("e = 0", "c = 0"),
+        ("v = 0 and i = 1", "i = 1 and v = 0"),
+        ("i = 1 and i = 1", "i = 1"),
+        ("n = 2 or n = 1", "n = 1 or n = 2"),
+        (
+            "v = 0 and i % 10 is 1 or f mod 10 in 4, 1",
+            "f % 10 = 1,4 or i % 10 = 1 and v = 0",
+        ),
+    ] {
+        assert_eq!(
+            normalized(source).condition,
+            normalized(equivalent).condition,
+            "{} and {}",
+            source,
+            equivalent
+        );
+    }
+}
+
+#[test]
+fn normalize_keeps_meaning() {
+    for (source, other) in [
+        ("n = 1", "n within 1"),
+        ("n != 1", "n not within 1"),
+        ("i = 1..2", "i = 1,2"),
+        ("n % 10 = 1", "n % 100 = 1"),
+    ] {
+        assert_ne!(normalized(source), normalized(other));
+    }
+
+    // The samples are left as written.
+    assert_eq!(
+        normalized("n is 1 @integer 1").samples,
+        parse("n = 1 @integer 1").unwrap().samples
+    );
+}
+
+#[test]
+fn normalize_is_idempotent() {
+    for (_, source) in common::cldr_rules() {
+        let once = normalized(&source);
+        let mut twice = once.clone();
+        twice.normalize();
+        assert_eq!(once, twice, "{}", source);
+
+        // The normalized rule reparses as itself.
+        assert_eq!(normalized(&once.to_string()), once, "{}", source);
+    }
+}
+
+#[test]
+fn negated_operators() {
+    use ast::Operator;
+
+    for (operator, negated) in [
+        (Operator::In, Operator::NotIn),
+        (Operator::Within, Operator::NotWithin),
+        (Operator::Is, Operator::IsNot),
+        (Operator::EQ, Operator::NotEQ),
+    ] {
+        assert_eq!(negated, operator.clone().negate());
+        assert_eq!(operator, negated.clone().negate());
+        assert_eq!(operator, operator.clone().negate().negate());
+    }
+}
+
+#[cfg(feature = "interpreter")]
+#[test]
+fn negated_conditions() {
+    use intl_pluralrules::operands::PluralOperands;
+
+    let numbers: Vec<PluralOperands> = (0..=200_u64)
+        .map(PluralOperands::from)
+        .chain(
+            ["0.0", "0.5", "1.0", "1.5", "2.30", "10.1", "101.01"]
+                .iter()
+                .map(|n| PluralOperands::try_from(*n).expect("Valid operands")),
+        )
+        .collect();
+
+    for (_, source) in common::cldr_rules() {
+        let rule = parse(&source).expect("Parsing succeeded");
+        let Some(condition) = rule.condition.negate() else {
+            assert!(rule.condition.0.is_empty(), "{}", source);
+            continue;
+        };
+        let negated = ast::Rule {
+            condition,
+            samples: None,
+        };
+        for po in &numbers {
+            assert_ne!(
+                rule.matches(po),
+                negated.matches(po),
+                "{} for {:?}",
+                source,
+                po
+            );
+        }
+    }
+}
+
+#[test]
+fn double_negation() {
+    for source in [
+        "n = 1",
+        "i = 1 and v = 0",
+        "n % 10 = 1 and n % 100 != 11 or n within 2..4",
+    ] {
+        let mut twice = parse(source).expect("Parsing succeeded");
+        twice.condition = twice
+            .condition
+            .negate()
+            .and_then(|negated| negated.negate())
+            .expect("The conditions don't always hold");
+        twice.normalize();
+
+        // Distributing the negations back leaves redundant `and` conditions, which select no other numbers.
+        let rule = normalized(source);
+        for and_condition in &rule.condition.0 {
+            assert!(twice.condition.0.contains(and_condition), "{}", source);
+        }
+    }
+}
+
+#[test]
+fn rules_equal_ignores_writing_and_samples() {
+    let rule = parse("n mod 10 in 2..4 and n mod 100 not in 12..14 @integer 2~4").unwrap();
+    let equal = parse("n % 100 != 12..14 and n % 10 = 2..4").unwrap();
+    let distinct = parse("n % 10 = 2..4").unwrap();
+    assert!(rules_equal(&rule, &equal));
+    assert!(rules_equal(&equal, &rule));
+    assert!(!rules_equal(&rule, &distinct));
+}
+
+#[test]
+fn equivalence_classes_group_identical_locales() {
+    let rules = |one: &str| {
+        vec![
+            ("one", parse(one).unwrap()),
+            ("other", parse("@integer 0, 2~16").unwrap()),
+        ]
+    };
+    let locales = [
+        ("de", rules("i = 1 and v = 0 @integer 1")),
+        ("en", rules("v = 0 and i = 1")),
+        ("fr", rules("i = 0,1")),
+        ("nl", rules("i = 1 and v = 0")),
+    ];
+    let classes = equivalence_classes(
+        locales
+            .iter()
+            .map(|(locale, rules)| (*locale, rules.as_slice())),
+    );
+    assert_eq!(classes, [vec!["de", "en", "nl"], vec!["fr"]]);
+
+    // A locale without the category of another is in a class of its own.
+    let without_one = [("other", parse("").unwrap())];
+    let classes = equivalence_classes([("en", &locales[1].1[..]), ("ja", &without_one[..])]);
+    assert_eq!(classes, [vec!["en"], vec!["ja"]]);
+}
@@ -0,0 +1,515 @@
+use cldr_pluralrules_parser::ast::*;
+use cldr_pluralrules_parser::*;
+#[cfg(feature = "interpreter")]
+use intl_pluralrules::PluralCategory;
+
+#[test]
+fn standalone_samples() {
+    let test = "@integer 0, 5~19, 100, … @decimal 0.0, 5.0";
+
+    assert_eq!(
+        Ok(Some(Samples {
+            integer: Some(SampleList {
+                sample_ranges: vec![
+                    SampleRange {
+                        lower_val: DecimalValue {
+                            negative: false,
+                            integer: "0".to_owned(),
+                            decimal: None,
+                            exponent: None,
+                        },
+                        upper_val: None,
+                    },
+                    SampleRange {
+                        lower_val: DecimalValue {
+                            negative: false,
+                            integer: "5".to_owned(),
+                            decimal: None,
+                            exponent: None,
+                        },
+                        upper_val: Some(DecimalValue {
+                            negative: false,
+                            integer: "19".to_owned(),
+                            decimal: None,
+                            exponent: None,
+                        }),
+                    },
+                    SampleRange {
+                        lower_val: DecimalValue {
+                            negative: false,
+                            integer: "100".to_owned(),
+                            decimal: None,
+                            exponent: None,
+                        },
+                        upper_val: None,
+                    },
+                ],
+                ellipsis: true,
+            }),
+            decimal: Some(SampleList {
+                sample_ranges: vec![
+                    SampleRange {
+                        lower_val: DecimalValue {
+                            negative: false,
+                            integer: "0".to_owned(),
+                            decimal: Some("0".to_string()),
+                            exponent: None,
+                        },
+                        upper_val: None,
+                    },
+                    SampleRange {
+                        lower_val: DecimalValue {
+                            negative: false,
+                            integer: "5".to_owned(),
+                            decimal: Some("0".to_string()),
+                            exponent: None,
+                        },
+                        upper_val: None,
+                    },
+                ],
+                ellipsis: false,
+            }),
+        })),
+        parse_samples(test)
+    );
+}
+
+#[test]
+fn standalone_decimal_samples() {
+    let test = " @decimal 1.5";
+
+    assert_eq!(
+        Ok(Some(Samples {
+            integer: None,
+            decimal: Some(SampleList {
+                sample_ranges: vec![SampleRange {
+                    lower_val: DecimalValue {
+                        negative: false,
+                        integer: "1".to_owned(),
+                        decimal: Some("5".to_string()),
+                        exponent: None,
+                    },
+                    upper_val: None,
+                }],
+                ellipsis: false,
+            }),
+        })),
+        parse_samples(test)
+    );
+
+    assert_eq!(Ok(None), parse_samples(""));
+    assert_eq!(
+        Err(PluralRuleParseError::InvalidSyntax { offset: 0 }),
+        parse_samples("i = 1 @integer 1")
+    );
+}
+
+#[test]
+fn samples_in_any_order() {
+    let samples = |source| {
+        parse(source)
+            .expect("Parsing succeeded")
+            .samples
+            .expect("Samples are present")
+    };
+
+    let decimal_first = samples("n = 1 @decimal 1.0, 1.00 @integer 1");
+    assert_eq!(
+        decimal_first,
+        samples("n = 1 @integer 1 @decimal 1.0, 1.00")
+    );
+    assert_eq!(decimal_first.integer.unwrap().sample_ranges.len(), 1);
+    assert_eq!(decimal_first.decimal.unwrap().sample_ranges.len(), 2);
+
+    let reversed = samples(" @decimal 0.0~1.5, 10.0, … @integer 0, 2~16, 100, …");
+    assert_eq!(
+        reversed,
+        samples(" @integer 0, 2~16, 100, … @decimal 0.0~1.5, 10.0, …")
+    );
+    assert!(reversed.integer.unwrap().ellipsis);
+    assert!(reversed.decimal.unwrap().ellipsis);
+
+    let integer_only = samples("i = 1 and v = 0 @integer 1");
+    assert!(integer_only.integer.is_some());
+    assert_eq!(integer_only.decimal, None);
+
+    let decimal_only = samples("n = 1 @decimal 1.0");
+    assert_eq!(decimal_only.integer, None);
+    assert!(decimal_only.decimal.is_some());
+}
+
+#[test]
+fn repeated_sample_section() {
+    assert!(parse("n = 1 @integer 1 @integer 2").is_err());
+    assert!(parse("n = 1 @decimal 1.0 @integer 1 @decimal 2.0").is_err());
+}
+
+#[test]
+fn sample_list_endings() {
+    let skip = ParseOptions {
+        skip_samples: true,
+        ..Default::default()
+    };
+    for (test, ellipsis) in [
+        ("n = 1 @integer 1, 2, … @decimal 1.0", true),
+        ("n = 1 @integer 1, 2 … @decimal 1.0", true),
+        ("n = 1 @integer 1, 2 ... @decimal 1.0", true),
+        ("n = 1 @integer 1, 2, @decimal 1.0", false),
+        ("n = 1 @integer 1, 2,", false),
+    ] {
+        let rule = parse(test).expect("Parsing succeeded");
+        let integer = rule
+            .samples
+            .and_then(|s| s.integer)
+            .expect("Has integer samples");
+        assert_eq!(2, integer.sample_ranges.len(), "{}", test);
+        assert_eq!(ellipsis, integer.ellipsis, "{}", test);
+        assert!(parse_with(test, &skip).is_ok(), "{}", test);
+    }
+}
+
+#[test]
+fn compact_decimal_samples() {
+    let test = "@decimal 1.0c6~1.2c6, 2.05c6, 3c6, …";
+    let samples = parse_samples(test)
+        .expect("Parsing succeeded")
+        .expect("Samples are present")
+        .decimal
+        .expect("Decimal samples are present");
+
+    assert_eq!(
+        SampleRange {
+            lower_val: DecimalValue {
+                negative: false,
+                integer: "1".to_owned(),
+                decimal: Some("0".to_string()),
+                exponent: Some(Value(6)),
+            },
+            upper_val: Some(DecimalValue {
+                negative: false,
+                integer: "1".to_owned(),
+                decimal: Some("2".to_string()),
+                exponent: Some(Value(6)),
+            }),
+        },
+        samples.sample_ranges[0]
+    );
+    assert_eq!("1.0c6~1.2c6, 2.05c6, 3c6, …", samples.to_string());
+
+    assert_eq!(
+        vec![1000000.0, 1100000.0, 1200000.0],
+        samples.sample_ranges[0].expand()
+    );
+    assert_eq!(vec![2050000.0], samples.sample_ranges[1].expand());
+    assert_eq!(vec![3000000.0], samples.sample_ranges[2].expand());
+}
+
+#[test]
+fn compact_locale_rule() {
+    // The `many` rule of French in CLDR 38.
+    let test = "e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5 @integer 1000000, 1c6, 2c6, 3c6, 4c6, 5c6, 6c6, … @decimal 1.0000001c6, 1.1c6, 2.0000001c6, 2.1c6, 3.0000001c6, 3.1c6, …";
+    let samples = parse_plural_rule(test)
+        .expect("Parsing succeeded")
+        .samples
+        .expect("Samples are present");
+
+    let integer = samples.integer.expect("Integer samples are present");
+    assert_eq!(
+        "1000000, 1c6, 2c6, 3c6, 4c6, 5c6, 6c6, …",
+        integer.to_string()
+    );
+    assert_eq!(vec![1000000.0], integer.sample_ranges[1].expand());
+
+    let decimal = samples.decimal.expect("Decimal samples are present");
+    assert_eq!(6, decimal.sample_ranges.len());
+    assert!(decimal.ellipsis);
+    assert_eq!(
+        DecimalValue {
+            negative: false,
+            integer: "1".to_owned(),
+            decimal: Some("0000001".to_string()),
+            exponent: Some(Value(6)),
+        },
+        decimal.sample_ranges[0].lower_val
+    );
+    assert_eq!(vec![1000000.1], decimal.sample_ranges[0].expand());
+    assert_eq!(vec![1100000.0], decimal.sample_ranges[1].expand());
+
+    // The deprecated `e` spelling of the exponent parses to the same samples.
+    let deprecated =
+        parse_samples("@decimal 1.0000001e6, 1.1e6, 2.0000001e6, 2.1e6, 3.0000001e6, 3.1e6, …")
+            .expect("Parsing succeeded")
+            .expect("Samples are present")
+            .decimal
+            .expect("Decimal samples are present");
+    assert_eq!(decimal, deprecated);
+}
+
+#[test]
+fn decimal_samples_round_trip() {
+    let test = "@decimal 0.0, 0.00, 1.05, 0.0~1.5, …";
+    let samples = parse_samples(test)
+        .expect("Parsing succeeded")
+        .expect("Samples are present")
+        .decimal
+        .expect("Decimal samples are present");
+
+    assert_eq!("0.0, 0.00, 1.05, 0.0~1.5, …", samples.to_string());
+    assert_eq!(vec![1.05], samples.sample_ranges[2].expand());
+    assert_eq!(16, samples.sample_ranges[3].expand().len());
+}
+
+#[test]
+fn negative_decimal_samples() {
+    let test = "@decimal -1.0, -1.5, -0.2~-0.0, -1~1";
+    let samples = parse_samples(test)
+        .expect("Parsing succeeded")
+        .expect("Samples are present")
+        .decimal
+        .expect("Decimal samples are present");
+
+    assert_eq!(
+        DecimalValue {
+            negative: true,
+            integer: "1".to_owned(),
+            decimal: Some("0".to_string()),
+            exponent: None,
+        },
+        samples.sample_ranges[0].lower_val
+    );
+    assert_eq!(
+        DecimalValue {
+            negative: true,
+            integer: "1".to_owned(),
+            decimal: Some("5".to_string()),
+            exponent: None,
+        },
+        samples.sample_ranges[1].lower_val
+    );
+    assert_eq!("-1.0, -1.5, -0.2~-0.0, -1~1", samples.to_string());
+    assert_eq!(vec![-1.5], samples.sample_ranges[1].expand());
+
+    // A negative range steps from its lower bound up, and one crossing zero only yields its bounds.
+    assert_eq!(
+        vec!["-0.2", "-0.1", "-0.0"],
+        samples.sample_ranges[2].values()
+    );
+    assert_eq!(vec![-0.2, -0.1, -0.0], samples.sample_ranges[2].expand());
+    assert_eq!(vec!["-1", "1"], samples.sample_ranges[3].values());
+    assert!(parse("i = 0,1 @decimal -1.0, -1.5").is_ok());
+}
+
+#[cfg(feature = "interpreter")]
+#[test]
+fn negative_samples_select_their_magnitude() {
+    let rules = vec![(PluralCategory::ONE, parse("i = 0,1").unwrap())];
+    let one = parse_samples("@decimal -1.0, -1.5, -0.2~-0.0")
+        .expect("Parsing succeeded")
+        .expect("Samples are present");
+    assert!(one.validate_against(&rules, PluralCategory::ONE).is_ok());
+}
+
+#[test]
+fn oversized_decimal_samples() {
+    let test = "@decimal 1.00000000000000000001~1.00000000000000000002";
+    let samples = parse_samples(test)
+        .expect("Parsing succeeded")
+        .expect("Samples are present")
+        .decimal
+        .expect("Decimal samples are present");
+
+    assert_eq!(
+        vec![1.00000000000000000001, 1.00000000000000000002],
+        samples.sample_ranges[0].expand()
+    );
+}
+
+#[test]
+fn huge_samples() {
+    // Stepping through the range, or writing out the digits of the exponent, would exhaust the memory.
+    let samples = parse_samples("@integer 0~18446744073709551615, 1c4000000000, 1~1000")
+        .expect("Parsing succeeded")
+        .expect("Samples are present");
+    let ranges = &samples
+        .integer
+        .as_ref()
+        .expect("Samples are present")
+        .sample_ranges;
+
+    assert_eq!(ranges[0].values(), vec!["0", "18446744073709551615"]);
+    assert_eq!(ranges[0].expand(), vec![0.0, 18446744073709551615.0]);
+    assert_eq!(ranges[1].values(), vec!["1c4000000000"]);
+    assert_eq!(ranges[1].expand(), vec![f64::INFINITY]);
+    // An exponent beyond `i32` saturates rather than wrapping around to `1`.
+    let compact = parse_samples("@integer 1c4294967297~2c4294967297")
+        .expect("Parsing succeeded")
+        .expect("Samples are present");
+    assert_eq!(
+        compact.integer.expect("Samples are present").sample_ranges[0].expand(),
+        vec![f64::INFINITY, f64::INFINITY]
+    );
+    // A range of 1000 values is still stepped through.
+    assert_eq!(ranges[2].values().len(), 1000);
+    assert_eq!(samples.values().len(), 1003);
+}
+
+#[cfg(feature = "interpreter")]
+#[test]
+fn huge_samples_validate() {
+    let samples = parse_samples("@integer 0~18446744073709551615, 1c4000000000, 1~1000")
+        .expect("Parsing succeeded")
+        .expect("Samples are present");
+    let rules = vec![(PluralCategory::ONE, parse("i = 1 and v = 0").unwrap())];
+    assert!(
+        samples
+            .validate_against(&rules, PluralCategory::OTHER)
+            .is_err()
+    );
+}
+
+#[cfg(feature = "interpreter")]
+#[test]
+fn huge_compact_samples_are_unevaluable() {
+    let rules = vec![(PluralCategory::ONE, parse("i = 1 and v = 0").unwrap())];
+    let samples = parse_samples("@integer 0, 18446744073709551616, 1c4000000000")
+        .expect("Parsing succeeded")
+        .expect("Samples are present");
+    assert_eq!(
+        samples.validate_against(&rules, PluralCategory::OTHER),
+        Err(SampleError::Unevaluable {
+            sample: "1c4000000000".to_owned()
+        })
+    );
+}
+
+#[cfg(feature = "interpreter")]
+#[test]
+fn compact_samples() {
+    // The French rules of CLDR 38, whose `many` samples only hold with the compact exponent.
+    let sources = [
+        (
+            PluralCategory::ONE,
+            "i = 0,1 @integer 0, 1 @decimal 0.0~1.5",
+        ),
+        (
+            PluralCategory::MANY,
+            "e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5 \
+             @integer 1000000, 1c6, 2c6, 3c6, 4c6, 5c6, 6c6, … \
+             @decimal 1.0000001c6, 1.1c6, 2.0000001c6, 2.1c6, 3.0000001c6, 3.1c6, …",
+        ),
+        (
+            PluralCategory::OTHER,
+            " @integer 2~17, 100, 1000, 10000, 100000, 1c3, 2c3, 3c3, 4c3, 5c3, 6c3, … \
+             @decimal 2.0~3.5, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, 1.0001c3, 1.1c3, \
+             2.0001c3, 2.1c3, 3.0001c3, 3.1c3, …",
+        ),
+    ];
+    let parsed: Vec<_> = sources
+        .iter()
+        .map(|(category, source)| (*category, parse(source).expect("Parsing succeeded")))
+        .collect();
+    let rules: Vec<_> = parsed
+        .iter()
+        .filter(|(category, _)| *category != PluralCategory::OTHER)
+        .cloned()
+        .collect();
+
+    for (category, rule) in &parsed {
+        let samples = rule.samples.as_ref().expect("Samples are present");
+        assert_eq!(samples.validate_against(&rules, *category), Ok(()));
+    }
+}
+
+#[test]
+fn samples_beyond_u64() {
+    // `u64::MAX` is 18446744073709551615.
+    let test = "n = 1 @integer 18446744073709551615, 18446744073709551616~18446744073709551617, 99999999999999999999c6 @decimal 18446744073709551616.5";
+    let samples = parse(test)
+        .expect("Parsing succeeded")
+        .samples
+        .expect("Samples are present");
+    let integer = samples
+        .integer
+        .as_ref()
+        .expect("Integer samples are present");
+
+    assert_eq!(
+        integer.sample_ranges[1].lower_val.integer,
+        "18446744073709551616"
+    );
+    assert_eq!(
+        integer.to_string(),
+        "18446744073709551615, 18446744073709551616~18446744073709551617, 99999999999999999999c6"
+    );
+    // Ranges beyond `u64` only yield their bounds.
+    assert_eq!(
+        integer.sample_ranges[1].values(),
+        vec!["18446744073709551616", "18446744073709551617"]
+    );
+    assert_eq!(integer.sample_ranges[2].lower_val.to_f64(), 1e26);
+    assert_eq!(samples.values().len(), 5);
+    assert!(
+        parse_with(
+            test,
+            &ParseOptions {
+                skip_samples: true,
+                ..Default::default()
+            }
+        )
+        .is_ok()
+    );
+}
+
+#[test]
+fn samples_without_condition() {
+    for test in [" @integer 0, 2~16", "@integer 0, 2~16"] {
+        let rule = parse(test).expect("Parsing succeeded");
+
+        assert_eq!(Condition(vec![]), rule.condition);
+        assert_eq!(
+            parse_samples("@integer 0, 2~16").expect("Parsing succeeded"),
+            rule.samples
+        );
+    }
+}
+
+#[test]
+fn representative_sample_values() {
+    let samples = parse_samples("@integer 2~17, 100 @decimal 0.1~0.9, 1.5")
+        .expect("Parsing succeeded")
+        .expect("Samples are present");
+
+    let mut expected: Vec<String> = (2..=17).map(|i| i.to_string()).collect();
+    expected.push("100".to_string());
+    // Decimal ranges only yield their endpoints.
+    expected.extend(["0.1", "0.9", "1.5"].map(String::from));
+    assert_eq!(expected, samples.representative_values());
+}
+
+#[test]
+fn skipped_samples() {
+    let options = ParseOptions {
+        skip_samples: true,
+        ..Default::default()
+    };
+    let test = "v = 0 and i % 10 = 1 @integer 1, 21, 31, 41, 51, 61, 71, 81, 101, 1001, … @decimal 0.1~0.9";
+    let rule = parse_with(test, &ParseOptions::default()).expect("Parsing succeeded");
+    let skipped = parse_with(test, &options).expect("Parsing succeeded");
+
+    assert!(rule.samples.is_some());
+    assert_eq!(rule.condition, skipped.condition);
+    assert_eq!(None, skipped.samples);
+
+    // The samples of a rule without a condition are skipped too.
+    assert_eq!(
+        Rule {
+            condition: Condition(vec![]),
+            samples: None,
+        },
+        parse_with(" @integer 0, 2~16, 100 @decimal 0.0~1.5", &options).expect("Parsing succeeded")
+    );
+
+    // Skipped samples are still checked.
+    assert!(parse_with("n = 1 @integer 1~", &options).is_err());
+    assert!(parse_with("n = 1 @integer 1 @integer 2", &options).is_err());
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "serde")]
+
+use cldr_pluralrules_parser::ast::*;
+use cldr_pluralrules_parser::*;
+
+#[test]
+fn json_round_trip() {
+    let rule = parse(
+        "n % 10 = 3..4,9 and n % 100 != 10..19,70..79,90..99 or v = 0 \
+         @integer 3, 4, 9, 23, … @decimal 3.0, 1.1c6~1.5c6",
+    )
+    .expect("Parsing succeeded");
+
+    let json = serde_json::to_string(&rule).expect("Serializing succeeded");
+    let deserialized: Rule = serde_json::from_str(&json).expect("Deserializing succeeded");
+
+    assert_eq!(rule, deserialized);
+}
+
+#[test]
+fn json_shape() {
+    let relation = parse("i = 1").expect("Parsing succeeded").condition.0[0].0[0].clone();
+
+    assert_eq!(
+        r#"{"expression":{"operand":"I","modulus":null},"operator":"EQ","range_list":[{"Value":1}]}"#,
+        serde_json::to_string(&relation).expect("Serializing succeeded")
+    );
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "spans")]
+
+use cldr_pluralrules_parser::spans::RelationSpan;
+use cldr_pluralrules_parser::*;
+
+#[test]
+fn relation_spans() {
+    let source = "n % 10 = 3..4,9 and n % 100 not in 10..19,70..79,90..99 @integer 3, 4, 9";
+    let (rule, spans) =
+        parse_with_spans(source, &ParseOptions::default()).expect("Parsing succeeded");
+
+    assert_eq!(rule, parse(source).expect("Parsing succeeded"));
+    assert_eq!(spans.relations.len(), 1);
+    assert_eq!(spans.relations[0].len(), 2);
+    assert_eq!(
+        spans.relation(0, 1),
+        Some(&RelationSpan {
+            relation: 20..55,
+            expression: 20..27,
+            operator: 28..34,
+            range_list: 35..55,
+        })
+    );
+    let span = spans.relation(0, 1).unwrap();
+    assert_eq!(
+        &source[span.relation.clone()],
+        "n % 100 not in 10..19,70..79,90..99"
+    );
+    assert_eq!(&source[span.expression.clone()], "n % 100");
+    assert_eq!(&source[span.operator.clone()], "not in");
+    assert_eq!(&source[span.range_list.clone()], "10..19,70..79,90..99");
+    assert_eq!(spans.relation(1, 0), None);
+}
+
+#[test]
+fn relation_spans_of_or_conditions() {
+    let source = "i = 1 and v = 0 or   i = 2";
+    let (_, spans) = parse_with_spans(source, &ParseOptions::default()).expect("Parsing succeeded");
+
+    let relations: Vec<Vec<&str>> = spans
+        .relations
+        .iter()
+        .map(|and_condition| {
+            and_condition
+                .iter()
+                .map(|span| &source[span.relation.clone()])
+                .collect()
+        })
+        .collect();
+    assert_eq!(relations, [vec!["i = 1", "v = 0"], vec!["i = 2"]]);
+}
+
+#[test]
+fn spans_without_condition() {
+    for source in ["", "@integer 0, 5~19"] {
+        let (rule, spans) =
+            parse_with_spans(source, &ParseOptions::default()).expect("Parsing succeeded");
+        assert_eq!(rule, parse(source).expect("Parsing succeeded"));
+        assert!(spans.relations.is_empty());
+    }
+}
+
+#[test]
+fn spans_errors() {
+    for source in ["n % i = 0", "n = 1 garbage", "i is 1 and"] {
+        assert_eq!(
+            parse_with_spans(source, &ParseOptions::default()).map(|(rule, _)| rule),
+            parse(source)
+        );
+    }
+}
+
+#[test]
+fn grouped_relation_spans() {
+    let source = "(n = 1 or n = 2) and i = 0";
+    let (rule, spans) =
+        parse_with_spans(source, &ParseOptions::default()).expect("Parsing succeeded");
+
+    assert_eq!(rule, parse(source).expect("Parsing succeeded"));
+    let texts: Vec<Vec<&str>> = spans
+        .relations
+        .iter()
+        .map(|relations| {
+            relations
+                .iter()
+                .map(|span| &source[span.relation.clone()])
+                .collect()
+        })
+        .collect();
+    assert_eq!(texts, [["n = 1", "i = 0"], ["n = 2", "i = 0"]]);
+}
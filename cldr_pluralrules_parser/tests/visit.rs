@@ -0,0 +1,123 @@
+use cldr_pluralrules_parser::ast::*;
+use cldr_pluralrules_parser::visit::{self, Visit};
+use cldr_pluralrules_parser::*;
+use std::collections::BTreeSet;
+
+/// Collects the operands referenced by a rule.
+#[derive(Default)]
+struct Operands(BTreeSet<Operand>);
+
+impl Visit for Operands {
+    fn visit_operand(&mut self, operand: &Operand) {
+        self.0.insert(operand.clone());
+    }
+}
+
+/// Records the operators and values of a rule, and the values of the moduli separately.
+#[derive(Default)]
+struct Nodes {
+    operators: Vec<Operator>,
+    values: Vec<u64>,
+    moduli: Vec<u64>,
+}
+
+impl Visit for Nodes {
+    fn visit_operator(&mut self, operator: &Operator) {
+        self.operators.push(operator.clone());
+    }
+
+    fn visit_modulo(&mut self, modulo: &Modulo) {
+        self.moduli.push(modulo.0.0);
+    }
+
+    fn visit_value(&mut self, value: &Value) {
+        self.values.push(value.0);
+    }
+}
+
+#[test]
+fn collect_operands() {
+    // The Polish `many` rule.
+    let rule = parse(
+        "v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 12..14 @integer 0, 5~19",
+    )
+    .expect("Parsing succeeded");
+
+    let mut operands = Operands::default();
+    operands.visit_rule(&rule);
+    assert_eq!(operands.0, BTreeSet::from([Operand::I, Operand::V]));
+
+    let rule = parse("n % 10 = 1 and t != 0 or e = 5").expect("Parsing succeeded");
+    let mut operands = Operands::default();
+    operands.visit_rule(&rule);
+    assert_eq!(
+        operands.0,
+        BTreeSet::from([Operand::N, Operand::T, Operand::E])
+    );
+}
+
+#[test]
+fn visit_in_source_order() {
+    let rule = parse("n % 10 = 1..3, 7 and i % 100 not in 11, 12").expect("Parsing succeeded");
+
+    let mut nodes = Nodes::default();
+    nodes.visit_rule(&rule);
+    assert_eq!(nodes.operators, [Operator::EQ, Operator::NotIn]);
+    assert_eq!(nodes.values, [1, 3, 7, 11, 12]);
+    assert_eq!(nodes.moduli, [10, 100]);
+
+    // Walking on from an overriding method also visits the children.
+    struct Moduli(Vec<u64>);
+    impl Visit for Moduli {
+        fn visit_modulo(&mut self, modulo: &Modulo) {
+            self.0.push(modulo.0.0);
+            visit::walk_modulo(self, modulo);
+        }
+
+        fn visit_value(&mut self, value: &Value) {
+            self.0.push(value.0);
+        }
+    }
+    let mut moduli = Moduli(Vec::new());
+    moduli.visit_condition(&rule.condition);
+    assert_eq!(moduli.0, [10, 10, 1, 3, 7, 100, 100, 11, 12]);
+}
+
+#[test]
+fn operands_and_operators_used() {
+    let rule = |source| parse(source).expect("Parsing succeeded");
+
+    // The `one` rule of English depends on `i` and `v` only.
+    let en_one = rule("i = 1 and v = 0 @integer 1");
+    assert_eq!(
+        en_one.operands_used(),
+        BTreeSet::from([Operand::I, Operand::V])
+    );
+    assert_eq!(en_one.operators_used(), BTreeSet::from([Operator::EQ]));
+
+    let lv_one = rule(
+        "n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 or v != 2 and f % 10 = 1",
+    );
+    assert_eq!(
+        lv_one.operands_used(),
+        BTreeSet::from([Operand::N, Operand::V, Operand::F])
+    );
+    assert_eq!(
+        lv_one.operators_used(),
+        BTreeSet::from([Operator::EQ, Operator::NotEQ])
+    );
+
+    let within = rule("n within 0..2 and n is not 2 and i not in 3..5");
+    assert_eq!(
+        within.operands_used(),
+        BTreeSet::from([Operand::N, Operand::I])
+    );
+    assert_eq!(
+        within.operators_used(),
+        BTreeSet::from([Operator::Within, Operator::IsNot, Operator::NotIn])
+    );
+
+    let other = rule(" @integer 0, 2~16");
+    assert!(other.operands_used().is_empty());
+    assert!(other.operators_used().is_empty());
+}
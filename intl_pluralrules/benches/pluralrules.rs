@@ -3,7 +3,9 @@ use criterion::Criterion;
 use criterion::criterion_group;
 use criterion::criterion_main;
 
-use intl_pluralrules::{PluralRuleType, PluralRules};
+use intl_pluralrules::{PluralRuleType, PluralRules, operands::PluralOperands};
+use std::hint::black_box;
+use std::str::FromStr;
 use unic_langid::{LanguageIdentifier, langid};
 
 fn plural_rules(c: &mut Criterion) {
@@ -65,5 +67,116 @@ fn plural_rules(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, plural_rules,);
+// Selecting the category of the 207 values of `all_locales_samples` with the rules of the 309
+// cardinal and ordinal locales of CLDR 37:
+//
+//   conditions in category name order:            209 µs .. 246 µs
+//   conditions ordered by matches among 0..=200:  209 µs .. 244 µs
+//
+// Most values select `other`, which is only reached once every condition failed, so the order of
+// the conditions makes no measurable difference and the generator keeps the category name order.
+fn all_locales(c: &mut Criterion) {
+    let rules: Vec<PluralRules> = [PluralRuleType::CARDINAL, PluralRuleType::ORDINAL]
+        .into_iter()
+        .flat_map(|prt| {
+            PluralRules::get_locales(prt)
+                .into_iter()
+                .map(move |lang| PluralRules::create(lang, prt).unwrap())
+        })
+        .collect();
+    let samples = all_locales_samples();
+
+    c.bench_with_input(
+        BenchmarkId::new("select_all_locales", rules.len() * samples.len()),
+        &samples,
+        |b, samples| {
+            b.iter(|| {
+                for ipr in &rules {
+                    for operands in samples {
+                        black_box(ipr.select(operands.clone()).unwrap());
+                    }
+                }
+            });
+        },
+    );
+}
+
+// Constructing the operands of 1000 formatted numbers for the rules of `pl`, which read `i` and `v`:
+//
+//   integers, `PluralOperands::try_from`:        40 µs .. 44 µs
+//   integers, `PluralRules::minimal_operands`:   17 µs .. 19 µs
+//   decimals, `PluralOperands::try_from`:        50 µs .. 54 µs
+//   decimals, `PluralRules::minimal_operands`:   44 µs .. 46 µs
+//
+// Integers skip the float parse altogether, while decimals still scan their fraction digits.
+fn minimal_operands(c: &mut Criterion) {
+    let ipr = PluralRules::create(langid!("pl"), PluralRuleType::CARDINAL).unwrap();
+    let integers: Vec<String> = (0..1000_u64).map(|n| (n * 7919).to_string()).collect();
+    let decimals: Vec<String> = (0..1000_u64)
+        .map(|n| format!("{}.{:02}", n * 7919, n % 100))
+        .collect();
+
+    for (name, numbers) in [("integers", &integers), ("decimals", &decimals)] {
+        c.bench_with_input(
+            BenchmarkId::new(format!("full_operands_{}", name), numbers.len()),
+            numbers,
+            |b, numbers| {
+                b.iter(|| {
+                    for number in numbers {
+                        black_box(PluralOperands::try_from(number.as_str()).unwrap());
+                    }
+                });
+            },
+        );
+        c.bench_with_input(
+            BenchmarkId::new(format!("minimal_operands_{}", name), numbers.len()),
+            numbers,
+            |b, numbers| {
+                b.iter(|| {
+                    for number in numbers {
+                        black_box(ipr.minimal_operands(number).unwrap());
+                    }
+                });
+            },
+        );
+    }
+}
+
+// Constructing the operands of `42`:
+//
+//   `PluralOperands::from_str("42")`:     23 ns .. 24 ns
+//   `PluralOperands::from_integer(42)`:   1.7 ns .. 1.8 ns
+//
+// About 13 times faster, as `from_integer` neither parses the digits twice, as an `f64` and a `u64`,
+// nor looks for fraction digits. Both yield the same operands.
+fn integer_operands(c: &mut Criterion) {
+    assert_eq!(
+        PluralOperands::from_str("42").unwrap(),
+        PluralOperands::from_integer(42)
+    );
+
+    c.bench_function("integer_operands_from_str", |b| {
+        b.iter(|| PluralOperands::from_str(black_box("42")).unwrap())
+    });
+    c.bench_function("integer_operands_from_integer", |b| {
+        b.iter(|| PluralOperands::from_integer(black_box(42)))
+    });
+}
+
+/// The integers up to 200 and a few decimals, mostly selecting the common categories such as `one` and `other`.
+fn all_locales_samples() -> Vec<PluralOperands> {
+    let mut samples: Vec<PluralOperands> = (0..=200_u64).map(PluralOperands::from).collect();
+    for decimal in ["0.5", "1.0", "1.5", "2.25", "10.1", "101.01"] {
+        samples.push(decimal.parse().unwrap());
+    }
+    samples
+}
+
+criterion_group!(
+    benches,
+    plural_rules,
+    all_locales,
+    minimal_operands,
+    integer_operands,
+);
 criterion_main!(benches);
@@ -28,9 +28,18 @@
 /// A public AST module for plural rule representations.
 pub mod operands;
 #[cfg(not(tarpaulin_include))]
+#[rustfmt::skip]
 mod rules;
+/// A public module of test helpers.
+#[cfg(feature = "test-util")]
+pub mod test_util;
+/// A public module binding plural selection to JavaScript.
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
+use std::fmt;
+use std::str::FromStr;
 
 use unic_langid::LanguageIdentifier;
 
@@ -39,7 +48,9 @@ use crate::rules::*;
 
 /// A public enum for handling the plural category.
 /// Each plural category will vary, depending on the language that is being used and whether that language has that plural category.
-#[derive(Debug, Eq, PartialEq)]
+///
+/// Categories are ordered as CLDR lists them, from `ZERO` to `OTHER`.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, PartialOrd, Ord)]
 pub enum PluralCategory {
     ZERO,
     ONE,
@@ -49,8 +60,198 @@ pub enum PluralCategory {
     OTHER,
 }
 
+impl PluralCategory {
+    /// All categories, in CLDR order.
+    pub const ALL: [PluralCategory; 6] = [
+        PluralCategory::ZERO,
+        PluralCategory::ONE,
+        PluralCategory::TWO,
+        PluralCategory::FEW,
+        PluralCategory::MANY,
+        PluralCategory::OTHER,
+    ];
+
+    /// Returns an iterator over all categories, in CLDR order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intl_pluralrules::PluralCategory;
+    ///
+    /// let keywords: Vec<String> = PluralCategory::all().map(|c| c.to_string()).collect();
+    /// assert_eq!(keywords, ["zero", "one", "two", "few", "many", "other"]);
+    /// ```
+    pub fn all() -> impl ExactSizeIterator<Item = PluralCategory> {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the CLDR keyword of the category, such as `one`, as displayed by `Display`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intl_pluralrules::PluralCategory;
+    ///
+    /// const KEYWORD: &str = PluralCategory::FEW.as_str();
+    /// assert_eq!(KEYWORD, "few");
+    /// ```
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            PluralCategory::ZERO => "zero",
+            PluralCategory::ONE => "one",
+            PluralCategory::TWO => "two",
+            PluralCategory::FEW => "few",
+            PluralCategory::MANY => "many",
+            PluralCategory::OTHER => "other",
+        }
+    }
+
+    /// Returns the stable code of the category, from `0` for `ZERO` to `5` for `OTHER` in CLDR order,
+    /// independent of the layout of the enum, such as for passing it over FFI or storing it compactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intl_pluralrules::PluralCategory;
+    ///
+    /// const CODE: u8 = PluralCategory::FEW.to_u8();
+    /// assert_eq!(CODE, 3);
+    /// assert_eq!(PluralCategory::from_u8(CODE), Some(PluralCategory::FEW));
+    /// ```
+    pub const fn to_u8(&self) -> u8 {
+        match self {
+            PluralCategory::ZERO => 0,
+            PluralCategory::ONE => 1,
+            PluralCategory::TWO => 2,
+            PluralCategory::FEW => 3,
+            PluralCategory::MANY => 4,
+            PluralCategory::OTHER => 5,
+        }
+    }
+
+    /// Returns the category of a code returned by [`PluralCategory::to_u8`], or `None` above `5`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intl_pluralrules::PluralCategory;
+    ///
+    /// assert_eq!(PluralCategory::from_u8(0), Some(PluralCategory::ZERO));
+    /// assert_eq!(PluralCategory::from_u8(6), None);
+    /// ```
+    pub const fn from_u8(code: u8) -> Option<PluralCategory> {
+        match code {
+            0 => Some(PluralCategory::ZERO),
+            1 => Some(PluralCategory::ONE),
+            2 => Some(PluralCategory::TWO),
+            3 => Some(PluralCategory::FEW),
+            4 => Some(PluralCategory::MANY),
+            5 => Some(PluralCategory::OTHER),
+            _ => None,
+        }
+    }
+
+    /// Returns the value given for the category, out of one per category in CLDR order.
+    ///
+    /// Unlike a `match`, this has no wildcard arm to leave categories unhandled. Values computed
+    /// only for the selected category can be given as closures, such as `fn() -> T`, and called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intl_pluralrules::PluralCategory;
+    ///
+    /// let message = PluralCategory::ONE.fold("no files", "a file", "two files", "files", "files", "files");
+    /// assert_eq!(message, "a file");
+    /// ```
+    pub fn fold<T>(&self, zero: T, one: T, two: T, few: T, many: T, other: T) -> T {
+        match self {
+            PluralCategory::ZERO => zero,
+            PluralCategory::ONE => one,
+            PluralCategory::TWO => two,
+            PluralCategory::FEW => few,
+            PluralCategory::MANY => many,
+            PluralCategory::OTHER => other,
+        }
+    }
+}
+
+/// Displays the category as its CLDR keyword, such as `one`.
+///
+/// # Examples
+///
+/// ```
+/// use intl_pluralrules::PluralCategory;
+///
+/// assert_eq!(PluralCategory::FEW.to_string(), "few");
+/// ```
+impl fmt::Display for PluralCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parses a CLDR keyword, such as `one`, into its category.
+///
+/// # Examples
+///
+/// ```
+/// use intl_pluralrules::PluralCategory;
+///
+/// assert_eq!("many".parse(), Ok(PluralCategory::MANY));
+/// assert!("MANY".parse::<PluralCategory>().is_err());
+/// ```
+impl FromStr for PluralCategory {
+    type Err = &'static str;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "zero" => Ok(PluralCategory::ZERO),
+            "one" => Ok(PluralCategory::ONE),
+            "two" => Ok(PluralCategory::TWO),
+            "few" => Ok(PluralCategory::FEW),
+            "many" => Ok(PluralCategory::MANY),
+            "other" => Ok(PluralCategory::OTHER),
+            _ => Err("Unknown plural category"),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PluralCategory {
+    type Error = &'static str;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        input.parse()
+    }
+}
+
+/// Compares a category with a CLDR keyword, as returned by [`PluralCategory::as_str`].
+///
+/// A string which is not a CLDR keyword equals no category.
+///
+/// # Examples
+///
+/// ```
+/// use intl_pluralrules::PluralCategory;
+///
+/// assert!(PluralCategory::ONE == "one");
+/// assert!(PluralCategory::ONE != "other");
+/// assert!(PluralCategory::ONE != "ONE");
+/// ```
+impl PartialEq<str> for PluralCategory {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for PluralCategory {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
 /// A public enum for handling plural type.
-#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum PluralRuleType {
     /// Ordinal numbers express position or rank in a sequence. [More about oridinal numbers](https://en.wikipedia.org/wiki/Ordinal_number_(linguistics))
     ORDINAL,
@@ -58,10 +259,68 @@ pub enum PluralRuleType {
     CARDINAL,
 }
 
+impl PluralRuleType {
+    /// Returns the CLDR name of the plural rule type, as in the `plurals-type-<name>` sections of CLDR data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intl_pluralrules::PluralRuleType;
+    ///
+    /// assert_eq!(PluralRuleType::ORDINAL.as_str(), "ordinal");
+    /// ```
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            PluralRuleType::ORDINAL => "ordinal",
+            PluralRuleType::CARDINAL => "cardinal",
+        }
+    }
+}
+
+/// Displays the plural rule type as its CLDR name, such as `cardinal`.
+impl fmt::Display for PluralRuleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parses a CLDR name, such as `cardinal`, into its plural rule type.
+///
+/// # Examples
+///
+/// ```
+/// use intl_pluralrules::PluralRuleType;
+///
+/// assert_eq!("cardinal".parse(), Ok(PluralRuleType::CARDINAL));
+/// assert!("cardinals".parse::<PluralRuleType>().is_err());
+/// ```
+impl FromStr for PluralRuleType {
+    type Err = &'static str;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "ordinal" => Ok(PluralRuleType::ORDINAL),
+            "cardinal" => Ok(PluralRuleType::CARDINAL),
+            _ => Err("Unknown plural rule type"),
+        }
+    }
+}
+
 // pub use rules::PluralRuleType;
 /// CLDR_VERSION is the version of CLDR extracted from the file used to generate rules.rs.
 pub use crate::rules::CLDR_VERSION;
 
+/// Returns the rule at `idx` in the table for the plural rule type, in the order of `PluralRules::get_locales`.
+///
+/// This is used by `intl_pluralrules_macros` to embed rules resolved at compile time, and is not meant to be used directly.
+#[doc(hidden)]
+pub const fn __rule_at(prt: PluralRuleType, idx: usize) -> fn(&PluralOperands) -> PluralCategory {
+    match prt {
+        PluralRuleType::CARDINAL => rules::PRS_CARDINAL[idx].1,
+        PluralRuleType::ORDINAL => rules::PRS_ORDINAL[idx].1,
+    }
+}
+
 /// The main structure for selecting plural rules.
 ///
 /// # Examples
@@ -80,6 +339,8 @@ pub use crate::rules::CLDR_VERSION;
 pub struct PluralRules {
     locale: LanguageIdentifier,
     function: PluralRule,
+    categories: &'static [PluralCategory],
+    operands: &'static str,
 }
 
 impl PluralRules {
@@ -103,20 +364,26 @@ impl PluralRules {
         prt: PluralRuleType,
     ) -> Result<Self, &'static str> {
         let langid = langid.into();
-        let returned_rule = match prt {
-            PluralRuleType::CARDINAL => {
-                let idx = rules::PRS_CARDINAL.binary_search_by_key(&&langid, |(l, _)| l);
-                idx.map(|idx| rules::PRS_CARDINAL[idx].1)
-            }
-            PluralRuleType::ORDINAL => {
-                let idx = rules::PRS_ORDINAL.binary_search_by_key(&&langid, |(l, _)| l);
-                idx.map(|idx| rules::PRS_ORDINAL[idx].1)
-            }
+        let (prs, categories, operands) = match prt {
+            PluralRuleType::CARDINAL => (
+                rules::PRS_CARDINAL,
+                rules::PRS_CARDINAL_CATEGORIES,
+                rules::PRS_CARDINAL_OPERANDS,
+            ),
+            PluralRuleType::ORDINAL => (
+                rules::PRS_ORDINAL,
+                rules::PRS_ORDINAL_CATEGORIES,
+                rules::PRS_ORDINAL_OPERANDS,
+            ),
         };
-        match returned_rule {
-            Ok(returned_rule) => Ok(Self {
+        // The tables are generated from the same sorted list of locales, so they share indices.
+        let idx = prs.binary_search_by_key(&&langid, |(l, _)| l);
+        match idx {
+            Ok(idx) => Ok(Self {
                 locale: langid,
-                function: returned_rule,
+                function: prs[idx].1,
+                categories: categories[idx].1,
+                operands: operands[idx],
             }),
             Err(_) => Err("unknown locale"),
         }
@@ -149,6 +416,30 @@ impl PluralRules {
         }
     }
 
+    /// Returns the plural category of each of `numbers`, resolving the rule of the locale only once.
+    ///
+    /// Fails like `create` if the locale is unknown.
+    ///
+    /// # Examples
+    /// ```
+    /// use intl_pluralrules::{PluralRules, PluralRuleType, PluralCategory};
+    /// use unic_langid::LanguageIdentifier;
+    ///
+    /// let langid: LanguageIdentifier = "naq".parse().expect("Parsing failed.");
+    /// let categories: Vec<_> = PluralRules::categorize(langid, PluralRuleType::CARDINAL, [1.into(), 2.into()])
+    ///     .unwrap()
+    ///     .collect();
+    /// assert_eq!(categories, [PluralCategory::ONE, PluralCategory::TWO]);
+    /// ```
+    pub fn categorize<L: Into<LanguageIdentifier>>(
+        langid: L,
+        prt: PluralRuleType,
+        numbers: impl IntoIterator<Item = PluralOperands>,
+    ) -> Result<impl Iterator<Item = PluralCategory>, &'static str> {
+        let pr = Self::create(langid, prt)?.function;
+        Ok(numbers.into_iter().map(move |ops| pr(&ops)))
+    }
+
     /// Returns a list of the available locales.
     ///
     /// # Examples
@@ -168,6 +459,53 @@ impl PluralRules {
         prs.iter().map(|(l, _)| l.clone()).collect()
     }
 
+    /// Returns the best supported locale for a list of requested locales, in order of preference.
+    ///
+    /// Each requested locale is matched exactly first, then with its variants, region and script
+    /// removed in turn. The first requested locale with a match wins.
+    ///
+    /// # Examples
+    /// ```
+    /// use intl_pluralrules::{PluralRules, PluralRuleType};
+    /// use unic_langid::LanguageIdentifier;
+    ///
+    /// let requested: Vec<LanguageIdentifier> = vec![
+    ///     "fr-CA".parse().expect("Parsing failed."),
+    ///     "en".parse().expect("Parsing failed."),
+    /// ];
+    /// assert_eq!(
+    ///     PluralRules::negotiate(&requested, PluralRuleType::CARDINAL),
+    ///     Some("fr".parse().expect("Parsing failed."))
+    /// );
+    /// ```
+    pub fn negotiate(
+        requested: &[LanguageIdentifier],
+        prt: PluralRuleType,
+    ) -> Option<LanguageIdentifier> {
+        let prs = match prt {
+            PluralRuleType::CARDINAL => rules::PRS_CARDINAL,
+            PluralRuleType::ORDINAL => rules::PRS_ORDINAL,
+        };
+        let is_supported =
+            |langid: &LanguageIdentifier| prs.binary_search_by_key(&langid, |(l, _)| l).is_ok();
+        requested.iter().find_map(|langid| {
+            let mut candidate = langid.clone();
+            if is_supported(&candidate) {
+                return Some(candidate);
+            }
+            candidate.clear_variants();
+            if is_supported(&candidate) {
+                return Some(candidate);
+            }
+            candidate.region = None;
+            if is_supported(&candidate) {
+                return Some(candidate);
+            }
+            candidate.script = None;
+            is_supported(&candidate).then_some(candidate)
+        })
+    }
+
     /// Returns the locale name for this PluralRule instance.
     ///
     /// # Examples
@@ -182,11 +520,171 @@ impl PluralRules {
     pub fn get_locale(&self) -> &LanguageIdentifier {
         &self.locale
     }
+
+    /// Returns the categories this PluralRule instance can select, in CLDR order.
+    ///
+    /// # Examples
+    /// ```
+    /// use intl_pluralrules::{PluralRules, PluralRuleType, PluralCategory};
+    /// use unic_langid::LanguageIdentifier;
+    ///
+    /// let langid: LanguageIdentifier = "naq".parse().expect("Parsing failed.");
+    /// let pr_naq = PluralRules::create(langid, PluralRuleType::CARDINAL).unwrap();
+    /// assert_eq!(
+    ///     pr_naq.categories(),
+    ///     &[PluralCategory::ONE, PluralCategory::TWO, PluralCategory::OTHER]
+    /// );
+    /// ```
+    pub fn categories(&self) -> &'static [PluralCategory] {
+        self.categories
+    }
+
+    /// Returns the letters of the operands the rules of this PluralRule instance read, such as `"iv"`.
+    ///
+    /// # Examples
+    /// ```
+    /// use intl_pluralrules::{PluralRules, PluralRuleType};
+    /// use unic_langid::LanguageIdentifier;
+    ///
+    /// let langid: LanguageIdentifier = "en".parse().expect("Parsing failed.");
+    /// let pr_en = PluralRules::create(langid, PluralRuleType::CARDINAL).unwrap();
+    /// assert_eq!(pr_en.operands(), "iv");
+    /// ```
+    pub fn operands(&self) -> &'static str {
+        self.operands
+    }
+
+    /// Returns the operands of a decimal string, only deriving those the rules of this PluralRule instance read.
+    ///
+    /// This skips work in loops formatting many numbers, as most rules only read `i` and `v`. The operands
+    /// select the same category as those of `PluralOperands::from_str`, but their other fields are `0`.
+    /// See `PluralOperands::parse_minimal`.
+    ///
+    /// # Examples
+    /// ```
+    /// use intl_pluralrules::{PluralRules, PluralRuleType, PluralCategory};
+    /// use unic_langid::LanguageIdentifier;
+    ///
+    /// let langid: LanguageIdentifier = "en".parse().expect("Parsing failed.");
+    /// let pr_en = PluralRules::create(langid, PluralRuleType::CARDINAL).unwrap();
+    /// let operands = pr_en.minimal_operands("1.5").unwrap();
+    /// assert_eq!(operands.f, 0);
+    /// assert_eq!(pr_en.select(operands), Ok(PluralCategory::OTHER));
+    /// ```
+    pub fn minimal_operands(&self, number: &str) -> Result<PluralOperands, &'static str> {
+        PluralOperands::parse_minimal(number, self.operands)
+    }
+}
+
+/// Returns the plural category of `operands` for a locale, in a single call.
+///
+/// The locale falls back as in `PluralRules::negotiate`, so `en-US` uses the rules of `en`. An unknown locale
+/// selects `OTHER`.
+///
+/// # Examples
+/// ```
+/// use intl_pluralrules::{category_for, PluralRuleType, PluralCategory};
+/// use intl_pluralrules::operands::PluralOperands;
+/// use unic_langid::LanguageIdentifier;
+///
+/// let langid: LanguageIdentifier = "en-US".parse().expect("Parsing failed.");
+/// let operands = PluralOperands::from(2);
+/// assert_eq!(category_for(&langid, PluralRuleType::ORDINAL, &operands), PluralCategory::TWO);
+/// ```
+pub fn category_for(
+    langid: &LanguageIdentifier,
+    prt: PluralRuleType,
+    operands: &PluralOperands,
+) -> PluralCategory {
+    PluralRules::negotiate(std::slice::from_ref(langid), prt)
+        .and_then(|langid| PluralRules::create(langid, prt).ok())
+        .map_or(PluralCategory::OTHER, |pr| (pr.function)(operands))
+}
+
+/// Returns the categories a locale can select, in CLDR order: those with a rule of their own, and `OTHER`.
+///
+/// The locale falls back as in `category_for`, and an unknown locale only selects `OTHER`.
+///
+/// # Examples
+/// ```
+/// use intl_pluralrules::{categories_for, PluralRuleType, PluralCategory};
+/// use unic_langid::LanguageIdentifier;
+///
+/// let langid: LanguageIdentifier = "ja".parse().expect("Parsing failed.");
+/// assert_eq!(categories_for(&langid, PluralRuleType::CARDINAL), &[PluralCategory::OTHER]);
+/// ```
+pub fn categories_for(
+    langid: &LanguageIdentifier,
+    prt: PluralRuleType,
+) -> &'static [PluralCategory] {
+    PluralRules::negotiate(std::slice::from_ref(langid), prt)
+        .and_then(|langid| PluralRules::create(langid, prt).ok())
+        .map_or(&[PluralCategory::OTHER], |pr| pr.categories)
+}
+
+/// The cardinal and ordinal rules of a locale, resolved once to select the categories of many numbers.
+///
+/// The locale falls back as in `category_for`, and an unknown locale selects `OTHER` for every number.
+///
+/// # Examples
+/// ```
+/// use intl_pluralrules::{LocalePluralRules, PluralCategory};
+/// use unic_langid::LanguageIdentifier;
+///
+/// let langid: LanguageIdentifier = "en-US".parse().expect("Parsing failed.");
+/// let pr_en = LocalePluralRules::new(langid);
+/// assert_eq!(pr_en.cardinal(&2.into()), PluralCategory::OTHER);
+/// assert_eq!(pr_en.ordinal(&2.into()), PluralCategory::TWO);
+/// ```
+#[derive(Clone)]
+pub struct LocalePluralRules {
+    locale: LanguageIdentifier,
+    cardinal: PluralRule,
+    ordinal: PluralRule,
+}
+
+impl LocalePluralRules {
+    /// Resolves the cardinal and ordinal rules of a locale.
+    pub fn new<L: Into<LanguageIdentifier>>(langid: L) -> Self {
+        let locale = langid.into();
+        let resolve = |prt| {
+            PluralRules::negotiate(std::slice::from_ref(&locale), prt)
+                .and_then(|langid| PluralRules::create(langid, prt).ok())
+                .map_or(select_other as PluralRule, |pr| pr.function)
+        };
+        Self {
+            cardinal: resolve(PluralRuleType::CARDINAL),
+            ordinal: resolve(PluralRuleType::ORDINAL),
+            locale,
+        }
+    }
+
+    /// Returns the cardinal category of the operands.
+    pub fn cardinal(&self, operands: &PluralOperands) -> PluralCategory {
+        (self.cardinal)(operands)
+    }
+
+    /// Returns the ordinal category of the operands.
+    pub fn ordinal(&self, operands: &PluralOperands) -> PluralCategory {
+        (self.ordinal)(operands)
+    }
+
+    /// Returns the locale the rules were resolved for, before falling back.
+    pub fn get_locale(&self) -> &LanguageIdentifier {
+        &self.locale
+    }
+}
+
+fn select_other(_: &PluralOperands) -> PluralCategory {
+    PluralCategory::OTHER
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{CLDR_VERSION, PluralCategory, PluralRuleType, PluralRules};
+    use super::{
+        CLDR_VERSION, LocalePluralRules, PluralCategory, PluralRuleType, PluralRules,
+        categories_for, category_for,
+    };
     use unic_langid::LanguageIdentifier;
 
     #[test]
@@ -199,7 +697,7 @@ mod tests {
 
         let langid: LanguageIdentifier = "xx".parse().expect("Parsing failed.");
         let pr_broken = PluralRules::create(langid, PluralRuleType::CARDINAL);
-        assert_eq!(pr_broken.is_err(), !pr_broken.is_ok());
+        assert!(pr_broken.is_err());
     }
 
     #[test]
@@ -210,16 +708,222 @@ mod tests {
         assert_eq!(pr_naq.select(113), Ok(PluralCategory::OTHER));
     }
 
+    #[test]
+    fn modulus_of_n_test() {
+        let pr = |locale: &str, ty| {
+            let langid: LanguageIdentifier = locale.parse().expect("Parsing failed.");
+            PluralRules::create(langid, ty).unwrap()
+        };
+
+        let pr_ar = pr("ar", PluralRuleType::CARDINAL);
+        assert_eq!(pr_ar.select(103), Ok(PluralCategory::FEW));
+        assert_eq!(pr_ar.select("103.0"), Ok(PluralCategory::FEW));
+        assert_eq!(pr_ar.select("10.1"), Ok(PluralCategory::OTHER));
+        assert_eq!(pr_ar.select(111), Ok(PluralCategory::MANY));
+
+        let pr_be = pr("be", PluralRuleType::CARDINAL);
+        assert_eq!(pr_be.select("0.1"), Ok(PluralCategory::OTHER));
+        assert_eq!(pr_be.select(22), Ok(PluralCategory::FEW));
+        assert_eq!(pr_be.select(112), Ok(PluralCategory::MANY));
+
+        let pr_kw = pr("kw", PluralRuleType::ORDINAL);
+        assert_eq!(pr_kw.select(101), Ok(PluralCategory::ONE));
+        assert_eq!(pr_kw.select(105), Ok(PluralCategory::MANY));
+    }
+
+    #[test]
+    fn categorize_test() {
+        let langid: LanguageIdentifier = "ar".parse().expect("Parsing failed.");
+        let numbers = [0, 1, 2, 5].map(Into::into);
+        let categories: Vec<_> = PluralRules::categorize(langid, PluralRuleType::CARDINAL, numbers)
+            .unwrap()
+            .collect();
+        assert_eq!(
+            categories,
+            [
+                PluralCategory::ZERO,
+                PluralCategory::ONE,
+                PluralCategory::TWO,
+                PluralCategory::FEW
+            ]
+        );
+
+        let langid: LanguageIdentifier = "xx".parse().expect("Parsing failed.");
+        assert!(PluralRules::categorize(langid, PluralRuleType::CARDINAL, []).is_err());
+    }
+
+    #[test]
+    fn locale_plural_rules_test() {
+        let pr = |locale: &str| {
+            let langid: LanguageIdentifier = locale.parse().expect("Parsing failed.");
+            LocalePluralRules::new(langid)
+        };
+
+        let pr_fr = pr("fr");
+        for _ in 0..3 {
+            assert_eq!(pr_fr.cardinal(&0.into()), PluralCategory::ONE);
+            assert_eq!(pr_fr.cardinal(&1.into()), PluralCategory::ONE);
+            assert_eq!(
+                pr_fr.cardinal(&"1.5".try_into().unwrap()),
+                PluralCategory::ONE
+            );
+            assert_eq!(pr_fr.cardinal(&2.into()), PluralCategory::OTHER);
+            assert_eq!(pr_fr.ordinal(&1.into()), PluralCategory::ONE);
+            assert_eq!(pr_fr.ordinal(&2.into()), PluralCategory::OTHER);
+        }
+
+        // The locale falls back to its language, and an unknown one selects `OTHER`.
+        let pr_fr_ca = pr("fr-CA");
+        assert_eq!(pr_fr_ca.get_locale().to_string(), "fr-CA");
+        assert_eq!(pr_fr_ca.cardinal(&1.into()), PluralCategory::ONE);
+        let pr_xx = pr("xx");
+        assert_eq!(pr_xx.cardinal(&1.into()), PluralCategory::OTHER);
+        assert_eq!(pr_xx.ordinal(&1.into()), PluralCategory::OTHER);
+    }
+
+    #[test]
+    fn category_for_test() {
+        let category = |locale: &str, ty, n: u64| {
+            let langid: LanguageIdentifier = locale.parse().expect("Parsing failed.");
+            category_for(&langid, ty, &n.into())
+        };
+
+        for (n, expected) in [
+            (1, PluralCategory::ONE),
+            (2, PluralCategory::TWO),
+            (3, PluralCategory::FEW),
+            (4, PluralCategory::OTHER),
+            (11, PluralCategory::OTHER),
+            (22, PluralCategory::TWO),
+        ] {
+            assert_eq!(category("en", PluralRuleType::ORDINAL, n), expected);
+            assert_eq!(category("en-GB", PluralRuleType::ORDINAL, n), expected);
+        }
+        assert_eq!(
+            category("en", PluralRuleType::CARDINAL, 1),
+            PluralCategory::ONE
+        );
+        assert_eq!(
+            category("en", PluralRuleType::CARDINAL, 2),
+            PluralCategory::OTHER
+        );
+        assert_eq!(
+            category("pl", PluralRuleType::CARDINAL, 3),
+            PluralCategory::FEW
+        );
+        assert_eq!(
+            category("pl", PluralRuleType::CARDINAL, 5),
+            PluralCategory::MANY
+        );
+        assert_eq!(
+            category("cy", PluralRuleType::ORDINAL, 0),
+            PluralCategory::ZERO
+        );
+        assert_eq!(
+            category("xx", PluralRuleType::CARDINAL, 1),
+            PluralCategory::OTHER
+        );
+    }
+
+    #[test]
+    fn categories_for_test() {
+        let categories = |locale: &str, ty| {
+            let langid: LanguageIdentifier = locale.parse().expect("Parsing failed.");
+            categories_for(&langid, ty)
+        };
+
+        assert_eq!(
+            categories("ja", PluralRuleType::CARDINAL),
+            &[PluralCategory::OTHER]
+        );
+        assert_eq!(
+            categories("ar", PluralRuleType::CARDINAL),
+            PluralCategory::ALL
+        );
+        assert_eq!(
+            categories("ar-EG", PluralRuleType::CARDINAL),
+            PluralCategory::ALL
+        );
+        assert_eq!(
+            categories("en", PluralRuleType::ORDINAL),
+            &[
+                PluralCategory::ONE,
+                PluralCategory::TWO,
+                PluralCategory::FEW,
+                PluralCategory::OTHER
+            ]
+        );
+        assert_eq!(
+            categories("xx", PluralRuleType::CARDINAL),
+            &[PluralCategory::OTHER]
+        );
+    }
+
     #[test]
     fn version_test() {
-        assert_eq!(CLDR_VERSION, 37);
+        assert_eq!(CLDR_VERSION, "37");
     }
 
     #[test]
-    fn locale_test() {
+    fn categories_test() {
+        let langid: LanguageIdentifier = "ar".parse().expect("Parsing failed.");
+        let pr_ar = PluralRules::create(langid, PluralRuleType::CARDINAL).unwrap();
+        assert_eq!(
+            pr_ar.categories(),
+            &[
+                PluralCategory::ZERO,
+                PluralCategory::ONE,
+                PluralCategory::TWO,
+                PluralCategory::FEW,
+                PluralCategory::MANY,
+                PluralCategory::OTHER,
+            ]
+        );
+
+        let langid: LanguageIdentifier = "en".parse().expect("Parsing failed.");
+        let pr_en = PluralRules::create(langid, PluralRuleType::CARDINAL).unwrap();
         assert_eq!(
-            PluralRules::get_locales(PluralRuleType::CARDINAL).is_empty(),
-            false
+            pr_en.categories(),
+            &[PluralCategory::ONE, PluralCategory::OTHER]
         );
+
+        let langid: LanguageIdentifier = "en".parse().expect("Parsing failed.");
+        let pr_en = PluralRules::create(langid, PluralRuleType::ORDINAL).unwrap();
+        assert_eq!(pr_en.categories().len(), 4);
+    }
+
+    #[test]
+    fn negotiate_test() {
+        let langids = |ids: &[&str]| -> Vec<LanguageIdentifier> {
+            ids.iter()
+                .map(|id| id.parse().expect("Parsing failed."))
+                .collect()
+        };
+        let negotiate =
+            |ids: &[&str], prt| PluralRules::negotiate(&langids(ids), prt).map(|l| l.to_string());
+
+        assert_eq!(
+            negotiate(&["fr-CA", "en"], PluralRuleType::CARDINAL),
+            Some("fr".to_string())
+        );
+        assert_eq!(
+            negotiate(&["pt-PT", "pt"], PluralRuleType::CARDINAL),
+            Some("pt-PT".to_string())
+        );
+        assert_eq!(
+            negotiate(&["sr-Latn-RS", "en"], PluralRuleType::CARDINAL),
+            Some("sr".to_string())
+        );
+        assert_eq!(
+            negotiate(&["xx", "de-AT"], PluralRuleType::ORDINAL),
+            Some("de".to_string())
+        );
+        assert_eq!(negotiate(&["xx", "yy-ZZ"], PluralRuleType::CARDINAL), None);
+        assert_eq!(negotiate(&[], PluralRuleType::CARDINAL), None);
+    }
+
+    #[test]
+    fn locale_test() {
+        assert!(!PluralRules::get_locales(PluralRuleType::CARDINAL).is_empty());
     }
 }
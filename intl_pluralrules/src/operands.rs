@@ -48,16 +48,43 @@
 //!    t: 45,
 //! }), PluralOperands::try_from(123.45))
 //! ```
+//!
+//! The operands of a float are those of its shortest decimal representation that parses back to
+//! the same value, as printed by `Display`, without any further rounding: `2.50` has the single
+//! fraction digit of `2.5`, `2.0` has none, and `0.1 + 0.2` has the 17 fraction digits of
+//! `0.30000000000000004`. Use [`PluralOperands::from_f64_with_precision`] to round to the fraction
+//! digits actually displayed. Beyond `u64::MAX`, `i` saturates, and non-finite floats have no fraction
+//! digits, with an `i` of `0` for NaN and `u64::MAX` for infinities.
+//!
+//! ```
+//! use intl_pluralrules::operands::*;
+//! assert_eq!(PluralOperands::from(0.1 + 0.2).v, 17);
+//! assert_eq!(PluralOperands::from(2.50).v, 1);
+//! ```
+//!
+//! As in CLDR, the operands are those of the absolute value of the number: the sign of a signed integer,
+//! a float or a string is dropped, so `-2` selects the same category as `2`.
+//!
+//! ```
+//! use intl_pluralrules::operands::*;
+//! assert_eq!(PluralOperands::from(-2), PluralOperands::from(2));
+//! assert_eq!(PluralOperands::from(-1.5), PluralOperands::from(1.5));
+//! assert_eq!("-1.50".parse::<PluralOperands>(), "1.50".parse());
+//! ```
 #![allow(clippy::cast_lossless)]
 use std::convert::TryFrom;
+use std::fmt;
 use std::str::FromStr;
 
 /// A full plural operands representation of a number. See [CLDR Plural Rules](https://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules) for complete operands description.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PluralOperands {
     /// Absolute value of input
+    ///
+    /// Without fraction digits (`v == 0`), this is always `i as f64`, so rules comparing `n` and `i`
+    /// to the same integer agree. Integers above 2<sup>53</sup> are rounded to the nearest `f64`.
     pub n: f64,
-    /// Integer value of input
+    /// Integer value of the absolute value of input
     pub i: u64,
     /// Number of visible fraction digits with trailing zeros
     pub v: usize,
@@ -69,15 +96,219 @@ pub struct PluralOperands {
     pub t: u64,
 }
 
+impl PluralOperands {
+    /// Returns the operands with each of `i`, `v`, `w`, `f` and `t` set explicitly, deriving `n` from them.
+    ///
+    /// Unlike a number, this can express visible trailing zeros, such as `1.0` with `v = 1` rather than `1`.
+    /// The operands are not checked against each other beyond their digit counts: `w` must not exceed `v`,
+    /// and `f` and `t` must have at most `v` and `w` digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intl_pluralrules::operands::PluralOperands;
+    ///
+    /// assert_eq!(PluralOperands::new(1, 2, 1, 50, 5), Ok("1.50".parse().unwrap()));
+    /// assert!(PluralOperands::new(1, 1, 2, 5, 5).is_err());
+    /// ```
+    pub fn new(i: u64, v: usize, w: usize, f: u64, t: u64) -> Result<Self, &'static str> {
+        let digits = |value: u64| value.checked_ilog10().map_or(0, |log| log as usize + 1);
+        if w > v {
+            return Err("w must not exceed v!");
+        }
+        if digits(f) > v {
+            return Err("f must have at most v digits!");
+        }
+        if digits(t) > w {
+            return Err("t must have at most w digits!");
+        }
+
+        let n = if v == 0 {
+            i as f64
+        } else {
+            f64::from_str(&format!("{}.{:0>width$}", i, f, width = v))
+                .map_err(|_| "Incorrect number passed!")?
+        };
+        Ok(PluralOperands { n, i, v, w, f, t })
+    }
+
+    /// Returns the operands of an integer, `n` and `i` set to it and no fraction digits.
+    ///
+    /// This skips the parsing and the fraction digits of `from_str`, which yields the same operands for
+    /// the decimal string of `n`; the `From` implementations of the integer types go through it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intl_pluralrules::operands::PluralOperands;
+    ///
+    /// assert_eq!(PluralOperands::from_integer(42), "42".parse().unwrap());
+    /// ```
+    pub fn from_integer(n: u64) -> Self {
+        PluralOperands {
+            n: n as f64,
+            i: n,
+            v: 0,
+            w: 0,
+            f: 0,
+            t: 0,
+        }
+    }
+
+    /// Returns the operands of `n` as it would be displayed with at most `max_frac_digits` fraction digits.
+    ///
+    /// Binary floating point can't represent most decimal fractions exactly, so deriving the
+    /// fraction operands from an `f64` directly may yield surprising fraction digits
+    /// (e.g. `0.1 + 0.2` gives `0.30000000000000004`). The number is rounded to `max_frac_digits`
+    /// and trailing zeros are dropped before the operands are derived.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intl_pluralrules::operands::PluralOperands;
+    ///
+    /// assert_eq!(
+    ///     PluralOperands::from_f64_with_precision(0.1 + 0.2, 2),
+    ///     Ok(PluralOperands {
+    ///         n: 0.3_f64,
+    ///         i: 0,
+    ///         v: 1,
+    ///         w: 1,
+    ///         f: 3,
+    ///         t: 3,
+    ///     })
+    /// );
+    /// ```
+    pub fn from_f64_with_precision(n: f64, max_frac_digits: usize) -> Result<Self, &'static str> {
+        let rounded = format!("{:.*}", max_frac_digits, n);
+        let rounded = if rounded.contains('.') {
+            rounded.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            &rounded
+        };
+        PluralOperands::try_from(rounded)
+    }
+
+    /// Parses the operands of a decimal string like `from_str`, but only derives the operands named in
+    /// `needed`, such as `"iv"`, along with `i` and `v`, which are cheap, and the `n` of an integer.
+    /// The others are left at `0`.
+    ///
+    /// `needed` is usually the list of operands a rule reads, as returned by [`PluralRules::operands`]:
+    /// the operands then select the same category as those of `from_str`, without parsing a float
+    /// unless the rule reads the `n` of a number with fraction digits. The same strings are rejected.
+    ///
+    /// [`PluralRules::operands`]: crate::PluralRules::operands
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intl_pluralrules::operands::PluralOperands;
+    ///
+    /// let operands = PluralOperands::parse_minimal("1.50", "iv").unwrap();
+    /// assert_eq!((operands.i, operands.v, operands.f, operands.t), (1, 2, 0, 0));
+    /// assert_eq!(PluralOperands::parse_minimal("1.50", "nivwft"), "1.50".parse());
+    /// ```
+    pub fn parse_minimal(input: &str, needed: &str) -> Result<Self, &'static str> {
+        let abs_str = input.strip_prefix(['-', '+']).unwrap_or(input);
+        // `u64::from_str` accepts a leading `+`, which `from_str` rejects after a sign.
+        let parse_digits = |digits: &str| match digits.as_bytes().first() {
+            Some(b'0'..=b'9') => u64::from_str(digits).ok(),
+            _ => None,
+        };
+        // Anything but plain digits, such as `1e3` or an integer part too large for `i`, takes the full path.
+        let Some((int_str, dec_str)) = abs_str.split_once('.') else {
+            return match parse_digits(abs_str) {
+                // Without fraction digits, `n` is `i`, as in `from_str`.
+                Some(i) => Ok(PluralOperands::from(i)),
+                None => PluralOperands::try_from(input),
+            };
+        };
+        // Fraction digits beyond those of `u64::MAX` are rejected by `from_str`.
+        let is_fraction = !dec_str.is_empty()
+            && dec_str.len() < 20
+            && dec_str.bytes().all(|b| b.is_ascii_digit());
+        let i = match parse_digits(int_str) {
+            Some(i) if is_fraction && !needed.contains('n') => i,
+            _ => return PluralOperands::try_from(input),
+        };
+
+        let mut operands = PluralOperands {
+            n: 0.0,
+            i,
+            v: dec_str.len(),
+            w: 0,
+            f: 0,
+            t: 0,
+        };
+        let trimmed = dec_str.trim_end_matches('0');
+        if needed.contains('w') {
+            operands.w = trimmed.len();
+        }
+        if needed.contains('f') {
+            operands.f = u64::from_str(dec_str).unwrap_or(0);
+        }
+        if needed.contains('t') {
+            operands.t = u64::from_str(trimmed).unwrap_or(0);
+        }
+        Ok(operands)
+    }
+
+    /// Returns the operands of an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intl_pluralrules::operands::PluralOperands;
+    ///
+    /// assert_eq!(PluralOperands::integer(5), PluralOperands::from(5_u64));
+    /// ```
+    #[cfg(feature = "test-util")]
+    pub fn integer(n: u64) -> Self {
+        Self::from_integer(n)
+    }
+
+    /// Returns the operands as an `(n, i, v, w, f, t)` tuple, in the order of the CLDR operand table,
+    /// to print or compare them compactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intl_pluralrules::operands::PluralOperands;
+    ///
+    /// let po: PluralOperands = "1.30".parse().unwrap();
+    /// assert_eq!(po.as_tuple(), (1.3, 1, 2, 1, 30, 3));
+    /// ```
+    pub fn as_tuple(&self) -> (f64, u64, usize, usize, u64, u64) {
+        (self.n, self.i, self.v, self.w, self.f, self.t)
+    }
+
+    /// Returns the operands of a decimal string, keeping its visible fraction digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intl_pluralrules::operands::PluralOperands;
+    ///
+    /// assert_eq!(PluralOperands::decimal("2.50").v, 2);
+    /// ```
+    #[cfg(feature = "test-util")]
+    pub fn decimal(n: &str) -> Self {
+        Self::try_from(n).unwrap_or_else(|_| panic!("`{}` is not a number", n))
+    }
+}
+
 impl<'a> TryFrom<&'a str> for PluralOperands {
     type Error = &'static str;
 
     fn try_from(input: &'a str) -> Result<Self, Self::Error> {
-        let abs_str = if let Some(stripped) = input.strip_prefix('-') {
-            stripped
-        } else {
-            input
-        };
+        let abs_str = input.strip_prefix(['-', '+']).unwrap_or(input);
+        if abs_str.starts_with(['-', '+']) {
+            return Err("Incorrect number passed!");
+        }
 
         let absolute_value = f64::from_str(abs_str).map_err(|_| "Incorrect number passed!")?;
 
@@ -102,7 +333,8 @@ impl<'a> TryFrom<&'a str> for PluralOperands {
                 u64::from_str(dec_str).map_err(|_| "Could not convert string to integer!")?;
             fraction_digits = u64::from_str(backtrace).unwrap_or(0);
         } else {
-            integer_digits = absolute_value as u64;
+            // Parse the digits themselves, as large integers are not exact in `absolute_value`.
+            integer_digits = u64::from_str(abs_str).unwrap_or(absolute_value as u64);
             num_fraction_digits0 = 0;
             num_fraction_digits = 0;
             fraction_digits0 = 0;
@@ -110,7 +342,11 @@ impl<'a> TryFrom<&'a str> for PluralOperands {
         }
 
         Ok(PluralOperands {
-            n: absolute_value,
+            n: if num_fraction_digits0 == 0 {
+                integer_digits as f64
+            } else {
+                absolute_value
+            },
             i: integer_digits,
             v: num_fraction_digits0,
             w: num_fraction_digits,
@@ -120,19 +356,58 @@ impl<'a> TryFrom<&'a str> for PluralOperands {
     }
 }
 
+/// Parses the operands of a decimal string, such as `"-1234.560"`, keeping its visible fraction digits.
+///
+/// An optional leading sign is ignored. Grouping separators are not accepted.
+///
+/// # Examples
+///
+/// ```
+/// use intl_pluralrules::operands::PluralOperands;
+///
+/// let operands: PluralOperands = "1.50".parse().unwrap();
+/// assert_eq!((operands.i, operands.v, operands.w, operands.f, operands.t), (1, 2, 1, 50, 5));
+/// ```
+impl FromStr for PluralOperands {
+    type Err = &'static str;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        PluralOperands::try_from(input)
+    }
+}
+
+/// Writes the decimal string of the operands, such as `"1.50"`, its integer part `i` followed by the `v`
+/// digits of `f`, with leading zeros, after a dot.
+///
+/// This is the string `from_str` parses into the same operands, as long as `f` has at most `v` digits.
+/// The other operands are not read: as `n`, `w` and `t` are derived from `i`, `v` and `f`, operands
+/// built otherwise may not write the number they were built from. The sign of the original number
+/// has already been dropped, as have the digits of a large integer beyond `u64::MAX`.
+///
+/// # Examples
+///
+/// ```
+/// use intl_pluralrules::operands::PluralOperands;
+///
+/// assert_eq!(PluralOperands::new(1, 2, 1, 50, 5).unwrap().to_string(), "1.50");
+/// assert_eq!("-0.05".parse::<PluralOperands>().unwrap().to_string(), "0.05");
+/// assert_eq!(PluralOperands::from(2.5).to_string(), "2.5");
+/// ```
+impl fmt::Display for PluralOperands {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.i)?;
+        if self.v > 0 {
+            write!(f, ".{:0width$}", self.f, width = self.v)?;
+        }
+        Ok(())
+    }
+}
+
 macro_rules! impl_integer_type {
     ($ty:ident) => {
         impl From<$ty> for PluralOperands {
             fn from(input: $ty) -> Self {
-                // XXXManishearth converting from u32 or u64 to isize may wrap
-                PluralOperands {
-                    n: input as f64,
-                    i: input as u64,
-                    v: 0,
-                    w: 0,
-                    f: 0,
-                    t: 0,
-                }
+                PluralOperands::from_integer(input as u64)
             }
         }
     };
@@ -143,19 +418,9 @@ macro_rules! impl_integer_type {
 
 macro_rules! impl_signed_integer_type {
     ($ty:ident) => {
-        impl TryFrom<$ty> for PluralOperands {
-            type Error = &'static str;
-            fn try_from(input: $ty) -> Result<Self, Self::Error> {
-                // XXXManishearth converting from i64 to isize may wrap
-                let x = (input as isize).checked_abs().ok_or("Number too big")?;
-                Ok(PluralOperands {
-                    n: x as f64,
-                    i: x as u64,
-                    v: 0,
-                    w: 0,
-                    f: 0,
-                    t: 0,
-                })
+        impl From<$ty> for PluralOperands {
+            fn from(input: $ty) -> Self {
+                PluralOperands::from_integer(input.unsigned_abs() as u64)
             }
         }
     };
@@ -164,6 +429,32 @@ macro_rules! impl_signed_integer_type {
     };
 }
 
+// See the module documentation for the fraction digits of floats.
+macro_rules! impl_float_type {
+    ($ty:ident) => {
+        impl From<$ty> for PluralOperands {
+            fn from(input: $ty) -> Self {
+                let abs = input.abs();
+                if !abs.is_finite() || abs.fract() == 0.0 {
+                    return PluralOperands {
+                        n: abs as f64,
+                        i: abs as u64,
+                        v: 0,
+                        w: 0,
+                        f: 0,
+                        t: 0,
+                    };
+                }
+                PluralOperands::try_from(abs.to_string().as_str())
+                    .expect("A finite float displays as a decimal number")
+            }
+        }
+    };
+    ($($ty:ident)+) => {
+        $(impl_float_type!($ty);)+
+    };
+}
+
 macro_rules! impl_convert_type {
     ($ty:ident) => {
         impl TryFrom<$ty> for PluralOperands {
@@ -181,5 +472,73 @@ macro_rules! impl_convert_type {
 
 impl_integer_type!(u8 u16 u32 u64 usize);
 impl_signed_integer_type!(i8 i16 i32 i64 isize);
-// XXXManishearth we can likely have dedicated float impls here
-impl_convert_type!(f32 f64 String);
+impl_float_type!(f32 f64);
+impl_convert_type!(String);
+
+/// Reads the operands from the digits of the decimal, so that its visible trailing zeros count towards `v`,
+/// as written by its `Display` and parsed by `from_str`, which yields the same operands.
+///
+/// An integer part beyond `u64::MAX` saturates, as it does in `from_str`, while fraction digits beyond
+/// `u64::MAX` are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::UnsignedDecimal;
+/// use intl_pluralrules::operands::PluralOperands;
+/// use std::convert::TryFrom;
+///
+/// let mut dec = UnsignedDecimal::from(150_u32);
+/// dec.multiply_pow10(-2);
+/// let operands = PluralOperands::try_from(&dec).unwrap();
+/// assert_eq!((operands.i, operands.v, operands.w, operands.f, operands.t), (1, 2, 1, 50, 5));
+/// ```
+#[cfg(feature = "fixed_decimal")]
+impl TryFrom<&fixed_decimal::UnsignedDecimal> for PluralOperands {
+    type Error = &'static str;
+
+    fn try_from(input: &fixed_decimal::UnsignedDecimal) -> Result<Self, Self::Error> {
+        // The lowest magnitude of the visible digits, trailing zeros included, is at most `0`.
+        let v = input.magnitude_range().start().unsigned_abs() as usize;
+        let w = input.nonzero_magnitude_end().min(0).unsigned_abs() as usize;
+        let i = (0..=input.nonzero_magnitude_start())
+            .rev()
+            .fold(0_u64, |i, magnitude| {
+                i.saturating_mul(10)
+                    .saturating_add(input.digit_at(magnitude).into())
+            });
+        let fraction = |len: usize| {
+            (1..=len as i16).try_fold(0_u64, |digits, position| {
+                digits
+                    .checked_mul(10)?
+                    .checked_add(input.digit_at(-position).into())
+            })
+        };
+        let f = fraction(v).ok_or("Could not convert string to integer!")?;
+        let t = fraction(w).ok_or("Could not convert string to integer!")?;
+        PluralOperands::new(i, v, w, f, t)
+    }
+}
+
+/// Reads the operands of the absolute value of the decimal, as for an `UnsignedDecimal`.
+///
+/// Plural selection can thus take a decimal directly, such as `PluralRules::select(&dec)`.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::Decimal;
+/// use intl_pluralrules::operands::PluralOperands;
+/// use std::convert::TryFrom;
+///
+/// let dec: Decimal = "-1.50".parse().unwrap();
+/// assert_eq!(PluralOperands::try_from(&dec), "1.50".parse());
+/// ```
+#[cfg(feature = "fixed_decimal")]
+impl TryFrom<&fixed_decimal::Decimal> for PluralOperands {
+    type Error = &'static str;
+
+    fn try_from(input: &fixed_decimal::Decimal) -> Result<Self, Self::Error> {
+        PluralOperands::try_from(&input.absolute)
+    }
+}
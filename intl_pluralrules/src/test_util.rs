@@ -0,0 +1,43 @@
+//! Helpers for writing concise tests against plural rules, available with the `test-util` feature.
+
+use crate::PluralCategory;
+use crate::operands::PluralOperands;
+
+/// Asserts that `rule` selects `expected` for the decimal string `number`.
+///
+/// `rule` can be any function from operands to a category, such as a generated rule or an interpreter.
+///
+/// # Panics
+///
+/// Panics if `number` is not a number, or if `rule` selects another category.
+///
+/// # Examples
+///
+/// ```
+/// use intl_pluralrules::PluralCategory;
+/// use intl_pluralrules::operands::PluralOperands;
+/// use intl_pluralrules::test_util::assert_selects;
+///
+/// let rule = |po: &PluralOperands| {
+///     if po.i == 1 && po.v == 0 {
+///         PluralCategory::ONE
+///     } else {
+///         PluralCategory::OTHER
+///     }
+/// };
+/// assert_selects(rule, "1", PluralCategory::ONE);
+/// assert_selects(rule, "1.0", PluralCategory::OTHER);
+/// ```
+#[track_caller]
+pub fn assert_selects<R: Fn(&PluralOperands) -> PluralCategory>(
+    rule: R,
+    number: &str,
+    expected: PluralCategory,
+) {
+    let actual = rule(&PluralOperands::decimal(number));
+    assert_eq!(
+        actual, expected,
+        "`{}` selects {:?} instead of {:?}",
+        number, actual, expected
+    );
+}
@@ -0,0 +1,48 @@
+//! A `wasm-bindgen` binding selecting plural categories from JavaScript, available with the `wasm` feature.
+//!
+//! The locale and the number are passed as strings, such as `"pl"` and `"3"`, and the category is returned
+//! as its CLDR keyword, such as `"few"`. Invalid input throws a JavaScript error.
+
+use unic_langid::LanguageIdentifier;
+use wasm_bindgen::prelude::*;
+
+use crate::{PluralRuleType, PluralRules};
+
+/// Selects the category keyword of `number` with the rules of `locale`, both given as strings.
+///
+/// The locale is negotiated as with `PluralRules::negotiate`, so `en-US` selects with the rules of `en`.
+///
+/// This is the function behind the JavaScript binding, which can also be called natively.
+///
+/// # Examples
+///
+/// ```
+/// use intl_pluralrules::PluralRuleType;
+/// use intl_pluralrules::wasm::select_keyword;
+///
+/// assert_eq!(select_keyword("pl", "3", PluralRuleType::CARDINAL), Ok("few".to_string()));
+/// assert_eq!(select_keyword("en", "2", PluralRuleType::ORDINAL), Ok("two".to_string()));
+/// assert!(select_keyword("en", "two", PluralRuleType::CARDINAL).is_err());
+/// ```
+pub fn select_keyword(
+    locale: &str,
+    number: &str,
+    prt: PluralRuleType,
+) -> Result<String, &'static str> {
+    let langid: LanguageIdentifier = locale.parse().map_err(|_| "Invalid locale")?;
+    let supported = PluralRules::negotiate(&[langid], prt).ok_or("unknown locale")?;
+    let pr = PluralRules::create(supported, prt)?;
+    Ok(pr.select(number)?.to_string())
+}
+
+/// Selects the cardinal category keyword of `number` with the rules of `locale`.
+#[wasm_bindgen(js_name = selectCardinal)]
+pub fn select_cardinal(locale: &str, number: &str) -> Result<String, JsError> {
+    select_keyword(locale, number, PluralRuleType::CARDINAL).map_err(JsError::new)
+}
+
+/// Selects the ordinal category keyword of `number` with the rules of `locale`.
+#[wasm_bindgen(js_name = selectOrdinal)]
+pub fn select_ordinal(locale: &str, number: &str) -> Result<String, JsError> {
+    select_keyword(locale, number, PluralRuleType::ORDINAL).map_err(JsError::new)
+}
@@ -0,0 +1,140 @@
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+
+use intl_pluralrules::PluralCategory;
+
+#[test]
+fn category_keywords() {
+    let tests = [
+        (PluralCategory::ZERO, "zero"),
+        (PluralCategory::ONE, "one"),
+        (PluralCategory::TWO, "two"),
+        (PluralCategory::FEW, "few"),
+        (PluralCategory::MANY, "many"),
+        (PluralCategory::OTHER, "other"),
+    ];
+
+    for (category, keyword) in tests {
+        assert_eq!(category.to_string(), keyword);
+        assert_eq!(category.as_str(), keyword);
+        assert_eq!(category.as_str().parse(), Ok(category));
+        assert_eq!(keyword.parse(), Ok(category));
+        assert_eq!(PluralCategory::try_from(keyword), Ok(category));
+    }
+}
+
+#[test]
+fn fold_categories() {
+    for category in PluralCategory::all() {
+        let keyword = category.fold("zero", "one", "two", "few", "many", "other");
+        assert_eq!(keyword, category.as_str());
+    }
+
+    // Only the closure of the category is called.
+    let lazy: fn() -> String = || unreachable!("Only the closure of `few` is called");
+    let few = PluralCategory::FEW.fold(lazy, lazy, lazy, || "few".to_owned(), lazy, lazy);
+    assert_eq!(few(), "few");
+}
+
+#[test]
+fn category_equals_keyword() {
+    for category in PluralCategory::all() {
+        let keyword = category.as_str();
+        assert_eq!(category, keyword);
+        assert!(category == *keyword);
+        assert!(category == keyword.to_string().as_str());
+        for other in PluralCategory::all().filter(|other| *other != category) {
+            assert_ne!(category, other.as_str());
+        }
+    }
+    for keyword in ["", "One", "ONE", "several", " one"] {
+        assert!(PluralCategory::all().all(|category| category != keyword));
+    }
+}
+
+#[test]
+fn const_category_keyword() {
+    const FEW: &str = PluralCategory::FEW.as_str();
+    assert_eq!(FEW, "few");
+}
+
+#[test]
+fn category_codes() {
+    let tests = [
+        (PluralCategory::ZERO, 0),
+        (PluralCategory::ONE, 1),
+        (PluralCategory::TWO, 2),
+        (PluralCategory::FEW, 3),
+        (PluralCategory::MANY, 4),
+        (PluralCategory::OTHER, 5),
+    ];
+
+    for (category, code) in tests {
+        assert_eq!(category.to_u8(), code);
+        assert_eq!(PluralCategory::from_u8(code), Some(category));
+    }
+    for code in 6..=u8::MAX {
+        assert_eq!(PluralCategory::from_u8(code), None);
+    }
+
+    const OTHER: Option<PluralCategory> = PluralCategory::from_u8(PluralCategory::OTHER.to_u8());
+    assert_eq!(OTHER, Some(PluralCategory::OTHER));
+}
+
+#[test]
+fn unknown_category_keyword() {
+    for keyword in ["", "One", "ONE", "several", " one"] {
+        assert_eq!(
+            keyword.parse::<PluralCategory>(),
+            Err("Unknown plural category")
+        );
+        assert!(PluralCategory::try_from(keyword).is_err());
+    }
+}
+
+#[test]
+fn all_categories() {
+    assert_eq!(PluralCategory::ALL.len(), 6);
+    assert_eq!(PluralCategory::all().len(), 6);
+    assert_eq!(
+        PluralCategory::all().collect::<Vec<_>>(),
+        [
+            PluralCategory::ZERO,
+            PluralCategory::ONE,
+            PluralCategory::TWO,
+            PluralCategory::FEW,
+            PluralCategory::MANY,
+            PluralCategory::OTHER,
+        ]
+    );
+}
+
+#[test]
+fn category_order() {
+    let mut categories = vec![
+        PluralCategory::MANY,
+        PluralCategory::OTHER,
+        PluralCategory::ONE,
+        PluralCategory::ZERO,
+        PluralCategory::FEW,
+        PluralCategory::TWO,
+    ];
+    categories.sort();
+    assert_eq!(categories, PluralCategory::ALL);
+
+    let set: BTreeSet<_> = [
+        PluralCategory::OTHER,
+        PluralCategory::FEW,
+        PluralCategory::ONE,
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        set.into_iter().collect::<Vec<_>>(),
+        [
+            PluralCategory::ONE,
+            PluralCategory::FEW,
+            PluralCategory::OTHER
+        ]
+    );
+}
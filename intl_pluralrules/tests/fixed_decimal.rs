@@ -0,0 +1,66 @@
+#![cfg(feature = "fixed_decimal")]
+
+use fixed_decimal::{Decimal, UnsignedDecimal};
+use intl_pluralrules::operands::PluralOperands;
+use intl_pluralrules::{PluralCategory, PluralRuleType, PluralRules};
+use std::convert::TryFrom;
+use unic_langid::langid;
+
+#[test]
+fn decimals_match_strings() {
+    for number in [
+        "0",
+        "1",
+        "1.0",
+        "1.50",
+        "0.00",
+        "0.05",
+        "0.050",
+        "10.10",
+        "100",
+        "1000.000",
+        "-2.30",
+        "123456789.987654321000",
+    ] {
+        let dec: Decimal = number.parse().expect("The number is a decimal");
+        assert_eq!(PluralOperands::try_from(&dec), number.parse(), "{}", number);
+        assert_eq!(
+            PluralOperands::try_from(&dec.absolute),
+            number.parse(),
+            "{}",
+            number
+        );
+    }
+}
+
+#[test]
+fn trailing_zeros_from_magnitude() {
+    // `250` with an exponent of `-3` displays as `0.250`, whose trailing zero is visible.
+    let mut dec = UnsignedDecimal::from(250_u32);
+    dec.multiply_pow10(-3);
+    let operands = PluralOperands::try_from(&dec).expect("The digits fit");
+    assert_eq!(
+        (operands.i, operands.v, operands.w, operands.f, operands.t),
+        (0, 3, 2, 250, 25)
+    );
+
+    // Padding adds trailing zeros without changing the value.
+    dec.pad_end(-5);
+    assert_eq!(PluralOperands::try_from(&dec), "0.25000".parse());
+
+    let pr = PluralRules::create(langid!("en"), PluralRuleType::CARDINAL).unwrap();
+    let one: Decimal = "1".parse().unwrap();
+    let one_zero: Decimal = "1.0".parse().unwrap();
+    assert_eq!(pr.select(&one), Ok(PluralCategory::ONE));
+    assert_eq!(pr.select(&one_zero), Ok(PluralCategory::OTHER));
+}
+
+#[test]
+fn large_decimals() {
+    // As in `from_str`, the integer part saturates, while too many fraction digits are an error.
+    let dec: Decimal = "123456789012345678901234567890".parse().unwrap();
+    assert_eq!(PluralOperands::try_from(&dec).map(|po| po.i), Ok(u64::MAX));
+    let dec: Decimal = "0.123456789012345678901234567890".parse().unwrap();
+    assert!(PluralOperands::try_from(&dec).is_err());
+    assert!(PluralOperands::try_from("0.123456789012345678901234567890").is_err());
+}
@@ -54,15 +54,15 @@ fn test_operands_from_int() {
 
     for test in tests {
         assert_eq!(
-            Ok(PluralOperands {
+            PluralOperands {
                 n: (test.0).0,
                 i: (test.0).1,
                 v: (test.0).2,
                 w: (test.0).3,
                 f: (test.0).4,
                 t: (test.0).5,
-            }),
-            PluralOperands::try_from(test.1)
+            },
+            PluralOperands::from(test.1)
         );
     }
 }
@@ -77,6 +77,33 @@ fn test_operands_from_float() {
         ((1234.567_f64, 1234, 3, 3, 567, 567), -1234.567),
     ];
 
+    for test in tests {
+        assert_eq!(
+            PluralOperands {
+                n: (test.0).0,
+                i: (test.0).1,
+                v: (test.0).2,
+                w: (test.0).3,
+                f: (test.0).4,
+                t: (test.0).5,
+            },
+            PluralOperands::from(test.1)
+        );
+    }
+}
+
+#[test]
+fn test_operands_from_float_with_precision() {
+    let tests = vec![
+        ((0.1_f64, 0, 1, 1, 1, 1), (0.1, 1)),
+        ((0.1_f64, 0, 1, 1, 1, 1), (0.1, 2)),
+        ((0_f64, 0, 0, 0, 0, 0), (0.1, 0)),
+        ((0.3_f64, 0, 1, 1, 3, 3), (0.1 + 0.2, 2)),
+        ((1.01_f64, 1, 2, 2, 1, 1), (1.005_f64 + 0.001, 2)),
+        ((2_f64, 2, 0, 0, 0, 0), (1.999, 2)),
+        ((123.45_f64, 123, 2, 2, 45, 45), (-123.45, 5)),
+    ];
+
     for test in tests {
         assert_eq!(
             Ok(PluralOperands {
@@ -87,14 +114,14 @@ fn test_operands_from_float() {
                 f: (test.0).4,
                 t: (test.0).5,
             }),
-            PluralOperands::try_from(test.1)
+            PluralOperands::from_f64_with_precision((test.1).0, (test.1).1)
         );
     }
 }
 
 #[test]
 fn test_incorrect_operand() {
-    assert_eq!(PluralOperands::try_from("foo").is_err(), true);
+    assert!(PluralOperands::try_from("foo").is_err());
 }
 
 #[test]
@@ -136,5 +163,295 @@ fn custom_type() {
 fn many_decimal_places() {
     // this should not panic on an i32 system
     let num: f64 = 2.813829837982735;
-    assert_eq!(dbg!(PluralOperands::try_from(num)).is_ok(), true);
+    assert_eq!(dbg!(PluralOperands::from(num)).v, 15);
+}
+
+#[test]
+fn integer_n_equals_i() {
+    let po = PluralOperands::try_from("7").unwrap();
+    assert_eq!(po.n, 7_f64);
+    assert_eq!(po.i, 7);
+    assert_eq!(po, PluralOperands::from(7_u64));
+
+    // Beyond 2^53 `i` must hold the exact digits, while `n` is its nearest `f64`.
+    let po = PluralOperands::try_from("9007199254740993").unwrap();
+    assert_eq!(po.i, 9007199254740993);
+    assert_eq!(po.n, po.i as f64);
+}
+
+#[test]
+fn from_integer_matches_from_str() {
+    for n in [0, 1, 42, 1000, 9007199254740993, u64::MAX] {
+        let po = PluralOperands::from_integer(n);
+        assert_eq!(
+            po,
+            PluralOperands::try_from(n.to_string().as_str()).unwrap()
+        );
+        assert_eq!(po, PluralOperands::from(n));
+    }
+    assert_eq!(
+        PluralOperands::from_integer(7),
+        PluralOperands::from(-7_i64)
+    );
+    assert_eq!(PluralOperands::from_integer(0), PluralOperands::from(0_i8));
+}
+
+#[test]
+fn test_operands_parse() {
+    // The examples of the CLDR operands table, with signs and trailing zeros.
+    let tests = vec![
+        ((1_f64, 1, 0, 0, 0, 0), "1"),
+        ((1_f64, 1, 1, 0, 0, 0), "1.0"),
+        ((1_f64, 1, 2, 0, 0, 0), "1.00"),
+        ((1.3_f64, 1, 1, 1, 3, 3), "1.3"),
+        ((1.3_f64, 1, 2, 1, 30, 3), "1.30"),
+        ((1.03_f64, 1, 2, 2, 3, 3), "1.03"),
+        ((1.23_f64, 1, 3, 2, 230, 23), "1.230"),
+        ((1.5_f64, 1, 2, 1, 50, 5), "1.50"),
+        ((1234.56_f64, 1234, 3, 2, 560, 56), "1234.560"),
+        ((0.001_f64, 0, 3, 3, 1, 1), "0.001"),
+        ((0.1_f64, 0, 4, 1, 1000, 1), "0.1000"),
+        ((5_f64, 5, 0, 0, 0, 0), "+5"),
+        ((5.10_f64, 5, 2, 1, 10, 1), "-5.10"),
+        ((5_f64, 5, 0, 0, 0, 0), "05"),
+    ];
+
+    for (operands, input) in tests {
+        assert_eq!(
+            Ok(PluralOperands {
+                n: operands.0,
+                i: operands.1,
+                v: operands.2,
+                w: operands.3,
+                f: operands.4,
+                t: operands.5,
+            }),
+            input.parse::<PluralOperands>(),
+            "{}",
+            input
+        );
+    }
+
+    for input in [
+        "", "-", "abc", "1,234", "1 000", "1.", ".5", "--1", "+-1", "1.5.0",
+    ] {
+        assert!(input.parse::<PluralOperands>().is_err(), "{}", input);
+    }
+}
+
+#[test]
+fn test_operands_from_numbers() {
+    let parse = |input: &str| input.parse::<PluralOperands>().unwrap();
+
+    for n in [0_u64, 1, 7, 100, 1_000_000, u64::MAX] {
+        assert_eq!(PluralOperands::from(n), parse(&n.to_string()));
+    }
+    for n in [0_u32, 1, 42, u32::MAX] {
+        assert_eq!(PluralOperands::from(n), parse(&n.to_string()));
+    }
+    for n in [0_i64, 1, -1, -42, i64::MAX, i64::MIN] {
+        assert_eq!(PluralOperands::from(n), parse(&n.to_string()));
+    }
+    for (n, s) in [
+        (0.0, "0"),
+        (2.0, "2"),
+        (-2.0, "2"),
+        (1.5, "1.5"),
+        (2.50, "2.5"),
+        (-1234.567, "1234.567"),
+        (0.001, "0.001"),
+        (0.1 + 0.2, "0.30000000000000004"),
+    ] {
+        assert_eq!(PluralOperands::from(n), parse(s), "{}", n);
+    }
+    assert_eq!(PluralOperands::from(0.25_f32), parse("0.25"));
+
+    let huge = PluralOperands::from(1e300);
+    assert_eq!((huge.n, huge.i, huge.v), (1e300, u64::MAX, 0));
+    let infinite = PluralOperands::from(f64::NEG_INFINITY);
+    assert_eq!(
+        (infinite.n, infinite.i, infinite.v),
+        (f64::INFINITY, u64::MAX, 0)
+    );
+    let nan = PluralOperands::from(f64::NAN);
+    assert!(nan.n.is_nan());
+    assert_eq!((nan.i, nan.v), (0, 0));
+}
+
+#[test]
+fn operands_new() {
+    assert_eq!(
+        PluralOperands::new(23, 0, 0, 0, 0),
+        Ok(PluralOperands::from(23_u64))
+    );
+    assert_eq!(
+        PluralOperands::new(0, 7, 4, 203000, 203),
+        PluralOperands::try_from("0.0203000")
+    );
+    assert_eq!(
+        PluralOperands::new(1234, 3, 3, 567, 567),
+        PluralOperands::try_from("1234.567")
+    );
+
+    assert_eq!(
+        PluralOperands::new(1, 1, 2, 0, 0),
+        Err("w must not exceed v!")
+    );
+    assert_eq!(
+        PluralOperands::new(1, 1, 1, 50, 5),
+        Err("f must have at most v digits!")
+    );
+    assert_eq!(
+        PluralOperands::new(1, 2, 1, 50, 50),
+        Err("t must have at most w digits!")
+    );
+}
+
+#[test]
+fn operands_new_trailing_zeros() {
+    // `1` and `1.0` are the same number, but only the former is `one` in English.
+    let integer = PluralOperands::new(1, 0, 0, 0, 0).unwrap();
+    let trailing_zero = PluralOperands::new(1, 1, 0, 0, 0).unwrap();
+    assert_eq!(integer.n, trailing_zero.n);
+
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let pr = PluralRules::create(langid, PluralRuleType::CARDINAL).unwrap();
+    assert_eq!(
+        pr.select(integer),
+        Ok(intl_pluralrules::PluralCategory::ONE)
+    );
+    assert_eq!(
+        pr.select(trailing_zero),
+        Ok(intl_pluralrules::PluralCategory::OTHER)
+    );
+}
+
+#[test]
+fn negative_numbers_select_like_their_magnitude() {
+    for (locale, prt) in [
+        ("en", PluralRuleType::CARDINAL),
+        ("fr", PluralRuleType::CARDINAL),
+        ("ru", PluralRuleType::CARDINAL),
+        ("ar", PluralRuleType::CARDINAL),
+        ("lv", PluralRuleType::CARDINAL),
+        ("en", PluralRuleType::ORDINAL),
+    ] {
+        let langid: LanguageIdentifier = locale.parse().expect("Parsing failed.");
+        let pr = PluralRules::create(langid, prt).unwrap();
+
+        assert_eq!(pr.select(-3), pr.select(3), "{}", locale);
+        assert_eq!(pr.select(-3_i64), pr.select(3_u64), "{}", locale);
+        assert_eq!(pr.select("-3"), pr.select("3"), "{}", locale);
+        assert_eq!(pr.select(-1.5), pr.select(1.5), "{}", locale);
+        assert_eq!(pr.select("-1.5"), pr.select("1.5"), "{}", locale);
+        assert_eq!(pr.select("-1.50"), pr.select("1.50"), "{}", locale);
+    }
+
+    assert_eq!(PluralOperands::from(-3), PluralOperands::from(3));
+    assert_eq!(PluralOperands::from(-1.5), PluralOperands::from(1.5));
+    assert_eq!(
+        PluralOperands::from_f64_with_precision(-1.25, 1),
+        PluralOperands::from_f64_with_precision(1.25, 1)
+    );
+    assert_eq!("-1.5".parse::<PluralOperands>(), "1.5".parse());
+}
+
+#[test]
+fn minimal_operands_select_like_full_operands() {
+    let mut numbers: Vec<String> = (0..=120).map(|n| n.to_string()).collect();
+    numbers.extend(
+        [
+            "-5",
+            "+7",
+            "0.0",
+            "1.0",
+            "1.5",
+            "2.50",
+            "0.01",
+            "10.10",
+            "21.3",
+            "101.01",
+            "1000000",
+            "1000000.0",
+            "18446744073709551616",
+            "1e3",
+        ]
+        .map(String::from),
+    );
+
+    for prt in [PluralRuleType::CARDINAL, PluralRuleType::ORDINAL] {
+        for lang in PluralRules::get_locales(prt) {
+            let pr = PluralRules::create(lang.clone(), prt).unwrap();
+            for number in &numbers {
+                assert_eq!(
+                    pr.select(pr.minimal_operands(number).unwrap()),
+                    pr.select(PluralOperands::try_from(number.as_str()).unwrap()),
+                    "`{}` for {:?} {}",
+                    number,
+                    prt,
+                    lang
+                );
+            }
+        }
+    }
+
+    for invalid in ["", "1.", ".5", "--1", "-+1", "1.+5", "1.x", "abc"] {
+        assert!(PluralOperands::try_from(invalid).is_err(), "{}", invalid);
+        for needed in ["", "iv", "nivwft"] {
+            assert!(
+                PluralOperands::parse_minimal(invalid, needed).is_err(),
+                "{} with {}",
+                invalid,
+                needed
+            );
+        }
+    }
+}
+
+#[test]
+fn operands_display_round_trip() {
+    for input in [
+        "0",
+        "1",
+        "1.0",
+        "1.50",
+        "0.05",
+        "0.000",
+        "10.10",
+        "1234.567",
+        "18446744073709551615",
+        "1.1234567890123456789",
+    ] {
+        let operands = input.parse::<PluralOperands>().unwrap();
+        assert_eq!(operands.to_string(), input);
+        assert_eq!(operands.to_string().parse(), Ok(operands));
+    }
+
+    // The sign and a leading `+` are dropped.
+    for (input, display) in [("-5", "5"), ("+2.50", "2.50"), ("-0.0", "0.0")] {
+        assert_eq!(
+            input.parse::<PluralOperands>().unwrap().to_string(),
+            display
+        );
+    }
+    assert_eq!(PluralOperands::from(7_u64).to_string(), "7");
+    assert_eq!(PluralOperands::from(1.25_f64).to_string(), "1.25");
+}
+
+#[test]
+fn operands_tuple() {
+    // The examples of the operand table of UTS #35.
+    for (input, expected) in [
+        ("1", (1.0, 1, 0, 0, 0, 0)),
+        ("1.0", (1.0, 1, 1, 0, 0, 0)),
+        ("1.00", (1.0, 1, 2, 0, 0, 0)),
+        ("1.3", (1.3, 1, 1, 1, 3, 3)),
+        ("1.30", (1.3, 1, 2, 1, 30, 3)),
+        ("1.03", (1.03, 1, 2, 2, 3, 3)),
+        ("1.230", (1.23, 1, 3, 2, 230, 23)),
+        ("1200000", (1200000.0, 1200000, 0, 0, 0, 0)),
+    ] {
+        let po = PluralOperands::try_from(input).expect("Valid operands");
+        assert_eq!(expected, po.as_tuple(), "{}", input);
+    }
 }
@@ -0,0 +1,38 @@
+#![cfg(feature = "wasm")]
+
+use intl_pluralrules::PluralRuleType;
+use intl_pluralrules::wasm::select_keyword;
+
+#[test]
+fn select_keywords() {
+    for (locale, number, prt, keyword) in [
+        ("pl", "1", PluralRuleType::CARDINAL, "one"),
+        ("pl", "3", PluralRuleType::CARDINAL, "few"),
+        ("pl", "12", PluralRuleType::CARDINAL, "many"),
+        ("pl", "5.0", PluralRuleType::CARDINAL, "other"),
+        ("en", "22", PluralRuleType::ORDINAL, "two"),
+        ("en-US", "1", PluralRuleType::CARDINAL, "one"),
+    ] {
+        assert_eq!(
+            select_keyword(locale, number, prt).as_deref(),
+            Ok(keyword),
+            "{} {}",
+            locale,
+            number
+        );
+    }
+}
+
+#[test]
+fn select_keyword_errors() {
+    let prt = PluralRuleType::CARDINAL;
+    assert_eq!(
+        select_keyword("not a locale", "1", prt),
+        Err("Invalid locale")
+    );
+    assert_eq!(select_keyword("xx", "1", prt), Err("unknown locale"));
+    assert_eq!(
+        select_keyword("en", "one", prt),
+        Err("Argument can not be parsed to operands.")
+    );
+}
@@ -0,0 +1,217 @@
+//! Compile-time plural category selection for locales known ahead of time.
+//!
+//! # Examples
+//!
+//! With a literal number, the category is selected while compiling and the macro expands to a constant.
+//!
+//! ```
+//! use intl_pluralrules::PluralCategory;
+//! use intl_pluralrules_macros::plural_select;
+//!
+//! const ONE: PluralCategory = plural_select!("en", cardinal, 1);
+//! assert_eq!(ONE, PluralCategory::ONE);
+//! assert_eq!(plural_select!("pl", cardinal, "3"), PluralCategory::FEW);
+//! assert_eq!(plural_select!("en", ordinal, 22), PluralCategory::TWO);
+//! ```
+//!
+//! Any other expression is passed at runtime to the rule of the locale, which is resolved while compiling.
+//! It must convert into `PluralOperands`.
+//!
+//! ```
+//! use intl_pluralrules::PluralCategory;
+//! use intl_pluralrules_macros::plural_select;
+//!
+//! let count: u32 = 5;
+//! assert_eq!(plural_select!("en", cardinal, count), PluralCategory::OTHER);
+//! ```
+//!
+//! The rule of a locale itself is resolved while compiling by `plural_rule_cardinal!` and `plural_rule_ordinal!`,
+//! for selecting the categories of many numbers without a runtime lookup.
+//!
+//! ```
+//! use intl_pluralrules::PluralCategory;
+//! use intl_pluralrules::operands::PluralOperands;
+//! use intl_pluralrules_macros::plural_rule_cardinal;
+//!
+//! const FR: fn(&PluralOperands) -> PluralCategory = plural_rule_cardinal!("fr");
+//! assert_eq!(FR(&PluralOperands::from(1_u32)), PluralCategory::ONE);
+//! assert_eq!(FR(&PluralOperands::from(2_u32)), PluralCategory::OTHER);
+//! ```
+
+use intl_pluralrules::{PluralCategory, PluralRuleType, PluralRules};
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Error, Expr, ExprLit, ExprUnary, Ident, Lit, LitStr, Token, UnOp, parse_macro_input};
+use unic_langid::LanguageIdentifier;
+
+/// The arguments of `plural_select!`: a locale, a plural rule type and a number.
+struct PluralSelect {
+    locale: LitStr,
+    prt: Ident,
+    number: Expr,
+}
+
+impl Parse for PluralSelect {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let locale = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let prt = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let number = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self {
+            locale,
+            prt,
+            number,
+        })
+    }
+}
+
+/// The argument of `plural_rule_cardinal!` and `plural_rule_ordinal!`: a locale.
+struct PluralRule {
+    locale: LitStr,
+}
+
+impl Parse for PluralRule {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let locale = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { locale })
+    }
+}
+
+/// Selects the plural category of a number for a locale known at compile time.
+///
+/// Takes a locale string literal, `cardinal` or `ordinal`, and a number. See the [crate documentation](crate) for examples.
+#[proc_macro]
+pub fn plural_select(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as PluralSelect);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Resolves the cardinal rule of a locale known at compile time.
+///
+/// Takes a locale string literal, and expands to a constant `fn(&PluralOperands) -> PluralCategory`.
+/// A locale without cardinal rules fails the compilation:
+///
+/// ```compile_fail
+/// use intl_pluralrules_macros::plural_rule_cardinal;
+///
+/// let rule = plural_rule_cardinal!("xx");
+/// ```
+#[proc_macro]
+pub fn plural_rule_cardinal(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as PluralRule);
+    expand_rule(input, PluralRuleType::CARDINAL)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Resolves the ordinal rule of a locale known at compile time.
+///
+/// Takes a locale string literal, and expands to a constant `fn(&PluralOperands) -> PluralCategory`.
+/// A locale without ordinal rules fails the compilation:
+///
+/// ```compile_fail
+/// use intl_pluralrules_macros::plural_rule_ordinal;
+///
+/// let rule = plural_rule_ordinal!("xx");
+/// ```
+#[proc_macro]
+pub fn plural_rule_ordinal(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as PluralRule);
+    expand_rule(input, PluralRuleType::ORDINAL)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_rule(input: PluralRule, prt: PluralRuleType) -> syn::Result<TokenStream2> {
+    let (_, idx) = resolve_locale(&input.locale, prt)?;
+    let prt_tokens = prt_tokens(prt);
+    Ok(quote!(::intl_pluralrules::__rule_at(#prt_tokens, #idx)))
+}
+
+/// Parses a locale literal, returning it along with the index of its rule in the table for `prt`.
+fn resolve_locale(
+    locale: &LitStr,
+    prt: PluralRuleType,
+) -> syn::Result<(LanguageIdentifier, usize)> {
+    let langid: LanguageIdentifier = locale
+        .value()
+        .parse()
+        .map_err(|_| Error::new(locale.span(), "invalid locale"))?;
+    let idx = PluralRules::get_locales(prt)
+        .iter()
+        .position(|l| *l == langid)
+        .ok_or_else(|| Error::new(locale.span(), "unsupported locale"))?;
+    Ok((langid, idx))
+}
+
+fn prt_tokens(prt: PluralRuleType) -> TokenStream2 {
+    match prt {
+        PluralRuleType::CARDINAL => quote!(::intl_pluralrules::PluralRuleType::CARDINAL),
+        PluralRuleType::ORDINAL => quote!(::intl_pluralrules::PluralRuleType::ORDINAL),
+    }
+}
+
+fn expand(input: PluralSelect) -> syn::Result<TokenStream2> {
+    let prt = match input.prt.to_string().as_str() {
+        "cardinal" => PluralRuleType::CARDINAL,
+        "ordinal" => PluralRuleType::ORDINAL,
+        _ => {
+            return Err(Error::new(
+                input.prt.span(),
+                "expected `cardinal` or `ordinal`",
+            ));
+        }
+    };
+    let prt_tokens = prt_tokens(prt);
+    let (langid, idx) = resolve_locale(&input.locale, prt)?;
+
+    if let Some(number) = literal_number(&input.number) {
+        let category = PluralRules::create(langid, prt)
+            .and_then(|pr| pr.select(number.as_str()))
+            .map_err(|err| Error::new_spanned(&input.number, err))?;
+        return Ok(category_tokens(category));
+    }
+
+    let number = input.number;
+    Ok(quote! {
+        (::intl_pluralrules::__rule_at(#prt_tokens, #idx))(
+            &::core::convert::Into::<::intl_pluralrules::operands::PluralOperands>::into(#number)
+        )
+    })
+}
+
+/// Returns the source of a (possibly negated) numeric or string literal.
+fn literal_number(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => match lit {
+            Lit::Int(lit) => Some(lit.base10_digits().to_owned()),
+            Lit::Float(lit) => Some(lit.base10_digits().to_owned()),
+            Lit::Str(lit) => Some(lit.value()),
+            _ => None,
+        },
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => literal_number(expr).map(|number| format!("-{}", number)),
+        _ => None,
+    }
+}
+
+fn category_tokens(category: PluralCategory) -> TokenStream2 {
+    match category {
+        PluralCategory::ZERO => quote!(::intl_pluralrules::PluralCategory::ZERO),
+        PluralCategory::ONE => quote!(::intl_pluralrules::PluralCategory::ONE),
+        PluralCategory::TWO => quote!(::intl_pluralrules::PluralCategory::TWO),
+        PluralCategory::FEW => quote!(::intl_pluralrules::PluralCategory::FEW),
+        PluralCategory::MANY => quote!(::intl_pluralrules::PluralCategory::MANY),
+        PluralCategory::OTHER => quote!(::intl_pluralrules::PluralCategory::OTHER),
+    }
+}
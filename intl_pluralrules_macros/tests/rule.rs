@@ -0,0 +1,51 @@
+use intl_pluralrules::operands::PluralOperands;
+use intl_pluralrules::{PluralCategory, PluralRuleType, PluralRules};
+use intl_pluralrules_macros::{plural_rule_cardinal, plural_rule_ordinal};
+use unic_langid::LanguageIdentifier;
+
+type Rule = fn(&PluralOperands) -> PluralCategory;
+
+const FR_CARDINAL: Rule = plural_rule_cardinal!("fr");
+const EN_ORDINAL: Rule = plural_rule_ordinal!("en");
+
+#[test]
+fn const_rules() {
+    assert_eq!(
+        FR_CARDINAL(&PluralOperands::from(0_u8)),
+        PluralCategory::ONE
+    );
+    assert_eq!(
+        FR_CARDINAL(&PluralOperands::from(1_u8)),
+        PluralCategory::ONE
+    );
+    assert_eq!(
+        FR_CARDINAL(&PluralOperands::from(2_u8)),
+        PluralCategory::OTHER
+    );
+    assert_eq!(
+        EN_ORDINAL(&PluralOperands::from(22_u8)),
+        PluralCategory::TWO
+    );
+    assert_eq!(
+        EN_ORDINAL(&PluralOperands::from(13_u8)),
+        PluralCategory::OTHER
+    );
+}
+
+#[test]
+fn rules_match_runtime_lookup() {
+    let tests: [(&str, PluralRuleType, Rule); 4] = [
+        ("ar", PluralRuleType::CARDINAL, plural_rule_cardinal!("ar")),
+        ("pl", PluralRuleType::CARDINAL, plural_rule_cardinal!("pl")),
+        ("cy", PluralRuleType::ORDINAL, plural_rule_ordinal!("cy")),
+        ("uk", PluralRuleType::ORDINAL, plural_rule_ordinal!("uk")),
+    ];
+
+    for (locale, prt, rule) in tests {
+        let langid: LanguageIdentifier = locale.parse().expect("Parsing failed.");
+        let pr = PluralRules::create(langid, prt).unwrap();
+        for n in [0_u64, 1, 2, 3, 5, 11, 12, 22, 100, 101] {
+            assert_eq!(Ok(rule(&PluralOperands::from(n))), pr.select(n));
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use intl_pluralrules::operands::PluralOperands;
+use intl_pluralrules::{PluralCategory, PluralRuleType, PluralRules};
+use intl_pluralrules_macros::plural_select;
+use unic_langid::LanguageIdentifier;
+
+#[test]
+fn literal_select() {
+    const ONE: PluralCategory = plural_select!("en", cardinal, 1);
+    const OTHER: PluralCategory = plural_select!("en", cardinal, 1.0);
+
+    assert_eq!(ONE, PluralCategory::ONE);
+    assert_eq!(OTHER, PluralCategory::OTHER);
+    assert_eq!(plural_select!("en", cardinal, -1), PluralCategory::ONE);
+    assert_eq!(plural_select!("pl", cardinal, "12"), PluralCategory::MANY);
+    assert_eq!(plural_select!("uk", ordinal, 33), PluralCategory::FEW);
+}
+
+#[test]
+fn runtime_select() {
+    let langid: LanguageIdentifier = "ar".parse().expect("Parsing failed.");
+    let pr = PluralRules::create(langid, PluralRuleType::CARDINAL).unwrap();
+
+    for n in [0_u64, 1, 2, 3, 11, 100] {
+        assert_eq!(Ok(plural_select!("ar", cardinal, n)), pr.select(n));
+    }
+    let po = PluralOperands::from(2_u8);
+    assert_eq!(plural_select!("ar", cardinal, po), PluralCategory::TWO);
+}
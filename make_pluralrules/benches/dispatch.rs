@@ -0,0 +1,54 @@
+use criterion::Criterion;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use std::hint::black_box;
+
+use intl_pluralrules::{PluralCategory, PluralRuleType, operands};
+
+// The generated language identifier tests are left out of benchmarks, which don't use the test harness.
+#[rustfmt::skip]
+#[allow(unused_imports)]
+#[path = "../tests/fixtures/cldr_pluralrules_33_match.rs"]
+mod cldr_33_match;
+
+use cldr_33_match::{PRS_CARDINAL, match_cardinal, select_cardinal};
+use operands::PluralOperands;
+
+// Selecting the cardinal category of 5 numbers for each of the 204 locales of CLDR 33:
+//
+//   binary searching the table for the rule, then calling it:  50.4 - 52.2 µs
+//   a `match` on the subtags of the locale:                    33.0 - 36.1 µs
+//
+// Looking a cardinal rule up in the table adds 20500 bytes to a stripped release binary, 9924 of them of code,
+// and the `match` 8192 bytes, 7328 of them of code.
+fn dispatch(c: &mut Criterion) {
+    let langs: Vec<_> = PRS_CARDINAL.iter().map(|(lang, _)| lang.clone()).collect();
+    let samples: Vec<_> = ["0", "1", "2", "5", "1.5"]
+        .iter()
+        .map(|sample| PluralOperands::try_from(*sample).expect("Valid operands"))
+        .collect();
+
+    let mut group = c.benchmark_group("dispatch");
+    group.bench_function("table", |b| {
+        b.iter(|| {
+            for lang in &langs {
+                for po in &samples {
+                    let _ = select_cardinal(black_box(lang)).map(|rule| rule(black_box(po)));
+                }
+            }
+        })
+    });
+    group.bench_function("match", |b| {
+        b.iter(|| {
+            for lang in &langs {
+                for po in &samples {
+                    let _ = match_cardinal(black_box(lang), black_box(po));
+                }
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, dispatch,);
+criterion_main!(benches);
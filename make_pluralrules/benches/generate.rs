@@ -0,0 +1,31 @@
+use criterion::Criterion;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use std::fs;
+use std::hint::black_box;
+
+use make_pluralrules::generate_rs;
+
+// Generating the cardinal and ordinal rules of CLDR 33, over three runs of each:
+//
+//   cloning each rule stream in `create_pr_type`:  4.69 ms .. 5.89 ms
+//   moving the rule streams into the table:        4.92 ms .. 5.87 ms
+//
+// The clones are a small part of generating the rules, so dropping them makes no measurable
+// difference, and the run-to-run noise is larger than either.
+fn generate(c: &mut Criterion) {
+    let inputs = [
+        "./tests/fixtures/cldr_pluralrules_cardinals_33.json",
+        "./tests/fixtures/cldr_pluralrules_ordinals_33.json",
+    ]
+    .iter()
+    .map(|path| fs::read_to_string(path).expect("Could not read input json"))
+    .collect::<Vec<_>>();
+
+    c.bench_function("generate_rs", |b| {
+        b.iter(|| generate_rs(black_box(&inputs)))
+    });
+}
+
+criterion_group!(benches, generate,);
+criterion_main!(benches);
@@ -0,0 +1,226 @@
+//! Checking the rules of CLDR JSON data without generating code.
+
+use std::fmt;
+
+use cldr_pluralrules_parser::ast::{Rule, SampleRange};
+use cldr_pluralrules_parser::{PluralRuleParseError, SampleError};
+use intl_pluralrules::PluralCategory;
+
+use crate::error::GenerateError;
+use crate::parser::resource::{Section, parse_plurals_resource_from_string};
+
+/// A problem with a rule of the input, found by `check_rules`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleIssue {
+    /// The locale listing the rule, as written in the input.
+    pub locale: String,
+    /// The name of the rule, such as `pluralRule-count-one`.
+    pub rule_name: String,
+    pub kind: RuleIssueKind,
+}
+
+/// The kind of a `RuleIssue`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleIssueKind {
+    /// The rule can't be parsed.
+    Invalid(PluralRuleParseError),
+    /// The rule name is not `pluralRule-count-<category>` for a CLDR plural category.
+    UnknownRuleName,
+    /// The rule of a category other than `other` has no condition, so it never applies.
+    EmptyCondition,
+    /// A sample range, such as `5~2`, has a lower bound above its upper bound, so it lists no values.
+    InvertedSampleRange(String),
+    /// A sample value selects another category than the one it is listed for with the rules of the locale,
+    /// as when the conditions of two categories overlap or leave a gap that falls through to `other`.
+    MisfiledSample {
+        sample: String,
+        actual: PluralCategory,
+    },
+    /// A sample value can't be converted to plural operands, such as a compact sample with a huge
+    /// exponent, so it isn't checked against the rules.
+    UnevaluableSample(String),
+}
+
+impl RuleIssue {
+    /// Whether the rule is unusable, rather than merely suspicious.
+    pub fn is_error(&self) -> bool {
+        matches!(
+            self.kind,
+            RuleIssueKind::Invalid(_) | RuleIssueKind::UnknownRuleName
+        )
+    }
+}
+
+impl fmt::Display for RuleIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (locale, rule_name) = (&self.locale, &self.rule_name);
+        match &self.kind {
+            RuleIssueKind::Invalid(error) => {
+                write!(f, "invalid `{}` rule of `{}`: {}", rule_name, locale, error)
+            }
+            RuleIssueKind::UnknownRuleName => {
+                write!(f, "unknown rule name `{}` of `{}`", rule_name, locale)
+            }
+            RuleIssueKind::EmptyCondition => {
+                write!(f, "`{}` rule of `{}` has no condition", rule_name, locale)
+            }
+            RuleIssueKind::InvertedSampleRange(range) => write!(
+                f,
+                "`{}` rule of `{}` has the inverted sample range `{}`",
+                rule_name, locale, range
+            ),
+            RuleIssueKind::MisfiledSample { sample, actual } => write!(
+                f,
+                "`{}` rule of `{}` lists the sample `{}`, which selects `{}`",
+                rule_name, locale, sample, actual
+            ),
+            RuleIssueKind::UnevaluableSample(sample) => write!(
+                f,
+                "`{}` rule of `{}` lists the sample `{}`, which can't be evaluated",
+                rule_name, locale, sample
+            ),
+        }
+    }
+}
+
+/// Parses every rule of a CLDR JSON input with `parse_rule_complete`, returning the problems found,
+/// in input order.
+///
+/// Unlike generating code, checking goes on after an invalid rule, so that all of them are reported.
+/// The sample values of the rules of a locale are checked against the rules with the interpreter, unless
+/// one of them is unusable, reporting the first misfiled sample of each rule.
+///
+/// # Examples
+///
+/// ```
+/// use intl_pluralrules::PluralCategory;
+/// use make_pluralrules::{RuleIssueKind, check_rules};
+///
+/// let json = r#"{
+///     "supplemental": {
+///         "version": { "_unicodeVersion": "13.0.0", "_cldrVersion": "37" },
+///         "plurals-type-cardinal": {
+///             "xx": {
+///                 "pluralRule-count-one": " @integer 1",
+///                 "pluralRule-count-other": " @integer 0, 2~16"
+///             }
+///         }
+///     }
+/// }"#;
+/// let issues = check_rules(json).expect("The input is CLDR JSON");
+/// assert_eq!(issues.len(), 2);
+/// assert_eq!(issues[0].kind, RuleIssueKind::EmptyCondition);
+/// assert!(!issues[0].is_error());
+/// // Without a condition, `one` also selects the samples of `other`.
+/// assert_eq!(
+///     issues[1].kind,
+///     RuleIssueKind::MisfiledSample {
+///         sample: "0".to_owned(),
+///         actual: PluralCategory::ONE,
+///     }
+/// );
+/// ```
+pub fn check_rules(cldr_json: &str) -> Result<Vec<RuleIssue>, GenerateError> {
+    let resource = parse_plurals_resource_from_string(cldr_json)
+        .map_err(|err| GenerateError::InvalidJson {
+            input: 0,
+            error: err.to_string(),
+        })?
+        .ok_or(GenerateError::NoRulesFound(0))?;
+
+    let mut issues = Vec::new();
+    for (key, section) in resource.supplemental.sections {
+        if !key.starts_with("plurals-type-") {
+            continue;
+        }
+        let Section::PluralRules(rules) = section else {
+            return Err(GenerateError::InvalidJson {
+                input: 0,
+                error: format!("`{}` is not an object of plural rules", key),
+            });
+        };
+        for (locale, lines) in rules.0 {
+            let mut parsed = Vec::new();
+            let mut complete = true;
+            for (rule_name, rule_line) in lines.0 {
+                let issue = |kind| RuleIssue {
+                    locale: locale.clone(),
+                    rule_name: rule_name.clone(),
+                    kind,
+                };
+                let Some(category) = rule_name
+                    .strip_prefix("pluralRule-count-")
+                    .filter(|c| matches!(*c, "zero" | "one" | "two" | "few" | "many" | "other"))
+                else {
+                    issues.push(issue(RuleIssueKind::UnknownRuleName));
+                    complete = false;
+                    continue;
+                };
+                let rule = match cldr_pluralrules_parser::parse_rule_complete(&rule_line) {
+                    Ok(rule) => rule,
+                    Err(error) => {
+                        issues.push(issue(RuleIssueKind::Invalid(error)));
+                        complete = false;
+                        continue;
+                    }
+                };
+
+                if category != "other" && rule.condition.0.is_empty() {
+                    issues.push(issue(RuleIssueKind::EmptyCondition));
+                }
+                let sample_ranges = rule
+                    .samples
+                    .iter()
+                    .flat_map(|samples| samples.integer.iter().chain(samples.decimal.iter()))
+                    .flat_map(|list| list.sample_ranges.iter());
+                for range in sample_ranges.filter(|range| is_inverted(range)) {
+                    issues.push(issue(RuleIssueKind::InvertedSampleRange(range.to_string())));
+                }
+                let category = category.parse().expect("The category is checked above");
+                parsed.push((category, rule_name, rule));
+            }
+            if complete {
+                issues.extend(misfiled_samples(&locale, &parsed));
+            }
+        }
+    }
+    Ok(issues)
+}
+
+/// Returns the first sample value of each rule of a locale which selects another category with its rules,
+/// or which can't be evaluated.
+fn misfiled_samples(locale: &str, rules: &[(PluralCategory, String, Rule)]) -> Vec<RuleIssue> {
+    let conditions: Vec<_> = rules
+        .iter()
+        .filter(|(category, _, _)| *category != PluralCategory::OTHER)
+        .map(|(category, _, rule)| (*category, rule.clone()))
+        .collect();
+    rules
+        .iter()
+        .filter_map(|(category, rule_name, rule)| {
+            let error = rule
+                .samples
+                .as_ref()?
+                .validate_against(&conditions, *category)
+                .err()?;
+            let kind = match error {
+                SampleError::Mismatch { sample, actual, .. } => {
+                    RuleIssueKind::MisfiledSample { sample, actual }
+                }
+                SampleError::Unevaluable { sample } => RuleIssueKind::UnevaluableSample(sample),
+            };
+            Some(RuleIssue {
+                locale: locale.to_owned(),
+                rule_name: rule_name.clone(),
+                kind,
+            })
+        })
+        .collect()
+}
+
+fn is_inverted(range: &SampleRange) -> bool {
+    range
+        .upper_val
+        .as_ref()
+        .is_some_and(|upper| upper.to_f64() < range.lower_val.to_f64())
+}
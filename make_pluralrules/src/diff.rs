@@ -0,0 +1,145 @@
+//! Comparison of the plural rules of two datasets, such as two CLDR versions.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use cldr_pluralrules_parser::ast::Rule;
+use intl_pluralrules::{PluralCategory, PluralRuleType};
+use serde_json::{Value as Json, json};
+use unic_langid::LanguageIdentifier;
+
+use crate::Dataset;
+use crate::parser::plural_type::type_name;
+
+/// A rule which differs between two datasets, found by `diff_datasets`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleChange {
+    pub locale: LanguageIdentifier,
+    pub ty: PluralRuleType,
+    pub category: PluralCategory,
+    pub kind: RuleChangeKind,
+}
+
+/// How a rule differs, with the conditions in CLDR syntax.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleChangeKind {
+    /// The category only has a rule in the new dataset.
+    Added(String),
+    /// The category only has a rule in the old dataset.
+    Removed(String),
+    /// The condition of the category differs.
+    Changed { old: String, new: String },
+}
+
+impl fmt::Display for RuleChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (ty, category, locale) = (type_name(self.ty), self.category, &self.locale);
+        match &self.kind {
+            RuleChangeKind::Added(new) => {
+                write!(f, "added {} `{}` of `{}`: `{}`", ty, category, locale, new)
+            }
+            RuleChangeKind::Removed(old) => {
+                write!(
+                    f,
+                    "removed {} `{}` of `{}`: `{}`",
+                    ty, category, locale, old
+                )
+            }
+            RuleChangeKind::Changed { old, new } => write!(
+                f,
+                "changed {} `{}` of `{}`: `{}` -> `{}`",
+                ty, category, locale, old, new
+            ),
+        }
+    }
+}
+
+impl RuleChange {
+    /// Returns the change as a JSON object, such as
+    /// `{ "locale": "fr", "type": "cardinal", "category": "one", "change": "changed", "old": "i = 0,1", "new": "i = 0..1" }`.
+    pub fn to_json(&self) -> Json {
+        let mut json = json!({
+            "locale": self.locale.to_string(),
+            "type": type_name(self.ty),
+            "category": self.category.to_string(),
+        });
+        match &self.kind {
+            RuleChangeKind::Added(new) => {
+                json["change"] = json!("added");
+                json["new"] = json!(new);
+            }
+            RuleChangeKind::Removed(old) => {
+                json["change"] = json!("removed");
+                json["old"] = json!(old);
+            }
+            RuleChangeKind::Changed { old, new } => {
+                json["change"] = json!("changed");
+                json["old"] = json!(old);
+                json["new"] = json!(new);
+            }
+        }
+        json
+    }
+}
+
+/// Compares the rule conditions of two datasets, as returned by `parse_dataset`, per locale, plural rule type
+/// and category.
+///
+/// Only the conditions are compared, after `Rule::normalize`, so rules whose samples alone differ or whose
+/// conditions are merely written differently, such as `n is 1` and `n = 1`, are not reported. A locale or
+/// plural rule type missing from one of the datasets reports each of its rules as added or removed.
+/// The changes are sorted by locale, plural rule type and category.
+pub fn diff_datasets(old: &Dataset, new: &Dataset) -> Vec<RuleChange> {
+    let mut changes = Vec::new();
+    let keys: BTreeSet<(&LanguageIdentifier, PluralRuleType)> = old
+        .iter()
+        .chain(new.iter())
+        .flat_map(|(lang, types)| types.keys().map(move |prt| (lang, *prt)))
+        .collect();
+    for (lang, prt) in keys {
+        let rules_of = |dataset: &'_ Dataset| -> Vec<(PluralCategory, Rule)> {
+            dataset
+                .get(lang)
+                .and_then(|types| types.get(&prt))
+                .into_iter()
+                .flatten()
+                .cloned()
+                .collect()
+        };
+        let (old_rules, new_rules) = (rules_of(old), rules_of(new));
+        let find = |rules: &[(PluralCategory, Rule)], category| {
+            rules
+                .iter()
+                .find(|(c, _)| *c == category)
+                .map(|(_, rule)| rule.clone())
+        };
+
+        for category in PluralCategory::all() {
+            let kind = match (find(&old_rules, category), find(&new_rules, category)) {
+                (None, Some(new)) => RuleChangeKind::Added(new.condition.to_string()),
+                (Some(old), None) => RuleChangeKind::Removed(old.condition.to_string()),
+                (Some(mut old), Some(mut new)) => {
+                    let (old_source, new_source) =
+                        (old.condition.to_string(), new.condition.to_string());
+                    old.normalize();
+                    new.normalize();
+                    if old.condition == new.condition {
+                        continue;
+                    }
+                    RuleChangeKind::Changed {
+                        old: old_source,
+                        new: new_source,
+                    }
+                }
+                _ => continue,
+            };
+            changes.push(RuleChange {
+                locale: lang.clone(),
+                ty: prt,
+                category,
+                kind,
+            });
+        }
+    }
+    changes
+}
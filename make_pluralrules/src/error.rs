@@ -0,0 +1,86 @@
+//! Errors reported while generating the Rust code.
+
+use cldr_pluralrules_parser::PluralRuleParseError;
+use intl_pluralrules::PluralCategory;
+use thiserror::Error;
+
+/// An error preventing the generation of plural rules.
+#[derive(Debug, Error)]
+pub enum GenerateError {
+    /// The input contains a `plurals-type-<name>` section for an unknown plural rule type.
+    #[error("unknown plural rule type `{0}`")]
+    UnknownPluralType(String),
+    /// The input at this index is not valid JSON, or doesn't have the shape of CLDR supplemental data.
+    #[error("input #{input} is not valid CLDR JSON: {error}")]
+    InvalidJson { input: usize, error: String },
+    /// The input is not valid ICU resource bundle text, or doesn't have the shape of plural rules data.
+    #[error("invalid ICU plural rules: {0}")]
+    InvalidIcu(String),
+    /// A locale of the input is not a valid language identifier.
+    #[error("invalid locale `{0}`")]
+    InvalidLocale(String),
+    /// The input at this index doesn't contain any plural rules.
+    #[error("input #{0} contains no plural rules")]
+    NoRulesFound(usize),
+    /// No input was given.
+    #[error("no inputs were given")]
+    NoInputs,
+    /// The input at this index is of another CLDR version than the earlier inputs, and no version overrides them.
+    #[error(
+        "input #{input} is of CLDR version `{found}`, but the earlier inputs are of `{expected}`"
+    )]
+    VersionMismatch {
+        input: usize,
+        expected: String,
+        found: String,
+    },
+    /// The CLDR version of the input is not made of dot separated numbers, such as `44.1`.
+    #[error("invalid CLDR version `{0}`")]
+    InvalidCldrVersion(String),
+    /// A rule name of the input is not `pluralRule-count-<category>` for a CLDR plural category.
+    #[error("unknown rule name `{rule_name}` of `{locale}`")]
+    UnknownRuleName { locale: String, rule_name: String },
+    /// A rule of a rule description, as of ICU data or `GenerateOptions::rule_overrides`, doesn't start with
+    /// the keyword of its category, such as `one:`.
+    #[error("the rule `{rule}` of `{locale}` has no category keyword")]
+    MissingCategoryKeyword { locale: String, rule: String },
+    /// A rule of the input is not valid CLDR plural rule syntax.
+    #[error("invalid `{rule_name}` rule of `{locale}`: {error}")]
+    InvalidRule {
+        locale: String,
+        rule_name: String,
+        error: PluralRuleParseError,
+    },
+    /// A sample value of a rule selects another category than the one it is listed for.
+    #[error("sample `{sample}` of `{locale}` for {expected:?} selects {actual:?}")]
+    SampleMismatch {
+        locale: String,
+        sample: String,
+        expected: PluralCategory,
+        actual: PluralCategory,
+    },
+    /// A sample value of a rule can't be converted to plural operands, so it can't be checked.
+    #[error("sample `{sample}` of `{locale}` can't be evaluated")]
+    UnevaluableSample { locale: String, sample: String },
+    /// A locale lists several rules for the same category, and merging them is not enabled.
+    #[error("`{locale}` lists several rules for {category:?}")]
+    DuplicateCategory {
+        locale: String,
+        category: PluralCategory,
+    },
+    /// The operands path is not a valid Rust path.
+    #[error("invalid operands path `{0}`")]
+    InvalidOperandsPath(String),
+    /// The runtime path is not a valid Rust path.
+    #[error("invalid runtime path `{0}`")]
+    InvalidRuntimePath(String),
+    /// The module name is not a valid Rust identifier.
+    #[error("invalid module name `{0}`")]
+    InvalidModuleName(String),
+    /// The operand accessor is not a valid Rust expression for a known operand.
+    #[error("invalid operand accessor `{0}`")]
+    InvalidOperandAccessor(String),
+    /// Reading or writing the generated file failed.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
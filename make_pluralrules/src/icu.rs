@@ -0,0 +1,211 @@
+//! Reading of plural rules in ICU's resource bundle text format, as an alternative to CLDR JSON.
+//!
+//! The rules are listed in a table per plural rule type, such as `cardinal`, with the rules of each locale
+//! in ICU's rule description syntax, where each rule is prefixed by its category keyword and ends with `;`:
+//!
+//! ```text
+//! // Comments run to the end of the line.
+//! plurals{
+//!     Version{"33"}
+//!     cardinal{
+//!         en{"one: i = 1 and v = 0 @integer 1; other: @integer 0, 2~16"}
+//!         root{"other: @integer 0~15"}
+//!     }
+//! }
+//! ```
+//!
+//! As in ICU resource bundles, the name of the outer table is free, and adjacent strings are concatenated.
+
+use crate::GenerateError;
+use serde_json::{Map, Value, json};
+
+/// A resource of the text format, a string or a table of named resources.
+enum Resource {
+    String(String),
+    Table(Vec<(String, Resource)>),
+}
+
+/// Reads the resources of the text format, keeping track of the line for errors.
+struct Reader<'a> {
+    rest: &'a str,
+    line: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn error(&self, message: &str) -> GenerateError {
+        GenerateError::InvalidIcu(format!("{} at line {}", message, self.line))
+    }
+
+    fn advance(&mut self, len: usize) {
+        self.line += self.rest[..len].matches('\n').count();
+        self.rest = &self.rest[len..];
+    }
+
+    /// Skips whitespace and `//` comments.
+    fn skip_space(&mut self) {
+        loop {
+            let len = self.rest.len() - self.rest.trim_start().len();
+            self.advance(len);
+            if !self.rest.starts_with("//") {
+                return;
+            }
+            let len = self.rest.find('\n').unwrap_or(self.rest.len());
+            self.advance(len);
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), GenerateError> {
+        self.skip_space();
+        if !self.rest.starts_with(c) {
+            return Err(self.error(&format!("expected `{}`", c)));
+        }
+        self.advance(c.len_utf8());
+        Ok(())
+    }
+
+    /// Reads the named resources of a table, up to its closing brace or the end of the input.
+    fn entries(&mut self) -> Result<Vec<(String, Resource)>, GenerateError> {
+        let mut entries = Vec::new();
+        loop {
+            self.skip_space();
+            if self.rest.is_empty() || self.rest.starts_with('}') {
+                return Ok(entries);
+            }
+            let len = self
+                .rest
+                .find(|c: char| c.is_whitespace() || matches!(c, '{' | '}' | '"'))
+                .unwrap_or(self.rest.len());
+            if len == 0 {
+                return Err(self.error("expected a resource name"));
+            }
+            let name = self.rest[..len].to_owned();
+            self.advance(len);
+            self.expect('{')?;
+            self.skip_space();
+            let resource = if self.rest.starts_with('"') {
+                let mut value = String::new();
+                while self.rest.starts_with('"') {
+                    value.push_str(&self.string()?);
+                    self.skip_space();
+                }
+                Resource::String(value)
+            } else {
+                Resource::Table(self.entries()?)
+            };
+            self.expect('}')?;
+            entries.push((name, resource));
+        }
+    }
+
+    /// Reads a quoted string, with the `\"`, `\\` and `\uXXXX` escapes.
+    fn string(&mut self) -> Result<String, GenerateError> {
+        let rest = self.rest;
+        let mut value = String::new();
+        let mut chars = rest.char_indices().skip(1);
+        while let Some((idx, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.advance(idx + 1);
+                    return Ok(value);
+                }
+                '\\' => match chars.next() {
+                    Some((_, escaped @ ('"' | '\\'))) => value.push(escaped),
+                    Some((start, 'u')) => {
+                        let code = rest
+                            .get(start + 1..start + 5)
+                            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| self.error("invalid `\\u` escape"))?;
+                        value.push(code);
+                        chars.nth(3);
+                    }
+                    _ => return Err(self.error("invalid escape")),
+                },
+                c => value.push(c),
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+}
+
+/// Converts plural rules in ICU's resource bundle text format into the equivalent CLDR JSON, which all the
+/// functions generating code take.
+///
+/// Each rule is checked with the parser, failing with `GenerateError::InvalidRule` as for CLDR JSON.
+///
+/// # Examples
+///
+/// ```
+/// use make_pluralrules::{generate_rs, icu_to_cldr_json};
+///
+/// let icu = r#"plurals{
+///     Version{"33"}
+///     cardinal{
+///         en{"one: i = 1 and v = 0 @integer 1; other: @integer 0, 2~16"}
+///     }
+/// }"#;
+/// let json = icu_to_cldr_json(icu).expect("The rules are valid");
+/// assert!(generate_rs(&[json]).is_ok());
+/// ```
+pub fn icu_to_cldr_json(source: &str) -> Result<String, GenerateError> {
+    let mut reader = Reader {
+        rest: source.strip_prefix('\u{FEFF}').unwrap_or(source),
+        line: 1,
+    };
+    let mut entries = reader.entries()?;
+    if !reader.rest.is_empty() {
+        return Err(reader.error("unexpected `}`"));
+    }
+    let (Some((_, Resource::Table(tables))), None) = (entries.pop(), entries.pop()) else {
+        return Err(GenerateError::InvalidIcu(
+            "the input is not a single table".to_owned(),
+        ));
+    };
+
+    let mut version = None;
+    let mut supplemental = Map::new();
+    for (name, resource) in tables {
+        match (name.as_str(), resource) {
+            ("Version", Resource::String(value)) => version = Some(value),
+            (type_name, Resource::Table(locales)) => {
+                let mut rules = Map::new();
+                for (locale, resource) in locales {
+                    let Resource::String(description) = resource else {
+                        return Err(GenerateError::InvalidIcu(format!(
+                            "the rules of `{}` are not a string",
+                            locale
+                        )));
+                    };
+                    let lines =
+                        crate::description_rules(&locale, &description).map_err(
+                            |err| match err {
+                                GenerateError::MissingCategoryKeyword { .. } => {
+                                    GenerateError::InvalidIcu(err.to_string())
+                                }
+                                err => err,
+                            },
+                        )?;
+                    let lines = lines
+                        .into_iter()
+                        .map(|(rule_name, body)| (rule_name, Value::String(body)))
+                        .collect();
+                    rules.insert(locale, Value::Object(lines));
+                }
+                supplemental.insert(format!("plurals-type-{}", type_name), Value::Object(rules));
+            }
+            (name, Resource::String(_)) => {
+                return Err(GenerateError::InvalidIcu(format!(
+                    "`{}` is not a table of rules",
+                    name
+                )));
+            }
+        }
+    }
+    let version =
+        version.ok_or_else(|| GenerateError::InvalidIcu("missing `Version`".to_owned()))?;
+    supplemental.insert(
+        "version".to_owned(),
+        json!({ "_unicodeVersion": "", "_cldrVersion": version }),
+    );
+    Ok(json!({ "supplemental": supplemental }).to_string())
+}
@@ -0,0 +1,169 @@
+//! Expansion of the `--input` entries of the command line into input files.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::parser::resource::parse_plurals_resource_from_string;
+
+/// An input file of the generator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    /// The path of the file, `-` standing for stdin.
+    pub path: PathBuf,
+    /// Whether the file was found by expanding a directory or a glob pattern, rather than listed itself.
+    /// Such files are skipped if they don't contain plural rules.
+    pub expanded: bool,
+}
+
+/// Expands the input entries into the files to read, in order and without duplicates.
+///
+/// A directory expands to the `*.json` files below it, recursively, and an entry containing `*` or `?`
+/// to the files matching it as a glob pattern, where `**` matches any number of directories.
+/// Both are sorted by path. Other entries, such as `-` for stdin, are kept as they are.
+///
+/// A file reached twice is only kept at its first position, as listed if it is listed itself.
+pub fn expand_inputs(entries: &[String]) -> io::Result<Vec<Input>> {
+    let mut inputs: Vec<Input> = Vec::new();
+    let mut seen: HashMap<PathBuf, usize> = HashMap::new();
+    for entry in entries {
+        let path = Path::new(entry);
+        let (mut paths, expanded) = if is_pattern(entry) {
+            (glob(path)?, true)
+        } else if path.is_dir() {
+            let mut paths = Vec::new();
+            walk_json(path, &mut paths)?;
+            (paths, true)
+        } else {
+            (vec![path.to_owned()], false)
+        };
+        paths.sort();
+
+        for path in paths {
+            let key = fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
+            match seen.get(&key) {
+                Some(&idx) => inputs[idx].expanded &= expanded,
+                None => {
+                    seen.insert(key, inputs.len());
+                    inputs.push(Input { path, expanded });
+                }
+            }
+        }
+    }
+    Ok(inputs)
+}
+
+/// Whether the JSON is a CLDR resource with a `plurals-type-<name>` section.
+pub fn contains_plural_rules(json: &str) -> bool {
+    matches!(parse_plurals_resource_from_string(json), Ok(Some(_)))
+}
+
+fn is_pattern(entry: &str) -> bool {
+    entry.contains(['*', '?'])
+}
+
+/// Collects the `*.json` files below `dir`.
+fn walk_json(dir: &Path, paths: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            walk_json(&path, paths)?;
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Collects the files matching the glob pattern.
+fn glob(pattern: &Path) -> io::Result<Vec<PathBuf>> {
+    // The leading components without wildcards name the directory to search.
+    let mut base = PathBuf::new();
+    let mut components = pattern.components().peekable();
+    while let Some(component) =
+        components.next_if(|c| !is_pattern(&c.as_os_str().to_string_lossy()))
+    {
+        base.push(component);
+    }
+    let parts: Vec<String> = components
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+
+    let mut paths = Vec::new();
+    let dir = if base.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        base.clone()
+    };
+    glob_in(&dir, &base, &parts, &mut paths)?;
+    Ok(paths)
+}
+
+/// Collects the files below `dir`, shown as `shown`, matching the pattern components.
+fn glob_in(dir: &Path, shown: &Path, parts: &[String], paths: &mut Vec<PathBuf>) -> io::Result<()> {
+    let Some((part, rest)) = parts.split_first() else {
+        return Ok(());
+    };
+    if part == "**" {
+        // `**` matches no directory, or one more directory followed by `**` again.
+        glob_in(dir, shown, rest, paths)?;
+        for entry in dir_entries(dir)? {
+            if entry.is_dir() {
+                let name = entry.file_name().unwrap();
+                glob_in(&entry, &shown.join(name), parts, paths)?;
+            }
+        }
+        return Ok(());
+    }
+    for entry in dir_entries(dir)? {
+        let name = entry.file_name().unwrap();
+        if !matches_wildcard(part, &name.to_string_lossy()) {
+            continue;
+        }
+        if rest.is_empty() {
+            if entry.is_file() {
+                paths.push(shown.join(name));
+            }
+        } else if entry.is_dir() {
+            glob_in(&entry, &shown.join(name), rest, paths)?;
+        }
+    }
+    Ok(())
+}
+
+fn dir_entries(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect()
+}
+
+/// Matches a file name against a pattern, where `*` matches any characters and `?` a single one.
+fn matches_wildcard(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // The positions after the last `*` and the name character it was matched up to, to backtrack to.
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p + 1, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
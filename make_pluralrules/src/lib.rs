@@ -7,113 +7,951 @@
 //! cargo run -- -i <./path/to/cldr.json>... -o <./path/to/output.rs>
 //! ```
 
+mod check;
+mod diff;
+mod error;
+#[cfg(feature = "icu")]
+mod icu;
+mod inputs;
 mod parser;
+mod pretty;
+mod stats;
 
+pub use crate::check::{RuleIssue, RuleIssueKind, check_rules};
+pub use crate::diff::{RuleChange, RuleChangeKind, diff_datasets};
+pub use crate::error::GenerateError;
+#[cfg(feature = "icu")]
+pub use crate::icu::icu_to_cldr_json;
+pub use crate::inputs::{Input, contains_plural_rules, expand_inputs};
+use crate::parser::gen_pr::OperandAccessors;
+use crate::parser::gen_rs::TypeTokens;
 use crate::parser::plural_category::PluralCategory;
+use crate::parser::plural_type;
 use crate::parser::resource::*;
-use proc_macro2::TokenStream;
-use std::collections::BTreeMap;
+pub use crate::pretty::pretty_print;
+pub use crate::stats::{LocaleSize, SizeReport, UsageStats, size_report, usage_stats};
+use cldr_pluralrules_parser::SampleError;
+use cldr_pluralrules_parser::ast::{Condition, Rule};
+use intl_pluralrules::PluralRuleType;
+use proc_macro2::{Ident, TokenStream};
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
 use unic_langid::LanguageIdentifier;
 
+/// Options controlling the shape of the generated Rust code.
+#[derive(Debug, Clone, Default, Hash)]
+pub struct GenerateOptions {
+    /// Store rules as `&'static (dyn Fn(&PluralOperands) -> PluralCategory + Sync)` trait objects
+    /// instead of bare `fn` pointers, so compiled rules and rules registered at runtime share one type.
+    pub dyn_rules: bool,
+    /// Export a `pub fn select_<locale>_<type>(po: &PluralOperands) -> PluralCategory` function per locale,
+    /// such as `select_en_cardinal`, which the tables refer to.
+    pub export_selectors: bool,
+    /// Fail with `GenerateError::SampleMismatch` if any sample value of a rule selects another category
+    /// than the one it is listed for, or with `GenerateError::UnevaluableSample` if it can't be evaluated.
+    pub strict_samples: bool,
+    /// The path of the module providing the `PluralCategory` and `PluralRuleType` types and the `operands` module
+    /// to the generated code, such as `intl_pluralrules` or `crate::plurals`, instead of `super`.
+    pub runtime_path: Option<String>,
+    /// Generate the code into a `pub mod` of this name, such as `v44`, instead of at the top level of the file.
+    /// Paths relative to the file, such as the default runtime path `super`, are resolved from the file.
+    pub module: Option<String>,
+    /// The path of the operands type the generated code uses instead of `<runtime path>::operands::PluralOperands`,
+    /// such as `crate::numbers::Operands`.
+    pub operands_path: Option<String>,
+    /// Accessors the generated code uses instead of the operand fields, keyed by operand,
+    /// such as `integer_part()` for `i`, which reads `i` as `po.integer_part()`.
+    /// The compact exponent `c`, which also covers its alias `e`, is `0` unless given an accessor.
+    pub operand_accessors: BTreeMap<char, String>,
+    /// Embed the source of each rule condition, looked up with a generated
+    /// `pub fn rule_source(lang, ty, category) -> Option<&'static str>`.
+    pub embed_sources: bool,
+    /// Embed the sample values of each rule, including `other`, with the ranges expanded and the compact
+    /// values, such as `1.1c6`, kept as written, looked up
+    /// with a generated `pub fn samples_for(lang, ty, category) -> Option<&'static [&'static str]>`.
+    pub embed_samples: bool,
+    /// Generate a `#[cfg(test)]` module asserting that every sample value of a rule, including `other`,
+    /// selects the category it is listed for. The operands type must implement `TryFrom<&str>`.
+    pub sample_tests: bool,
+    /// Look locales up in a generated `phf::Map` keyed by their canonical string, such as `PRS_CARDINAL_PHF`,
+    /// instead of binary searching the sorted tables. The crate including the code then needs a `phf`
+    /// dependency with its `macros` feature, and `std`, as canonicalizing a locale allocates.
+    ///
+    /// Finding a key in the map takes constant time, but `select_cardinal` and `select_ordinal` first
+    /// canonicalize the locale with `to_string`, allocating a `String` on every lookup.
+    pub phf_lookup: bool,
+    /// Generate the items of each plural rule type in their own module, such as `cardinal` and `ordinal`,
+    /// which the file re-exports, so the generated API stays the same.
+    pub type_modules: bool,
+    /// Join the conditions of a category listed twice for a locale with `or`, instead of failing with
+    /// `GenerateError::DuplicateCategory`. Such data usually comes from merging two sources.
+    pub merge_duplicate_categories: bool,
+    /// Generate code for `#![no_std]` crates, leaving out the `<type>_map` functions, which build a `HashMap`.
+    /// The rule tables, selectors, embedded sources and samples remain available.
+    pub no_std: bool,
+    /// The version emitted into `CLDR_VERSION` instead of the one the inputs declare,
+    /// which then don't need to agree on their version.
+    pub cldr_version: Option<String>,
+    /// Also generate a `pub fn match_<type>(lang, po) -> Option<PluralCategory>` function per plural rule type,
+    /// such as `match_cardinal`, which selects the category with a `match` on the subtags of the locale whose
+    /// arms hold the rules, instead of calling a function of the rule table. The tables are left out of a binary
+    /// only using these functions, which the compiler can inline.
+    pub match_dispatch: bool,
+    /// Also generate a `pub fn minimal_pairs(lang, ty) -> impl Iterator<Item = (PluralCategory, PluralOperands)>`
+    /// function, yielding one example number per category of a locale, such as for previews of the plural forms
+    /// of a message. The example is the first sample of the category which selects it, or otherwise the first
+    /// one of a range of integers and decimals to do so. The operands type must implement `TryFrom<&str>`.
+    pub minimal_pairs: bool,
+    /// Leave the root rules, which CLDR lists as `root` and the tables as `und`, out of the generated code.
+    /// Locales without rules then fall back to the rules of their language only, and otherwise select `OTHER`.
+    pub exclude_root: bool,
+    /// Rules to generate for a locale and plural rule type in place of those of the inputs, such as for constructed
+    /// locales or to correct CLDR, in the rule description syntax of ICU: `one: n = 1..3; other: @integer 0, 4~16`.
+    /// They replace all the rules of the locale for the type, as a later input would.
+    pub rule_overrides: BTreeMap<(LanguageIdentifier, PluralRuleType), String>,
+    /// The locales to generate rules for, all of them if empty. Subtags left out of an entry match any
+    /// subtag, so `pt` also selects `pt-PT`.
+    pub only: Vec<LanguageIdentifier>,
+}
+
 /// Takes a string representation of a CLDR JSON file and produces a string representation of the generated Rust code for the plural rules.
 ///
 /// The string representation of the Rust code is written to a specified Rust file and can be used to get the plural category for numerical input.
-pub fn generate_rs(cldr_jsons: &[String]) -> String {
+///
+/// The inputs are layered in order: the tables of each plural rule type are independent, and within a type a
+/// locale of a later input replaces all the rules of the same locale in the earlier inputs. An override file
+/// can hence be given after the stock CLDR data to change the rules of a few locales. All inputs must have the
+/// same CLDR version, unless `GenerateOptions::cldr_version` is set.
+pub fn generate_rs(cldr_jsons: &[String]) -> Result<String, GenerateError> {
+    generate_rs_with(cldr_jsons, &GenerateOptions::default())
+}
+
+/// Same as `generate_rs`, but allows customizing the generated code with `GenerateOptions`.
+pub fn generate_rs_with(
+    cldr_jsons: &[String],
+    options: &GenerateOptions,
+) -> Result<String, GenerateError> {
+    gen_rust(&parse_inputs(cldr_jsons, options)?, options)
+}
+
+/// Same as `generate_rs_with`, but reads the CLDR JSON sources incrementally from `readers`, such as files.
+///
+/// The sources are never held in memory as a whole, and their supplemental data other than the plural rules is
+/// skipped as it is read, which keeps the memory use low for very large inputs. Each reader is buffered.
+pub fn generate_rs_from_readers<R: Read>(
+    readers: impl IntoIterator<Item = R>,
+    options: &GenerateOptions,
+) -> Result<String, GenerateError> {
+    let resources = readers
+        .into_iter()
+        .map(|reader| parse_plurals_resource_from_reader(BufReader::new(reader)));
+    gen_rust(&parse_resources(resources, options)?, options)
+}
+
+/// Same as `generate_rs_with`, but generates the rules of each CLDR version the inputs declare into a module of its
+/// own, such as `v43`, or `v44_1` for version `44.1`, with its own `CLDR_VERSION` and tables, so that several versions
+/// can be used side by side, such as during a migration.
+///
+/// The inputs of a version are layered as in `generate_rs`, and the modules are in version order.
+/// `GenerateOptions::module` and `GenerateOptions::cldr_version` are ignored.
+pub fn generate_versioned_rs(
+    cldr_jsons: &[String],
+    options: &GenerateOptions,
+) -> Result<String, GenerateError> {
+    if cldr_jsons.is_empty() {
+        return Err(GenerateError::NoInputs);
+    }
+    let mut versions: BTreeMap<Vec<u64>, (String, Vec<Resource>)> = BTreeMap::new();
+    for (idx, cldr_json) in cldr_jsons.iter().enumerate() {
+        let resource = parse_plurals_resource_from_string(cldr_json)
+            .map_err(|err| GenerateError::InvalidJson {
+                input: idx,
+                error: err.to_string(),
+            })?
+            .ok_or(GenerateError::NoRulesFound(idx))?;
+        let version = resource.supplemental.version.cldr_version.clone();
+        let key = version
+            .split('.')
+            .map(|part| part.parse().ok())
+            .collect::<Option<Vec<u64>>>()
+            .ok_or_else(|| GenerateError::InvalidCldrVersion(version.clone()))?;
+        versions
+            .entry(key)
+            .or_insert_with(|| (version, Vec::new()))
+            .1
+            .push(resource);
+    }
+
+    let mut code = String::new();
+    for (version, resources) in versions.into_values() {
+        let options = GenerateOptions {
+            module: Some(format!("v{}", version.replace('.', "_"))),
+            cldr_version: None,
+            ..options.clone()
+        };
+        let parsed = parse_resources(resources.into_iter().map(|r| Ok(Some(r))), &options)?;
+        code.push_str(&gen_rust(&parsed, &options)?);
+    }
+    Ok(code)
+}
+
+/// An output format of the generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Emit {
+    /// The Rust code, as generated by `generate_rs`.
+    Rust,
+    /// A JSON dump of the parsed conditions, keyed by plural rule type, locale and category.
+    AstJson,
+    /// A JSON manifest of the CLDR version and the locales of each plural rule type, in table order.
+    Manifest,
+    /// A standalone `PluralOperands` type, as generated by `generate_operands_rs`.
+    Operands,
+}
+
+impl Emit {
+    /// The name of the file the output format is written to in an output directory.
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Emit::Rust => "pluralrules.rs",
+            Emit::AstJson => "pluralrules.json",
+            Emit::Manifest => "manifest.json",
+            Emit::Operands => "operands.rs",
+        }
+    }
+}
+
+/// Generates every output format of `emits` from `cldr_jsons`, which are parsed only once.
+///
+/// The outputs are returned in the order of `emits`.
+pub fn generate_all(
+    cldr_jsons: &[String],
+    emits: &[Emit],
+    options: &GenerateOptions,
+) -> Result<Vec<String>, GenerateError> {
+    let parsed = parse_inputs(cldr_jsons, options)?;
+    emits
+        .iter()
+        .map(|emit| match emit {
+            Emit::Rust => gen_rust(&parsed, options),
+            Emit::AstJson => Ok(parser::gen_json::gen_ast_json(&parsed)),
+            Emit::Manifest => Ok(parser::gen_json::gen_manifest(&parsed)),
+            Emit::Operands => Ok(generate_operands_rs()),
+        })
+        .collect()
+}
+
+/// Produces the Rust code of a module defining a standalone `PluralOperands` type, with its `FromStr`, `TryFrom`
+/// and `From` implementations, so that the generated rules compile without the `intl_pluralrules` runtime.
+///
+/// The module is meant to be placed next to the generated rules as `operands.rs`, which they refer to as
+/// `super::operands`. The type derives the operands as `intl_pluralrules::operands::PluralOperands` does.
+pub fn generate_operands_rs() -> String {
+    parser::gen_operands::gen_operands().to_string()
+}
+
+/// The parsed plural rules of every locale, by plural rule type.
+pub type Dataset = BTreeMap<
+    LanguageIdentifier,
+    BTreeMap<PluralRuleType, Vec<(intl_pluralrules::PluralCategory, Rule)>>,
+>;
+
+/// Parses `cldr_jsons` into the rules the generator builds the Rust code from, for analyses of the data itself.
+///
+/// The rules of a locale are sorted by category name, include the `other` rule, and keep their samples.
+pub fn parse_dataset(cldr_jsons: &[String]) -> Result<Dataset, GenerateError> {
+    let parsed = parse_inputs(cldr_jsons, &GenerateOptions::default())?;
+    let mut dataset = Dataset::new();
+    for (prt, locales) in parsed.types {
+        for locale in locales {
+            let rules = locale
+                .lines
+                .iter()
+                .map(|(rule_name, rule_line)| {
+                    let (category, rule) = parse_rule_line(&locale.lang, rule_name, rule_line)?;
+                    Ok((category.into(), rule))
+                })
+                .collect::<Result<_, GenerateError>>()?;
+            dataset.entry(locale.lang).or_default().insert(prt, rules);
+        }
+    }
+    Ok(dataset)
+}
+
+/// The rules of a locale, as parsed from the CLDR data.
+pub(crate) struct LocaleRules {
+    pub lang: LanguageIdentifier,
+    /// The raw rule lines, sorted by rule name such as `pluralRule-count-one`.
+    pub lines: Vec<(String, String)>,
+    /// The condition of every category but `OTHER`, along with its source.
+    pub conditions: Vec<(PluralCategory, String, Condition)>,
+}
+
+/// The rules of all inputs, shared by the output formats.
+pub(crate) struct ParsedRules {
+    pub cldr_version: String,
+    /// The locales of each plural rule type, sorted by language identifier.
+    pub types: HashMap<PluralRuleType, Vec<LocaleRules>>,
+}
+
+fn parse_inputs(
+    cldr_jsons: &[String],
+    options: &GenerateOptions,
+) -> Result<ParsedRules, GenerateError> {
+    let resources = cldr_jsons
+        .iter()
+        .map(|cldr_json| parse_plurals_resource_from_string(cldr_json));
+    parse_resources(resources, options)
+}
+
+/// Collects the rules of the parsed `resources`, in input order.
+fn parse_resources(
+    resources: impl Iterator<Item = Result<Option<Resource>, Box<dyn Error>>>,
+    options: &GenerateOptions,
+) -> Result<ParsedRules, GenerateError> {
     let mut cldr_version = None;
-    let mut tokens = BTreeMap::new();
+    let mut types = HashMap::new();
 
-    for cldr_json in cldr_jsons {
+    for (idx, resource) in resources.enumerate() {
         // resource_items is a struct representation of the raw CLDR rules.
-        let resource_items = parse_plurals_resource_from_string(cldr_json).unwrap();
+        let resource_items = resource
+            .map_err(|err| GenerateError::InvalidJson {
+                input: idx,
+                error: err.to_string(),
+            })?
+            .ok_or(GenerateError::NoRulesFound(idx))?;
 
         let res_cldr_version = resource_items.supplemental.version.cldr_version;
 
-        if cldr_version.is_none() {
+        if options.cldr_version.is_some() {
+            cldr_version.clone_from(&options.cldr_version);
+        } else if let Some(expected) = &cldr_version {
+            if *expected != res_cldr_version {
+                return Err(GenerateError::VersionMismatch {
+                    input: idx,
+                    expected: expected.clone(),
+                    found: res_cldr_version,
+                });
+            }
+        } else {
             cldr_version = Some(res_cldr_version);
-        } else if cldr_version != Some(res_cldr_version) {
-            panic!("All input resources must use the same CLDR version!");
         }
 
-        if let Some(data) = resource_items.supplemental.plurals_type_cardinal {
-            let rule_tokens = gen_type_rs(data);
-            if tokens.contains_key("cardinal") {
-                panic!("Cannot provide two inputs with the same data!");
-            }
-            tokens.insert("cardinal".to_owned(), rule_tokens);
-        }
+        for (key, section) in resource_items.supplemental.sections {
+            let Some(name) = key.strip_prefix("plurals-type-") else {
+                continue;
+            };
+            let prt = plural_type::from_type_name(name)
+                .ok_or_else(|| GenerateError::UnknownPluralType(name.to_owned()))?;
+            let Section::PluralRules(data) = section else {
+                return Err(GenerateError::InvalidJson {
+                    input: idx,
+                    error: format!("`{}` is not an object of plural rules", key),
+                });
+            };
 
-        if let Some(data) = resource_items.supplemental.plurals_type_ordinal {
-            let rule_tokens = gen_type_rs(data);
-            if tokens.contains_key("ordinal") {
-                panic!("Cannot provide two inputs with the same data!");
-            }
-            tokens.insert("ordinal".to_owned(), rule_tokens);
+            let locales = parse_locales(data, options)?;
+            let locales = match types.remove(&prt) {
+                Some(earlier) => merge_locales(earlier, locales),
+                None => locales,
+            };
+            types.insert(prt, locales);
         }
     }
 
-    if cldr_version.is_none() || tokens.is_empty() {
-        panic!("None of the input files provided core data!");
+    // Every input has plural rules, so there are rules as soon as there is an input.
+    let Some(cldr_version) = cldr_version else {
+        return Err(GenerateError::NoInputs);
+    };
+
+    let mut overrides: BTreeMap<PluralRuleType, Vec<(String, Entries<String>)>> = BTreeMap::new();
+    for ((lang, prt), description) in &options.rule_overrides {
+        let locale = lang.to_string();
+        let lines = description_rules(&locale, description)?;
+        overrides
+            .entry(*prt)
+            .or_default()
+            .push((locale, Entries(lines)));
+    }
+    for (prt, section) in overrides {
+        let locales = parse_locales(Entries(section), options)?;
+        let locales = match types.remove(&prt) {
+            Some(earlier) => merge_locales(earlier, locales),
+            None => locales,
+        };
+        types.insert(prt, locales);
+    }
+
+    Ok(ParsedRules {
+        cldr_version,
+        types,
+    })
+}
+
+fn gen_rust(parsed: &ParsedRules, options: &GenerateOptions) -> Result<String, GenerateError> {
+    let accessors = OperandAccessors::new(&options.operand_accessors)?;
+    let mut tokens = HashMap::new();
+    for (prt, locales) in &parsed.types {
+        tokens.insert(*prt, gen_type_rs(*prt, locales, options, &accessors)?);
     }
 
     // Call gen_rs to get Rust code. Convert TokenStream to string for file out.
-    parser::gen_rs::gen_fn(tokens, &cldr_version.unwrap()).to_string()
+    Ok(parser::gen_rs::gen_fn(tokens, &parsed.cldr_version, options)?.to_string())
 }
 
-fn gen_type_rs(rules: BTreeMap<String, BTreeMap<String, String>>) -> Vec<TokenStream> {
-    // rule_tokens is a vector of TokenStreams that represent the CLDR plural rules as Rust expressions.
-    let mut rule_tokens = Vec::<TokenStream>::new();
+/// Generates the Rust code for `cldr_jsons` into `out_path`, unless the inputs are unchanged since the last generation.
+///
+/// A hash of the inputs, the options and the version of this crate is stored next to the output, in a `.hash`
+/// sidecar file, and the generation is skipped when it matches. This is meant for `build.rs` scripts, which would
+/// otherwise regenerate the rules on every build. The hash is FNV-1a, which unlike the standard library hasher does
+/// not change between Rust releases, but it still goes through the `Hash` implementations of the standard library,
+/// so a toolchain changing them may regenerate the output once.
+///
+/// Returns whether the output was regenerated.
+pub fn generate_rs_if_changed<P: AsRef<Path>>(
+    cldr_jsons: &[String],
+    out_path: P,
+) -> Result<bool, GenerateError> {
+    generate_rs_if_changed_with(cldr_jsons, out_path, &GenerateOptions::default())
+}
+
+/// Same as `generate_rs_if_changed`, but allows customizing the generated code with `GenerateOptions`.
+pub fn generate_rs_if_changed_with<P: AsRef<Path>>(
+    cldr_jsons: &[String],
+    out_path: P,
+    options: &GenerateOptions,
+) -> Result<bool, GenerateError> {
+    let out_path = out_path.as_ref();
+    let hash_path = hash_path(out_path);
+
+    let mut hasher = FnvHasher::default();
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+    cldr_jsons.hash(&mut hasher);
+    options.hash(&mut hasher);
+    let hash = format!("{:016x}", hasher.finish());
+
+    if out_path.exists() && fs::read_to_string(&hash_path).is_ok_and(|stored| stored == hash) {
+        return Ok(false);
+    }
+
+    fs::write(out_path, generate_rs_with(cldr_jsons, options)?)?;
+    fs::write(hash_path, hash)?;
+    Ok(true)
+}
+
+fn hash_path(out_path: &Path) -> PathBuf {
+    let mut path = out_path.as_os_str().to_owned();
+    path.push(".hash");
+    path.into()
+}
+
+/// The 64-bit FNV-1a hash, whose values are stable across Rust releases.
+struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        FnvHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
 
-    let mut rules: Vec<(LanguageIdentifier, BTreeMap<String, String>)> = rules
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Merges the locales of a later input into those of the earlier inputs of the same plural rule type.
+///
+/// A locale of the later input replaces all the rules of the same locale in the earlier ones. The locales stay
+/// sorted by language identifier.
+fn merge_locales(earlier: Vec<LocaleRules>, later: Vec<LocaleRules>) -> Vec<LocaleRules> {
+    let mut merged: BTreeMap<LanguageIdentifier, LocaleRules> = earlier
         .into_iter()
-        .filter_map(|(key, value)| {
-            if key == "root" {
-                None
-            } else {
-                let langid = key
-                    .parse()
-                    .unwrap_or_else(|_| panic!("Parsing {} failed", key));
-                Some((langid, value))
-            }
-        })
+        .map(|locale| (locale.lang.clone(), locale))
         .collect();
+    for locale in later {
+        merged.insert(locale.lang.clone(), locale);
+    }
+    merged.into_values().collect()
+}
 
-    // We rely on sorted list for binary search in the consumer.
-    rules.sort_unstable_by(|(langid1, _), (langid2, _)| langid1.cmp(langid2));
+fn parse_locales(
+    rules: PluralRulesSection,
+    options: &GenerateOptions,
+) -> Result<Vec<LocaleRules>, GenerateError> {
+    // A locale listed twice contributes all of its rules. The map keeps the locales sorted,
+    // as we rely on sorted list for binary search in the consumer.
+    let mut locales: BTreeMap<LanguageIdentifier, Vec<(String, String)>> = BTreeMap::new();
+    for (key, lines) in rules.0 {
+        // CLDR lists the root rules, which apply to any language without rules of its own, as `root`.
+        let langid: LanguageIdentifier = if key == "root" {
+            LanguageIdentifier::default()
+        } else {
+            key.parse()
+                .map_err(|_| GenerateError::InvalidLocale(key.clone()))?
+        };
+        let langid = if langid == LanguageIdentifier::default() {
+            parser::gen_rs::root_langid()
+        } else {
+            langid
+        };
+        if !parser::gen_rs::is_generatable(&langid) {
+            return Err(GenerateError::InvalidLocale(key));
+        }
+        if options.exclude_root && langid == parser::gen_rs::root_langid() {
+            continue;
+        }
+        if is_selected(&langid, &options.only) {
+            locales.entry(langid).or_default().extend(lines.0);
+        }
+    }
 
-    for (lang, r) in rules {
-        // this_lang_rules is a vector of plural rules saved as a PluralCategory and a TokenStream
-        let mut this_lang_rules = Vec::<(PluralCategory, TokenStream)>::new();
-
-        for (rule_name, rule_line) in r {
-            // cat_name is the simplified category name from the CLDR source file
-            let cat_name = rule_name.split('-').collect::<Vec<_>>()[2];
-
-            // representation is the
-            let representation = cldr_pluralrules_parser::parse_plural_condition(rule_line)
-                .expect("Parsing of a condition succeeded");
-
-            let cat = if cat_name == "zero" {
-                PluralCategory::ZERO
-            } else if cat_name == "one" {
-                PluralCategory::ONE
-            } else if cat_name == "two" {
-                PluralCategory::TWO
-            } else if cat_name == "few" {
-                PluralCategory::FEW
-            } else if cat_name == "many" {
-                PluralCategory::MANY
-            } else {
-                PluralCategory::OTHER
-            };
+    locales
+        .into_iter()
+        .map(|(lang, mut lines)| {
+            lines.sort_by(|(name1, _), (name2, _)| name1.cmp(name2));
+
+            let mut conditions = Vec::<(PluralCategory, String, Condition)>::new();
+            for (rule_name, rule_line) in &lines {
+                let cat = category_from_rule_name(rule_name).ok_or_else(|| {
+                    GenerateError::UnknownRuleName {
+                        locale: lang.to_string(),
+                        rule_name: rule_name.clone(),
+                    }
+                })?;
 
-            // Only allow rules that are not `OTHER` to be added. `OTHER` can have no rules and is added outside of the loop.
-            if cat != PluralCategory::OTHER {
-                let tokens = parser::gen_pr::gen_pr(representation);
-                this_lang_rules.push((cat, tokens));
+                // Only allow rules that are not `OTHER` to be added. `OTHER` can have no rules and is added by the output formats.
+                if cat == PluralCategory::OTHER {
+                    continue;
+                }
+                let source = match rule_line.split_once('@') {
+                    Some((condition, _)) => condition.trim().to_owned(),
+                    None => rule_line.trim().to_owned(),
+                };
+                // representation is the AST of the condition. The samples are only checked with `strict_samples`.
+                let representation = cldr_pluralrules_parser::parse(&source)
+                    .map_err(|error| GenerateError::InvalidRule {
+                        locale: lang.to_string(),
+                        rule_name: rule_name.clone(),
+                        error,
+                    })?
+                    .condition;
+
+                match conditions.iter_mut().find(|(c, _, _)| *c == cat) {
+                    Some((_, merged_source, merged)) if options.merge_duplicate_categories => {
+                        *merged_source = format!("{} or {}", merged_source, source);
+                        merged.0.extend(representation.0);
+                    }
+                    Some(_) => {
+                        return Err(GenerateError::DuplicateCategory {
+                            locale: lang.to_string(),
+                            category: cat.into(),
+                        });
+                    }
+                    None => conditions.push((cat, source, representation)),
+                }
             }
+            Ok(LocaleRules {
+                lang,
+                lines,
+                conditions,
+            })
+        })
+        .collect()
+}
+
+fn is_selected(lang: &LanguageIdentifier, only: &[LanguageIdentifier]) -> bool {
+    only.is_empty() || only.iter().any(|filter| filter.matches(lang, true, false))
+}
+
+/// Returns the entries of an `only` locale filter which select none of the locales of `dataset`.
+pub fn unmatched_locales<'a>(
+    dataset: &Dataset,
+    only: &'a [LanguageIdentifier],
+) -> Vec<&'a LanguageIdentifier> {
+    only.iter()
+        .filter(|filter| !dataset.keys().any(|lang| filter.matches(lang, true, false)))
+        .collect()
+}
+
+/// Returns each locale of `dataset` along with the CLDR name of each of its plural rule types,
+/// such as `cardinal`, sorted by locale and then in the order the types are generated.
+pub fn locale_coverage(dataset: &Dataset) -> Vec<(&LanguageIdentifier, &'static str)> {
+    dataset
+        .iter()
+        .flat_map(|(lang, types)| {
+            plural_type::PLURAL_RULE_TYPES
+                .iter()
+                .filter(|(prt, _, _)| types.contains_key(prt))
+                .map(move |(_, type_name, _)| (lang, *type_name))
+        })
+        .collect()
+}
+
+/// Groups the locales of `dataset` whose rules are equal once normalized, ignoring their samples, by the
+/// CLDR name of each plural rule type in the order the types are generated.
+///
+/// Each class lists its locales in order, and the classes of a type are ordered by their first locale.
+///
+/// # Examples
+///
+/// ```
+/// use make_pluralrules::{equivalent_locales, parse_dataset};
+///
+/// let json = std::fs::read_to_string("./tests/fixtures/cldr_pluralrules_cardinals_33.json").unwrap();
+/// let dataset = parse_dataset(&[json]).expect("The rules parse");
+/// let classes = equivalent_locales(&dataset);
+/// let (_, english) = classes
+///     .iter()
+///     .find(|(_, langs)| langs.iter().any(|lang| lang.to_string() == "en"))
+///     .unwrap();
+/// assert!(english.iter().any(|lang| lang.to_string() == "de"));
+/// ```
+pub fn equivalent_locales(dataset: &Dataset) -> Vec<(&'static str, Vec<&LanguageIdentifier>)> {
+    plural_type::PLURAL_RULE_TYPES
+        .iter()
+        .flat_map(|(prt, type_name, _)| {
+            let locales = dataset
+                .iter()
+                .filter_map(|(lang, types)| Some((lang, types.get(prt)?.as_slice())));
+            cldr_pluralrules_parser::equivalence_classes(locales)
+                .into_iter()
+                .map(|class| (*type_name, class))
+        })
+        .collect()
+}
+
+/// A rule function shared by the locales with identical rules.
+struct SharedRule<'a> {
+    name: Ident,
+    langs: Vec<&'a LanguageIdentifier>,
+    rules: Vec<(PluralCategory, TokenStream)>,
+    /// The sources of the first locale using the rule.
+    sources: Vec<(PluralCategory, String)>,
+}
+
+fn gen_type_rs(
+    prt: PluralRuleType,
+    locales: &[LocaleRules],
+    options: &GenerateOptions,
+    accessors: &OperandAccessors,
+) -> Result<TypeTokens, GenerateError> {
+    // rule_tokens is a vector of TokenStreams that represent the CLDR plural rules as Rust expressions.
+    let mut rule_tokens = Vec::<TokenStream>::new();
+    let mut category_tokens = Vec::<TokenStream>::new();
+    let mut category_count_tokens = Vec::<TokenStream>::new();
+    let mut operand_tokens = Vec::<TokenStream>::new();
+    let mut function_tokens = Vec::<TokenStream>::new();
+    let mut source_tokens = Vec::<TokenStream>::new();
+    let mut phf_tokens = Vec::<TokenStream>::new();
+    let mut sample_tokens = Vec::<TokenStream>::new();
+    let mut minimal_pair_tokens = Vec::<TokenStream>::new();
+    // The locales with identical rules also share a `match` arm.
+    let mut match_groups = HashMap::<String, usize>::new();
+    let mut match_arms = Vec::<(Vec<&LanguageIdentifier>, Vec<_>)>::new();
+    // Many locales have identical rules, which share a single function keyed by its rules. The functions are
+    // generated once all the locales sharing them, named in their doc comments, are known.
+    let mut shared_rules = HashMap::<String, usize>::new();
+    let mut shared_functions = Vec::<SharedRule>::new();
+
+    for LocaleRules {
+        lang,
+        lines,
+        conditions,
+    } in locales
+    {
+        if options.strict_samples {
+            validate_samples(lang, lines)?;
+        }
+
+        // this_lang_rules is a vector of plural rules saved as a PluralCategory and a TokenStream
+        let this_lang_rules: Vec<_> = conditions
+            .iter()
+            .map(|(cat, _, condition)| (*cat, parser::gen_pr::gen_pr(condition.clone(), accessors)))
+            .collect();
+        let this_lang_sources: Vec<_> = conditions
+            .iter()
+            .map(|(cat, source, _)| (*cat, source.clone()))
+            .collect();
+        let categories: Vec<_> = this_lang_rules.iter().map(|(cat, _)| *cat).collect();
+        category_tokens.push(parser::gen_rs::gen_categories(lang, &categories));
+        category_count_tokens.push(parser::gen_rs::gen_category_count(&categories));
+        let operands = conditions
+            .iter()
+            .flat_map(|(_, _, condition)| {
+                let rule = Rule {
+                    condition: condition.clone(),
+                    samples: None,
+                };
+                rule.operands_used()
+            })
+            .collect();
+        operand_tokens.push(parser::gen_rs::gen_operands(&operands));
+        if options.embed_sources {
+            source_tokens.push(parser::gen_rs::gen_sources(lang, &this_lang_sources));
+        }
+        if options.embed_samples || options.sample_tests {
+            let this_lang_samples = locale_samples(lang, lines)?;
+            sample_tokens.push(parser::gen_rs::gen_samples(lang, &this_lang_samples));
         }
+        if options.minimal_pairs {
+            let pairs = locale_minimal_pairs(lang, lines)?;
+            minimal_pair_tokens.push(parser::gen_rs::gen_minimal_pairs(&pairs));
+        }
+        let key = this_lang_rules
+            .iter()
+            .map(|(cat, tokens)| format!("{:?} {}", cat, tokens))
+            .collect::<Vec<_>>()
+            .join("; ");
+        if options.match_dispatch {
+            let idx = *match_groups.entry(key.clone()).or_insert_with(|| {
+                match_arms.push((Vec::new(), this_lang_rules.clone()));
+                match_arms.len() - 1
+            });
+            match_arms[idx].0.push(lang);
+        }
+        let rule_name = if options.export_selectors {
+            let doc = parser::gen_rs::gen_rule_doc(&[lang], prt, &this_lang_sources);
+            function_tokens.push(parser::gen_rs::gen_selector(
+                lang,
+                prt,
+                &this_lang_rules,
+                &doc,
+            ));
+            parser::gen_rs::selector_name(lang, prt)
+        } else {
+            let idx = *shared_rules.entry(key).or_insert_with(|| {
+                shared_functions.push(SharedRule {
+                    name: parser::gen_rs::shared_rule_name(prt, shared_functions.len()),
+                    langs: Vec::new(),
+                    rules: this_lang_rules,
+                    sources: this_lang_sources,
+                });
+                shared_functions.len() - 1
+            });
+            shared_functions[idx].langs.push(lang);
+            shared_functions[idx].name.clone()
+        };
         // convert language rules to TokenStream and add them to all the rules
-        rule_tokens.push(parser::gen_rs::gen_mid(&lang, &this_lang_rules));
+        if options.phf_lookup {
+            phf_tokens.push(parser::gen_rs::gen_phf_entry(lang, &rule_name, options));
+        }
+        rule_tokens.push(parser::gen_rs::gen_mid(lang, &rule_name, options));
+    }
+    for shared in shared_functions {
+        let doc = parser::gen_rs::gen_rule_doc(&shared.langs, prt, &shared.sources);
+        function_tokens.push(parser::gen_rs::gen_shared_rule(
+            &shared.name,
+            &shared.rules,
+            &doc,
+        ));
+    }
+    Ok(TypeTokens {
+        rules: rule_tokens,
+        categories: category_tokens,
+        category_counts: category_count_tokens,
+        operands: operand_tokens,
+        functions: function_tokens,
+        sources: source_tokens,
+        samples: sample_tokens,
+        minimal_pairs: minimal_pair_tokens,
+        phf_entries: phf_tokens,
+        match_arms: match_arms
+            .iter()
+            .map(|(langs, rules)| parser::gen_rs::gen_match_arm(langs, rules))
+            .collect(),
+    })
+}
+
+/// Returns the category of a `pluralRule-count-<category>` rule name, or `None` for an unknown name,
+/// which `parse_locales` reports.
+fn category_from_rule_name(rule_name: &str) -> Option<PluralCategory> {
+    match rule_name.strip_prefix("pluralRule-count-")? {
+        "zero" => Some(PluralCategory::ZERO),
+        "one" => Some(PluralCategory::ONE),
+        "two" => Some(PluralCategory::TWO),
+        "few" => Some(PluralCategory::FEW),
+        "many" => Some(PluralCategory::MANY),
+        "other" => Some(PluralCategory::OTHER),
+        _ => None,
+    }
+}
+
+/// Splits a rule description, such as `one: n is 1; other:`, into CLDR rule lines keyed by rule name.
+///
+/// Each rule is checked with the parser, failing with `GenerateError::InvalidRule` as for CLDR JSON.
+pub(crate) fn description_rules(
+    locale: &str,
+    description: &str,
+) -> Result<Vec<(String, String)>, GenerateError> {
+    let mut lines = Vec::new();
+    for rule in description
+        .split(';')
+        .map(str::trim)
+        .filter(|rule| !rule.is_empty())
+    {
+        let (keyword, body) =
+            rule.split_once(':')
+                .ok_or_else(|| GenerateError::MissingCategoryKeyword {
+                    locale: locale.to_owned(),
+                    rule: rule.to_owned(),
+                })?;
+        let rule_name = format!("pluralRule-count-{}", keyword.trim());
+        if category_from_rule_name(&rule_name).is_none() {
+            return Err(GenerateError::UnknownRuleName {
+                locale: locale.to_owned(),
+                rule_name,
+            });
+        }
+        let body = body.trim();
+        cldr_pluralrules_parser::parse(body).map_err(|error| GenerateError::InvalidRule {
+            locale: locale.to_owned(),
+            rule_name: rule_name.clone(),
+            error,
+        })?;
+        lines.push((rule_name, body.to_owned()));
+    }
+    Ok(lines)
+}
+
+/// Returns the category and the parsed rule, including its samples, of a rule line of a locale.
+fn parse_rule_line(
+    lang: &LanguageIdentifier,
+    rule_name: &str,
+    rule_line: &str,
+) -> Result<(PluralCategory, Rule), GenerateError> {
+    let category =
+        category_from_rule_name(rule_name).expect("Rule names are checked when parsing locales");
+    let rule =
+        cldr_pluralrules_parser::parse(rule_line).map_err(|error| GenerateError::InvalidRule {
+            locale: lang.to_string(),
+            rule_name: rule_name.to_owned(),
+            error,
+        })?;
+    Ok((category, rule))
+}
+
+/// Returns the sample values of every rule of a locale, sorted by category.
+fn locale_samples(
+    lang: &LanguageIdentifier,
+    rules: &[(String, String)],
+) -> Result<Vec<(PluralCategory, Vec<String>)>, GenerateError> {
+    let mut samples = rules
+        .iter()
+        .map(|(rule_name, rule_line)| {
+            let (category, rule) = parse_rule_line(lang, rule_name, rule_line)?;
+            // The generated rules have no compact exponent, which `1.1c6` needs to select its category,
+            // so compact values are kept as written rather than as `1100000`.
+            let values = rule
+                .samples
+                .iter()
+                .flat_map(|samples| samples.integer.iter().chain(samples.decimal.iter()))
+                .flat_map(|sample_list| sample_list.sample_ranges.iter())
+                .flat_map(|range| match range.lower_val.exponent {
+                    Some(_) => range.endpoints(),
+                    None => range.values(),
+                })
+                .collect();
+            Ok((category, values))
+        })
+        .collect::<Result<Vec<_>, GenerateError>>()?;
+    samples.sort_by_key(|(cat, _)| *cat);
+    Ok(samples)
+}
+
+/// Returns one example value per category of a locale, which selects the category with the rules of the locale.
+///
+/// The example is the first sample value of the category to select it, so that rules whose samples are missing
+/// or misfiled get one of the integers up to 1000 or the decimals up to 100.9 with one fraction digit instead.
+/// A category none of these values selects is left out.
+fn locale_minimal_pairs(
+    lang: &LanguageIdentifier,
+    rules: &[(String, String)],
+) -> Result<Vec<(PluralCategory, String)>, GenerateError> {
+    let mut rules = rules
+        .iter()
+        .map(|(rule_name, rule_line)| parse_rule_line(lang, rule_name, rule_line))
+        .collect::<Result<Vec<_>, GenerateError>>()?;
+    rules.sort_by_key(|(cat, _)| *cat);
+    let conditions: Vec<_> = rules
+        .iter()
+        .filter(|(cat, _)| *cat != PluralCategory::OTHER)
+        .map(|(cat, rule)| ((*cat).into(), rule.clone()))
+        .collect();
+    let selects = |cat: PluralCategory, value: &str| {
+        let cat = intl_pluralrules::PluralCategory::from(cat);
+        intl_pluralrules::operands::PluralOperands::try_from(value)
+            .is_ok_and(|po| cldr_pluralrules_parser::select(&conditions, &po) == cat)
+    };
+    let derived = || {
+        (0..=1000)
+            .map(|n: u32| n.to_string())
+            .chain((0..=100).flat_map(|integer: u32| {
+                (0..10).map(move |fraction| format!("{}.{}", integer, fraction))
+            }))
+    };
+    Ok(rules
+        .iter()
+        .filter_map(|(cat, rule)| {
+            let value = rule
+                .samples
+                .iter()
+                .flat_map(|samples| samples.representative_values())
+                .find(|value| selects(*cat, value))
+                .or_else(|| derived().find(|value| selects(*cat, value)))?;
+            Some((*cat, value))
+        })
+        .collect())
+}
+
+/// Checks that the sample values of every rule of a locale select the category they are listed for.
+fn validate_samples(
+    lang: &LanguageIdentifier,
+    rules: &[(String, String)],
+) -> Result<(), GenerateError> {
+    let rules = rules
+        .iter()
+        .map(|(rule_name, rule_line)| {
+            let (category, rule) = parse_rule_line(lang, rule_name, rule_line)?;
+            Ok((category.into(), rule))
+        })
+        .collect::<Result<Vec<_>, GenerateError>>()?;
+    let conditions: Vec<_> = rules
+        .iter()
+        .filter(|(cat, _)| *cat != intl_pluralrules::PluralCategory::OTHER)
+        .cloned()
+        .collect();
+    for (cat, rule) in &rules {
+        let Some(samples) = &rule.samples else {
+            continue;
+        };
+        samples
+            .validate_against(&conditions, *cat)
+            .map_err(|error| match error {
+                SampleError::Mismatch {
+                    sample,
+                    expected,
+                    actual,
+                } => GenerateError::SampleMismatch {
+                    locale: lang.to_string(),
+                    sample,
+                    expected,
+                    actual,
+                },
+                SampleError::Unevaluable { sample } => GenerateError::UnevaluableSample {
+                    locale: lang.to_string(),
+                    sample,
+                },
+            })?;
     }
-    rule_tokens
+    Ok(())
 }
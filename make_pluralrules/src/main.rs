@@ -1,46 +1,707 @@
-use clap::Parser;
-use make_pluralrules::generate_rs;
-use std::process::Command;
+use clap::{Parser, Subcommand, ValueEnum};
+use make_pluralrules::{
+    Dataset, Emit, GenerateError, GenerateOptions, RuleIssueKind, check_rules,
+    contains_plural_rules, diff_datasets, equivalent_locales, expand_inputs, generate_all,
+    generate_operands_rs, generate_versioned_rs, locale_coverage, parse_dataset, pretty_print,
+    size_report, unmatched_locales, usage_stats,
+};
+use unic_langid::LanguageIdentifier;
 
+use std::collections::BTreeSet;
 use std::fs;
-use std::io::Write;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// The input path reading from stdin.
+const STDIN_INPUT: &str = "-";
+
+/// The output path writing to stdout.
+const STDOUT_OUTPUT: &str = "-";
+
+/// The first bytes of gzip-compressed data.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Reads an input file, or stdin for `-`.
+///
+/// Gzip-compressed inputs are detected by their magic bytes, and decompressed with the `gzip` feature.
+fn read_input(path: &Path) -> io::Result<String> {
+    let mut input = Vec::new();
+    if path == Path::new(STDIN_INPUT) {
+        io::stdin().read_to_end(&mut input)?;
+    } else {
+        input = fs::read(path)?;
+    }
+    if input.starts_with(&GZIP_MAGIC) {
+        input = decompress(&input)?;
+    }
+    String::from_utf8(input).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+#[cfg(feature = "gzip")]
+fn decompress(compressed: &[u8]) -> io::Result<Vec<u8>> {
+    let mut input = Vec::new();
+    flate2::read::MultiGzDecoder::new(compressed).read_to_end(&mut input)?;
+    Ok(input)
+}
+
+#[cfg(not(feature = "gzip"))]
+fn decompress(_: &[u8]) -> io::Result<Vec<u8>> {
+    Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        "the input is gzip-compressed, decompress it first or build with the `gzip` feature",
+    ))
+}
+
+/// Reads an input with `read_input`, exiting with an error if that fails.
+fn read_input_or_exit(path: &Path) -> String {
+    read_input(path).unwrap_or_else(|err| {
+        eprintln!("Error: could not read `{}`: {}", path.display(), err);
+        std::process::exit(1);
+    })
+}
+
+/// Writes an output file, or stdout for `-`.
+fn write_output(path: &Path, contents: &str) -> io::Result<()> {
+    if path == Path::new(STDOUT_OUTPUT) {
+        io::stdout().write_all(contents.as_bytes())
+    } else {
+        fs::write(path, contents)
+    }
+}
+
+/// Compares `generated` with the existing output file at `path`, printing the lines which differ as a unified diff
+/// of a single hunk, from the first to the last differing line, and returns whether they are the same.
+///
+/// A missing file is compared as an empty file.
+fn diff_generated(path: &Path, generated: &str) -> bool {
+    let existing = match fs::read_to_string(path) {
+        Ok(existing) => existing,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(err) => {
+            eprintln!("Error: could not read `{}`: {}", path.display(), err);
+            std::process::exit(1);
+        }
+    };
+    if existing == generated {
+        return true;
+    }
+
+    let old: Vec<&str> = existing.lines().collect();
+    let new: Vec<&str> = generated.lines().collect();
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let removed = &old[prefix..old.len() - suffix];
+    let added = &new[prefix..new.len() - suffix];
+    // An empty side of a hunk starts at the line before it.
+    let start = |lines: &[&str]| prefix + usize::from(!lines.is_empty());
+    println!("--- {}", path.display());
+    println!("+++ {} (generated)", path.display());
+    println!(
+        "@@ -{},{} +{},{} @@",
+        start(removed),
+        removed.len(),
+        start(added),
+        added.len()
+    );
+    for line in removed {
+        println!("-{}", line);
+    }
+    for line in added {
+        println!("+{}", line);
+    }
+    eprintln!("Error: `{}` is out of date", path.display());
+    false
+}
+
+/// The output formats of the `--emit` option.
+#[derive(Clone, Copy, ValueEnum)]
+enum EmitArg {
+    /// Rust code
+    Rust,
+    /// JSON dump of the parsed conditions
+    AstJson,
+    /// JSON manifest of the generated locales
+    Manifest,
+    /// Standalone `PluralOperands` type
+    Operands,
+}
+
+impl From<EmitArg> for Emit {
+    fn from(emit: EmitArg) -> Self {
+        match emit {
+            EmitArg::Rust => Emit::Rust,
+            EmitArg::AstJson => Emit::AstJson,
+            EmitArg::Manifest => Emit::Manifest,
+            EmitArg::Operands => Emit::Operands,
+        }
+    }
+}
+
+/// The input formats of the `--format` option.
+#[cfg(feature = "icu")]
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum InputFormat {
+    /// CLDR JSON
+    Json,
+    /// ICU resource bundle text, with the rules in ICU's rule description syntax
+    Icu,
+}
+
+/// Converts an input in ICU's text format to CLDR JSON, if `--format icu` is given or, without `--format`,
+/// the input is a `*.txt` file, exiting with an error if that fails.
+#[cfg(feature = "icu")]
+fn convert_icu_or_exit(path: &Path, input: String, format: Option<InputFormat>) -> String {
+    let is_icu = match format {
+        Some(format) => format == InputFormat::Icu,
+        None => path.extension().is_some_and(|ext| ext == "txt"),
+    };
+    if !is_icu {
+        return input;
+    }
+    make_pluralrules::icu_to_cldr_json(&input).unwrap_or_else(|err| {
+        eprintln!("Error: `{}`: {}", path.display(), err);
+        std::process::exit(1);
+    })
+}
 
 #[derive(Parser)]
 #[command(name = "CLDR Plural Rules Rust Generator")]
 #[command(version = "0.1.0")]
 #[command(about = "Generates Rust code for CLDR plural rules.")]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
-    /// Input CLDR JSON plural rules files
-    #[arg(short, long, required = true)]
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Input CLDR JSON plural rules files, `-` (the default) reading from stdin.
+    /// A locale of a later input replaces the same locale of the earlier ones.
+    /// A directory reads the `*.json` files below it, and a glob pattern the files it matches,
+    /// skipping those without plural rules
+    #[arg(short, long)]
     input: Vec<String>,
 
-    /// Output RS file
-    #[arg(short, long, required = true)]
-    output: String,
+    /// Format of the inputs, by default ICU text for `*.txt` files and CLDR JSON otherwise
+    #[cfg(feature = "icu")]
+    #[arg(long, value_enum)]
+    format: Option<InputFormat>,
+
+    /// Output RS file, `-` writing to stdout
+    #[arg(
+        short,
+        long,
+        required_unless_present_any = ["output_dir", "check", "list_locales", "equivalent_locales"],
+        conflicts_with = "output_dir"
+    )]
+    output: Option<String>,
+
+    /// Output directory, receiving one file per emitted format
+    #[arg(long)]
+    output_dir: Option<PathBuf>,
+
+    /// Only check that every rule of the inputs parses, reporting suspicious data, without generating code
+    #[arg(long, conflicts_with_all = ["output", "output_dir"])]
+    check: bool,
+
+    /// Only print each locale of the inputs along with its plural rule type, one per line, without generating code
+    #[arg(long, conflicts_with_all = ["output", "output_dir", "check", "manifest"])]
+    list_locales: bool,
+
+    /// Only print each class of locales with equal rules, by plural rule type, one class per line, without generating code
+    #[arg(long, conflicts_with_all = ["output", "output_dir", "check", "manifest", "list_locales"])]
+    equivalent_locales: bool,
+
+    /// Also print to stderr how many locales use each operand and operator
+    #[arg(long)]
+    stats: bool,
+
+    /// Also print to stderr an estimate of the size of each locale's rules: the relations, operands and tokens
+    #[arg(long)]
+    size_report: bool,
+
+    /// Output formats to generate from a single parse of the input, `rust` by default
+    #[arg(long, value_enum)]
+    emit: Vec<EmitArg>,
+
+    /// Also write a JSON manifest listing the CLDR version and the generated locales of each plural rule type
+    #[arg(long, value_name = "PATH", conflicts_with = "check")]
+    manifest: Option<PathBuf>,
 
     /// Do not format the output
     #[arg(short, long)]
     ugly: bool,
+
+    /// Compare the outputs with the existing output files instead of writing them, printing the lines which
+    /// differ and exiting with an error if any is out of date
+    #[arg(long, conflicts_with = "check")]
+    diff_generated: bool,
+
+    /// Also write a standalone `PluralOperands` type to `operands.rs`, next to the output, for the output to
+    /// compile without the `intl_pluralrules` operands
+    #[arg(long)]
+    emit_operands: bool,
+
+    /// Store rules as `&'static dyn Fn` trait objects instead of fn pointers
+    #[arg(long)]
+    dyn_rules: bool,
+
+    /// Export a `select_<locale>_<type>` function per locale
+    #[arg(long)]
+    export_selectors: bool,
+
+    /// Fail if any sample value selects another category than the one it is listed for
+    #[arg(long)]
+    strict_samples: bool,
+
+    /// Warn about each sample value selecting another category than the one it is listed for, still generating
+    #[arg(long, conflicts_with = "check")]
+    warn_samples: bool,
+
+    /// Path of the module providing the runtime types, such as `intl_pluralrules`, used instead of `super`
+    #[arg(long, value_name = "PATH")]
+    runtime_path: Option<String>,
+
+    /// Path of the operands type used instead of `<runtime path>::operands::PluralOperands`
+    #[arg(long, value_name = "PATH")]
+    operands_path: Option<String>,
+
+    /// Accessor used instead of an operand field, such as `i=integer_part()`
+    #[arg(long, value_name = "OPERAND=ACCESSOR")]
+    operand_accessor: Vec<String>,
+
+    /// Rules replacing those of the inputs for a locale and plural rule type, in ICU's rule description syntax,
+    /// such as `qaa:cardinal=one: n = 1..3; other:`
+    #[arg(long, value_name = "LOCALE:TYPE=RULES")]
+    override_rules: Vec<String>,
+
+    /// Embed the source of each rule, looked up with a generated `rule_source` function
+    #[arg(long)]
+    embed_sources: bool,
+
+    /// Embed the sample values of each rule, looked up with a generated `samples_for` function
+    #[arg(long)]
+    embed_samples: bool,
+
+    /// Generate a test module checking that each sample value of a rule selects its category
+    #[arg(long)]
+    sample_tests: bool,
+
+    /// Look locales up in a generated `phf::Map`, which needs a `phf` dependency, instead of binary searching
+    #[arg(long, conflicts_with = "no_std")]
+    phf: bool,
+
+    /// Generate the items of each plural rule type in their own module, such as `cardinal`
+    #[arg(long)]
+    type_modules: bool,
+
+    /// Generate code for `#![no_std]` crates, without the `HashMap` building `<type>_map` functions
+    #[arg(long)]
+    no_std: bool,
+
+    /// Also generate a `match_<type>` function per type, selecting the category with a `match` on the locale
+    #[arg(long)]
+    match_dispatch: bool,
+
+    /// Also generate a `minimal_pairs` function yielding an example number for each category of a locale
+    #[arg(long)]
+    minimal_pairs: bool,
+
+    /// Leave the root rules of `und` out, so that locales without rules only fall back to their language
+    #[arg(long)]
+    exclude_root: bool,
+
+    /// Join the rules of a category listed twice for a locale with `or` instead of failing
+    #[arg(long)]
+    merge_duplicate_categories: bool,
+
+    /// CLDR version emitted into `CLDR_VERSION` instead of the one detected from the inputs
+    #[arg(long, value_name = "VER")]
+    cldr_version: Option<String>,
+
+    /// Generate the rules of each CLDR version of the inputs into a module of its own, such as `v43` and `v44`
+    #[arg(long, conflicts_with_all = ["emit", "manifest", "cldr_version"])]
+    module_per_version: bool,
+
+    /// Only generate rules for the given locale, such as `pt`, which also selects `pt-PT`
+    #[arg(long, value_name = "LANGID")]
+    only: Vec<LanguageIdentifier>,
+}
+
+/// Reports the sample values of the inputs which select another category than the one they are listed for,
+/// or which can't be evaluated.
+fn warn_misfiled_samples(inputs: &[PathBuf], input_jsons: &[String]) {
+    for (path, json) in inputs.iter().zip(input_jsons) {
+        let issues = check_rules(json).unwrap_or_default();
+        for issue in issues.iter().filter(|issue| {
+            matches!(
+                issue.kind,
+                RuleIssueKind::MisfiledSample { .. } | RuleIssueKind::UnevaluableSample(_)
+            )
+        }) {
+            eprintln!("Warning: `{}`: {}", path.display(), issue);
+        }
+    }
+}
+
+/// Reports the problems with the rules of the inputs, exiting with an error if any rule is unusable.
+fn check(inputs: &[PathBuf], input_jsons: &[String]) {
+    let mut failed = false;
+    for (path, json) in inputs.iter().zip(input_jsons) {
+        let issues = match check_rules(json) {
+            Ok(issues) => issues,
+            Err(GenerateError::InvalidJson { error, .. }) => {
+                eprintln!(
+                    "Error: `{}` is not valid CLDR JSON: {}",
+                    path.display(),
+                    error
+                );
+                failed = true;
+                continue;
+            }
+            Err(GenerateError::NoRulesFound(_)) => {
+                eprintln!("Error: `{}` contains no plural rules", path.display());
+                failed = true;
+                continue;
+            }
+            Err(err) => {
+                eprintln!("Error: `{}`: {}", path.display(), err);
+                failed = true;
+                continue;
+            }
+        };
+        for issue in issues {
+            let level = if issue.is_error() { "Error" } else { "Warning" };
+            eprintln!("{}: `{}`: {}", level, path.display(), issue);
+            failed |= issue.is_error();
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Reports the rules which differ between two versions of CLDR JSON plural rules files
+    Diff {
+        /// Old CLDR JSON plural rules files
+        #[arg(long, required = true)]
+        old: Vec<PathBuf>,
+
+        /// New CLDR JSON plural rules files
+        #[arg(long, required = true)]
+        new: Vec<PathBuf>,
+
+        /// Print the changes as a JSON array
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Reads and parses the plural rules of a set of inputs, exiting with an error if that fails.
+fn read_dataset(paths: &[PathBuf]) -> Dataset {
+    let jsons: Vec<String> = paths.iter().map(|path| read_input_or_exit(path)).collect();
+    parse_dataset_or_exit(paths, &jsons)
+}
+
+/// Parses the plural rules of the inputs read from `paths`, exiting with an error if that fails.
+fn parse_dataset_or_exit(paths: &[PathBuf], jsons: &[String]) -> Dataset {
+    parse_dataset(jsons).unwrap_or_else(|err| {
+        let err = match err {
+            GenerateError::NoRulesFound(idx) => {
+                format!("`{}` contains no plural rules", paths[idx].display())
+            }
+            GenerateError::InvalidJson { input, error } => {
+                format!(
+                    "`{}` is not valid CLDR JSON: {}",
+                    paths[input].display(),
+                    error
+                )
+            }
+            err => err.to_string(),
+        };
+        eprintln!("Error: {}", err);
+        std::process::exit(1);
+    })
+}
+
+/// Prints the rules which differ between the old and new inputs.
+fn diff(old: &[PathBuf], new: &[PathBuf], json: bool) {
+    let changes = diff_datasets(&read_dataset(old), &read_dataset(new));
+    if json {
+        let changes: Vec<_> = changes.iter().map(|change| change.to_json()).collect();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&changes).expect("A JSON value can be serialized")
+        );
+        return;
+    }
+    for change in &changes {
+        println!("{}", change);
+    }
+    let locales: BTreeSet<_> = changes.iter().map(|change| &change.locale).collect();
+    let plural = |n: usize, word: &str| format!("{} {}{}", n, word, if n == 1 { "" } else { "s" });
+    println!(
+        "{} changed in {}",
+        plural(changes.len(), "rule"),
+        plural(locales.len(), "locale")
+    );
 }
 
 fn main() -> std::io::Result<()> {
     let args = Args::parse();
+    if let Some(Command::Diff { old, new, json }) = &args.command {
+        diff(old, new, *json);
+        return Ok(());
+    }
 
-    let input_jsons = args
-        .input
+    let entries = if args.input.is_empty() {
+        vec![STDIN_INPUT.to_owned()]
+    } else {
+        args.input
+    };
+    let expanded = expand_inputs(&entries)?;
+    if expanded
+        .iter()
+        .filter(|input| input.path == Path::new(STDIN_INPUT))
+        .count()
+        > 1
+    {
+        eprintln!("Error: stdin can only be read once");
+        std::process::exit(1);
+    }
+    let mut inputs = Vec::new();
+    let mut input_jsons = Vec::new();
+    for input in expanded {
+        let json = read_input_or_exit(&input.path);
+        #[cfg(feature = "icu")]
+        let json = convert_icu_or_exit(&input.path, json, args.format);
+        if input.expanded && !contains_plural_rules(&json) {
+            eprintln!(
+                "Warning: skipping `{}`, which contains no plural rules",
+                input.path.display()
+            );
+            continue;
+        }
+        inputs.push(input.path);
+        input_jsons.push(json);
+    }
+    if input_jsons.is_empty() {
+        eprintln!("Error: none of the inputs contains plural rules");
+        std::process::exit(1);
+    }
+    if args.stats {
+        eprint!(
+            "{}",
+            usage_stats(&parse_dataset_or_exit(&inputs, &input_jsons))
+        );
+    }
+    if args.size_report {
+        eprint!(
+            "{}",
+            size_report(&parse_dataset_or_exit(&inputs, &input_jsons))
+        );
+    }
+    if args.check {
+        check(&inputs, &input_jsons);
+        return Ok(());
+    }
+    if args.list_locales {
+        let dataset = parse_dataset_or_exit(&inputs, &input_jsons);
+        for (lang, type_name) in locale_coverage(&dataset) {
+            println!("{} {}", lang, type_name);
+        }
+        return Ok(());
+    }
+    if args.equivalent_locales {
+        let dataset = parse_dataset_or_exit(&inputs, &input_jsons);
+        for (type_name, langs) in equivalent_locales(&dataset) {
+            let langs: Vec<String> = langs.iter().map(ToString::to_string).collect();
+            println!("{} {}", type_name, langs.join(" "));
+        }
+        return Ok(());
+    }
+    let operand_accessors = args
+        .operand_accessor
         .iter()
-        .map(|path| fs::read_to_string(path).expect("file not found"))
-        .collect::<Vec<_>>();
-    let complete_rs_code = generate_rs(&input_jsons);
-
-    let mut file = fs::File::create(&args.output)?;
-    file.write_all(complete_rs_code.as_bytes())?;
-
-    if !args.ugly {
-        Command::new("rustfmt")
-            .args([&args.output])
-            .output()
-            .expect("Failed to format the output using `rustfmt`");
+        .map(|arg| {
+            let (operand, accessor) = arg
+                .split_once('=')
+                .filter(|(operand, _)| operand.chars().count() == 1)
+                .unwrap_or_else(|| {
+                    eprintln!("Error: `{}` is not of the form OPERAND=ACCESSOR", arg);
+                    std::process::exit(1);
+                });
+            (operand.chars().next().unwrap(), accessor.to_owned())
+        })
+        .collect();
+    let rule_overrides = args
+        .override_rules
+        .iter()
+        .map(|arg| {
+            let key = arg
+                .split_once('=')
+                .and_then(|(key, rules)| Some((key.split_once(':')?, rules)))
+                .and_then(|((locale, type_name), rules)| {
+                    Some((locale.parse().ok()?, type_name.parse().ok()?, rules))
+                });
+            let Some((lang, prt, rules)) = key else {
+                eprintln!("Error: `{}` is not of the form LOCALE:TYPE=RULES", arg);
+                std::process::exit(1);
+            };
+            ((lang, prt), rules.to_owned())
+        })
+        .collect();
+    let options = GenerateOptions {
+        dyn_rules: args.dyn_rules,
+        export_selectors: args.export_selectors,
+        strict_samples: args.strict_samples,
+        runtime_path: args.runtime_path,
+        operands_path: args.operands_path,
+        operand_accessors,
+        embed_sources: args.embed_sources,
+        embed_samples: args.embed_samples,
+        sample_tests: args.sample_tests,
+        phf_lookup: args.phf,
+        type_modules: args.type_modules,
+        merge_duplicate_categories: args.merge_duplicate_categories,
+        no_std: args.no_std,
+        match_dispatch: args.match_dispatch,
+        minimal_pairs: args.minimal_pairs,
+        exclude_root: args.exclude_root,
+        rule_overrides,
+        cldr_version: args.cldr_version,
+        module: None,
+        only: args.only,
+    };
+    if args.warn_samples {
+        warn_misfiled_samples(&inputs, &input_jsons);
+    }
+    if !options.only.is_empty()
+        && let Ok(dataset) = parse_dataset(&input_jsons)
+    {
+        for lang in unmatched_locales(&dataset, &options.only) {
+            eprintln!("Warning: `--only {}` matches no locale of the inputs", lang);
+        }
+    }
+    let mut emits: Vec<Emit> = if args.emit.is_empty() {
+        vec![Emit::Rust]
+    } else {
+        args.emit.iter().map(|emit| Emit::from(*emit)).collect()
+    };
+    let mut output_paths: Vec<PathBuf> = match (&args.output, &args.output_dir) {
+        (Some(output), _) if emits.len() == 1 => vec![output.into()],
+        (Some(_), _) => {
+            eprintln!("Error: emitting several formats requires `--output-dir`");
+            std::process::exit(1);
+        }
+        (None, Some(dir)) => emits
+            .iter()
+            .map(|emit| dir.join(emit.file_name()))
+            .collect(),
+        (None, None) => unreachable!("clap requires an output"),
+    };
+    if let Some(manifest) = &args.manifest {
+        emits.push(Emit::Manifest);
+        output_paths.push(manifest.clone());
+    }
+    if args.emit_operands && !emits.contains(&Emit::Operands) {
+        let dir = match (&args.output, &args.output_dir) {
+            (Some(output), _) if output == STDOUT_OUTPUT => {
+                eprintln!("Error: `--emit-operands` requires an output file or directory");
+                std::process::exit(1);
+            }
+            (Some(output), _) => Path::new(output)
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default(),
+            (None, Some(dir)) => dir.clone(),
+            (None, None) => unreachable!("clap requires an output"),
+        };
+        emits.push(Emit::Operands);
+        output_paths.push(dir.join(Emit::Operands.file_name()));
+    }
+    if args.diff_generated
+        && output_paths
+            .iter()
+            .any(|path| path == Path::new(STDOUT_OUTPUT))
+    {
+        eprintln!("Error: `--diff-generated` requires an output file or directory");
+        std::process::exit(1);
+    }
+    let generated = if args.module_per_version {
+        // The only other format is the standalone operands type, which is shared by the versions.
+        generate_versioned_rs(&input_jsons, &options).map(|code| {
+            emits
+                .iter()
+                .map(|emit| match emit {
+                    Emit::Operands => generate_operands_rs(),
+                    _ => code.clone(),
+                })
+                .collect()
+        })
+    } else {
+        generate_all(&input_jsons, &emits, &options)
+    };
+    let outputs = match generated {
+        Ok(outputs) => outputs,
+        Err(GenerateError::NoRulesFound(idx)) => {
+            eprintln!(
+                "Error: `{}` contains no plural rules",
+                inputs[idx].display()
+            );
+            std::process::exit(1);
+        }
+        Err(GenerateError::InvalidJson { input, error }) => {
+            eprintln!(
+                "Error: `{}` is not valid CLDR JSON: {}",
+                inputs[input].display(),
+                error
+            );
+            std::process::exit(1);
+        }
+        Err(GenerateError::VersionMismatch {
+            input,
+            expected,
+            found,
+        }) => {
+            eprintln!(
+                "Error: `{}` is of CLDR version `{}`, but the earlier inputs are of `{}`; pass `--cldr-version` to override it",
+                inputs[input].display(),
+                found,
+                expected
+            );
+            std::process::exit(1);
+        }
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    if let Some(dir) = &args.output_dir
+        && !args.diff_generated
+    {
+        fs::create_dir_all(dir)?;
+    }
+    let mut up_to_date = true;
+    for ((emit, output), path) in emits.iter().zip(outputs).zip(&output_paths) {
+        let output = match *emit {
+            Emit::Rust | Emit::Operands if !args.ugly => pretty_print(&output),
+            _ => output,
+        };
+        if args.diff_generated {
+            up_to_date &= diff_generated(path, &output);
+        } else {
+            write_output(path, &output)?;
+        }
+    }
+    if !up_to_date {
+        std::process::exit(1);
     }
 
     Ok(())
@@ -0,0 +1,92 @@
+//! gen_json dumps the CLDR Plural Rule ASTs as JSON, and lists the generated locales in a manifest.
+//!
+//! The dump describes the same rules as the generated Rust code, keyed by plural rule type, locale and category:
+//!
+//! ```json
+//! { "cldrVersion": "33", "cardinal": { "en": { "one": {
+//!     "source": "i = 1 and v = 0",
+//!     "condition": [[{ "operand": "i", "operator": "=", "ranges": [1] }, { "operand": "v", "operator": "=", "ranges": [0] }]]
+//! } } } }
+//! ```
+//!
+//! The conditions are lists of `or` alternatives, each a list of relations joined by `and`.
+//! Ranges are single values or `[lower, upper]` pairs.
+
+use super::plural_type::type_name;
+use crate::ParsedRules;
+use cldr_pluralrules_parser::ast::*;
+use serde_json::{Map, Value as Json, json};
+
+fn relation_json(relation: &Relation) -> Json {
+    let ranges: Vec<_> = relation
+        .range_list
+        .0
+        .iter()
+        .map(|item| match item {
+            RangeListItem::Value(value) => json!(value.0),
+            RangeListItem::Range(range) => json!([range.lower_val.0, range.upper_val.0]),
+        })
+        .collect();
+    let mut json = json!({
+        "operand": relation.expression.operand.to_string(),
+        "operator": relation.operator.to_string(),
+        "ranges": ranges,
+    });
+    if let Some(modulus) = &relation.expression.modulus {
+        json["modulus"] = json!((modulus.0).0);
+    }
+    json
+}
+
+fn condition_json(condition: &Condition) -> Json {
+    condition
+        .0
+        .iter()
+        .map(|and_condition| and_condition.0.iter().map(relation_json).collect::<Json>())
+        .collect()
+}
+
+/// Returns the pretty printed JSON dump of the parsed rules.
+pub fn gen_ast_json(parsed: &ParsedRules) -> String {
+    let mut root = Map::new();
+    root.insert("cldrVersion".to_owned(), json!(parsed.cldr_version));
+    for (prt, locales) in &parsed.types {
+        let locales: Map<_, _> = locales
+            .iter()
+            .map(|locale| {
+                let categories: Map<_, _> = locale
+                    .conditions
+                    .iter()
+                    .map(|(cat, source, condition)| {
+                        let rule = json!({
+                            "source": source,
+                            "condition": condition_json(condition),
+                        });
+                        (cat.name().to_owned(), rule)
+                    })
+                    .collect();
+                (locale.lang.to_string(), Json::Object(categories))
+            })
+            .collect();
+        root.insert(type_name(*prt).to_owned(), Json::Object(locales));
+    }
+    serde_json::to_string_pretty(&root).expect("A JSON value can be serialized")
+}
+
+/// Returns the pretty printed manifest of the generated locales, listed in table order by plural rule type:
+///
+/// ```json
+/// { "cldrVersion": "33", "cardinal": ["af", "ak", "am"], "ordinal": ["af", "am"] }
+/// ```
+pub fn gen_manifest(parsed: &ParsedRules) -> String {
+    let mut root = Map::new();
+    root.insert("cldrVersion".to_owned(), json!(parsed.cldr_version));
+    for (prt, locales) in &parsed.types {
+        let locales: Vec<_> = locales
+            .iter()
+            .map(|locale| locale.lang.to_string())
+            .collect();
+        root.insert(type_name(*prt).to_owned(), json!(locales));
+    }
+    serde_json::to_string_pretty(&root).expect("A JSON value can be serialized")
+}
@@ -0,0 +1,124 @@
+//! gen_operands generates a standalone `PluralOperands` type, for generated rules used without the `intl_pluralrules`
+//! runtime, such as in a crate of their own.
+//!
+//! The type has the fields the generated rules read, and derives them from numbers and decimal strings as
+//! `intl_pluralrules::operands::PluralOperands` does. Its helpers, such as `from_f64_with_precision`, are left out.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Returns the code of a module defining `PluralOperands`, to be placed next to the generated rules as `operands.rs`.
+pub fn gen_operands() -> TokenStream {
+    let unsigned = ["u8", "u16", "u32", "u64", "usize"].map(|ty| format_ident!("{}", ty));
+    let signed = ["i8", "i16", "i32", "i64", "isize"].map(|ty| format_ident!("{}", ty));
+    let floats = ["f32", "f64"].map(|ty| format_ident!("{}", ty));
+    quote! {
+        //! Plural operands in compliance with [CLDR Plural Rules](https://unicode.org/reports/tr35/tr35-numbers.html#Operands).
+        #![allow(clippy::unnecessary_cast, clippy::cast_lossless)]
+        use std::convert::TryFrom;
+        use std::fmt;
+        use std::str::FromStr;
+
+        /// The plural operands of the absolute value of a number.
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct PluralOperands {
+            /// Absolute value of input
+            pub n: f64,
+            /// Integer value of the absolute value of input
+            pub i: u64,
+            /// Number of visible fraction digits with trailing zeros
+            pub v: usize,
+            /// Number of visible fraction digits without trailing zeros
+            pub w: usize,
+            /// Visible fraction digits with trailing zeros
+            pub f: u64,
+            /// Visible fraction digits without trailing zeros
+            pub t: u64,
+        }
+
+        impl<'a> TryFrom<&'a str> for PluralOperands {
+            type Error = &'static str;
+
+            fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+                let abs_str = input.strip_prefix(['-', '+']).unwrap_or(input);
+                if abs_str.starts_with(['-', '+']) {
+                    return Err("Incorrect number passed!");
+                }
+                let absolute_value = f64::from_str(abs_str).map_err(|_| "Incorrect number passed!")?;
+                let Some((int_str, dec_str)) = abs_str.split_once('.') else {
+                    // Parse the digits themselves, as large integers are not exact in `absolute_value`.
+                    let i = u64::from_str(abs_str).unwrap_or(absolute_value as u64);
+                    return Ok(PluralOperands { n: i as f64, i, v: 0, w: 0, f: 0, t: 0 });
+                };
+                let i = u64::from_str(int_str).map_err(|_| "Could not convert string to integer!")?;
+                let backtrace = dec_str.trim_end_matches('0');
+                Ok(PluralOperands {
+                    n: if dec_str.is_empty() { i as f64 } else { absolute_value },
+                    i,
+                    v: dec_str.len(),
+                    w: backtrace.len(),
+                    f: u64::from_str(dec_str).map_err(|_| "Could not convert string to integer!")?,
+                    t: u64::from_str(backtrace).unwrap_or(0),
+                })
+            }
+        }
+
+        /// Parses the operands of a decimal string, such as `"-1234.560"`, keeping its visible fraction digits.
+        impl FromStr for PluralOperands {
+            type Err = &'static str;
+
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                PluralOperands::try_from(input)
+            }
+        }
+
+        /// Writes the decimal string of the operands, such as `"1.50"`, which `from_str` parses back.
+        impl fmt::Display for PluralOperands {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.i)?;
+                if self.v > 0 {
+                    write!(f, ".{:0width$}", self.f, width = self.v)?;
+                }
+                Ok(())
+            }
+        }
+
+        #(
+            impl From<#unsigned> for PluralOperands {
+                fn from(input: #unsigned) -> Self {
+                    PluralOperands { n: input as f64, i: input as u64, v: 0, w: 0, f: 0, t: 0 }
+                }
+            }
+        )*
+
+        #(
+            impl From<#signed> for PluralOperands {
+                fn from(input: #signed) -> Self {
+                    PluralOperands::from(input.unsigned_abs() as u64)
+                }
+            }
+        )*
+
+        #(
+            /// The operands of a float are those of its shortest decimal representation.
+            impl From<#floats> for PluralOperands {
+                fn from(input: #floats) -> Self {
+                    let abs = input.abs();
+                    if !abs.is_finite() || abs.fract() == 0.0 {
+                        return PluralOperands { n: abs as f64, i: abs as u64, v: 0, w: 0, f: 0, t: 0 };
+                    }
+                    PluralOperands::try_from(abs.to_string().as_str())
+                        .expect("A finite float displays as a decimal number")
+                }
+            }
+        )*
+
+        impl TryFrom<String> for PluralOperands {
+            type Error = &'static str;
+
+            fn try_from(input: String) -> Result<Self, Self::Error> {
+                PluralOperands::try_from(input.as_str())
+            }
+        }
+    }
+}
@@ -5,16 +5,69 @@
 /// This code utilizes the cldr_pluralrules_parser AST
 use cldr_pluralrules_parser::ast::*;
 /// proc_macro2 provides the TokenStream type
-use proc_macro2::{Ident, Literal, Span, TokenStream};
+use proc_macro2::{Literal, TokenStream};
 use quote::quote;
+use std::collections::BTreeMap;
+
+use crate::GenerateError;
+
+/// The expressions accessing the operands in the generated code, `po.<operand>` unless overridden.
+pub struct OperandAccessors(BTreeMap<char, TokenStream>);
+
+impl OperandAccessors {
+    /// Parses the accessors overriding `po.<operand>`, such as `integer_part()` for `i`.
+    pub fn new(accessors: &BTreeMap<char, String>) -> Result<Self, GenerateError> {
+        accessors
+            .iter()
+            .map(|(&operand, accessor)| {
+                if !"nivtwfc".contains(operand) {
+                    return Err(GenerateError::InvalidOperandAccessor(format!(
+                        "{}={}",
+                        operand, accessor
+                    )));
+                }
+                let tokens = accessor.parse().map_err(|_| {
+                    GenerateError::InvalidOperandAccessor(format!("{}={}", operand, accessor))
+                })?;
+                Ok((operand, tokens))
+            })
+            .collect::<Result<_, _>>()
+            .map(OperandAccessors)
+    }
+
+    /// Returns the expression accessing `operand` of `po`.
+    ///
+    /// `PluralOperands` has no compact exponent, so `c` and its alias `e` are `0` unless overridden.
+    fn get(&self, operand: &Operand) -> TokenStream {
+        let name = operand.canonical().as_char();
+        let accessor = match self.0.get(&name) {
+            Some(accessor) => accessor.clone(),
+            None if name == 'c' => return quote!(0),
+            None => name
+                .to_string()
+                .parse()
+                .expect("An operand is a valid field"),
+        };
+        quote!(po.#accessor)
+    }
+}
 
 /// Convert a usize to a Literal
-fn convert_literal(num: usize) -> Literal {
-    Literal::u64_unsuffixed(num as u64)
+fn convert_literal(num: u64) -> Literal {
+    Literal::u64_unsuffixed(num)
+}
+
+/// Convert an integer literal into the equivalent float literal, e.g. `1` into `1.0`
+///
+/// Appending `.0` tokens instead would stringify to `1 . 0`, which is a field access.
+fn convert_float_literal(lit: &Literal) -> Literal {
+    format!("{}.0", lit)
+        .parse()
+        .expect("An integer literal is a valid float literal")
 }
 
 /// Convert a usize pair into a tuple of literals
-fn convert_range(low: usize, up: usize) -> (Literal, Literal) {
+fn convert_range(low: u64, up: u64) -> (Literal, Literal) {
     let u = convert_literal(up);
     let d = convert_literal(low);
 
@@ -47,7 +100,7 @@ fn get_operator_symbol(op: &Operator) -> TokenStream {
 }
 
 /// Create a token stream representation from the AST Relation
-fn create_relation(rel: Relation) -> TokenStream {
+fn create_relation(rel: Relation, accessors: &OperandAccessors) -> TokenStream {
     let left = rel.expression;
     let operator = rel.operator;
     let right = rel.range_list;
@@ -55,21 +108,15 @@ fn create_relation(rel: Relation) -> TokenStream {
     // All relations that need to be represented in the rust code are folded here. They are unfolded into one token steam later.
     let mut relations = Vec::<TokenStream>::new();
 
-    let l = match left.operand {
-        Operand::N => "n",
-        Operand::I => "i",
-        Operand::V => "v",
-        Operand::T => "t",
-        Operand::W => "w",
-        Operand::F => "f",
-    };
-    let l = Ident::new(l, Span::call_site());
+    let l = accessors.get(&left.operand);
+    let i = accessors.get(&Operand::I);
+    let f = accessors.get(&Operand::F);
     let o = get_operator_symbol(&operator);
     let r1 = convert_rangl(right);
 
     // If there is a modulus, convert to literal. If not, placehold literal
-    let (mod_check, m) = if left.modulus.is_some() {
-        (true, convert_literal((left.modulus.unwrap().0).0))
+    let (mod_check, m) = if let Some(modulus) = left.modulus {
+        (true, convert_literal((modulus.0).0))
     } else {
         (false, convert_literal(0))
     };
@@ -82,32 +129,48 @@ fn create_relation(rel: Relation) -> TokenStream {
     // We also check for a modulus on the operand.
     // Lastly, we want to make sure we allow values and ranges to be indeterminately folded together.
 
-    // If within type operator, use format x < po && po < y
-    if operator == Operator::Within || operator == Operator::NotWithin {
-        let rfront = &(r1.1)[0].0;
-        let rback = &(r1.1)[0].1;
+    // A relation of `n` against integers only holds for integers, so a fraction never equals them.
+    let integer_n = |relation: TokenStream| match operator {
+        Operator::NotIn | Operator::NotEQ | Operator::IsNot => quote! { (#relation || #f != 0) },
+        _ => quote! { (#relation && #f == 0) },
+    };
 
-        // Variants handled here
-        let (rfront, rback, whole_symbol) = if left.operand == Operand::N {
-            if !mod_check {
-                (quote!(#rfront.0), quote!(#rback.0), quote!(po.#l))
+    // If within type operator, use format x <= po && po <= y for each range, which also holds for the
+    // fractions of `n` between the bounds.
+    if operator == Operator::Within || operator == Operator::NotWithin {
+        let is_n = left.operand == Operand::N;
+        let lit = |lit: &Literal| {
+            if is_n {
+                convert_float_literal(lit)
             } else {
-                (quote!(#rfront), quote!(#rback), quote!(po.i % #m))
+                lit.clone()
             }
+        };
+        let symbol = if mod_check {
+            let m = lit(&m);
+            quote!(#l % #m)
         } else {
-            (
-                quote!(#rfront),
-                quote!(#rback),
-                if !mod_check {
-                    quote!(po.#l)
-                } else {
-                    quote!(po.i % #m)
-                },
-            )
+            quote!(#l)
+        };
+        let values = r1.0.iter().map(|value| {
+            let value = lit(value);
+            quote! { (#symbol == #value) }
+        });
+        let ranges = r1.1.iter().map(|(rfront, rback)| {
+            let (rfront, rback) = (lit(rfront), lit(rback));
+            quote! { (#rfront <= #symbol && #symbol <= #rback) }
+        });
+        let relations: Vec<_> = values.chain(ranges).collect();
+        let any = if relations.len() > 1 {
+            quote! { (#(#relations)||*) }
+        } else {
+            quote! { #(#relations)* }
+        };
+        return if operator == Operator::Within {
+            any
+        } else {
+            quote! { !#any }
         };
-
-        let rel_tokens = quote! { #rfront #o #whole_symbol && #whole_symbol #o #rback};
-        relations.push(rel_tokens);
     // if not within type, use format po < x
     } else {
         // Recurisvely fold all values
@@ -115,22 +178,27 @@ fn create_relation(rel: Relation) -> TokenStream {
             // Variants handled here
             let (symbol, rval) = if left.operand == Operand::N {
                 if !mod_check {
-                    (quote!(po.#l), quote!(#r.0))
+                    let r = convert_float_literal(&r);
+                    (quote!(#l), quote!(#r))
                 } else {
-                    (quote!(po.i % #m), quote!(#r))
+                    (quote!(#i % #m), quote!(#r))
                 }
             } else {
                 (
                     if !mod_check {
-                        quote!(po.#l)
+                        quote!(#l)
                     } else {
-                        quote!(po.#l % #m)
+                        quote!(#l % #m)
                     },
                     quote!(#r),
                 )
             };
 
-            let rel_tokens = quote! { #symbol #o #rval };
+            let rel_tokens = if left.operand == Operand::N && mod_check {
+                integer_n(quote! { #symbol #o #rval })
+            } else {
+                quote! { #symbol #o #rval }
+            };
             relations.push(rel_tokens);
         }
         // Recursively fold all ranges
@@ -139,35 +207,29 @@ fn create_relation(rel: Relation) -> TokenStream {
             let rback = r.1;
 
             // Variants handled here
-            let (symbol, perim) = if left.operand == Operand::N {
-                if !mod_check {
-                    (quote!(po.i), quote! { && po.f == 0})
-                } else {
-                    (quote!(po.i), quote! {})
-                }
-            } else {
-                (
-                    if !mod_check {
-                        quote!(po.#l)
-                    } else {
-                        quote!(po.#l % #m)
-                    },
-                    quote! {},
-                )
+            let symbol = match (left.operand == Operand::N, mod_check) {
+                (true, false) => quote!(#i),
+                (true, true) => quote!(#i % #m),
+                (false, false) => quote!(#l),
+                (false, true) => quote!(#l % #m),
             };
 
             let rel_tokens = match operator {
                 Operator::In | Operator::Is | Operator::EQ => {
-                    quote! { (#rfront ..= #rback).contains(&(#symbol)) #perim }
+                    quote! { (#rfront ..= #rback).contains(&(#symbol)) }
                 }
                 Operator::NotIn | Operator::NotEQ | Operator::IsNot => {
-                    quote! { !(#rfront ..= #rback).contains(&(#symbol)) #perim }
+                    quote! { !(#rfront ..= #rback).contains(&(#symbol)) }
                 }
                 Operator::Within | Operator::NotWithin => {
-                    panic!("There was a problem with the source file.")
+                    unreachable!("`within` relations are generated above")
                 }
             };
-            relations.push(rel_tokens);
+            if left.operand == Operand::N {
+                relations.push(integer_n(rel_tokens));
+            } else {
+                relations.push(rel_tokens);
+            }
         }
     }
 
@@ -186,12 +248,12 @@ fn create_relation(rel: Relation) -> TokenStream {
 }
 
 // Unfold AndConditions and tokenize together with &&
-fn create_and_condition(acond: AndCondition) -> TokenStream {
+fn create_and_condition(acond: AndCondition, accessors: &OperandAccessors) -> TokenStream {
     let mut andcondvec = Vec::<TokenStream>::new();
 
     // unpack the AndCondition and get all relations from within it
     for a in acond.0 {
-        andcondvec.push(create_relation(a));
+        andcondvec.push(create_relation(a, accessors));
     }
 
     // Unfold AndConditions and tokenize together with &&
@@ -199,12 +261,12 @@ fn create_and_condition(acond: AndCondition) -> TokenStream {
 }
 
 // unfold OrConditions and tokenize together with ||
-fn create_condition(cond: Condition) -> TokenStream {
+fn create_condition(cond: Condition, accessors: &OperandAccessors) -> TokenStream {
     let mut condvec = Vec::<TokenStream>::new();
 
     // unpack the OrCondition and get all AndConditions from within it
     for c in cond.0 {
-        condvec.push(create_and_condition(c));
+        condvec.push(create_and_condition(c, accessors));
     }
 
     // unfold OrConditions and tokenize together with ||
@@ -212,7 +274,7 @@ fn create_condition(cond: Condition) -> TokenStream {
 }
 
 /// This Function takes a full condition as input and returns a TokenStream of the expression of the plural rule in Rust.
-pub fn gen_pr(cond: Condition) -> TokenStream {
+pub fn gen_pr(cond: Condition, accessors: &OperandAccessors) -> TokenStream {
     // create_condition(cond).into_token_stream()
-    create_condition(cond)
+    create_condition(cond, accessors)
 }
@@ -1,79 +1,632 @@
 //! gen_rs is a Rust code generator for expression representations of CLDR plural rules.
 use super::plural_category::PluralCategory;
-use proc_macro2::{Literal, TokenStream};
+use super::plural_type::{
+    PLURAL_RULE_TYPES, categories_table_name, category_counts_table_name, count_name, map_fn_name,
+    match_fn_name, minimal_pairs_table_name, operands_table_name, samples_table_name,
+    select_fn_name, sources_table_name, table_name, type_name,
+};
+use crate::{GenerateError, GenerateOptions};
+use cldr_pluralrules_parser::ast::Operand;
+use intl_pluralrules::PluralRuleType;
+use proc_macro2::{Delimiter, Ident, Literal, Span, TokenStream, TokenTree};
 use quote::quote;
-use std::collections::BTreeMap;
+use std::collections::{BTreeSet, HashMap};
 use std::str;
 use unic_langid::LanguageIdentifier;
+use unic_langid::subtags;
+
+/// The generated table entries of one plural rule type, one per locale.
+pub struct TypeTokens {
+    /// The rule entries, as generated by `gen_mid`.
+    pub rules: Vec<TokenStream>,
+    /// The category entries, as generated by `gen_categories`.
+    pub categories: Vec<TokenStream>,
+    /// The category count entries, as generated by `gen_category_count`.
+    pub category_counts: Vec<TokenStream>,
+    /// The operand entries, as generated by `gen_operands`.
+    pub operands: Vec<TokenStream>,
+    /// The functions the rule entries refer to, as generated by `gen_selector` or `gen_shared_rule`.
+    pub functions: Vec<TokenStream>,
+    /// The rule source entries, as generated by `gen_sources`.
+    pub sources: Vec<TokenStream>,
+    /// The sample value entries, as generated by `gen_samples`.
+    pub samples: Vec<TokenStream>,
+    /// The example value entries, as generated by `gen_minimal_pairs`.
+    pub minimal_pairs: Vec<TokenStream>,
+    /// The `phf_map!` entries keyed by locale string, as generated by `gen_phf_entry`.
+    pub phf_entries: Vec<TokenStream>,
+    /// The arms of the `match_<type>` function, one per distinct rule, as generated by `gen_match_arm`.
+    pub match_arms: Vec<TokenStream>,
+}
+
+/// Parses a `::` separated path of identifiers, such as `crate::numbers::Operands`.
+fn parse_path(path: &str) -> Option<TokenStream> {
+    if !path.split("::").map(str::trim).all(is_ident) {
+        return None;
+    }
+    path.parse().ok()
+}
+
+fn is_ident(segment: &str) -> bool {
+    segment.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && segment
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Returns `path` as seen from within the module of `GenerateOptions::module`, one level below the file,
+/// where paths relative to the file, starting with `self` or `super`, go through one more `super`.
+fn nested_path(path: &str, options: &GenerateOptions) -> String {
+    if options.module.is_none() {
+        return path.to_owned();
+    }
+    match path.split("::").next().map(str::trim) {
+        Some("self") => format!("super{}", &path.trim_start()["self".len()..]),
+        Some("super") => format!("super::{}", path),
+        _ => path.to_owned(),
+    }
+}
 
 /// Generates the complete TokenStream for the generated Rust code. This wraps the head and tail of the .rs file around the generated CLDR expressions.
-pub fn gen_fn(streams: BTreeMap<String, Vec<TokenStream>>, vr: &str) -> TokenStream {
+pub fn gen_fn(
+    mut streams: HashMap<PluralRuleType, TypeTokens>,
+    vr: &str,
+    options: &GenerateOptions,
+) -> Result<TokenStream, GenerateError> {
     let ignore_noncritical_errors = quote! {
-        #![allow(unused_variables, unused_parens)]
-        #![cfg_attr(feature = "cargo-clippy", allow(clippy::float_cmp))]
-        #![cfg_attr(feature = "cargo-clippy", allow(clippy::unreadable_literal))]
-        #![cfg_attr(feature = "cargo-clippy", allow(clippy::nonminimal_bool))]
+        #![allow(unused_variables, unused_parens, unused_braces, dead_code)]
+        #![allow(clippy::float_cmp)]
+        #![allow(clippy::unreadable_literal, clippy::type_complexity)]
+        #![allow(clippy::nonminimal_bool, clippy::double_parens, clippy::manual_is_multiple_of)]
+    };
+    let runtime_path = options.runtime_path.as_deref().unwrap_or("super");
+    let runtime = parse_path(&nested_path(runtime_path, options))
+        .ok_or_else(|| GenerateError::InvalidRuntimePath(runtime_path.to_owned()))?;
+    let operands_import = match &options.operands_path {
+        Some(path) => {
+            let path = parse_path(&nested_path(path, options))
+                .ok_or_else(|| GenerateError::InvalidOperandsPath(path.to_owned()))?;
+            quote!(use #path as PluralOperands;)
+        }
+        None => quote!(
+            use #runtime::operands::PluralOperands;
+        ),
     };
     let use_statements = quote! {
-        use super::operands::PluralOperands;
-        use super::PluralCategory;
+        #operands_import
+        use #runtime::PluralRuleType;
+        use #runtime::PluralCategory;
         use unic_langid::LanguageIdentifier;
         use unic_langid::subtags;
     };
+    // SAFETY: `gen_langid` only passes the raw values of the subtags of identifiers accepted by
+    // `is_generatable`, which are valid subtags, to the unchecked constructors.
     let langid_macro = quote! {
         macro_rules! langid {
-            ($lang:expr, $script:expr, $region:expr) => {
+            ($lang:expr, $script:expr, $region:expr, $variants:expr) => {
                 {
                     unsafe {
                         LanguageIdentifier::from_raw_parts_unchecked(
                             $lang,
                             $script,
                             $region,
-                            None,
+                            $variants,
                         )
                     }
                 }
             };
         }
     };
-    let plural_function = quote! { pub type PluralRule = fn(&PluralOperands) -> PluralCategory; };
-    let num: isize = vr.parse().unwrap();
-    let ver = Literal::u64_unsuffixed(num as u64);
-    let version = quote! { pub static CLDR_VERSION: usize = #ver; };
-    let head = quote! { #ignore_noncritical_errors #use_statements #plural_function #version #langid_macro };
-    let mut tokens = Vec::<TokenStream>::new();
-    for (pr_type, stream) in streams {
-        tokens.push(create_pr_type(&pr_type, stream));
+    let plural_function = if options.dyn_rules {
+        quote! { pub type PluralRule = &'static (dyn Fn(&PluralOperands) -> PluralCategory + Sync); }
+    } else {
+        quote! { pub type PluralRule = fn(&PluralOperands) -> PluralCategory; }
+    };
+    // Versions are dot separated numbers, such as `33` or `44.1`.
+    let is_version = vr
+        .split('.')
+        .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()));
+    if !is_version {
+        return Err(GenerateError::InvalidCldrVersion(vr.to_owned()));
     }
+    let version = quote! { pub static CLDR_VERSION: &str = #vr; };
+    let head = quote! { #ignore_noncritical_errors #use_statements #plural_function #version #langid_macro };
+    let prts: Vec<_> = PLURAL_RULE_TYPES
+        .iter()
+        .map(|(prt, _, _)| *prt)
+        .filter(|prt| streams.contains_key(prt))
+        .collect();
+    let select = gen_select(&prts, options);
+    let supported_locales = gen_supported_locales(&prts);
+    let rules_and_find = gen_rules_and_find(&prts);
+    let category_count = gen_category_count_fn(&prts, options);
+    let rule_source = if options.embed_sources {
+        gen_rule_source(&prts)
+    } else {
+        quote!()
+    };
+    let samples_for = if options.embed_samples {
+        gen_samples_for(&prts)
+    } else {
+        quote!()
+    };
+    let minimal_pairs = if options.minimal_pairs {
+        gen_minimal_pairs_fn(&prts, options)
+    } else {
+        quote!()
+    };
+    let sample_tests = if options.sample_tests {
+        gen_sample_tests(&prts, &streams, options)
+    } else {
+        quote!()
+    };
+    // Parsing the strings of the identifiers needs `std`.
+    let langid_tests = if options.no_std {
+        quote!()
+    } else {
+        gen_langid_tests(&prts)
+    };
+    let tokens = prts.iter().filter_map(|prt| {
+        let tokens = create_pr_type(*prt, streams.remove(prt)?, options);
+        if !options.type_modules {
+            return Some(tokens);
+        }
+        // The module shares the header of the file, and its items are re-exported under their usual names.
+        let module = Ident::new(type_name(*prt), Span::call_site());
+        Some(quote! {
+            pub mod #module {
+                use super::*;
+                #tokens
+            }
+            pub use self::#module::*;
+        })
+    });
     let prs = quote! { #(#tokens)* };
-    quote! { #head #prs }
+    let code = quote! { #head #prs #select #supported_locales #rules_and_find #category_count #rule_source #samples_for #minimal_pairs #sample_tests #langid_tests };
+    match &options.module {
+        Some(name) if is_ident(name) => {
+            let module = Ident::new(name, Span::call_site());
+            Ok(quote! { pub mod #module { #code } })
+        }
+        Some(name) => Err(GenerateError::InvalidModuleName(name.to_owned())),
+        None => Ok(code),
+    }
+}
+
+/// Generates the `select` function, selecting the category of the operands for a locale.
+///
+/// Locales without rules fall back to the rules of `und`, if any and not excluded with `exclude_root`,
+/// and otherwise select `OTHER`.
+fn gen_select(prts: &[PluralRuleType], options: &GenerateOptions) -> TokenStream {
+    let arms = prts.iter().map(|prt| {
+        let variant = Ident::new(&type_name(*prt).to_uppercase(), Span::call_site());
+        let select_fn_name = Ident::new(&select_fn_name(*prt), Span::call_site());
+        quote! { PluralRuleType::#variant => #select_fn_name, }
+    });
+    let root_fallback = gen_root_fallback(quote!(select_rule), options);
+    quote! {
+        pub fn select(
+            lang: &LanguageIdentifier,
+            po: &PluralOperands,
+            ty: PluralRuleType,
+        ) -> PluralCategory {
+            #[allow(unreachable_patterns)]
+            let select_rule: fn(&LanguageIdentifier) -> Option<PluralRule> = match ty {
+                #(#arms)*
+                _ => return PluralCategory::OTHER,
+            };
+            // Fall back to the rules of the language alone, then to the root rules of `und`.
+            let rule = select_rule(lang)
+                .or_else(|| select_rule(&LanguageIdentifier::from_parts(lang.language, None, None, &[])))
+                #root_fallback;
+            match rule {
+                Some(rule) => rule(po),
+                None => PluralCategory::OTHER,
+            }
+        }
+    }
+}
+
+/// Generates the last fallback of a lookup, to the root rules of `und`, unless `exclude_root` leaves them out.
+fn gen_root_fallback(lookup: TokenStream, options: &GenerateOptions) -> TokenStream {
+    if options.exclude_root {
+        return quote!();
+    }
+    let root = gen_langid(&root_langid());
+    quote! { .or_else(|| #lookup(&#root)) }
+}
+
+/// Generates the `supported_locales` function listing the locales with rules of a plural rule type.
+fn gen_supported_locales(prts: &[PluralRuleType]) -> TokenStream {
+    let arms = prts.iter().map(|prt| {
+        let variant = Ident::new(&type_name(*prt).to_uppercase(), Span::call_site());
+        let match_name = Ident::new(table_name(*prt), Span::call_site());
+        quote! { PluralRuleType::#variant => #match_name, }
+    });
+    quote! {
+        pub fn supported_locales(
+            ty: PluralRuleType,
+        ) -> impl Iterator<Item = &'static LanguageIdentifier> {
+            #[allow(unreachable_patterns)]
+            let table: &'static [(LanguageIdentifier, PluralRule)] = match ty {
+                #(#arms)*
+                _ => &[],
+            };
+            table.iter().map(|(lang, _)| lang)
+        }
+    }
+}
+
+/// Generates the `rules` function listing the locales of a plural rule type along with their rules,
+/// and the `find` function looking up the rule of a locale without falling back to other locales.
+fn gen_rules_and_find(prts: &[PluralRuleType]) -> TokenStream {
+    let table_arms = prts.iter().map(|prt| {
+        let variant = Ident::new(&type_name(*prt).to_uppercase(), Span::call_site());
+        let match_name = Ident::new(table_name(*prt), Span::call_site());
+        quote! { PluralRuleType::#variant => #match_name, }
+    });
+    let select_arms = prts.iter().map(|prt| {
+        let variant = Ident::new(&type_name(*prt).to_uppercase(), Span::call_site());
+        let select_fn_name = Ident::new(&select_fn_name(*prt), Span::call_site());
+        quote! { PluralRuleType::#variant => #select_fn_name, }
+    });
+    quote! {
+        pub fn rules(
+            ty: PluralRuleType,
+        ) -> impl Iterator<Item = (&'static LanguageIdentifier, PluralRule)> {
+            #[allow(unreachable_patterns)]
+            let table: &'static [(LanguageIdentifier, PluralRule)] = match ty {
+                #(#table_arms)*
+                _ => &[],
+            };
+            table.iter().map(|(lang, rule)| (lang, *rule))
+        }
+
+        pub fn find(ty: PluralRuleType, lang: &LanguageIdentifier) -> Option<PluralRule> {
+            #[allow(unreachable_patterns)]
+            let select_rule: fn(&LanguageIdentifier) -> Option<PluralRule> = match ty {
+                #(#select_arms)*
+                _ => return None,
+            };
+            select_rule(lang)
+        }
+    }
+}
+
+/// Generates the `category_count` function returning the number of categories of a locale.
+///
+/// Locales are looked up with the same fallbacks as in `select`, and locales without rules have the `OTHER` category only.
+fn gen_category_count_fn(prts: &[PluralRuleType], options: &GenerateOptions) -> TokenStream {
+    let arms = prts.iter().map(|prt| {
+        let variant = Ident::new(&type_name(*prt).to_uppercase(), Span::call_site());
+        let match_name = Ident::new(table_name(*prt), Span::call_site());
+        let counts_name = Ident::new(&category_counts_table_name(*prt), Span::call_site());
+        quote! { PluralRuleType::#variant => (#match_name, #counts_name), }
+    });
+    let root_fallback = gen_root_fallback(quote!(index), options);
+    quote! {
+        pub fn category_count(lang: &LanguageIdentifier, ty: PluralRuleType) -> usize {
+            #[allow(unreachable_patterns)]
+            let (table, counts): (&[(LanguageIdentifier, PluralRule)], &[u8]) = match ty {
+                #(#arms)*
+                _ => return 1,
+            };
+            let index = |lang: &LanguageIdentifier| table.binary_search_by(|(l, _)| l.cmp(lang)).ok();
+            let idx = index(lang)
+                .or_else(|| index(&LanguageIdentifier::from_parts(lang.language, None, None, &[])))
+                #root_fallback;
+            idx.map_or(1, |idx| counts[idx] as usize)
+        }
+    }
+}
+
+/// Generates the `rule_source` function looking up the embedded source of a rule.
+fn gen_rule_source(prts: &[PluralRuleType]) -> TokenStream {
+    let arms = prts.iter().map(|prt| {
+        let variant = Ident::new(&type_name(*prt).to_uppercase(), Span::call_site());
+        let sources_name = Ident::new(&sources_table_name(*prt), Span::call_site());
+        quote! { PluralRuleType::#variant => #sources_name, }
+    });
+    quote! {
+        pub fn rule_source(
+            lang: &LanguageIdentifier,
+            ty: PluralRuleType,
+            category: PluralCategory,
+        ) -> Option<&'static str> {
+            #[allow(unreachable_patterns)]
+            let table = match ty {
+                #(#arms)*
+                _ => return None,
+            };
+            let idx = table.binary_search_by_key(&lang, |(l, _)| l).ok()?;
+            table[idx]
+                .1
+                .iter()
+                .find(|(c, _)| *c == category)
+                .map(|(_, source)| *source)
+        }
+    }
+}
+
+/// Generates the `samples_for` function looking up the embedded sample values of a category.
+fn gen_samples_for(prts: &[PluralRuleType]) -> TokenStream {
+    let arms = prts.iter().map(|prt| {
+        let variant = Ident::new(&type_name(*prt).to_uppercase(), Span::call_site());
+        let samples_name = Ident::new(&samples_table_name(*prt), Span::call_site());
+        quote! { PluralRuleType::#variant => #samples_name, }
+    });
+    quote! {
+        pub fn samples_for(
+            lang: &LanguageIdentifier,
+            ty: PluralRuleType,
+            category: PluralCategory,
+        ) -> Option<&'static [&'static str]> {
+            #[allow(unreachable_patterns)]
+            let table = match ty {
+                #(#arms)*
+                _ => return None,
+            };
+            let idx = table.binary_search_by_key(&lang, |(l, _)| l).ok()?;
+            table[idx]
+                .1
+                .iter()
+                .find(|(c, _)| *c == category)
+                .map(|(_, samples)| *samples)
+        }
+    }
+}
+
+/// Generates the `minimal_pairs` function yielding the example value of each category of a locale, with the same
+/// fallbacks as `select`.
+fn gen_minimal_pairs_fn(prts: &[PluralRuleType], options: &GenerateOptions) -> TokenStream {
+    let arms = prts.iter().map(|prt| {
+        let variant = Ident::new(&type_name(*prt).to_uppercase(), Span::call_site());
+        let match_name = Ident::new(table_name(*prt), Span::call_site());
+        let pairs_name = Ident::new(&minimal_pairs_table_name(*prt), Span::call_site());
+        quote! { PluralRuleType::#variant => (#match_name, #pairs_name), }
+    });
+    let root_fallback = gen_root_fallback(quote!(index), options);
+    quote! {
+        pub fn minimal_pairs(
+            lang: &LanguageIdentifier,
+            ty: PluralRuleType,
+        ) -> impl Iterator<Item = (PluralCategory, PluralOperands)> {
+            #[allow(unreachable_patterns)]
+            let (table, pairs): (&[(LanguageIdentifier, PluralRule)], &[&[(PluralCategory, &str)]]) = match ty {
+                #(#arms)*
+                _ => (&[], &[]),
+            };
+            let index = |lang: &LanguageIdentifier| table.binary_search_by(|(l, _)| l.cmp(lang)).ok();
+            let idx = index(lang)
+                .or_else(|| index(&LanguageIdentifier::from_parts(lang.language, None, None, &[])))
+                #root_fallback;
+            idx.map_or(&[][..], |idx| pairs[idx])
+                .iter()
+                .filter_map(|(category, value)| {
+                    let po: PluralOperands = core::convert::TryFrom::try_from(*value).ok()?;
+                    Some((*category, po))
+                })
+        }
+    }
+}
+
+/// Generates the `#[cfg(test)]` module checking that every sample value of a rule selects its category.
+///
+/// The module lists the sample values itself, unless they are embedded with `embed_samples` already.
+fn gen_sample_tests(
+    prts: &[PluralRuleType],
+    streams: &HashMap<PluralRuleType, TypeTokens>,
+    options: &GenerateOptions,
+) -> TokenStream {
+    let tests = prts.iter().map(|prt| {
+        let samples_name = Ident::new(&samples_table_name(*prt), Span::call_site());
+        let select_fn_name = Ident::new(&select_fn_name(*prt), Span::call_site());
+        let test_name = Ident::new(&format!("{}_samples", type_name(*prt)), Span::call_site());
+        let samples_table = if options.embed_samples {
+            quote!()
+        } else {
+            let samples = &streams[prt].samples;
+            quote! {
+                const #samples_name: &[(LanguageIdentifier, &[(PluralCategory, &[&str])])] = &[ #(#samples),* ];
+            }
+        };
+        quote! {
+            #samples_table
+            #[test]
+            fn #test_name() {
+                for (lang, categories) in #samples_name {
+                    let rule = #select_fn_name(lang).expect("The locale has rules");
+                    for (category, samples) in categories.iter() {
+                        for sample in samples.iter() {
+                            // Samples with a compact exponent, such as `1c6`, have no plural operands.
+                            let Ok(po): Result<PluralOperands, _> = std::convert::TryFrom::try_from(*sample) else {
+                                continue;
+                            };
+                            assert_eq!(rule(&po), *category, "sample `{}` of `{}`", sample, lang);
+                        }
+                    }
+                }
+            }
+        }
+    });
+    quote! {
+        #[cfg(test)]
+        mod sample_tests {
+            use super::*;
+            #(#tests)*
+        }
+    }
+}
+
+/// Generates a `#[cfg(test)]` module checking that every identifier of the tables, constructed without
+/// validation by the `langid!` macro, is the identifier its string parses into.
+fn gen_langid_tests(prts: &[PluralRuleType]) -> TokenStream {
+    let tests = prts.iter().map(|prt| {
+        let match_name = Ident::new(table_name(*prt), Span::call_site());
+        let test_name = Ident::new(&format!("{}_langids", type_name(*prt)), Span::call_site());
+        quote! {
+            #[test]
+            fn #test_name() {
+                for (lang, _) in #match_name.iter() {
+                    let source = std::string::ToString::to_string(lang);
+                    // The root rules are listed under an explicit `und` language, which parsing leaves empty.
+                    if source == "und" {
+                        continue;
+                    }
+                    assert_eq!(source.parse::<LanguageIdentifier>().ok().as_ref(), Some(lang), "`{}`", source);
+                }
+            }
+        }
+    });
+    quote! {
+        #[cfg(test)]
+        mod langid_tests {
+            use super::*;
+            #(#tests)*
+        }
+    }
 }
 
 // Function wraps all match statements for plural rules in a match for ordinal and cardinal rules
-fn create_pr_type(pr_type: &str, streams: Vec<TokenStream>) -> TokenStream {
-    let mut tokens = Vec::<TokenStream>::new();
+fn create_pr_type(
+    prt: PluralRuleType,
+    streams: TypeTokens,
+    options: &GenerateOptions,
+) -> TokenStream {
+    let match_name = Ident::new(table_name(prt), Span::call_site());
+    let categories_name = Ident::new(&categories_table_name(prt), Span::call_site());
+    let category_counts_name = Ident::new(&category_counts_table_name(prt), Span::call_site());
+    let operands_name = Ident::new(&operands_table_name(prt), Span::call_site());
+    let count_name = Ident::new(&count_name(prt), Span::call_site());
+    let map_fn_name = Ident::new(&map_fn_name(prt), Span::call_site());
+    let select_fn_name = Ident::new(&select_fn_name(prt), Span::call_site());
+    let count = Literal::usize_unsuffixed(streams.rules.len());
+    let TypeTokens {
+        rules,
+        categories,
+        category_counts,
+        operands,
+        functions,
+        sources,
+        samples,
+        minimal_pairs,
+        phf_entries,
+        match_arms,
+    } = streams;
+    let sources = if options.embed_sources {
+        let sources_name = Ident::new(&sources_table_name(prt), Span::call_site());
+        quote! {
+            pub const #sources_name: &[(LanguageIdentifier, &[(PluralCategory, &str)])] = &[ #(#sources),* ];
+        }
+    } else {
+        quote!()
+    };
+    let map_fn = if options.no_std {
+        quote!()
+    } else {
+        quote! {
+            pub fn #map_fn_name() -> std::collections::HashMap<LanguageIdentifier, PluralRule> {
+                static MAP: std::sync::OnceLock<std::collections::HashMap<LanguageIdentifier, PluralRule>> =
+                    std::sync::OnceLock::new();
+                MAP.get_or_init(|| #match_name.iter().cloned().collect()).clone()
+            }
+        }
+    };
+    let samples = if options.embed_samples {
+        let samples_name = Ident::new(&samples_table_name(prt), Span::call_site());
+        quote! {
+            pub const #samples_name: &[(LanguageIdentifier, &[(PluralCategory, &[&str])])] = &[ #(#samples),* ];
+        }
+    } else {
+        quote!()
+    };
+    let minimal_pairs = if options.minimal_pairs {
+        let pairs_name = Ident::new(&minimal_pairs_table_name(prt), Span::call_site());
+        quote! {
+            /// An example value of each category of each locale, at the index of the locale in the rule table.
+            pub const #pairs_name: &[&[(PluralCategory, &str)]] = &[ #(#minimal_pairs),* ];
+        }
+    } else {
+        quote!()
+    };
 
-    let match_name = match pr_type {
-        "cardinal" => quote! { PRS_CARDINAL },
-        "ordinal" => quote! { PRS_ORDINAL },
-        _ => panic!("Unknown plural rule type"),
+    let select_fn = if options.phf_lookup {
+        let phf_name = Ident::new(&format!("{}_PHF", table_name(prt)), Span::call_site());
+        quote! {
+            pub static #phf_name: phf::Map<&'static str, PluralRule> = phf::phf_map! { #(#phf_entries),* };
+            /// Looks the rule of a locale up by its canonical string, which is allocated on every call.
+            pub fn #select_fn_name(lang: &LanguageIdentifier) -> Option<PluralRule> {
+                #phf_name.get(lang.to_string().as_str()).copied()
+            }
+        }
+    } else {
+        quote! {
+            pub fn #select_fn_name(lang: &LanguageIdentifier) -> Option<PluralRule> {
+                #match_name
+                    .binary_search_by(|(l, _)| l.cmp(lang))
+                    .ok()
+                    .map(|idx| #match_name[idx].1)
+            }
+        }
+    };
+    let match_fn = if options.match_dispatch {
+        let match_fn_name = Ident::new(&match_fn_name(prt), Span::call_site());
+        quote! {
+            /// Selects the category of the operands for a locale, without falling back to other locales,
+            /// with a `match` on the subtags of the locale.
+            pub fn #match_fn_name(lang: &LanguageIdentifier, po: &PluralOperands) -> Option<PluralCategory> {
+                let key = (
+                    lang.language.as_str(),
+                    lang.script.as_ref().map(subtags::Script::as_str),
+                    lang.region.as_ref().map(subtags::Region::as_str),
+                    lang.variants().len(),
+                );
+                Some(match key {
+                    #(#match_arms)*
+                    _ => return None,
+                })
+            }
+        }
+    } else {
+        quote!()
     };
 
-    for func in &streams {
-        tokens.push(func.clone());
+    // The rule streams are consumed as they are, so they don't need to be cloned.
+    quote! {
+        #(#functions)*
+        pub const #match_name: &[(LanguageIdentifier, PluralRule)] = &[ #(#rules),* ];
+        pub const #categories_name: &[(LanguageIdentifier, &[PluralCategory])] = &[ #(#categories),* ];
+        /// The number of categories of each locale, at the index of the locale in the rule table.
+        pub const #category_counts_name: &[u8] = &[ #(#category_counts),* ];
+        /// The letters of the operands the rules of each locale read, at the index of the locale in the rule table.
+        pub const #operands_name: &[&str] = &[ #(#operands),* ];
+        pub const #count_name: usize = #count;
+        #select_fn
+        #match_fn
+        #map_fn
+        #sources
+        #samples
+        #minimal_pairs
+    }
+}
+
+fn category_tokens(cat: PluralCategory) -> TokenStream {
+    match cat {
+        PluralCategory::ZERO => quote! { PluralCategory::ZERO },
+        PluralCategory::ONE => quote! { PluralCategory::ONE },
+        PluralCategory::TWO => quote! { PluralCategory::TWO },
+        PluralCategory::FEW => quote! { PluralCategory::FEW },
+        PluralCategory::MANY => quote! { PluralCategory::MANY },
+        PluralCategory::OTHER => quote! { PluralCategory::OTHER },
     }
-    quote! { pub const #match_name: &[(LanguageIdentifier, PluralRule)] = &[ #(#tokens),* ]; }
 }
 
 // Function wraps an expression in a match statement for plural category
 fn create_return(cat: PluralCategory, exp: &TokenStream) -> TokenStream {
+    let category = category_tokens(cat);
     match cat {
-        PluralCategory::ZERO => quote! {if #exp { PluralCategory::ZERO } },
-        PluralCategory::ONE => quote! {if #exp { PluralCategory::ONE } },
-        PluralCategory::TWO => quote! {if #exp { PluralCategory::TWO } },
-        PluralCategory::FEW => quote! {if #exp { PluralCategory::FEW } },
-        PluralCategory::MANY => quote! {if #exp { PluralCategory::MANY } },
-        PluralCategory::OTHER => quote! { { PluralCategory::OTHER } },
+        PluralCategory::OTHER => quote! { { #category } },
+        _ => quote! {if #exp { #category } },
     }
 }
 
@@ -93,8 +646,34 @@ fn str_to_u32(s: &str) -> u32 {
     u32::from_le_bytes(bytes)
 }
 
+/// Returns the identifier the root rules are listed under, `und`.
+///
+/// Parsing `und` leaves the language subtag empty, which a constant table can't hold, so the tables
+/// spell the language out. This identifier sorts, and is generated, as the tables hold it.
+pub fn root_langid() -> LanguageIdentifier {
+    // SAFETY: `und` is a valid language subtag.
+    let language = unsafe { subtags::Language::from_raw_unchecked(str_to_u64("und")) };
+    LanguageIdentifier::from_parts(language, None, None, &[])
+}
+
+/// Returns whether `gen_langid` can construct `id`: its subtags must survive a round trip through
+/// its string, and it must have a language, apart from the root identifier.
+///
+/// The generated `langid!` macro constructs identifiers without validation, which is only sound for such identifiers.
+pub fn is_generatable(id: &LanguageIdentifier) -> bool {
+    if *id == root_langid() {
+        return true;
+    }
+    !id.language.is_empty()
+        && id.to_string().parse::<LanguageIdentifier>().ok().as_ref() == Some(id)
+}
+
+/// Generates a call of the `langid!` macro emitted by `gen_fn`, constructing `id`, which must be accepted
+/// by `is_generatable`.
+///
+/// Variants are boxed, so a table listing a locale with variants can't be a constant.
 pub fn gen_langid(id: &LanguageIdentifier) -> TokenStream {
-    let (language, script, region, _) = id.clone().into_parts();
+    let (language, script, region, variants) = id.clone().into_parts();
 
     // Language is always present (not optional) - takes u64
     let lang_str = language.as_str();
@@ -119,51 +698,272 @@ pub fn gen_langid(id: &LanguageIdentifier) -> TokenStream {
         quote!(None)
     };
 
-    // No support for variants yet
+    // Variants are optional - each takes u64
+    let variants = if variants.is_empty() {
+        quote!(None)
+    } else {
+        let variants = variants.iter().map(|variant| {
+            let variant_raw = str_to_u64(variant.as_str());
+            quote!(subtags::Variant::from_raw_unchecked(#variant_raw))
+        });
+        quote!(Some(Box::new([#(#variants),*])))
+    };
 
     quote! {
         langid!(
             #lang,
             #script,
-            #region
+            #region,
+            #variants
         )
     }
 }
 
-/// Generates the closures that comprise the majority of the generated rust code.
+/// Generates the body of a rule, selecting the category of the first matching condition.
+///
+/// The chain ends with a single unconditional `OTHER` arm: an `OTHER` entry of the set takes its place,
+/// and any entry after it is unreachable and left out.
+fn gen_rule_body(pluralrule_set: &[(PluralCategory, TokenStream)]) -> TokenStream {
+    let mut tokens: Option<TokenStream> = None;
+    for (cat, exp) in pluralrule_set {
+        let arm = create_return(*cat, exp);
+        tokens = Some(match tokens {
+            Some(tokens) => quote! { #tokens else #arm },
+            None => arm,
+        });
+        if *cat == PluralCategory::OTHER {
+            return tokens.unwrap();
+        }
+    }
+    match tokens {
+        Some(tokens) => quote! { #tokens else { PluralCategory::OTHER } },
+        None => quote! { { PluralCategory::OTHER } },
+    }
+}
+
+/// Returns whether a rule body only uses operations allowed in a `const fn`.
 ///
-/// These statements are the expression representations of the CLDR plural rules.
-pub fn gen_mid(
+/// The bodies are made of comparisons, arithmetic and boolean operators, which are all allowed, and of method
+/// calls, such as `RangeInclusive::contains` or custom operand accessors, which may not be.
+fn is_const_body(body: &TokenStream) -> bool {
+    let tokens: Vec<TokenTree> = body.clone().into_iter().collect();
+    tokens.iter().enumerate().all(|(idx, tt)| match tt {
+        TokenTree::Punct(punct) if punct.as_char() == '.' => !matches!(
+            (tokens.get(idx + 1), tokens.get(idx + 2)),
+            (Some(TokenTree::Ident(_)), Some(TokenTree::Group(group)))
+                if group.delimiter() == Delimiter::Parenthesis
+        ),
+        TokenTree::Group(group) => is_const_body(&group.stream()),
+        _ => true,
+    })
+}
+
+/// Returns the `const` qualifier of a rule function, if its body allows it.
+fn const_qualifier(body: &TokenStream) -> Option<TokenStream> {
+    is_const_body(body).then(|| quote!(const))
+}
+
+/// Returns the name of the exported selection function of a locale, such as `select_en_cardinal`.
+pub fn selector_name(lang: &LanguageIdentifier, prt: PluralRuleType) -> Ident {
+    let lang = lang.to_string().to_lowercase().replace('-', "_");
+    Ident::new(
+        &format!("select_{}_{}", lang, type_name(prt)),
+        Span::call_site(),
+    )
+}
+
+/// Generates the doc comment of a rule function, naming the locales it is used by and the CLDR source of
+/// each of its categories, such as `` - one: `i = 1 and v = 0` ``.
+pub fn gen_rule_doc(
+    langs: &[&LanguageIdentifier],
+    prt: PluralRuleType,
+    sources: &[(PluralCategory, String)],
+) -> TokenStream {
+    let langs: Vec<_> = langs.iter().map(|lang| format!("`{}`", lang)).collect();
+    let mut lines = vec![
+        format!(" The {} rules of {}.", type_name(prt), langs.join(", ")),
+        String::new(),
+    ];
+    if sources.is_empty() {
+        lines.push(" Every number selects `other`.".to_owned());
+    }
+    for (cat, source) in sources {
+        lines.push(format!(" - {}: `{}`", cat.name(), source));
+    }
+    quote! { #(#[doc = #lines])* }
+}
+
+/// Generates the exported selection function of a locale.
+pub fn gen_selector(
     lang: &LanguageIdentifier,
+    prt: PluralRuleType,
     pluralrule_set: &[(PluralCategory, TokenStream)],
+    doc: &TokenStream,
 ) -> TokenStream {
-    let langid = gen_langid(lang);
-    // make pluralrule_set iterable
-    let mut iter = pluralrule_set.iter();
-
-    let queued = iter.next();
-    let rule_tokens = match queued {
-        Some(pair) => {
-            // instantiate tokenstream for folded match rules
-            let mut tokens = create_return(pair.0, &pair.1);
-
-            // add all tokens to token stream, separated by commas
-            for pair in iter {
-                let condition = create_return(pair.0, &pair.1);
-                tokens = quote! { #tokens else #condition };
-            }
-            tokens = quote! { #tokens else { PluralCategory::OTHER } };
-            tokens
+    let name = selector_name(lang, prt);
+    let rule_tokens = gen_rule_body(pluralrule_set);
+    let constness = const_qualifier(&rule_tokens);
+    quote! {
+        #doc
+        pub #constness fn #name(po: &PluralOperands) -> PluralCategory {
+            #rule_tokens
         }
-        None => quote! { { PluralCategory::OTHER }  },
+    }
+}
+
+/// Generates the arm of the `match_<type>` function selecting the category of the locales sharing a rule.
+///
+/// The locales are matched by their language, script and region subtags, along with their number of variants,
+/// which the const tables can't hold, so that a locale with variants never matches.
+pub fn gen_match_arm(
+    langs: &[&LanguageIdentifier],
+    pluralrule_set: &[(PluralCategory, TokenStream)],
+) -> TokenStream {
+    let rule_tokens = gen_rule_body(pluralrule_set);
+    let subtag = |subtag: Option<&str>| match subtag {
+        Some(subtag) => quote!(Some(#subtag)),
+        None => quote!(None),
     };
+    let patterns = langs.iter().map(|lang| {
+        let language = lang.language.as_str();
+        let script = subtag(lang.script.as_ref().map(subtags::Script::as_str));
+        let region = subtag(lang.region.as_ref().map(subtags::Region::as_str));
+        quote! { (#language, #script, #region, 0) }
+    });
+    quote! { #(#patterns)|* => #rule_tokens, }
+}
 
-    // We can't use a closure here because closures can't get rvalue
-    // promoted to statics. They may in the future.
-    quote! {(
-        #langid,
-        |po| {
+/// Returns the name of a rule function shared by the locales with identical rules, such as `cardinal_rule_0`.
+pub fn shared_rule_name(prt: PluralRuleType, idx: usize) -> Ident {
+    Ident::new(
+        &format!("{}_rule_{}", type_name(prt), idx),
+        Span::call_site(),
+    )
+}
+
+/// Generates a rule function shared by the locales with identical rules.
+pub fn gen_shared_rule(
+    name: &Ident,
+    pluralrule_set: &[(PluralCategory, TokenStream)],
+    doc: &TokenStream,
+) -> TokenStream {
+    let rule_tokens = gen_rule_body(pluralrule_set);
+    let constness = const_qualifier(&rule_tokens);
+    quote! {
+        #doc
+        #constness fn #name(po: &PluralOperands) -> PluralCategory {
             #rule_tokens
         }
+    }
+}
+
+/// Generates the table entries that comprise the majority of the generated rust code.
+///
+/// Each entry refers to the function `rule`, generated by `gen_shared_rule` or, with
+/// `export_selectors`, by `gen_selector`.
+pub fn gen_mid(lang: &LanguageIdentifier, rule: &Ident, options: &GenerateOptions) -> TokenStream {
+    let langid = gen_langid(lang);
+
+    // Trait object rules borrow the function, which gets promoted to `'static`
+    // as part of the const table.
+    let rule = if options.dyn_rules {
+        quote! { &#rule }
+    } else {
+        quote! { #rule }
+    };
+    quote! {(
+        #langid,
+        #rule
+    )}
+}
+
+/// Generates the `phf_map!` entry of a locale, keyed by its canonical string, such as `"en-US" => cardinal_rule_0`.
+pub fn gen_phf_entry(
+    lang: &LanguageIdentifier,
+    rule: &Ident,
+    options: &GenerateOptions,
+) -> TokenStream {
+    let key = lang.to_string();
+    let rule = if options.dyn_rules {
+        quote! { &#rule }
+    } else {
+        quote! { #rule }
+    };
+    quote! { #key => #rule }
+}
+
+/// Generates the entry listing the source of each rule of a locale, such as `i = 1 and v = 0`.
+pub fn gen_sources(lang: &LanguageIdentifier, sources: &[(PluralCategory, String)]) -> TokenStream {
+    let langid = gen_langid(lang);
+    let sources = sources.iter().map(|(cat, source)| {
+        let category = category_tokens(*cat);
+        quote! { (#category, #source) }
+    });
+    quote! {(
+        #langid,
+        &[ #(#sources),* ]
+    )}
+}
+
+/// Generates the entry listing the sample values of each rule of a locale, including `other`.
+pub fn gen_samples(
+    lang: &LanguageIdentifier,
+    samples: &[(PluralCategory, Vec<String>)],
+) -> TokenStream {
+    let langid = gen_langid(lang);
+    let samples = samples.iter().map(|(cat, values)| {
+        let category = category_tokens(*cat);
+        quote! { (#category, &[ #(#values),* ]) }
+    });
+    quote! {(
+        #langid,
+        &[ #(#samples),* ]
+    )}
+}
+
+/// Generates the entry listing an example value of each category of a locale, in CLDR order.
+///
+/// Like the operands table, the entries leave the locale out.
+pub fn gen_minimal_pairs(pairs: &[(PluralCategory, String)]) -> TokenStream {
+    let pairs = pairs.iter().map(|(cat, value)| {
+        let category = category_tokens(*cat);
+        quote! { (#category, #value) }
+    });
+    quote!(&[ #(#pairs),* ])
+}
+
+/// Generates the entry listing the letters of the operands the rules of a locale read, such as `"iv"`.
+///
+/// Unlike the other tables, the entries leave the locale out, as the table is only indexed like the rule table.
+pub fn gen_operands(operands: &BTreeSet<Operand>) -> TokenStream {
+    let letters: String = operands.iter().map(Operand::to_string).collect();
+    quote!(#letters)
+}
+
+/// Generates the entry holding the number of categories a locale can produce, including `OTHER`.
+///
+/// Like the operands table, the entries leave the locale out.
+pub fn gen_category_count(categories: &[PluralCategory]) -> TokenStream {
+    let mut categories = categories.to_vec();
+    categories.push(PluralCategory::OTHER);
+    categories.sort_unstable();
+    categories.dedup();
+    let count = Literal::u8_unsuffixed(categories.len() as u8);
+    quote!(#count)
+}
+
+/// Generates the entry listing the categories a locale can produce, in CLDR order.
+///
+/// `OTHER` is always included, as every locale falls back to it.
+pub fn gen_categories(lang: &LanguageIdentifier, categories: &[PluralCategory]) -> TokenStream {
+    let langid = gen_langid(lang);
+    let mut categories = categories.to_vec();
+    categories.push(PluralCategory::OTHER);
+    categories.sort_unstable();
+    categories.dedup();
+    let categories = categories.into_iter().map(category_tokens);
+    quote! {(
+        #langid,
+        &[ #(#categories),* ]
     )}
 }
@@ -1,4 +1,7 @@
+pub mod gen_json;
+pub mod gen_operands;
 pub mod gen_pr;
 pub mod gen_rs;
 pub mod plural_category;
+pub mod plural_type;
 pub mod resource;
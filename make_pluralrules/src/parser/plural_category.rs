@@ -1,7 +1,7 @@
 //! Plural rule categories in compliance with [Unicode](https://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules)
 
-/// An enum for all plural rule categories.
-#[derive(Clone, Copy, Debug, PartialEq)]
+/// An enum for all plural rule categories, in CLDR order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum PluralCategory {
     ZERO,
@@ -11,3 +11,30 @@ pub enum PluralCategory {
     MANY,
     OTHER,
 }
+
+impl PluralCategory {
+    /// Returns the CLDR name of the category, such as `one`.
+    pub fn name(self) -> &'static str {
+        match self {
+            PluralCategory::ZERO => "zero",
+            PluralCategory::ONE => "one",
+            PluralCategory::TWO => "two",
+            PluralCategory::FEW => "few",
+            PluralCategory::MANY => "many",
+            PluralCategory::OTHER => "other",
+        }
+    }
+}
+
+impl From<PluralCategory> for intl_pluralrules::PluralCategory {
+    fn from(cat: PluralCategory) -> Self {
+        match cat {
+            PluralCategory::ZERO => Self::ZERO,
+            PluralCategory::ONE => Self::ONE,
+            PluralCategory::TWO => Self::TWO,
+            PluralCategory::FEW => Self::FEW,
+            PluralCategory::MANY => Self::MANY,
+            PluralCategory::OTHER => Self::OTHER,
+        }
+    }
+}
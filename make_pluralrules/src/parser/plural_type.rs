@@ -0,0 +1,92 @@
+//! Plural rule types known to the generator.
+//!
+//! Supporting a new plural rule type only requires adding it to `PLURAL_RULE_TYPES`.
+
+use intl_pluralrules::PluralRuleType;
+
+/// All known plural rule types in the order they are emitted, along with their CLDR type name
+/// (as in `plurals-type-<name>`) and the name of the generated table.
+pub const PLURAL_RULE_TYPES: &[(PluralRuleType, &str, &str)] = &[
+    (
+        PluralRuleType::CARDINAL,
+        PluralRuleType::CARDINAL.as_str(),
+        "PRS_CARDINAL",
+    ),
+    (
+        PluralRuleType::ORDINAL,
+        PluralRuleType::ORDINAL.as_str(),
+        "PRS_ORDINAL",
+    ),
+];
+
+/// Returns the plural rule type for a CLDR type name.
+pub fn from_type_name(name: &str) -> Option<PluralRuleType> {
+    name.parse().ok()
+}
+
+fn entry(prt: PluralRuleType) -> &'static (PluralRuleType, &'static str, &'static str) {
+    PLURAL_RULE_TYPES
+        .iter()
+        .find(|(p, _, _)| *p == prt)
+        .expect("All plural rule types are listed")
+}
+
+/// Returns the CLDR type name of a plural rule type.
+pub fn type_name(prt: PluralRuleType) -> &'static str {
+    entry(prt).1
+}
+
+/// Returns the name of the generated table for a plural rule type.
+pub fn table_name(prt: PluralRuleType) -> &'static str {
+    entry(prt).2
+}
+
+/// Returns the name of the generated constant holding the number of locales in the table for a plural rule type.
+pub fn count_name(prt: PluralRuleType) -> String {
+    format!("{}_LOCALE_COUNT", entry(prt).1.to_uppercase())
+}
+
+/// Returns the name of the generated table listing the categories of each locale for a plural rule type.
+pub fn categories_table_name(prt: PluralRuleType) -> String {
+    format!("{}_CATEGORIES", table_name(prt))
+}
+
+/// Returns the name of the generated table holding the number of categories of each locale for a plural rule type.
+pub fn category_counts_table_name(prt: PluralRuleType) -> String {
+    format!("{}_CATEGORY_COUNTS", table_name(prt))
+}
+
+/// Returns the name of the generated table listing the operands the rules of each locale read for a plural rule type.
+pub fn operands_table_name(prt: PluralRuleType) -> String {
+    format!("{}_OPERANDS", table_name(prt))
+}
+
+/// Returns the name of the generated table holding the source of each rule for a plural rule type.
+pub fn sources_table_name(prt: PluralRuleType) -> String {
+    format!("{}_SOURCES", table_name(prt))
+}
+
+/// Returns the name of the generated table holding the sample values of each rule for a plural rule type.
+pub fn samples_table_name(prt: PluralRuleType) -> String {
+    format!("{}_SAMPLES", table_name(prt))
+}
+
+/// Returns the name of the generated table holding an example value per category of each locale for a plural rule type.
+pub fn minimal_pairs_table_name(prt: PluralRuleType) -> String {
+    format!("{}_MINIMAL_PAIRS", table_name(prt))
+}
+
+/// Returns the name of the generated function looking up the rule of a locale for a plural rule type.
+pub fn select_fn_name(prt: PluralRuleType) -> String {
+    format!("select_{}", entry(prt).1)
+}
+
+/// Returns the name of the generated function selecting the category of a locale with a `match` for a plural rule type.
+pub fn match_fn_name(prt: PluralRuleType) -> String {
+    format!("match_{}", entry(prt).1)
+}
+
+/// Returns the name of the generated function building a map of the table for a plural rule type.
+pub fn map_fn_name(prt: PluralRuleType) -> String {
+    format!("{}_map", entry(prt).1)
+}
@@ -1,9 +1,16 @@
 //! This reference parser uses serde_json to produce the plural rules from a CLDR data JSON file.
 
+use serde::de::{Deserializer, IgnoredAny, MapAccess, Visitor};
 use serde::{Deserialize, Serialize};
 
 use std::collections::BTreeMap;
 use std::error::Error;
+use std::fmt;
+use std::io::BufRead;
+use std::marker::PhantomData;
+
+/// The byte order mark some editors write at the start of UTF-8 files, which JSON parsers reject.
+const BOM: &str = "\u{FEFF}";
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Resource {
@@ -23,14 +30,138 @@ pub struct Version {
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Supplemental {
     pub version: Version,
-    #[serde(rename = "plurals-type-cardinal")]
-    pub plurals_type_cardinal: Option<BTreeMap<String, BTreeMap<String, String>>>,
-    #[serde(rename = "plurals-type-ordinal")]
-    pub plurals_type_ordinal: Option<BTreeMap<String, BTreeMap<String, String>>>,
+    /// All other supplemental data, including the `plurals-type-<name>` sections.
+    #[serde(flatten)]
+    pub sections: BTreeMap<String, Section>,
+}
+
+/// A supplemental data section.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(untagged)]
+pub enum Section {
+    /// A `plurals-type-<name>` section.
+    PluralRules(PluralRulesSection),
+    /// Any other supplemental data.
+    Other(serde_json::Value),
+}
+
+/// The entries of a JSON object in source order, keeping duplicate keys.
+///
+/// Merged CLDR data may list a locale, or a rule of a locale, twice, which a map would silently collapse.
+#[derive(Serialize, Debug)]
+pub struct Entries<T>(pub Vec<(String, T)>);
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Entries<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EntriesVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for EntriesVisitor<T> {
+            type Value = Entries<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a JSON object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(Entries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor(PhantomData))
+    }
 }
 
+/// The plural rules of a single `plurals-type-<name>` section, keyed by locale and rule name.
+pub type PluralRulesSection = Entries<Entries<String>>;
+
 /// Will parse a CLDR compliant source from a &str.
-pub fn parse_plurals_resource_from_string(body: &str) -> Result<Resource, Box<dyn Error>> {
-    let u = serde_json::from_str(body)?;
-    Ok(u)
+///
+/// Returns `None` if the source doesn't contain any `plurals-type-<name>` section. A leading byte order mark is skipped.
+pub fn parse_plurals_resource_from_string(body: &str) -> Result<Option<Resource>, Box<dyn Error>> {
+    let body = body.strip_prefix(BOM).unwrap_or(body);
+    let value: serde_json::Value = serde_json::from_str(body)?;
+    let has_plural_rules = value
+        .get("supplemental")
+        .and_then(serde_json::Value::as_object)
+        .is_some_and(|supplemental| {
+            supplemental
+                .keys()
+                .any(|key| key.starts_with("plurals-type-"))
+        });
+    if !has_plural_rules {
+        return Ok(None);
+    }
+    // Deserialize from the source, as `value` only kept the last of duplicate keys.
+    Ok(Some(serde_json::from_str(body)?))
+}
+
+/// The top level of a CLDR JSON source read incrementally, keeping only the plural rules of its supplemental data.
+struct StreamedResource(Supplemental);
+
+impl<'de> Deserialize<'de> for StreamedResource {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Root {
+            supplemental: StreamedSupplemental,
+        }
+        Root::deserialize(deserializer).map(|root| StreamedResource(root.supplemental.0))
+    }
+}
+
+/// The supplemental data of a CLDR JSON source, skipping other sections than `version` and
+/// `plurals-type-<name>` without keeping them in memory.
+struct StreamedSupplemental(Supplemental);
+
+impl<'de> Deserialize<'de> for StreamedSupplemental {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SupplementalVisitor;
+
+        impl<'de> Visitor<'de> for SupplementalVisitor {
+            type Value = StreamedSupplemental;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("CLDR supplemental data")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut version = None;
+                let mut sections = BTreeMap::new();
+                while let Some(key) = map.next_key::<String>()? {
+                    if key == "version" {
+                        version = Some(map.next_value()?);
+                    } else if key.starts_with("plurals-type-") {
+                        sections.insert(key, Section::PluralRules(map.next_value()?));
+                    } else {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                }
+                let version = version.ok_or_else(|| serde::de::Error::missing_field("version"))?;
+                Ok(StreamedSupplemental(Supplemental { version, sections }))
+            }
+        }
+
+        deserializer.deserialize_map(SupplementalVisitor)
+    }
+}
+
+/// Will parse a CLDR compliant source from a reader, incrementally.
+///
+/// Unlike `parse_plurals_resource_from_string`, the source is never held in memory as a whole, and the
+/// supplemental sections other than the plural rules are skipped, so the returned `Resource` only lists
+/// the `plurals-type-<name>` sections. Returns `None` if the source doesn't contain any.
+pub fn parse_plurals_resource_from_reader<R: BufRead>(
+    mut reader: R,
+) -> Result<Option<Resource>, Box<dyn Error>> {
+    if reader.fill_buf()?.starts_with(BOM.as_bytes()) {
+        reader.consume(BOM.len());
+    }
+    let StreamedResource(supplemental) = serde_json::from_reader(reader)?;
+    if supplemental.sections.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Resource { supplemental }))
 }
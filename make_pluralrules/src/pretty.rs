@@ -0,0 +1,845 @@
+//! A pretty-printer for the generated Rust code, independent of the installed rustfmt version.
+//!
+//! The generated code has a fixed shape, so a few layout rules are enough: every item goes on its own line,
+//! and so do the entries of a constant table, the statements and match arms of a block and the items of a
+//! module, indented. A line wider than `MAX_WIDTH` breaks the way rustfmt would break it: after an `=`,
+//! before `||` or `&&`, before the calls of a method chain, or inside its last parenthesized or bracketed
+//! group. Within a line, tokens are separated by single spaces where Rust requires or conventionally puts
+//! them, and doc attributes are printed as `///` comments.
+//! The same code always yields the same bytes.
+
+use proc_macro2::{Delimiter, Group, Spacing, TokenStream, TokenTree};
+
+/// The width lines are kept within, where their tokens can be broken over lines.
+const MAX_WIDTH: usize = 100;
+
+const INDENT: &str = "    ";
+
+/// Keywords followed by a space before a parenthesized expression, unlike function names.
+const KEYWORDS: &[&str] = &[
+    "if", "in", "match", "return", "while", "move", "as", "let", "for", "impl", "dyn",
+];
+
+/// Multi-character punctuation, which two adjacent tokens must not accidentally form.
+///
+/// `>>` and `<<` are left out, as they only close or open nested generic argument lists in the generated code.
+const COMPOUND_PUNCTS: &[&str] = &[
+    "::", "->", "=>", "==", "!=", "<=", ">=", "&&", "||", "+=", "-=", "*=", "/=", "%=", "^=", "&=",
+    "|=", "..", "..=", "...", "<-", "//", "/*",
+];
+
+/// Laid out lines, with their indentation depth.
+type Lines = Vec<(usize, String)>;
+
+/// A token or a sequence of joint punctuation, such as `::` or `..=`.
+enum Atom {
+    Word(String),
+    Literal(String),
+    Lifetime(String),
+    Op(String),
+    Group(Group),
+}
+
+impl Atom {
+    fn is_op(&self, op: &str) -> bool {
+        matches!(self, Atom::Op(o) if o == op)
+    }
+}
+
+/// Groups joint punctuation into the operators of `COMPOUND_PUNCTS`.
+fn atoms(stream: TokenStream) -> Vec<Atom> {
+    let mut atoms = Vec::new();
+    let mut pending = String::new();
+    for tt in stream {
+        match tt {
+            TokenTree::Punct(punct) => {
+                let c = punct.as_char();
+                if c == '\'' {
+                    if !pending.is_empty() {
+                        atoms.push(Atom::Op(std::mem::take(&mut pending)));
+                    }
+                    pending.push(c);
+                    continue;
+                }
+                let joined = format!("{}{}", pending, c);
+                if !pending.is_empty() && !COMPOUND_PUNCTS.iter().any(|p| p.starts_with(&joined)) {
+                    atoms.push(Atom::Op(std::mem::take(&mut pending)));
+                }
+                pending.push(c);
+                if punct.spacing() == Spacing::Alone {
+                    atoms.push(Atom::Op(std::mem::take(&mut pending)));
+                }
+            }
+            TokenTree::Ident(ident) if pending == "'" => {
+                pending.clear();
+                atoms.push(Atom::Lifetime(format!("'{}", ident)));
+            }
+            tt => {
+                if !pending.is_empty() {
+                    atoms.push(Atom::Op(std::mem::take(&mut pending)));
+                }
+                atoms.push(match tt {
+                    TokenTree::Ident(ident) => Atom::Word(ident.to_string()),
+                    TokenTree::Literal(literal) => Atom::Literal(literal.to_string()),
+                    TokenTree::Group(group) => Atom::Group(group),
+                    TokenTree::Punct(_) => unreachable!(),
+                });
+            }
+        }
+    }
+    if !pending.is_empty() {
+        atoms.push(Atom::Op(pending));
+    }
+    atoms
+}
+
+/// Lays out a token sequence on a single line.
+#[derive(Default)]
+struct Line<'a> {
+    out: String,
+    /// The last atom printed.
+    prev: Option<&'a Atom>,
+    /// The number of open generic argument lists.
+    generics: usize,
+    /// Whether the parameters of a closure are being printed.
+    closure_params: bool,
+}
+
+impl<'a> Line<'a> {
+    /// Continues the line `out`, which ends with `prev`.
+    fn after(out: String, prev: &'a Atom) -> Self {
+        Line {
+            out,
+            prev: Some(prev),
+            ..Line::default()
+        }
+    }
+
+    fn print(mut self, atoms: &'a [Atom]) -> String {
+        for atom in atoms {
+            self.push(atom, &render_atom(atom));
+        }
+        self.out
+    }
+
+    /// Prints `text` for `atom`, which is only the opening delimiter where a group is broken over lines.
+    fn push(&mut self, atom: &'a Atom, text: &str) {
+        if let Some(prev) = self.prev
+            && (self.space_between(prev, atom) || glues(&self.out, prev, text))
+        {
+            self.out.push(' ');
+        }
+        self.out.push_str(text);
+        self.update(atom);
+        self.prev = Some(atom);
+    }
+
+    fn space_between(&self, prev: &Atom, next: &Atom) -> bool {
+        use Atom::*;
+        match (prev, next) {
+            (_, Op(o)) if o == "," || o == ";" || o == "?" || o == "." || o == "::" || o == ":" => {
+                false
+            }
+            (Op(o), Word(_)) if o == "!" && self.out.ends_with("macro_rules!") => true,
+            (Op(o), Group(g)) if o == "!" && g.delimiter() == Delimiter::Brace => true,
+            (Op(o), _) if o == "." || o == "::" || o == "&" || o == "*" || o == "!" => false,
+            (Op(o), _) if o == "#" || o == "$" => false,
+            (Op(o), _) | (_, Op(o)) if o == ".." || o == "..=" => false,
+            (Word(w), Op(o)) if o == "!" => KEYWORDS.contains(&w.as_str()),
+            (Op(o), Op(p)) if o == "#" && p == "!" => false,
+            (Word(_), Op(o)) if o == "<" => false,
+            (Op(o), _) if o == "<" && self.generics > 0 => false,
+            (_, Op(o)) if o == ">" && self.generics > 0 => false,
+            (Op(o), _) if o == "|" && self.closure_params => false,
+            (_, Op(o)) if o == "|" && self.closure_params => false,
+            (Word(w), Group(g)) if g.delimiter() == Delimiter::Parenthesis => {
+                KEYWORDS.contains(&w.as_str())
+            }
+            (Word(_), Group(g)) if g.delimiter() == Delimiter::Bracket => false,
+            (Op(o), Group(g)) if o == ">" && g.delimiter() == Delimiter::Parenthesis => false,
+            (Group(_), Group(g)) if g.delimiter() != Delimiter::Brace => false,
+            _ => true,
+        }
+    }
+
+    fn update(&mut self, atom: &Atom) {
+        match atom {
+            Atom::Op(o) if o == "<" && opens_generics(self.prev) => self.generics += 1,
+            Atom::Op(o) if o == ">" && self.generics > 0 => self.generics -= 1,
+            // Outside of closure parameters, the generated code only uses `|` between patterns, after an
+            // operand.
+            Atom::Op(o) if o == "|" && (self.closure_params || !is_operand(self.prev)) => {
+                self.closure_params = !self.closure_params
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Whether a `<` after `prev` opens generic arguments, as in `Option<T>` or `parse::<T>`, rather than
+/// comparing.
+fn opens_generics(prev: Option<&Atom>) -> bool {
+    matches!(prev, Some(Atom::Word(_))) || matches!(prev, Some(a) if a.is_op("::"))
+}
+
+/// Whether `prev` ends an operand, so that an operator after it is binary.
+fn is_operand(prev: Option<&Atom>) -> bool {
+    match prev {
+        Some(Atom::Word(w)) => !KEYWORDS.contains(&w.as_str()) && w != "move",
+        Some(Atom::Literal(_) | Atom::Group(_)) => true,
+        _ => false,
+    }
+}
+
+/// Returns whether printing `next` right after `prev` would lex differently.
+fn glues(out: &str, prev: &Atom, next: &str) -> bool {
+    let (Some(last), Some(first)) = (out.chars().last(), next.chars().next()) else {
+        return false;
+    };
+    let word = |c: char| c.is_alphanumeric() || c == '_';
+    if word(last) && word(first) {
+        return true;
+    }
+    COMPOUND_PUNCTS
+        .iter()
+        .any(|p| p.starts_with(last) && p[last.len_utf8()..].starts_with(first))
+        && matches!(prev, Atom::Op(_))
+}
+
+fn render_atom(atom: &Atom) -> String {
+    match atom {
+        Atom::Word(s) | Atom::Literal(s) | Atom::Lifetime(s) | Atom::Op(s) => s.clone(),
+        Atom::Group(group) => {
+            let inner = Line::default().print(&contents(group));
+            match group.delimiter() {
+                Delimiter::Parenthesis => format!("({})", inner),
+                Delimiter::Bracket => format!("[{}]", inner),
+                Delimiter::Brace if inner.is_empty() => "{}".to_owned(),
+                Delimiter::Brace => format!("{{ {} }}", inner),
+                Delimiter::None => inner,
+            }
+        }
+    }
+}
+
+fn contents(group: &Group) -> Vec<Atom> {
+    atoms(group.stream())
+}
+
+fn is_group(atom: &Atom, delimiter: Delimiter) -> bool {
+    matches!(atom, Atom::Group(g) if g.delimiter() == delimiter)
+}
+
+fn width(text: &str) -> usize {
+    text.chars().count()
+}
+
+/// Whether `text` fits on a line at `depth`, after `offset` columns of other text.
+fn fits(depth: usize, offset: usize, text: &str) -> bool {
+    depth * INDENT.len() + offset + width(text) <= MAX_WIDTH
+}
+
+/// Returns the indices of the atoms outside of generic argument lists.
+fn outside_generics(atoms: &[Atom]) -> Vec<usize> {
+    let mut generics = 0;
+    let mut indices = Vec::new();
+    for (i, atom) in atoms.iter().enumerate() {
+        if atom.is_op("<") && i > 0 && opens_generics(atoms.get(i - 1)) {
+            generics += 1;
+        } else if atom.is_op(">") && generics > 0 {
+            generics -= 1;
+        } else if generics == 0 {
+            indices.push(i);
+        }
+    }
+    indices
+}
+
+/// Returns the indices of the `op`s in `atoms`, outside of generic argument lists.
+fn op_indices(atoms: &[Atom], op: &str) -> Vec<usize> {
+    outside_generics(atoms)
+        .into_iter()
+        .filter(|&i| atoms[i].is_op(op))
+        .collect()
+}
+
+/// Returns the indices of the `op`s in `atoms` that are binary operators.
+fn binary_indices(atoms: &[Atom], op: &str) -> Vec<usize> {
+    op_indices(atoms, op)
+        .into_iter()
+        .filter(|&i| i > 0 && is_operand(atoms.get(i - 1)))
+        .collect()
+}
+
+/// Splits a token sequence before each of the `cuts` indices.
+fn cut(atoms: &[Atom], cuts: impl IntoIterator<Item = usize>) -> Vec<&[Atom]> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for cut in cuts {
+        if cut > start && cut < atoms.len() {
+            parts.push(&atoms[start..cut]);
+            start = cut;
+        }
+    }
+    if start < atoms.len() {
+        parts.push(&atoms[start..]);
+    }
+    parts
+}
+
+/// Splits a token sequence after every top-level `sep`, keeping the separators.
+fn split_after<'a>(atoms: &'a [Atom], sep: &str) -> Vec<&'a [Atom]> {
+    cut(atoms, op_indices(atoms, sep).into_iter().map(|i| i + 1))
+}
+
+/// Whether `atoms` contain a block with statements or match arms, or an `else` branch, which rustfmt
+/// never puts on one line.
+fn must_break(atoms: &[Atom]) -> bool {
+    let has_block = atoms.iter().any(|a| is_group(a, Delimiter::Brace));
+    atoms.iter().any(|atom| match atom {
+        Atom::Word(w) => w == "else" && has_block,
+        Atom::Group(group) => {
+            let inner = contents(group);
+            (group.delimiter() == Delimiter::Brace
+                && inner.iter().any(|a| a.is_op(";") || a.is_op("=>")))
+                || must_break(&inner)
+        }
+        _ => false,
+    })
+}
+
+/// Lays out `atoms` continuing `line`, which ends with `prev`, leaving `reserve` columns after them.
+fn continue_line(line: (usize, String), prev: &Atom, atoms: &[Atom], reserve: usize) -> Lines {
+    let (depth, text) = line;
+    let Some(first) = atoms.first() else {
+        return vec![(depth, text)];
+    };
+    let sep = if Line::after(text.clone(), prev).space_between(prev, first)
+        || glues(&text, prev, &render_atom(first))
+    {
+        " "
+    } else {
+        ""
+    };
+    let prefix = format!("{}{}", text, sep);
+    let mut lines = layout(atoms, depth, width(&prefix) + reserve);
+    lines[0].1.insert_str(0, &prefix);
+    lines
+}
+
+/// Lays out `atoms` at `depth`, `offset` columns into the first line.
+fn layout(atoms: &[Atom], depth: usize, offset: usize) -> Lines {
+    let flat = Line::default().print(atoms);
+    if fits(depth, offset, &flat) && !must_break(atoms) {
+        return vec![(depth, flat)];
+    }
+    if let Some(&arrow) = op_indices(atoms, "=>").first() {
+        return layout_arm(atoms, arrow, depth, offset);
+    }
+    if atoms.iter().any(|a| is_group(a, Delimiter::Brace)) {
+        return layout_blocks(atoms, depth, offset);
+    }
+    if let Some(&eq) = op_indices(atoms, "=").first() {
+        return layout_assignment(atoms, eq, depth, offset);
+    }
+    for op in ["||", "&&"] {
+        let operators = binary_indices(atoms, op);
+        if !operators.is_empty() {
+            return layout_continued(cut(atoms, operators), depth, offset);
+        }
+    }
+    if let Some(lines) = layout_chain(atoms, depth, offset) {
+        return lines;
+    }
+    if let Some(lines) = layout_group(atoms, depth, offset) {
+        return lines;
+    }
+    vec![(depth, flat)]
+}
+
+/// Lays out the first part on the first line and the others on their own lines, indented.
+fn layout_continued(parts: Vec<&[Atom]>, depth: usize, offset: usize) -> Lines {
+    let mut lines = layout(parts[0], depth, offset);
+    for part in &parts[1..] {
+        lines.extend(layout(part, depth + 1, 0));
+    }
+    lines
+}
+
+/// Lays out a match arm or a `macro_rules!` rule, with a long pattern broken before its top-level `|`s,
+/// and a body that breaks on the next lines unless it is a block.
+fn layout_arm(atoms: &[Atom], arrow: usize, depth: usize, offset: usize) -> Lines {
+    let pattern = &atoms[..arrow];
+    let mut lines = match binary_indices(pattern, "|") {
+        alternatives
+            if !alternatives.is_empty()
+                && !fits(depth, offset, &Line::default().print(pattern)) =>
+        {
+            let mut alternatives = cut(pattern, alternatives).into_iter();
+            let mut lines = layout(
+                alternatives.next().expect("A pattern has an alternative"),
+                depth,
+                0,
+            );
+            for alternative in alternatives {
+                let [bar, alternative @ ..] = alternative else {
+                    unreachable!("Alternatives are cut before their `|`")
+                };
+                lines.extend(continue_line(
+                    (depth, render_atom(bar)),
+                    bar,
+                    alternative,
+                    0,
+                ));
+            }
+            lines
+        }
+        _ => layout(pattern, depth, offset),
+    };
+    let (depth, text) = lines.pop().expect("A pattern has a line");
+    let arrow_line = (depth, text + " =>");
+    let body = &atoms[arrow + 1..];
+    if is_group(&body[0], Delimiter::Brace)
+        || (fits(
+            depth,
+            width(&arrow_line.1) + 1,
+            &Line::default().print(body),
+        ) && !must_break(body))
+    {
+        lines.extend(continue_line(arrow_line, &atoms[arrow], body, 0));
+    } else {
+        lines.push(arrow_line);
+        lines.extend(layout(body, depth + 1, 0));
+    }
+    lines
+}
+
+/// Lays out a sequence with blocks, such as an `if` chain, with the contents of each block on its own
+/// lines. The `{` of a control flow head that breaks goes on its own line, and other heads, such as
+/// `let x =` before a `match`, keep it on their last line, with the block indented to match.
+fn layout_blocks(atoms: &[Atom], depth: usize, offset: usize) -> Lines {
+    let mut lines = Lines::new();
+    let mut start = 0;
+    let mut prev = None;
+    for (i, atom) in atoms.iter().enumerate() {
+        let Atom::Group(group) = atom else {
+            continue;
+        };
+        if group.delimiter() != Delimiter::Brace {
+            continue;
+        }
+        let head = &atoms[start..i];
+        let mut head_lines = match prev {
+            Some(prev) => continue_line(lines.pop().expect("A block has a line"), prev, head, 2),
+            None if head.is_empty() => vec![(depth, String::new())],
+            // `let PATTERN = VALUE else {` breaks before the `else`.
+            None if matches!(head, [.., Atom::Word(w)] if w == "else")
+                && !fits(depth, offset + 2, &Line::default().print(head)) =>
+            {
+                let mut lines = layout(&head[..head.len() - 1], depth, offset);
+                lines.push((depth, "else".to_owned()));
+                lines
+            }
+            None => layout(head, depth, offset + 2),
+        };
+        let is_control_flow = matches!(head.first(), Some(Atom::Word(w))
+            if matches!(w.as_str(), "if" | "else" | "for" | "while" | "loop" | "match"));
+        let block_depth = if is_control_flow || prev.is_some() {
+            depth
+        } else {
+            head_lines.last().expect("A head has a line").0
+        };
+        let body = layout_body(group.stream(), block_depth + 1);
+        let open = if body.is_empty() { "{}" } else { "{" };
+        if head_lines.len() > 1 && (is_control_flow || prev.is_some()) {
+            head_lines.push((depth, open.to_owned()));
+        } else {
+            let text = &mut head_lines.last_mut().expect("A head has a line").1;
+            if !text.is_empty() {
+                text.push(' ');
+            }
+            text.push_str(open);
+        }
+        lines.extend(head_lines);
+        if !body.is_empty() {
+            lines.extend(body);
+            lines.push((block_depth, "}".to_owned()));
+        }
+        start = i + 1;
+        prev = Some(atom);
+    }
+    let prev = prev.expect("The sequence has a block");
+    let last = lines.pop().expect("A block has a line");
+    lines.extend(continue_line(last, prev, &atoms[start..], 0));
+    lines
+}
+
+/// Lays out `head = value`, with the value after the `=` where its first line fits there.
+fn layout_assignment(atoms: &[Atom], eq: usize, depth: usize, offset: usize) -> Lines {
+    let head = format!("{} =", Line::default().print(&atoms[..eq]));
+    let value = &atoms[eq + 1..];
+    let value_offset = offset + width(&head) + 1;
+    let mut lines = layout(value, depth, value_offset);
+    if fits(depth, value_offset, &lines[0].1) {
+        lines[0].1 = format!("{} {}", head, lines[0].1);
+        return lines;
+    }
+    let mut lines = vec![(depth, head)];
+    lines.extend(layout(value, depth + 1, 0));
+    lines
+}
+
+/// Lays out a method call chain with every call on its own line, except that a receiver no wider than
+/// the indentation keeps its first call, as in rustfmt. A single call is only moved to its own line
+/// where that avoids breaking it.
+fn layout_chain(atoms: &[Atom], depth: usize, offset: usize) -> Option<Lines> {
+    let calls: Vec<usize> = op_indices(atoms, ".")
+        .into_iter()
+        .filter(|&i| {
+            matches!(atoms.get(i + 1), Some(Atom::Word(_)))
+                && matches!(atoms.get(i + 2), Some(a) if is_group(a, Delimiter::Parenthesis))
+        })
+        .collect();
+    let receiver = Line::default().print(&atoms[..*calls.first()?]);
+    if let [call] = calls.as_slice()
+        && !(fits(depth, offset, &receiver)
+            && fits(depth + 1, 0, &Line::default().print(&atoms[*call..])))
+    {
+        return None;
+    }
+    let calls = if width(&receiver) <= INDENT.len() {
+        &calls[1..]
+    } else {
+        &calls[..]
+    };
+    if calls.is_empty() {
+        return None;
+    }
+    Some(layout_continued(
+        cut(atoms, calls.iter().copied()),
+        depth,
+        offset,
+    ))
+}
+
+/// Breaks the last top-level parenthesized or bracketed group with contents, either around a single
+/// element that breaks itself, or with each element on its own line.
+fn layout_group(atoms: &[Atom], depth: usize, offset: usize) -> Option<Lines> {
+    let i = outside_generics(atoms).into_iter().rfind(|&i| {
+        matches!(&atoms[i], Atom::Group(g) if matches!(g.delimiter(), Delimiter::Parenthesis | Delimiter::Bracket)
+            && !g.stream().is_empty())
+    })?;
+    let Atom::Group(group) = &atoms[i] else {
+        unreachable!()
+    };
+    let (open, close) = match group.delimiter() {
+        Delimiter::Bracket => ("[", "]"),
+        _ => ("(", ")"),
+    };
+    let mut line = Line::default();
+    for atom in &atoms[..i] {
+        line.push(atom, &render_atom(atom));
+    }
+    line.push(&atoms[i], open);
+    let prefix = line.out;
+
+    let inner = contents(group);
+    let elements = split_after(&inner, ",");
+    if let [element] = elements.as_slice() {
+        let mut lines = layout(element, depth, offset + width(&prefix));
+        if lines.len() > 1 {
+            lines[0].1.insert_str(0, &prefix);
+            let (depth, text) = lines.pop().expect("The element has lines");
+            lines.extend(continue_line(
+                (depth, text + close),
+                &atoms[i],
+                &atoms[i + 1..],
+                0,
+            ));
+            return Some(lines);
+        }
+    }
+    let mut lines = vec![(depth, prefix)];
+    for element in elements {
+        lines.extend(layout(element, depth + 1, 0));
+    }
+    lines.extend(continue_line(
+        (depth, close.to_owned()),
+        &atoms[i],
+        &atoms[i + 1..],
+        0,
+    ));
+    Some(lines)
+}
+
+/// Splits the leading attributes off a statement or item.
+fn attributes(mut atoms: &[Atom]) -> (Vec<&[Atom]>, &[Atom]) {
+    let mut attributes = Vec::new();
+    loop {
+        let len = match atoms {
+            [hash, group, ..] if hash.is_op("#") && is_group(group, Delimiter::Bracket) => 2,
+            [hash, bang, group, ..]
+                if hash.is_op("#") && bang.is_op("!") && is_group(group, Delimiter::Bracket) =>
+            {
+                3
+            }
+            _ => return (attributes, atoms),
+        };
+        attributes.push(&atoms[..len]);
+        atoms = &atoms[len..];
+    }
+}
+
+/// Prints an attribute, as a `///` or `//!` comment where it's a doc attribute that one can hold.
+fn attribute_line(attribute: &[Atom]) -> String {
+    let (comment, group) = match attribute {
+        [_, Atom::Group(group)] => ("///", group),
+        [_, _, Atom::Group(group)] => ("//!", group),
+        _ => unreachable!("`attributes` only returns attributes"),
+    };
+    if let [Atom::Word(doc), eq, Atom::Literal(literal)] = contents(group).as_slice()
+        && doc == "doc"
+        && eq.is_op("=")
+        && let Some(text) = doc_text(literal)
+    {
+        return format!("{}{}", comment, text);
+    }
+    Line::default().print(attribute)
+}
+
+/// Returns the value of a string literal, if a line comment can hold it.
+fn doc_text(literal: &str) -> Option<String> {
+    let text = if let Some(raw) = literal.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        raw.get(hashes + 1..raw.len().checked_sub(hashes + 1)?)?
+            .to_owned()
+    } else {
+        unescape(literal.strip_prefix('"')?.strip_suffix('"')?)?
+    };
+    // `////` starts a plain comment.
+    (!text.contains(['\n', '\r']) && !text.starts_with('/')).then_some(text)
+}
+
+/// Resolves the escapes of a string literal's contents.
+fn unescape(escaped: &str) -> Option<String> {
+    let mut text = String::new();
+    let mut chars = escaped.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        text.push(match chars.next()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '\\' => '\\',
+            '"' => '"',
+            '\'' => '\'',
+            'u' => {
+                let rest = chars.as_str();
+                let end = rest.find('}')?;
+                let c = char::from_u32(u32::from_str_radix(rest.get(1..end)?, 16).ok()?)?;
+                chars = rest[end + 1..].chars();
+                c
+            }
+            _ => return None,
+        });
+    }
+    Some(text)
+}
+
+/// Lays out the contents of a block at `depth`, with each statement, match arm or comma-separated element
+/// on its own lines.
+fn layout_body(stream: TokenStream, depth: usize) -> Lines {
+    let atoms = atoms(stream);
+    let parts = if atoms.iter().any(|a| a.is_op(";")) {
+        statements(&atoms)
+    } else if !op_indices(&atoms, "=>").is_empty() {
+        arms(&atoms)
+    } else {
+        split_after(&atoms, ",")
+    };
+    let mut lines = Lines::new();
+    for part in parts {
+        let (attributes, part) = attributes(part);
+        for attribute in attributes {
+            lines.push((depth, attribute_line(attribute)));
+        }
+        if !part.is_empty() {
+            lines.extend(layout(part, depth, 0));
+        }
+    }
+    lines
+}
+
+/// Splits statements after each `;` and after each block that ends a statement, such as a `for` loop's.
+fn statements(atoms: &[Atom]) -> Vec<&[Atom]> {
+    let mut cuts = Vec::new();
+    let mut start = 0;
+    for (i, atom) in atoms.iter().enumerate() {
+        let ends_statement = match atom {
+            Atom::Op(o) => o == ";",
+            Atom::Group(g) if g.delimiter() == Delimiter::Brace => {
+                let (_, statement) = attributes(&atoms[start..i]);
+                let is_control_flow = match statement.first() {
+                    Some(Atom::Word(w)) => {
+                        matches!(
+                            w.as_str(),
+                            "if" | "for" | "while" | "loop" | "match" | "unsafe"
+                        )
+                    }
+                    _ => statement.is_empty(),
+                };
+                is_control_flow
+                    && !matches!(atoms.get(i + 1), Some(Atom::Word(w)) if w == "else")
+                    && !matches!(atoms.get(i + 1), Some(Atom::Op(_)))
+            }
+            _ => false,
+        };
+        if ends_statement {
+            cuts.push(i + 1);
+            start = i + 1;
+        }
+    }
+    cut(atoms, cuts)
+}
+
+/// Splits match arms after each `,` and after each block that is an arm's body.
+fn arms(atoms: &[Atom]) -> Vec<&[Atom]> {
+    let mut cuts = op_indices(atoms, ",");
+    cuts.extend((1..atoms.len()).filter(|&i| {
+        atoms[i - 1].is_op("=>")
+            && is_group(&atoms[i], Delimiter::Brace)
+            && !matches!(atoms.get(i + 1), Some(a) if a.is_op(","))
+    }));
+    cuts.sort_unstable();
+    cut(atoms, cuts.into_iter().map(|i| i + 1))
+}
+
+/// Splits a sequence of items after each `;` and attribute, and after each body that isn't part of an
+/// initializer, such as a function's.
+fn items(atoms: &[Atom]) -> Vec<&[Atom]> {
+    let mut cuts = Vec::new();
+    let mut start = 0;
+    for (i, atom) in atoms.iter().enumerate() {
+        let item = &atoms[start..i];
+        let ends_item = match atom {
+            Atom::Op(o) => o == ";",
+            Atom::Group(g) if g.delimiter() == Delimiter::Bracket => {
+                matches!(item, [hash] | [hash, _] if hash.is_op("#"))
+            }
+            Atom::Group(g) if g.delimiter() == Delimiter::Brace => op_indices(item, "=").is_empty(),
+            _ => false,
+        };
+        if ends_item {
+            cuts.push(i + 1);
+            start = i + 1;
+        }
+    }
+    cut(atoms, cuts)
+}
+
+/// Returns the keyword of an item after its visibility and qualifiers, such as `fn` in `pub const fn`.
+fn item_keyword(mut item: &[Atom]) -> Option<&str> {
+    loop {
+        match item {
+            [Atom::Word(w), group, rest @ ..]
+                if w == "pub" && is_group(group, Delimiter::Parenthesis) =>
+            {
+                item = rest
+            }
+            [Atom::Word(w), Atom::Literal(_), rest @ ..] if w == "extern" => item = rest,
+            [Atom::Word(w), rest @ ..]
+                if matches!(w.as_str(), "pub" | "const" | "unsafe" | "async") =>
+            {
+                item = rest
+            }
+            [Atom::Word(w), ..] => return Some(w),
+            _ => return None,
+        }
+    }
+}
+
+fn print_item(item: &[Atom], depth: usize) -> Lines {
+    if let (attributes, []) = attributes(item) {
+        return attributes
+            .into_iter()
+            .map(|attribute| (depth, attribute_line(attribute)))
+            .collect();
+    }
+
+    // `pub const NAME: TYPE = &[ENTRY, ...];` with one entry per line.
+    let is_table = matches!(item.first(), Some(Atom::Word(w)) if w == "pub")
+        && matches!(item.get(1), Some(Atom::Word(w)) if w == "const")
+        && matches!(item, [.., Atom::Op(amp), Atom::Group(g), Atom::Op(semi)]
+            if amp == "&" && semi == ";" && g.delimiter() == Delimiter::Bracket);
+    if is_table {
+        let [head @ .., Atom::Group(entries), semi] = item else {
+            unreachable!()
+        };
+        let entries = contents(entries);
+        let mut lines = vec![(depth, Line::default().print(head))];
+        if entries.is_empty() {
+            lines[0].1.push_str("[]");
+        } else {
+            lines[0].1.push('[');
+            for entry in split_after(&entries, ",") {
+                lines.extend(layout(entry, depth + 1, 0));
+            }
+            lines.push((depth, "]".to_owned()));
+        }
+        let last = lines.last_mut().expect("A table has a line");
+        last.1.push_str(&render_atom(semi));
+        return lines;
+    }
+
+    // `fn NAME(...) -> TYPE { ... }` and other items with a body, with the body on its own lines. The items
+    // of a module, `impl` or trait are laid out as at the top level, and other bodies as blocks.
+    if let [head @ .., Atom::Group(body)] = item
+        && body.delimiter() == Delimiter::Brace
+        && op_indices(head, "=").is_empty()
+    {
+        let inner = match item_keyword(head) {
+            Some("mod" | "impl" | "trait") => print_items(&contents(body), depth + 1),
+            _ => layout_body(body.stream(), depth + 1),
+        };
+        let mut lines = layout(head, depth, 2);
+        let last = lines.last_mut().expect("A head has a line");
+        if inner.is_empty() {
+            last.1.push_str(" {}");
+        } else {
+            last.1.push_str(" {");
+            lines.extend(inner);
+            lines.push((depth, "}".to_owned()));
+        }
+        return lines;
+    }
+
+    layout(item, depth, 0)
+}
+
+fn print_items(atoms: &[Atom], depth: usize) -> Lines {
+    items(atoms)
+        .into_iter()
+        .flat_map(|item| print_item(item, depth))
+        .collect()
+}
+
+/// Lays out generated Rust code, such as the output of `generate_rs`, without depending on rustfmt.
+///
+/// # Panics
+///
+/// Panics if `code` can't be tokenized as Rust.
+pub fn pretty_print(code: &str) -> String {
+    let stream: TokenStream = code.parse().expect("The generated code is valid Rust");
+    let mut out = String::new();
+    for (depth, text) in print_items(&atoms(stream), 0) {
+        for _ in 0..depth {
+            out.push_str(INDENT);
+        }
+        out.push_str(&text);
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,166 @@
+//! Statistics of the operands and operators the rules of CLDR data use, and of the size of each locale's rules.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use cldr_pluralrules_parser::ast::{Operand, Relation};
+use cldr_pluralrules_parser::visit::{Visit, walk_relation};
+use proc_macro2::{TokenStream, TokenTree};
+use unic_langid::LanguageIdentifier;
+
+use crate::Dataset;
+use crate::parser::gen_pr::{OperandAccessors, gen_pr};
+
+/// The operands, in the order `UsageStats` lists them.
+const OPERANDS: [Operand; 8] = [
+    Operand::N,
+    Operand::I,
+    Operand::V,
+    Operand::W,
+    Operand::F,
+    Operand::T,
+    Operand::C,
+    Operand::E,
+];
+
+/// The number of locales whose rules, of any plural rule type, use each operand and operator, from `usage_stats`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageStats {
+    /// The number of locales of the data.
+    pub locales: usize,
+    /// The number of locales using each operand, including those no locale uses.
+    pub operands: BTreeMap<Operand, usize>,
+    /// The number of locales using each operator used by any locale, keyed by its canonical syntax,
+    /// so `in` counts as `=`.
+    pub operators: BTreeMap<String, usize>,
+}
+
+/// Counts the locales of `dataset` whose rules use each operand and operator.
+pub fn usage_stats(dataset: &Dataset) -> UsageStats {
+    let mut stats = UsageStats {
+        locales: dataset.len(),
+        operands: OPERANDS.into_iter().map(|operand| (operand, 0)).collect(),
+        operators: BTreeMap::new(),
+    };
+    for types in dataset.values() {
+        let rules = || types.values().flatten().map(|(_, rule)| rule);
+        let operands: BTreeSet<_> = rules().flat_map(|rule| rule.operands_used()).collect();
+        let operators: BTreeSet<_> = rules()
+            .flat_map(|rule| rule.operators_used())
+            .map(|operator| operator.to_string())
+            .collect();
+        for operand in operands {
+            *stats.operands.entry(operand).or_default() += 1;
+        }
+        for operator in operators {
+            *stats.operators.entry(operator).or_default() += 1;
+        }
+    }
+    stats
+}
+
+/// Writes the counts as two tables, one row per operand and per operator.
+impl fmt::Display for UsageStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} locales", self.locales)?;
+        writeln!(f, "{:<12}locales", "operand")?;
+        for (operand, count) in &self.operands {
+            writeln!(f, "{:<12}{}", operand.to_string(), count)?;
+        }
+        writeln!(f, "{:<12}locales", "operator")?;
+        for (operator, count) in &self.operators {
+            writeln!(f, "{:<12}{}", operator, count)?;
+        }
+        Ok(())
+    }
+}
+
+/// The size of the rules of a locale, of all plural rule types, from `size_report`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocaleSize {
+    pub lang: LanguageIdentifier,
+    /// The number of relations of the rules, such as the 2 of `i = 1 and v = 0`.
+    pub relations: usize,
+    /// The operands the rules read.
+    pub operands: BTreeSet<Operand>,
+    /// The number of tokens of the conditions generated for the rules, as an estimate of their share of the
+    /// generated code, which is otherwise about the same for every locale.
+    pub tokens: usize,
+}
+
+/// The size of the rules of each locale, from `size_report`, for choosing the locales to generate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SizeReport(pub Vec<LocaleSize>);
+
+/// Counts the visited relations.
+struct RelationCount(usize);
+
+impl Visit for RelationCount {
+    fn visit_relation(&mut self, relation: &Relation) {
+        self.0 += 1;
+        walk_relation(self, relation);
+    }
+}
+
+/// Counts the tokens of `stream`, a group counting as one token along with those it delimits.
+fn token_count(stream: TokenStream) -> usize {
+    stream
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Group(group) => 1 + token_count(group.stream()),
+            _ => 1,
+        })
+        .sum()
+}
+
+/// Estimates the size of the rules of each locale of `dataset`, in the order of the locales.
+///
+/// The conditions are generated as with the default options, reading each operand as a field.
+pub fn size_report(dataset: &Dataset) -> SizeReport {
+    let accessors = OperandAccessors::new(&BTreeMap::new()).expect("No accessor is overridden");
+    let sizes = dataset
+        .iter()
+        .map(|(lang, types)| {
+            let mut size = LocaleSize {
+                lang: lang.clone(),
+                relations: 0,
+                operands: BTreeSet::new(),
+                tokens: 0,
+            };
+            for (_, rule) in types.values().flatten() {
+                let mut relations = RelationCount(0);
+                relations.visit_rule(rule);
+                size.relations += relations.0;
+                size.operands.extend(rule.operands_used());
+                if !rule.condition.0.is_empty() {
+                    size.tokens += token_count(gen_pr(rule.condition.clone(), &accessors));
+                }
+            }
+            size
+        })
+        .collect();
+    SizeReport(sizes)
+}
+
+/// Writes a table with one row per locale.
+impl fmt::Display for SizeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<12}{:<12}{:<12}operands",
+            "locale", "relations", "tokens"
+        )?;
+        for size in &self.0 {
+            let operands: Vec<String> = size.operands.iter().map(ToString::to_string).collect();
+            writeln!(
+                f,
+                "{:<12}{:<12}{:<12}{}",
+                size.lang.to_string(),
+                size.relations,
+                size.tokens,
+                operands.join(" ")
+            )?;
+        }
+        Ok(())
+    }
+}
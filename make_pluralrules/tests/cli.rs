@@ -0,0 +1,674 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Runs the generator with `args`, piping `stdin` into it, and returns the written output.
+fn run_with_stdin(name: &str, args: &[&str], stdin: &str) -> String {
+    let output_path: PathBuf = std::env::temp_dir().join(format!(
+        "make_pluralrules_{}_{}.rs",
+        name,
+        std::process::id()
+    ));
+    let mut child = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args(args)
+        .arg("-o")
+        .arg(&output_path)
+        .arg("-u")
+        .stdin(Stdio::piped())
+        .spawn()
+        .expect("The generator starts");
+    child
+        .stdin
+        .take()
+        .expect("Stdin is piped")
+        .write_all(stdin.as_bytes())
+        .expect("Writing to stdin succeeded");
+    assert!(child.wait().expect("The generator runs").success());
+
+    let output = fs::read_to_string(&output_path).expect("Could not read output rs");
+    fs::remove_file(&output_path).expect("Could not remove output rs");
+    output
+}
+
+#[test]
+fn stdin_input() {
+    let input_json = fs::read_to_string("./tests/fixtures/cldr_pluralrules_within_test.json")
+        .expect("Could not read input json");
+    let output_rs = fs::read_to_string("./tests/fixtures/cldr_pluralrules_within_test.rs")
+        .expect("Could not read output rs");
+
+    assert_eq!(run_with_stdin("implicit", &[], &input_json), output_rs);
+    assert_eq!(
+        run_with_stdin("explicit", &["-i", "-"], &input_json),
+        output_rs
+    );
+}
+
+#[test]
+fn directory_input() {
+    let cardinal_json = fs::read_to_string("./tests/fixtures/inputs/cardinals.json")
+        .expect("Could not read input json");
+    let ordinal_json = fs::read_to_string("./tests/fixtures/inputs/nested/ordinals.json")
+        .expect("Could not read input json");
+    let expected = make_pluralrules::generate_rs(&[cardinal_json, ordinal_json])
+        .expect("Generating the rules succeeded");
+
+    // `currencies.json` has no plural rules and is skipped.
+    assert_eq!(
+        run_with_stdin("directory", &["-i", "./tests/fixtures/inputs"], ""),
+        expected
+    );
+}
+
+#[test]
+fn stdin_and_file_input() {
+    let cardinal_json = fs::read_to_string("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+        .expect("Could not read input json");
+    let output_rs = fs::read_to_string("./tests/fixtures/cldr_pluralrules_33.rs")
+        .expect("Could not read output rs");
+
+    let args = [
+        "-i",
+        "-",
+        "-i",
+        "./tests/fixtures/cldr_pluralrules_ordinals_33.json",
+    ];
+    assert_eq!(run_with_stdin("mixed", &args, &cardinal_json), output_rs);
+}
+
+#[test]
+fn invalid_json_input() {
+    let mut args = vec!["-i", "./tests/fixtures/inputs/README.txt", "-o", "-"];
+    // With the `icu` feature, `*.txt` inputs are read as ICU text unless the format is given.
+    if cfg!(feature = "icu") {
+        args.extend(["--format", "json"]);
+    }
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args(args)
+        .output()
+        .expect("The generator runs");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).expect("The error is UTF-8");
+    assert!(
+        stderr.starts_with("Error: `./tests/fixtures/inputs/README.txt` is not valid CLDR JSON: "),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn unknown_plural_type() {
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_unknown_type_test.json",
+            "-o",
+            "-",
+        ])
+        .output()
+        .expect("The generator runs");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).expect("The error is UTF-8");
+    assert_eq!(stderr, "Error: unknown plural rule type `cardinals`\n");
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn gzip_input() {
+    let generate = |input: &str| {
+        let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+            .args(["-i", input, "-o", "-"])
+            .output()
+            .expect("The generator runs");
+        assert!(output.status.success(), "{:?}", output);
+        output.stdout
+    };
+
+    assert_eq!(
+        generate("./tests/fixtures/gzip/cardinals.json.gz"),
+        generate("./tests/fixtures/inputs/cardinals.json")
+    );
+}
+
+#[cfg(not(feature = "gzip"))]
+#[test]
+fn gzip_input() {
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args(["-i", "./tests/fixtures/gzip/cardinals.json.gz", "-o", "-"])
+        .output()
+        .expect("The generator runs");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).expect("The error is UTF-8");
+    assert_eq!(
+        stderr.trim_end(),
+        "Error: could not read `./tests/fixtures/gzip/cardinals.json.gz`: \
+         the input is gzip-compressed, decompress it first or build with the `gzip` feature"
+    );
+}
+
+#[test]
+fn stdout_output() {
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_cardinals_33.json",
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_ordinals_33.json",
+            "-o",
+            "-",
+        ])
+        .output()
+        .expect("The generator runs");
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).expect("The output is UTF-8");
+    assert!(stdout.contains("PRS_CARDINAL"));
+    assert_eq!(
+        stdout,
+        make_pluralrules::pretty_print(
+            &fs::read_to_string("./tests/fixtures/cldr_pluralrules_33.rs")
+                .expect("Could not read output rs")
+        )
+    );
+}
+
+#[test]
+fn list_locales() {
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_modules_test.json",
+            "--list-locales",
+        ])
+        .output()
+        .expect("The generator runs");
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).expect("The output is UTF-8");
+    assert_eq!(stdout, "en cardinal\nen ordinal\npl cardinal\npl ordinal\n");
+}
+
+#[test]
+fn equivalent_locales() {
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_cardinals_33.json",
+            "--equivalent-locales",
+        ])
+        .output()
+        .expect("The generator runs");
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).expect("The output is UTF-8");
+    let class_of = |lang: &str| {
+        stdout
+            .lines()
+            .find(|line| line.split(' ').skip(1).any(|member| member == lang))
+            .unwrap_or_else(|| panic!("`{}` is in a class", lang))
+    };
+    assert!(class_of("en").starts_with("cardinal "));
+    // English and German cardinal rules are identical, unlike French ones.
+    assert_eq!(class_of("en"), class_of("de"));
+    assert_ne!(class_of("en"), class_of("fr"));
+}
+
+#[test]
+fn usage_stats() {
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_modules_test.json",
+            "-o",
+            "-",
+            "--stats",
+        ])
+        .output()
+        .expect("The generator runs");
+    assert!(output.status.success());
+    assert!(!output.stdout.is_empty());
+
+    let stderr = String::from_utf8(output.stderr).expect("The stats are UTF-8");
+    assert_eq!(
+        stderr,
+        "\
+2 locales
+operand     locales
+n           1
+i           2
+v           2
+w           0
+f           0
+t           0
+c           0
+e           0
+operator    locales
+!=          2
+=           2
+"
+    );
+}
+
+#[test]
+fn size_report() {
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_modules_test.json",
+            "-o",
+            "-",
+            "--size-report",
+        ])
+        .output()
+        .expect("The generator runs");
+    assert!(output.status.success());
+    assert!(!output.stdout.is_empty());
+
+    let stderr = String::from_utf8(output.stderr).expect("The report is UTF-8");
+    assert_eq!(
+        stderr,
+        "\
+locale      relations   tokens      operands
+en          8           126         n i v
+pl          12          146         i v
+"
+    );
+}
+
+fn generated_cldr_version(extra_args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_cardinals_33.json",
+            "-o",
+            "-",
+        ])
+        .args(extra_args)
+        .output()
+        .expect("The generator runs");
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).expect("The output is UTF-8");
+    stdout
+        .lines()
+        .find(|line| line.starts_with("pub static CLDR_VERSION"))
+        .expect("The output has a CLDR_VERSION")
+        .to_owned()
+}
+
+#[test]
+fn cldr_version_override() {
+    assert_eq!(
+        generated_cldr_version(&["--cldr-version", "44.1"]),
+        r#"pub static CLDR_VERSION: &str = "44.1";"#
+    );
+    assert_eq!(
+        generated_cldr_version(&[]),
+        r#"pub static CLDR_VERSION: &str = "33";"#
+    );
+}
+
+#[test]
+fn cldr_version_mismatch() {
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_cardinals_33.json",
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_versions_44.json",
+            "-o",
+            "-",
+        ])
+        .output()
+        .expect("The generator runs");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).expect("The error is UTF-8");
+    assert!(
+        stderr.contains(
+            "`./tests/fixtures/cldr_pluralrules_versions_44.json` is of CLDR version `44`"
+        ),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn only_locales() {
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_cardinals_33.json",
+            "-o",
+            "-",
+            "--only",
+            "en",
+            "--only",
+            "fr",
+            "--only",
+            "xx",
+        ])
+        .output()
+        .expect("The generator runs");
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).expect("The errors are UTF-8");
+    assert!(
+        stderr.contains("Warning: `--only xx` matches no locale of the inputs"),
+        "{}",
+        stderr
+    );
+
+    let stdout = String::from_utf8(output.stdout).expect("The output is UTF-8");
+    let table: Vec<&str> = stdout
+        .lines()
+        .skip_while(|line| !line.starts_with("pub const PRS_CARDINAL:"))
+        .skip(1)
+        .take_while(|line| *line != "];")
+        .collect();
+    let language = |lang: &str| {
+        let raw: Option<u64> = lang
+            .parse::<unic_langid::subtags::Language>()
+            .expect("The language is valid")
+            .into();
+        format!(
+            "(langid!(subtags::Language::from_raw_unchecked({}u64), None, None, None), ",
+            raw.unwrap()
+        )
+    };
+    assert_eq!(table.len(), 2, "{:?}", table);
+    assert!(table[0].trim_start().starts_with(&language("en")));
+    assert!(table[1].trim_start().starts_with(&language("fr")));
+}
+
+#[test]
+fn check_rules() {
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "--check",
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_check_test.json",
+        ])
+        .output()
+        .expect("The generator runs");
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+
+    let stderr = String::from_utf8(output.stderr).expect("The errors are UTF-8");
+    let path = "`./tests/fixtures/cldr_pluralrules_check_test.json`";
+    assert_eq!(
+        stderr.lines().collect::<Vec<_>>(),
+        [
+            format!(
+                "Error: {}: invalid `pluralRule-count-few` rule of `xx`: expected a relation at offset 18",
+                path
+            ),
+            format!(
+                "Warning: {}: `pluralRule-count-many` rule of `xx` has no condition",
+                path
+            ),
+            format!(
+                "Warning: {}: `pluralRule-count-other` rule of `xx` has the inverted sample range `16~5`",
+                path
+            ),
+        ]
+    );
+
+    // Valid data passes the check, without an output being written.
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "--check",
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_cardinals_33.json",
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_ordinals_33.json",
+        ])
+        .output()
+        .expect("The generator runs");
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty() && output.stderr.is_empty());
+}
+
+#[test]
+fn misfiled_samples() {
+    let path = "`./tests/fixtures/cldr_pluralrules_misfiled_test.json`";
+    let expected = [
+        format!(
+            "Warning: {}: `pluralRule-count-one` rule of `zz` lists the sample `2`, which selects `other`",
+            path
+        ),
+        format!(
+            "Warning: {}: `pluralRule-count-few` rule of `zz` lists the sample `5`, which selects `other`",
+            path
+        ),
+    ];
+
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "--check",
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_misfiled_test.json",
+        ])
+        .output()
+        .expect("The generator runs");
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).expect("The warnings are UTF-8");
+    assert_eq!(stderr.lines().collect::<Vec<_>>(), expected);
+
+    // The warnings are also reported while generating, which still succeeds.
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "--warn-samples",
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_misfiled_test.json",
+            "-o",
+            "-",
+        ])
+        .output()
+        .expect("The generator runs");
+    assert!(output.status.success());
+    assert!(!output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).expect("The warnings are UTF-8");
+    assert_eq!(stderr.lines().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn manifest_output() {
+    let manifest_path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("manifest_output.json");
+    let _ = fs::remove_file(&manifest_path);
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_cardinals_33.json",
+            "-o",
+            "-",
+            "--only",
+            "fr",
+            "--manifest",
+        ])
+        .arg(&manifest_path)
+        .output()
+        .expect("The generator runs");
+    assert!(output.status.success());
+    assert!(
+        String::from_utf8(output.stdout)
+            .expect("The output is UTF-8")
+            .contains("PRS_CARDINAL")
+    );
+
+    let manifest = fs::read_to_string(&manifest_path).expect("The manifest was written");
+    let manifest: serde_json::Value =
+        serde_json::from_str(&manifest).expect("The manifest is JSON");
+    assert_eq!(
+        manifest,
+        serde_json::json!({ "cldrVersion": "33", "cardinal": ["fr"] })
+    );
+}
+
+#[test]
+fn diff_rules() {
+    let diff = |extra_args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+            .args([
+                "diff",
+                "--old",
+                "./tests/fixtures/diff/old.json",
+                "--new",
+                "./tests/fixtures/diff/new.json",
+            ])
+            .args(extra_args)
+            .output()
+            .expect("The generator runs");
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).expect("The output is UTF-8")
+    };
+
+    assert_eq!(
+        diff(&[]).lines().collect::<Vec<_>>(),
+        [
+            "changed cardinal `one` of `fr`: `i = 0,1` -> `i = 0..1`",
+            "added cardinal `many` of `fr`: `e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5`",
+            "2 rules changed in 1 locale",
+        ]
+    );
+
+    let changes: serde_json::Value =
+        serde_json::from_str(&diff(&["--json"])).expect("The output is JSON");
+    assert_eq!(
+        changes[0],
+        serde_json::json!({
+            "locale": "fr",
+            "type": "cardinal",
+            "category": "one",
+            "change": "changed",
+            "old": "i = 0,1",
+            "new": "i = 0..1",
+        })
+    );
+    assert_eq!(changes.as_array().map(Vec::len), Some(2));
+}
+
+#[test]
+fn emit_operands() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("emit_operands");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("Could not create the output dir");
+    let status = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_cardinals_33.json",
+            "--emit-operands",
+            "-o",
+        ])
+        .arg(dir.join("rules.rs"))
+        .status()
+        .expect("The generator runs");
+    assert!(status.success());
+    assert!(dir.join("rules.rs").exists());
+    let operands = fs::read_to_string(dir.join("operands.rs")).expect("The operands were written");
+    assert_eq!(
+        operands,
+        make_pluralrules::pretty_print(&make_pluralrules::generate_operands_rs())
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_cardinals_33.json",
+            "--emit-operands",
+            "-o",
+            "-",
+        ])
+        .output()
+        .expect("The generator runs");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stderr).expect("The error is UTF-8"),
+        "Error: `--emit-operands` requires an output file or directory\n"
+    );
+}
+
+#[test]
+fn module_per_version() {
+    let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args([
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_versions_44.json",
+            "-i",
+            "./tests/fixtures/cldr_pluralrules_modules_test.json",
+            "-o",
+            "-",
+            "--module-per-version",
+        ])
+        .output()
+        .expect("The generator runs");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).expect("The output is UTF-8");
+    let lines: Vec<_> = stdout.lines().collect();
+    assert_eq!(lines[0], "pub mod v33 {");
+    assert!(lines.contains(&"pub mod v44 {"));
+    assert!(lines.contains(&"    pub static CLDR_VERSION: &str = \"44\";"));
+}
+
+#[test]
+fn diff_generated() {
+    let generate = |output: &Path, extra_args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+            .args([
+                "-i",
+                "./tests/fixtures/cldr_pluralrules_within_test.json",
+                "-o",
+            ])
+            .arg(output)
+            .args(extra_args)
+            .output()
+            .expect("The generator runs")
+    };
+
+    // The committed fixture is up to date, and left as it is.
+    let fixture = Path::new("./tests/fixtures/cldr_pluralrules_within_test.rs");
+    let output = generate(fixture, &["-u", "--diff-generated"]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+
+    let stale_path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("diff_generated.rs");
+    assert!(generate(&stale_path, &[]).status.success());
+    let fresh = fs::read_to_string(&stale_path).expect("The output was written");
+    let stale = fresh.replace(
+        "pub static CLDR_VERSION: &str = \"0\";",
+        "pub static CLDR_VERSION: &str = \"stale\";",
+    );
+    assert_ne!(stale, fresh);
+    fs::write(&stale_path, &stale).expect("Could not write the stale output");
+
+    let output = generate(&stale_path, &["--diff-generated"]);
+    assert_eq!(output.status.code(), Some(1));
+    let diff = String::from_utf8(output.stdout).expect("The diff is UTF-8");
+    let lines: Vec<_> = diff.lines().collect();
+    assert!(lines[2].starts_with("@@ -"));
+    assert_eq!(
+        &lines[3..],
+        [
+            "-pub static CLDR_VERSION: &str = \"stale\";",
+            "+pub static CLDR_VERSION: &str = \"0\";",
+        ]
+    );
+    assert_eq!(
+        String::from_utf8(output.stderr).expect("The error is UTF-8"),
+        format!("Error: `{}` is out of date\n", stale_path.display())
+    );
+    // The file is not overwritten.
+    assert_eq!(
+        fs::read_to_string(&stale_path).expect("The output exists"),
+        stale
+    );
+
+    let output = generate(Path::new("-"), &["--diff-generated"]);
+    assert_eq!(output.status.code(), Some(1));
+}
@@ -0,0 +1 @@
+# ! [allow (unused_variables , unused_parens , unused_braces , dead_code)] # ! [allow (clippy :: float_cmp)] # ! [allow (clippy :: unreadable_literal , clippy :: type_complexity)] # ! [allow (clippy :: nonminimal_bool , clippy :: double_parens , clippy :: manual_is_multiple_of)] use super :: operands :: PluralOperands ; use super :: PluralRuleType ; use super :: PluralCategory ; use unic_langid :: LanguageIdentifier ; use unic_langid :: subtags ; pub type PluralRule = fn (& PluralOperands) -> PluralCategory ; pub static CLDR_VERSION : & str = "33" ; macro_rules ! langid { ($ lang : expr , $ script : expr , $ region : expr , $ variants : expr) => { { unsafe { LanguageIdentifier :: from_raw_parts_unchecked ($ lang , $ script , $ region , $ variants ,) } } } ; } # [doc = " The cardinal rules of `af`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_af_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ak`."] # [doc = ""] # [doc = " - one: `n = 0..1`"] pub fn select_ak_cardinal (po : & PluralOperands) -> PluralCategory { if (((0 ..= 1) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `am`."] # [doc = ""] # [doc = " - one: `i = 0 or n = 1`"] pub const fn select_am_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 0) || (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ar`."] # [doc = ""] # [doc = " - few: `n % 100 = 3..10`"] # [doc = " - many: `n % 100 = 11..99`"] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] # [doc = " - zero: `n = 0`"] pub fn select_ar_cardinal (po : & PluralOperands) -> PluralCategory { if (((3 ..= 10) . contains (& (po . i % 100)) && po . f == 0)) { PluralCategory :: FEW } else if (((11 ..= 99) . contains (& (po . i % 100)) && po . f == 0)) { PluralCategory :: MANY } else if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else if (po . n == 0.0) { PluralCategory :: ZERO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ars`."] # [doc = ""] # [doc = " - few: `n % 100 = 3..10`"] # [doc = " - many: `n % 100 = 11..99`"] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] # [doc = " - zero: `n = 0`"] pub fn select_ars_cardinal (po : & PluralOperands) -> PluralCategory { if (((3 ..= 10) . contains (& (po . i % 100)) && po . f == 0)) { PluralCategory :: FEW } else if (((11 ..= 99) . contains (& (po . i % 100)) && po . f == 0)) { PluralCategory :: MANY } else if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else if (po . n == 0.0) { PluralCategory :: ZERO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `as`."] # [doc = ""] # [doc = " - one: `i = 0 or n = 1`"] pub const fn select_as_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 0) || (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `asa`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_asa_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ast`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_ast_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `az`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_az_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `be`."] # [doc = ""] # [doc = " - few: `n % 10 = 2..4 and n % 100 != 12..14`"] # [doc = " - many: `n % 10 = 0 or n % 10 = 5..9 or n % 100 = 11..14`"] # [doc = " - one: `n % 10 = 1 and n % 100 != 11`"] pub fn select_be_cardinal (po : & PluralOperands) -> PluralCategory { if (((2 ..= 4) . contains (& (po . i % 10)) && po . f == 0) && (! (12 ..= 14) . contains (& (po . i % 100)) || po . f != 0)) { PluralCategory :: FEW } else if ((po . i % 10 == 0 && po . f == 0)) || (((5 ..= 9) . contains (& (po . i % 10)) && po . f == 0)) || (((11 ..= 14) . contains (& (po . i % 100)) && po . f == 0)) { PluralCategory :: MANY } else if ((po . i % 10 == 1 && po . f == 0) && (po . i % 100 != 11 || po . f != 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `bem`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_bem_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `bez`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_bez_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `bg`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_bg_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `bh`."] # [doc = ""] # [doc = " - one: `n = 0..1`"] pub fn select_bh_cardinal (po : & PluralOperands) -> PluralCategory { if (((0 ..= 1) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `bm`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_bm_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `bn`."] # [doc = ""] # [doc = " - one: `i = 0 or n = 1`"] pub const fn select_bn_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 0) || (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `bo`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_bo_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `br`."] # [doc = ""] # [doc = " - few: `n % 10 = 3..4,9 and n % 100 != 10..19,70..79,90..99`"] # [doc = " - many: `n != 0 and n % 1000000 = 0`"] # [doc = " - one: `n % 10 = 1 and n % 100 != 11,71,91`"] # [doc = " - two: `n % 10 = 2 and n % 100 != 12,72,92`"] pub fn select_br_cardinal (po : & PluralOperands) -> PluralCategory { if (((po . i % 10 == 9 && po . f == 0) || ((3 ..= 4) . contains (& (po . i % 10)) && po . f == 0)) && (! (10 ..= 19) . contains (& (po . i % 100)) || po . f != 0) && (! (70 ..= 79) . contains (& (po . i % 100)) || po . f != 0) && (! (90 ..= 99) . contains (& (po . i % 100)) || po . f != 0)) { PluralCategory :: FEW } else if (po . n != 0.0 && (po . i % 1000000 == 0 && po . f == 0)) { PluralCategory :: MANY } else if ((po . i % 10 == 1 && po . f == 0) && (po . i % 100 != 11 || po . f != 0) && (po . i % 100 != 71 || po . f != 0) && (po . i % 100 != 91 || po . f != 0)) { PluralCategory :: ONE } else if ((po . i % 10 == 2 && po . f == 0) && (po . i % 100 != 12 || po . f != 0) && (po . i % 100 != 72 || po . f != 0) && (po . i % 100 != 92 || po . f != 0)) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `brx`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_brx_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `bs`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14`"] # [doc = " - one: `v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11`"] pub fn select_bs_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (2 ..= 4) . contains (& (po . i % 10)) && ! (12 ..= 14) . contains (& (po . i % 100))) || ((2 ..= 4) . contains (& (po . f % 10)) && ! (12 ..= 14) . contains (& (po . f % 100))) { PluralCategory :: FEW } else if (po . v == 0 && po . i % 10 == 1 && po . i % 100 != 11) || (po . f % 10 == 1 && po . f % 100 != 11) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ca`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_ca_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ce`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ce_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `cgg`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_cgg_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `chr`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_chr_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ckb`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ckb_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `cs`."] # [doc = ""] # [doc = " - few: `i = 2..4 and v = 0`"] # [doc = " - many: `v != 0`"] # [doc = " - one: `i = 1 and v = 0`"] pub fn select_cs_cardinal (po : & PluralOperands) -> PluralCategory { if ((2 ..= 4) . contains (& (po . i)) && po . v == 0) { PluralCategory :: FEW } else if (po . v != 0) { PluralCategory :: MANY } else if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `cy`."] # [doc = ""] # [doc = " - few: `n = 3`"] # [doc = " - many: `n = 6`"] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] # [doc = " - zero: `n = 0`"] pub const fn select_cy_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 3.0) { PluralCategory :: FEW } else if (po . n == 6.0) { PluralCategory :: MANY } else if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else if (po . n == 0.0) { PluralCategory :: ZERO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `da`."] # [doc = ""] # [doc = " - one: `n = 1 or t != 0 and i = 0,1`"] pub const fn select_da_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) || (po . t != 0 && (po . i == 0 || po . i == 1)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `de`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_de_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `dsb`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 100 = 3..4 or f % 100 = 3..4`"] # [doc = " - one: `v = 0 and i % 100 = 1 or f % 100 = 1`"] # [doc = " - two: `v = 0 and i % 100 = 2 or f % 100 = 2`"] pub fn select_dsb_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (3 ..= 4) . contains (& (po . i % 100))) || ((3 ..= 4) . contains (& (po . f % 100))) { PluralCategory :: FEW } else if (po . v == 0 && po . i % 100 == 1) || (po . f % 100 == 1) { PluralCategory :: ONE } else if (po . v == 0 && po . i % 100 == 2) || (po . f % 100 == 2) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `dv`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_dv_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `dz`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_dz_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ee`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ee_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `el`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_el_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `en`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_en_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `eo`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_eo_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `es`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_es_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `et`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_et_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `eu`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_eu_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `fa`."] # [doc = ""] # [doc = " - one: `i = 0 or n = 1`"] pub const fn select_fa_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 0) || (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ff`."] # [doc = ""] # [doc = " - one: `i = 0,1`"] pub const fn select_ff_cardinal (po : & PluralOperands) -> PluralCategory { if ((po . i == 0 || po . i == 1)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `fi`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_fi_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `fil`."] # [doc = ""] # [doc = " - one: `v = 0 and i = 1,2,3 or v = 0 and i % 10 != 4,6,9 or v != 0 and f % 10 != 4,6,9`"] pub const fn select_fil_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (po . i == 1 || po . i == 2 || po . i == 3)) || (po . v == 0 && po . i % 10 != 4 && po . i % 10 != 6 && po . i % 10 != 9) || (po . v != 0 && po . f % 10 != 4 && po . f % 10 != 6 && po . f % 10 != 9) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `fo`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_fo_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `fr`."] # [doc = ""] # [doc = " - one: `i = 0,1`"] pub const fn select_fr_cardinal (po : & PluralOperands) -> PluralCategory { if ((po . i == 0 || po . i == 1)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `fur`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_fur_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `fy`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_fy_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ga`."] # [doc = ""] # [doc = " - few: `n = 3..6`"] # [doc = " - many: `n = 7..10`"] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] pub fn select_ga_cardinal (po : & PluralOperands) -> PluralCategory { if (((3 ..= 6) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: FEW } else if (((7 ..= 10) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: MANY } else if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `gd`."] # [doc = ""] # [doc = " - few: `n = 3..10,13..19`"] # [doc = " - one: `n = 1,11`"] # [doc = " - two: `n = 2,12`"] pub fn select_gd_cardinal (po : & PluralOperands) -> PluralCategory { if ((((3 ..= 10) . contains (& (po . i)) && po . f == 0) || ((13 ..= 19) . contains (& (po . i)) && po . f == 0))) { PluralCategory :: FEW } else if ((po . n == 1.0 || po . n == 11.0)) { PluralCategory :: ONE } else if ((po . n == 2.0 || po . n == 12.0)) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `gl`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_gl_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `gsw`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_gsw_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `gu`."] # [doc = ""] # [doc = " - one: `i = 0 or n = 1`"] pub const fn select_gu_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 0) || (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `guw`."] # [doc = ""] # [doc = " - one: `n = 0..1`"] pub fn select_guw_cardinal (po : & PluralOperands) -> PluralCategory { if (((0 ..= 1) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `gv`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 100 = 0,20,40,60,80`"] # [doc = " - many: `v != 0`"] # [doc = " - one: `v = 0 and i % 10 = 1`"] # [doc = " - two: `v = 0 and i % 10 = 2`"] pub const fn select_gv_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (po . i % 100 == 0 || po . i % 100 == 20 || po . i % 100 == 40 || po . i % 100 == 60 || po . i % 100 == 80)) { PluralCategory :: FEW } else if (po . v != 0) { PluralCategory :: MANY } else if (po . v == 0 && po . i % 10 == 1) { PluralCategory :: ONE } else if (po . v == 0 && po . i % 10 == 2) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ha`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ha_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `haw`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_haw_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `he`."] # [doc = ""] # [doc = " - many: `v = 0 and n != 0..10 and n % 10 = 0`"] # [doc = " - one: `i = 1 and v = 0`"] # [doc = " - two: `i = 2 and v = 0`"] pub fn select_he_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (! (0 ..= 10) . contains (& (po . i)) || po . f != 0) && (po . i % 10 == 0 && po . f == 0)) { PluralCategory :: MANY } else if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else if (po . i == 2 && po . v == 0) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `hi`."] # [doc = ""] # [doc = " - one: `i = 0 or n = 1`"] pub const fn select_hi_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 0) || (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `hr`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14`"] # [doc = " - one: `v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11`"] pub fn select_hr_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (2 ..= 4) . contains (& (po . i % 10)) && ! (12 ..= 14) . contains (& (po . i % 100))) || ((2 ..= 4) . contains (& (po . f % 10)) && ! (12 ..= 14) . contains (& (po . f % 100))) { PluralCategory :: FEW } else if (po . v == 0 && po . i % 10 == 1 && po . i % 100 != 11) || (po . f % 10 == 1 && po . f % 100 != 11) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `hsb`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 100 = 3..4 or f % 100 = 3..4`"] # [doc = " - one: `v = 0 and i % 100 = 1 or f % 100 = 1`"] # [doc = " - two: `v = 0 and i % 100 = 2 or f % 100 = 2`"] pub fn select_hsb_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (3 ..= 4) . contains (& (po . i % 100))) || ((3 ..= 4) . contains (& (po . f % 100))) { PluralCategory :: FEW } else if (po . v == 0 && po . i % 100 == 1) || (po . f % 100 == 1) { PluralCategory :: ONE } else if (po . v == 0 && po . i % 100 == 2) || (po . f % 100 == 2) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `hu`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_hu_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `hy`."] # [doc = ""] # [doc = " - one: `i = 0,1`"] pub const fn select_hy_cardinal (po : & PluralOperands) -> PluralCategory { if ((po . i == 0 || po . i == 1)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `id`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_id_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ig`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_ig_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ii`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_ii_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `in`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_in_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `io`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_io_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `is`."] # [doc = ""] # [doc = " - one: `t = 0 and i % 10 = 1 and i % 100 != 11 or t != 0`"] pub const fn select_is_cardinal (po : & PluralOperands) -> PluralCategory { if (po . t == 0 && po . i % 10 == 1 && po . i % 100 != 11) || (po . t != 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `it`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_it_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `iu`."] # [doc = ""] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] pub const fn select_iu_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `iw`."] # [doc = ""] # [doc = " - many: `v = 0 and n != 0..10 and n % 10 = 0`"] # [doc = " - one: `i = 1 and v = 0`"] # [doc = " - two: `i = 2 and v = 0`"] pub fn select_iw_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (! (0 ..= 10) . contains (& (po . i)) || po . f != 0) && (po . i % 10 == 0 && po . f == 0)) { PluralCategory :: MANY } else if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else if (po . i == 2 && po . v == 0) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ja`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_ja_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `jbo`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_jbo_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `jgo`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_jgo_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ji`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_ji_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `jmc`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_jmc_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `jv`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_jv_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `jw`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_jw_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ka`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ka_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `kab`."] # [doc = ""] # [doc = " - one: `i = 0,1`"] pub const fn select_kab_cardinal (po : & PluralOperands) -> PluralCategory { if ((po . i == 0 || po . i == 1)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `kaj`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_kaj_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `kcg`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_kcg_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `kde`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_kde_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `kea`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_kea_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `kk`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_kk_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `kkj`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_kkj_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `kl`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_kl_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `km`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_km_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `kn`."] # [doc = ""] # [doc = " - one: `i = 0 or n = 1`"] pub const fn select_kn_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 0) || (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ko`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_ko_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ks`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ks_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ksb`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ksb_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ksh`."] # [doc = ""] # [doc = " - one: `n = 1`"] # [doc = " - zero: `n = 0`"] pub const fn select_ksh_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 0.0) { PluralCategory :: ZERO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ku`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ku_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `kw`."] # [doc = ""] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] pub const fn select_kw_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ky`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ky_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `lag`."] # [doc = ""] # [doc = " - one: `i = 0,1 and n != 0`"] # [doc = " - zero: `n = 0`"] pub const fn select_lag_cardinal (po : & PluralOperands) -> PluralCategory { if ((po . i == 0 || po . i == 1) && po . n != 0.0) { PluralCategory :: ONE } else if (po . n == 0.0) { PluralCategory :: ZERO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `lb`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_lb_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `lg`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_lg_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `lkt`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_lkt_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ln`."] # [doc = ""] # [doc = " - one: `n = 0..1`"] pub fn select_ln_cardinal (po : & PluralOperands) -> PluralCategory { if (((0 ..= 1) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `lo`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_lo_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `lt`."] # [doc = ""] # [doc = " - few: `n % 10 = 2..9 and n % 100 != 11..19`"] # [doc = " - many: `f != 0`"] # [doc = " - one: `n % 10 = 1 and n % 100 != 11..19`"] pub fn select_lt_cardinal (po : & PluralOperands) -> PluralCategory { if (((2 ..= 9) . contains (& (po . i % 10)) && po . f == 0) && (! (11 ..= 19) . contains (& (po . i % 100)) || po . f != 0)) { PluralCategory :: FEW } else if (po . f != 0) { PluralCategory :: MANY } else if ((po . i % 10 == 1 && po . f == 0) && (! (11 ..= 19) . contains (& (po . i % 100)) || po . f != 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `lv`."] # [doc = ""] # [doc = " - one: `n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 or v != 2 and f % 10 = 1`"] # [doc = " - zero: `n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19`"] pub fn select_lv_cardinal (po : & PluralOperands) -> PluralCategory { if ((po . i % 10 == 1 && po . f == 0) && (po . i % 100 != 11 || po . f != 0)) || (po . v == 2 && po . f % 10 == 1 && po . f % 100 != 11) || (po . v != 2 && po . f % 10 == 1) { PluralCategory :: ONE } else if ((po . i % 10 == 0 && po . f == 0)) || (((11 ..= 19) . contains (& (po . i % 100)) && po . f == 0)) || (po . v == 2 && (11 ..= 19) . contains (& (po . f % 100))) { PluralCategory :: ZERO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `mas`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_mas_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `mg`."] # [doc = ""] # [doc = " - one: `n = 0..1`"] pub fn select_mg_cardinal (po : & PluralOperands) -> PluralCategory { if (((0 ..= 1) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `mgo`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_mgo_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `mk`."] # [doc = ""] # [doc = " - one: `v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11`"] pub const fn select_mk_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && po . i % 10 == 1 && po . i % 100 != 11) || (po . f % 10 == 1 && po . f % 100 != 11) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ml`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ml_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `mn`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_mn_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `mo`."] # [doc = ""] # [doc = " - few: `v != 0 or n = 0 or n != 1 and n % 100 = 1..19`"] # [doc = " - one: `i = 1 and v = 0`"] pub fn select_mo_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v != 0) || (po . n == 0.0) || (po . n != 1.0 && ((1 ..= 19) . contains (& (po . i % 100)) && po . f == 0)) { PluralCategory :: FEW } else if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `mr`."] # [doc = ""] # [doc = " - one: `i = 0 or n = 1`"] pub const fn select_mr_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 0) || (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ms`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_ms_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `mt`."] # [doc = ""] # [doc = " - few: `n = 0 or n % 100 = 2..10`"] # [doc = " - many: `n % 100 = 11..19`"] # [doc = " - one: `n = 1`"] pub fn select_mt_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 0.0) || (((2 ..= 10) . contains (& (po . i % 100)) && po . f == 0)) { PluralCategory :: FEW } else if (((11 ..= 19) . contains (& (po . i % 100)) && po . f == 0)) { PluralCategory :: MANY } else if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `my`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_my_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `nah`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_nah_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `naq`."] # [doc = ""] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] pub const fn select_naq_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `nb`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_nb_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `nd`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_nd_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ne`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ne_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `nl`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_nl_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `nn`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_nn_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `nnh`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_nnh_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `no`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_no_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `nqo`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_nqo_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `nr`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_nr_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `nso`."] # [doc = ""] # [doc = " - one: `n = 0..1`"] pub fn select_nso_cardinal (po : & PluralOperands) -> PluralCategory { if (((0 ..= 1) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ny`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ny_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `nyn`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_nyn_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `om`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_om_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `or`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_or_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `os`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_os_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `pa`."] # [doc = ""] # [doc = " - one: `n = 0..1`"] pub fn select_pa_cardinal (po : & PluralOperands) -> PluralCategory { if (((0 ..= 1) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `pap`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_pap_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `pl`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 10 = 2..4 and i % 100 != 12..14`"] # [doc = " - many: `v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 12..14`"] # [doc = " - one: `i = 1 and v = 0`"] pub fn select_pl_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (2 ..= 4) . contains (& (po . i % 10)) && ! (12 ..= 14) . contains (& (po . i % 100))) { PluralCategory :: FEW } else if (po . v == 0 && po . i != 1 && (0 ..= 1) . contains (& (po . i % 10))) || (po . v == 0 && (5 ..= 9) . contains (& (po . i % 10))) || (po . v == 0 && (12 ..= 14) . contains (& (po . i % 100))) { PluralCategory :: MANY } else if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `prg`."] # [doc = ""] # [doc = " - one: `n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 or v != 2 and f % 10 = 1`"] # [doc = " - zero: `n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19`"] pub fn select_prg_cardinal (po : & PluralOperands) -> PluralCategory { if ((po . i % 10 == 1 && po . f == 0) && (po . i % 100 != 11 || po . f != 0)) || (po . v == 2 && po . f % 10 == 1 && po . f % 100 != 11) || (po . v != 2 && po . f % 10 == 1) { PluralCategory :: ONE } else if ((po . i % 10 == 0 && po . f == 0)) || (((11 ..= 19) . contains (& (po . i % 100)) && po . f == 0)) || (po . v == 2 && (11 ..= 19) . contains (& (po . f % 100))) { PluralCategory :: ZERO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ps`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ps_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `pt`."] # [doc = ""] # [doc = " - one: `i = 0..1`"] pub fn select_pt_cardinal (po : & PluralOperands) -> PluralCategory { if ((0 ..= 1) . contains (& (po . i))) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `pt-PT`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_pt_pt_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `rm`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_rm_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ro`."] # [doc = ""] # [doc = " - few: `v != 0 or n = 0 or n != 1 and n % 100 = 1..19`"] # [doc = " - one: `i = 1 and v = 0`"] pub fn select_ro_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v != 0) || (po . n == 0.0) || (po . n != 1.0 && ((1 ..= 19) . contains (& (po . i % 100)) && po . f == 0)) { PluralCategory :: FEW } else if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `rof`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_rof_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ru`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 10 = 2..4 and i % 100 != 12..14`"] # [doc = " - many: `v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14`"] # [doc = " - one: `v = 0 and i % 10 = 1 and i % 100 != 11`"] pub fn select_ru_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (2 ..= 4) . contains (& (po . i % 10)) && ! (12 ..= 14) . contains (& (po . i % 100))) { PluralCategory :: FEW } else if (po . v == 0 && po . i % 10 == 0) || (po . v == 0 && (5 ..= 9) . contains (& (po . i % 10))) || (po . v == 0 && (11 ..= 14) . contains (& (po . i % 100))) { PluralCategory :: MANY } else if (po . v == 0 && po . i % 10 == 1 && po . i % 100 != 11) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `rwk`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_rwk_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `sah`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_sah_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `saq`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_saq_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `scn`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_scn_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `sd`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_sd_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `sdh`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_sdh_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `se`."] # [doc = ""] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] pub const fn select_se_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `seh`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_seh_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ses`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_ses_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `sg`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_sg_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `sh`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14`"] # [doc = " - one: `v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11`"] pub fn select_sh_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (2 ..= 4) . contains (& (po . i % 10)) && ! (12 ..= 14) . contains (& (po . i % 100))) || ((2 ..= 4) . contains (& (po . f % 10)) && ! (12 ..= 14) . contains (& (po . f % 100))) { PluralCategory :: FEW } else if (po . v == 0 && po . i % 10 == 1 && po . i % 100 != 11) || (po . f % 10 == 1 && po . f % 100 != 11) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `shi`."] # [doc = ""] # [doc = " - few: `n = 2..10`"] # [doc = " - one: `i = 0 or n = 1`"] pub fn select_shi_cardinal (po : & PluralOperands) -> PluralCategory { if (((2 ..= 10) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: FEW } else if (po . i == 0) || (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `si`."] # [doc = ""] # [doc = " - one: `n = 0,1 or i = 0 and f = 1`"] pub const fn select_si_cardinal (po : & PluralOperands) -> PluralCategory { if ((po . n == 0.0 || po . n == 1.0)) || (po . i == 0 && po . f == 1) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `sk`."] # [doc = ""] # [doc = " - few: `i = 2..4 and v = 0`"] # [doc = " - many: `v != 0`"] # [doc = " - one: `i = 1 and v = 0`"] pub fn select_sk_cardinal (po : & PluralOperands) -> PluralCategory { if ((2 ..= 4) . contains (& (po . i)) && po . v == 0) { PluralCategory :: FEW } else if (po . v != 0) { PluralCategory :: MANY } else if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `sl`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 100 = 3..4 or v != 0`"] # [doc = " - one: `v = 0 and i % 100 = 1`"] # [doc = " - two: `v = 0 and i % 100 = 2`"] pub fn select_sl_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (3 ..= 4) . contains (& (po . i % 100))) || (po . v != 0) { PluralCategory :: FEW } else if (po . v == 0 && po . i % 100 == 1) { PluralCategory :: ONE } else if (po . v == 0 && po . i % 100 == 2) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `sma`."] # [doc = ""] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] pub const fn select_sma_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `smi`."] # [doc = ""] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] pub const fn select_smi_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `smj`."] # [doc = ""] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] pub const fn select_smj_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `smn`."] # [doc = ""] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] pub const fn select_smn_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `sms`."] # [doc = ""] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] pub const fn select_sms_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `sn`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_sn_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `so`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_so_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `sq`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_sq_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `sr`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14`"] # [doc = " - one: `v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11`"] pub fn select_sr_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (2 ..= 4) . contains (& (po . i % 10)) && ! (12 ..= 14) . contains (& (po . i % 100))) || ((2 ..= 4) . contains (& (po . f % 10)) && ! (12 ..= 14) . contains (& (po . f % 100))) { PluralCategory :: FEW } else if (po . v == 0 && po . i % 10 == 1 && po . i % 100 != 11) || (po . f % 10 == 1 && po . f % 100 != 11) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ss`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ss_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ssy`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ssy_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `st`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_st_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `sv`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_sv_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `sw`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_sw_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `syr`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_syr_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ta`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ta_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `te`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_te_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `teo`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_teo_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `th`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_th_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ti`."] # [doc = ""] # [doc = " - one: `n = 0..1`"] pub fn select_ti_cardinal (po : & PluralOperands) -> PluralCategory { if (((0 ..= 1) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `tig`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_tig_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `tk`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_tk_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `tl`."] # [doc = ""] # [doc = " - one: `v = 0 and i = 1,2,3 or v = 0 and i % 10 != 4,6,9 or v != 0 and f % 10 != 4,6,9`"] pub const fn select_tl_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (po . i == 1 || po . i == 2 || po . i == 3)) || (po . v == 0 && po . i % 10 != 4 && po . i % 10 != 6 && po . i % 10 != 9) || (po . v != 0 && po . f % 10 != 4 && po . f % 10 != 6 && po . f % 10 != 9) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `tn`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_tn_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `to`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_to_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `tr`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_tr_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ts`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ts_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `tzm`."] # [doc = ""] # [doc = " - one: `n = 0..1 or n = 11..99`"] pub fn select_tzm_cardinal (po : & PluralOperands) -> PluralCategory { if (((0 ..= 1) . contains (& (po . i)) && po . f == 0)) || (((11 ..= 99) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ug`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ug_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `uk`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 10 = 2..4 and i % 100 != 12..14`"] # [doc = " - many: `v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14`"] # [doc = " - one: `v = 0 and i % 10 = 1 and i % 100 != 11`"] pub fn select_uk_cardinal (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (2 ..= 4) . contains (& (po . i % 10)) && ! (12 ..= 14) . contains (& (po . i % 100))) { PluralCategory :: FEW } else if (po . v == 0 && po . i % 10 == 0) || (po . v == 0 && (5 ..= 9) . contains (& (po . i % 10))) || (po . v == 0 && (11 ..= 14) . contains (& (po . i % 100))) { PluralCategory :: MANY } else if (po . v == 0 && po . i % 10 == 1 && po . i % 100 != 11) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `und`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_und_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ur`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_ur_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `uz`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_uz_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ve`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_ve_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `vi`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_vi_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `vo`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_vo_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `vun`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_vun_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `wa`."] # [doc = ""] # [doc = " - one: `n = 0..1`"] pub fn select_wa_cardinal (po : & PluralOperands) -> PluralCategory { if (((0 ..= 1) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `wae`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_wae_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `wo`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_wo_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `xh`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_xh_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `xog`."] # [doc = ""] # [doc = " - one: `n = 1`"] pub const fn select_xog_cardinal (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `yi`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] pub const fn select_yi_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `yo`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_yo_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `yue`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_yue_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `zh`."] # [doc = ""] # [doc = " Every number selects `other`."] pub const fn select_zh_cardinal (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `zu`."] # [doc = ""] # [doc = " - one: `i = 0 or n = 1`"] pub const fn select_zu_cardinal (po : & PluralOperands) -> PluralCategory { if (po . i == 0) || (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } pub const PRS_CARDINAL : & [(LanguageIdentifier , PluralRule)] = & [(langid ! (subtags :: Language :: from_raw_unchecked (26209u64) , None , None , None) , select_af_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (27489u64) , None , None , None) , select_ak_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28001u64) , None , None , None) , select_am_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29281u64) , None , None , None) , select_ar_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7565921u64) , None , None , None) , select_ars_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29537u64) , None , None , None) , select_as_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6386529u64) , None , None , None) , select_asa_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7631713u64) , None , None , None) , select_ast_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (31329u64) , None , None , None) , select_az_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (25954u64) , None , None , None) , select_be_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7169378u64) , None , None , None) , select_bem_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (8021346u64) , None , None , None) , select_bez_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26466u64) , None , None , None) , select_bg_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26722u64) , None , None , None) , select_bh_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28002u64) , None , None , None) , select_bm_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28258u64) , None , None , None) , select_bn_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28514u64) , None , None , None) , select_bo_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29282u64) , None , None , None) , select_br_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7893602u64) , None , None , None) , select_brx_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29538u64) , None , None , None) , select_bs_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (24931u64) , None , None , None) , select_ca_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (25955u64) , None , None , None) , select_ce_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6776675u64) , None , None , None) , select_cgg_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7497827u64) , None , None , None) , select_chr_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6450019u64) , None , None , None) , select_ckb_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29539u64) , None , None , None) , select_cs_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (31075u64) , None , None , None) , select_cy_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (24932u64) , None , None , None) , select_da_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (25956u64) , None , None , None) , select_de_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6452068u64) , None , None , None) , select_dsb_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30308u64) , None , None , None) , select_dv_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (31332u64) , None , None , None) , select_dz_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (25957u64) , None , None , None) , select_ee_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (27749u64) , None , None , None) , select_el_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28261u64) , None , None , None) , select_en_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28517u64) , None , None , None) , select_eo_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29541u64) , None , None , None) , select_es_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29797u64) , None , None , None) , select_et_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30053u64) , None , None , None) , select_eu_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (24934u64) , None , None , None) , select_fa_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26214u64) , None , None , None) , select_ff_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26982u64) , None , None , None) , select_fi_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7104870u64) , None , None , None) , select_fil_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28518u64) , None , None , None) , select_fo_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29286u64) , None , None , None) , select_fr_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7501158u64) , None , None , None) , select_fur_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (31078u64) , None , None , None) , select_fy_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (24935u64) , None , None , None) , select_ga_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (25703u64) , None , None , None) , select_gd_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (27751u64) , None , None , None) , select_gl_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7828327u64) , None , None , None) , select_gsw_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30055u64) , None , None , None) , select_gu_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7828839u64) , None , None , None) , select_guw_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30311u64) , None , None , None) , select_gv_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (24936u64) , None , None , None) , select_ha_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7823720u64) , None , None , None) , select_haw_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (25960u64) , None , None , None) , select_he_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26984u64) , None , None , None) , select_hi_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29288u64) , None , None , None) , select_hr_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6452072u64) , None , None , None) , select_hsb_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30056u64) , None , None , None) , select_hu_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (31080u64) , None , None , None) , select_hy_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (25705u64) , None , None , None) , select_id_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26473u64) , None , None , None) , select_ig_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26985u64) , None , None , None) , select_ii_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28265u64) , None , None , None) , select_in_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28521u64) , None , None , None) , select_io_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29545u64) , None , None , None) , select_is_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29801u64) , None , None , None) , select_it_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30057u64) , None , None , None) , select_iu_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30569u64) , None , None , None) , select_iw_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (24938u64) , None , None , None) , select_ja_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7299690u64) , None , None , None) , select_jbo_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7300970u64) , None , None , None) , select_jgo_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26986u64) , None , None , None) , select_ji_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6516074u64) , None , None , None) , select_jmc_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30314u64) , None , None , None) , select_jv_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30570u64) , None , None , None) , select_jw_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (24939u64) , None , None , None) , select_ka_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6447467u64) , None , None , None) , select_kab_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6971755u64) , None , None , None) , select_kaj_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6775659u64) , None , None , None) , select_kcg_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6644843u64) , None , None , None) , select_kde_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6382955u64) , None , None , None) , select_kea_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (27499u64) , None , None , None) , select_kk_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6974315u64) , None , None , None) , select_kkj_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (27755u64) , None , None , None) , select_kl_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28011u64) , None , None , None) , select_km_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28267u64) , None , None , None) , select_kn_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28523u64) , None , None , None) , select_ko_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29547u64) , None , None , None) , select_ks_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6452075u64) , None , None , None) , select_ksb_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6845291u64) , None , None , None) , select_ksh_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30059u64) , None , None , None) , select_ku_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30571u64) , None , None , None) , select_kw_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (31083u64) , None , None , None) , select_ky_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6775148u64) , None , None , None) , select_lag_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (25196u64) , None , None , None) , select_lb_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26476u64) , None , None , None) , select_lg_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7629676u64) , None , None , None) , select_lkt_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28268u64) , None , None , None) , select_ln_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28524u64) , None , None , None) , select_lo_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29804u64) , None , None , None) , select_lt_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30316u64) , None , None , None) , select_lv_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7561581u64) , None , None , None) , select_mas_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26477u64) , None , None , None) , select_mg_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7300973u64) , None , None , None) , select_mgo_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (27501u64) , None , None , None) , select_mk_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (27757u64) , None , None , None) , select_ml_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28269u64) , None , None , None) , select_mn_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28525u64) , None , None , None) , select_mo_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29293u64) , None , None , None) , select_mr_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29549u64) , None , None , None) , select_ms_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29805u64) , None , None , None) , select_mt_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (31085u64) , None , None , None) , select_my_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6840686u64) , None , None , None) , select_nah_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7430510u64) , None , None , None) , select_naq_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (25198u64) , None , None , None) , select_nb_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (25710u64) , None , None , None) , select_nd_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (25966u64) , None , None , None) , select_ne_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (27758u64) , None , None , None) , select_nl_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28270u64) , None , None , None) , select_nn_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6844014u64) , None , None , None) , select_nnh_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28526u64) , None , None , None) , select_no_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7303534u64) , None , None , None) , select_nqo_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29294u64) , None , None , None) , select_nr_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7304046u64) , None , None , None) , select_nso_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (31086u64) , None , None , None) , select_ny_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7240046u64) , None , None , None) , select_nyn_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28015u64) , None , None , None) , select_om_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29295u64) , None , None , None) , select_or_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29551u64) , None , None , None) , select_os_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (24944u64) , None , None , None) , select_pa_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7364976u64) , None , None , None) , select_pap_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (27760u64) , None , None , None) , select_pl_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6779504u64) , None , None , None) , select_prg_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29552u64) , None , None , None) , select_ps_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29808u64) , None , None , None) , select_pt_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29808u64) , None , Some (subtags :: Region :: from_raw_unchecked (21584u32)) , None) , select_pt_pt_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28018u64) , None , None , None) , select_rm_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28530u64) , None , None , None) , select_ro_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6713202u64) , None , None , None) , select_rof_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30066u64) , None , None , None) , select_ru_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7042930u64) , None , None , None) , select_rwk_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6840691u64) , None , None , None) , select_sah_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7430515u64) , None , None , None) , select_saq_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7234419u64) , None , None , None) , select_scn_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (25715u64) , None , None , None) , select_sd_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6841459u64) , None , None , None) , select_sdh_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (25971u64) , None , None , None) , select_se_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6841715u64) , None , None , None) , select_seh_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7562611u64) , None , None , None) , select_ses_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26483u64) , None , None , None) , select_sg_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26739u64) , None , None , None) , select_sh_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6908019u64) , None , None , None) , select_shi_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26995u64) , None , None , None) , select_si_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (27507u64) , None , None , None) , select_sk_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (27763u64) , None , None , None) , select_sl_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6385011u64) , None , None , None) , select_sma_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6909299u64) , None , None , None) , select_smi_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6974835u64) , None , None , None) , select_smj_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7236979u64) , None , None , None) , select_smn_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7564659u64) , None , None , None) , select_sms_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28275u64) , None , None , None) , select_sn_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28531u64) , None , None , None) , select_so_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29043u64) , None , None , None) , select_sq_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29299u64) , None , None , None) , select_sr_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29555u64) , None , None , None) , select_ss_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7959411u64) , None , None , None) , select_ssy_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29811u64) , None , None , None) , select_st_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30323u64) , None , None , None) , select_sv_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30579u64) , None , None , None) , select_sw_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7502195u64) , None , None , None) , select_syr_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (24948u64) , None , None , None) , select_ta_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (25972u64) , None , None , None) , select_te_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7300468u64) , None , None , None) , select_teo_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26740u64) , None , None , None) , select_th_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26996u64) , None , None , None) , select_ti_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6777204u64) , None , None , None) , select_tig_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (27508u64) , None , None , None) , select_tk_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (27764u64) , None , None , None) , select_tl_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28276u64) , None , None , None) , select_tn_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28532u64) , None , None , None) , select_to_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29300u64) , None , None , None) , select_tr_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29556u64) , None , None , None) , select_ts_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7174772u64) , None , None , None) , select_tzm_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26485u64) , None , None , None) , select_ug_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (27509u64) , None , None , None) , select_uk_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6581877u64) , None , None , None) , select_und_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (29301u64) , None , None , None) , select_ur_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (31349u64) , None , None , None) , select_uz_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (25974u64) , None , None , None) , select_ve_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26998u64) , None , None , None) , select_vi_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28534u64) , None , None , None) , select_vo_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (7239030u64) , None , None , None) , select_vun_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (24951u64) , None , None , None) , select_wa_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6644087u64) , None , None , None) , select_wae_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28535u64) , None , None , None) , select_wo_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26744u64) , None , None , None) , select_xh_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6778744u64) , None , None , None) , select_xog_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (27001u64) , None , None , None) , select_yi_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (28537u64) , None , None , None) , select_yo_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (6649209u64) , None , None , None) , select_yue_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (26746u64) , None , None , None) , select_zh_cardinal) , (langid ! (subtags :: Language :: from_raw_unchecked (30074u64) , None , None , None) , select_zu_cardinal)] ; pub const PRS_CARDINAL_CATEGORIES : & [(LanguageIdentifier , & [PluralCategory])] = & [(langid ! (subtags :: Language :: from_raw_unchecked (26209u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27489u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28001u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29281u64) , None , None , None) , & [PluralCategory :: ZERO , PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7565921u64) , None , None , None) , & [PluralCategory :: ZERO , PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29537u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6386529u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7631713u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31329u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25954u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7169378u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (8021346u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26466u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26722u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28002u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28258u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28514u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29282u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7893602u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29538u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24931u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25955u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6776675u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7497827u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6450019u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29539u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31075u64) , None , None , None) , & [PluralCategory :: ZERO , PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24932u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25956u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6452068u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30308u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31332u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25957u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27749u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28261u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28517u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29541u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29797u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30053u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24934u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26214u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26982u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7104870u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28518u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29286u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7501158u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31078u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24935u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25703u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27751u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7828327u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30055u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7828839u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30311u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24936u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7823720u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25960u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26984u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29288u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6452072u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30056u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31080u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25705u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26473u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26985u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28265u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28521u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29545u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29801u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30057u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30569u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24938u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7299690u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7300970u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26986u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6516074u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30314u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30570u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24939u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6447467u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6971755u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6775659u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6644843u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6382955u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27499u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6974315u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27755u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28011u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28267u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28523u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29547u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6452075u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6845291u64) , None , None , None) , & [PluralCategory :: ZERO , PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30059u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30571u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31083u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6775148u64) , None , None , None) , & [PluralCategory :: ZERO , PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25196u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26476u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7629676u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28268u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28524u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29804u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30316u64) , None , None , None) , & [PluralCategory :: ZERO , PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7561581u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26477u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7300973u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27501u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27757u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28269u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28525u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29293u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29549u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29805u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31085u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6840686u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7430510u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25198u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25710u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25966u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27758u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28270u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6844014u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28526u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7303534u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29294u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7304046u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31086u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7240046u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28015u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29295u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29551u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24944u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7364976u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27760u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6779504u64) , None , None , None) , & [PluralCategory :: ZERO , PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29552u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29808u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29808u64) , None , Some (subtags :: Region :: from_raw_unchecked (21584u32)) , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28018u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28530u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6713202u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30066u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7042930u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6840691u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7430515u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7234419u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25715u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6841459u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25971u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6841715u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7562611u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26483u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26739u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6908019u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26995u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27507u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27763u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6385011u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6909299u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6974835u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7236979u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7564659u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28275u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28531u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29043u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29299u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29555u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7959411u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29811u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30323u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30579u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7502195u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24948u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25972u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7300468u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26740u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26996u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6777204u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27508u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27764u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28276u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28532u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29300u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29556u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7174772u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26485u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27509u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6581877u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29301u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31349u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25974u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26998u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28534u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7239030u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24951u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6644087u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28535u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26744u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6778744u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27001u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28537u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6649209u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26746u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30074u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER])] ; # [doc = r" The number of categories of each locale, at the index of the locale in the rule table."] pub const PRS_CARDINAL_CATEGORY_COUNTS : & [u8] = & [2 , 2 , 2 , 6 , 6 , 2 , 2 , 2 , 2 , 4 , 2 , 2 , 2 , 2 , 1 , 2 , 1 , 5 , 2 , 3 , 2 , 2 , 2 , 2 , 2 , 4 , 6 , 2 , 2 , 4 , 2 , 1 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 5 , 4 , 2 , 2 , 2 , 2 , 5 , 2 , 2 , 4 , 2 , 3 , 4 , 2 , 2 , 1 , 1 , 1 , 1 , 2 , 2 , 2 , 3 , 4 , 1 , 1 , 2 , 2 , 2 , 1 , 1 , 2 , 2 , 2 , 2 , 1 , 1 , 2 , 2 , 2 , 1 , 2 , 1 , 2 , 2 , 3 , 2 , 3 , 2 , 3 , 2 , 2 , 1 , 2 , 1 , 4 , 3 , 2 , 2 , 2 , 2 , 2 , 2 , 3 , 2 , 1 , 4 , 1 , 2 , 3 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 1 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 4 , 3 , 2 , 2 , 2 , 2 , 3 , 2 , 4 , 2 , 1 , 2 , 2 , 2 , 2 , 3 , 2 , 1 , 1 , 3 , 3 , 2 , 4 , 4 , 3 , 3 , 3 , 3 , 3 , 2 , 2 , 2 , 3 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 1 , 2 , 2 , 2 , 2 , 2 , 1 , 2 , 2 , 2 , 2 , 4 , 1 , 2 , 2 , 2 , 1 , 2 , 2 , 2 , 2 , 1 , 2 , 2 , 2 , 1 , 1 , 1 , 2] ; # [doc = r" The letters of the operands the rules of each locale read, at the index of the locale in the rule table."] pub const PRS_CARDINAL_OPERANDS : & [& str] = & ["n" , "n" , "ni" , "n" , "n" , "ni" , "n" , "iv" , "n" , "n" , "n" , "n" , "n" , "n" , "" , "ni" , "" , "n" , "n" , "ivf" , "iv" , "n" , "n" , "n" , "n" , "iv" , "n" , "nit" , "iv" , "ivf" , "n" , "" , "n" , "n" , "iv" , "n" , "n" , "iv" , "n" , "ni" , "i" , "iv" , "ivf" , "n" , "i" , "n" , "iv" , "n" , "n" , "iv" , "n" , "ni" , "n" , "iv" , "n" , "n" , "niv" , "ni" , "ivf" , "ivf" , "n" , "i" , "" , "" , "" , "" , "iv" , "it" , "iv" , "n" , "niv" , "" , "" , "n" , "iv" , "n" , "" , "" , "n" , "i" , "n" , "n" , "" , "" , "n" , "n" , "n" , "" , "ni" , "" , "n" , "n" , "n" , "n" , "n" , "n" , "ni" , "n" , "n" , "" , "n" , "" , "nf" , "nvf" , "n" , "n" , "n" , "ivf" , "n" , "n" , "niv" , "ni" , "" , "n" , "" , "n" , "n" , "n" , "n" , "n" , "iv" , "n" , "n" , "n" , "" , "n" , "n" , "n" , "n" , "n" , "n" , "n" , "n" , "n" , "iv" , "nvf" , "n" , "i" , "iv" , "n" , "niv" , "n" , "iv" , "n" , "" , "n" , "iv" , "n" , "n" , "n" , "n" , "" , "" , "ivf" , "ni" , "nif" , "iv" , "iv" , "n" , "n" , "n" , "n" , "n" , "n" , "n" , "n" , "ivf" , "n" , "n" , "n" , "iv" , "iv" , "n" , "n" , "n" , "n" , "" , "n" , "n" , "n" , "ivf" , "n" , "" , "n" , "n" , "n" , "n" , "iv" , "" , "iv" , "n" , "n" , "" , "n" , "n" , "n" , "n" , "" , "n" , "n" , "iv" , "" , "" , "" , "ni"] ; pub const CARDINAL_LOCALE_COUNT : usize = 205 ; pub fn select_cardinal (lang : & LanguageIdentifier) -> Option < PluralRule > { PRS_CARDINAL . binary_search_by (| (l , _) | l . cmp (lang)) . ok () . map (| idx | PRS_CARDINAL [idx] . 1) } pub fn cardinal_map () -> std :: collections :: HashMap < LanguageIdentifier , PluralRule > { static MAP : std :: sync :: OnceLock < std :: collections :: HashMap < LanguageIdentifier , PluralRule >> = std :: sync :: OnceLock :: new () ; MAP . get_or_init (|| PRS_CARDINAL . iter () . cloned () . collect ()) . clone () } pub fn select (lang : & LanguageIdentifier , po : & PluralOperands , ty : PluralRuleType ,) -> PluralCategory { # [allow (unreachable_patterns)] let select_rule : fn (& LanguageIdentifier) -> Option < PluralRule > = match ty { PluralRuleType :: CARDINAL => select_cardinal , _ => return PluralCategory :: OTHER , } ; let rule = select_rule (lang) . or_else (|| select_rule (& LanguageIdentifier :: from_parts (lang . language , None , None , & []))) . or_else (|| select_rule (& langid ! (subtags :: Language :: from_raw_unchecked (6581877u64) , None , None , None))) ; match rule { Some (rule) => rule (po) , None => PluralCategory :: OTHER , } } pub fn supported_locales (ty : PluralRuleType ,) -> impl Iterator < Item = & 'static LanguageIdentifier > { # [allow (unreachable_patterns)] let table : & 'static [(LanguageIdentifier , PluralRule)] = match ty { PluralRuleType :: CARDINAL => PRS_CARDINAL , _ => & [] , } ; table . iter () . map (| (lang , _) | lang) } pub fn rules (ty : PluralRuleType ,) -> impl Iterator < Item = (& 'static LanguageIdentifier , PluralRule) > { # [allow (unreachable_patterns)] let table : & 'static [(LanguageIdentifier , PluralRule)] = match ty { PluralRuleType :: CARDINAL => PRS_CARDINAL , _ => & [] , } ; table . iter () . map (| (lang , rule) | (lang , * rule)) } pub fn find (ty : PluralRuleType , lang : & LanguageIdentifier) -> Option < PluralRule > { # [allow (unreachable_patterns)] let select_rule : fn (& LanguageIdentifier) -> Option < PluralRule > = match ty { PluralRuleType :: CARDINAL => select_cardinal , _ => return None , } ; select_rule (lang) } pub fn category_count (lang : & LanguageIdentifier , ty : PluralRuleType) -> usize { # [allow (unreachable_patterns)] let (table , counts) : (& [(LanguageIdentifier , PluralRule)] , & [u8]) = match ty { PluralRuleType :: CARDINAL => (PRS_CARDINAL , PRS_CARDINAL_CATEGORY_COUNTS) , _ => return 1 , } ; let index = | lang : & LanguageIdentifier | table . binary_search_by (| (l , _) | l . cmp (lang)) . ok () ; let idx = index (lang) . or_else (|| index (& LanguageIdentifier :: from_parts (lang . language , None , None , & []))) . or_else (|| index (& langid ! (subtags :: Language :: from_raw_unchecked (6581877u64) , None , None , None))) ; idx . map_or (1 , | idx | counts [idx] as usize) } # [cfg (test)] mod langid_tests { use super :: * ; # [test] fn cardinal_langids () { for (lang , _) in PRS_CARDINAL . iter () { let source = std :: string :: ToString :: to_string (lang) ; if source == "und" { continue ; } assert_eq ! (source . parse :: < LanguageIdentifier > () . ok () . as_ref () , Some (lang) , "`{}`" , source) ; } } }
\ No newline at end of file
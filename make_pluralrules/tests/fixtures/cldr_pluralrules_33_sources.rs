@@ -0,0 +1 @@
+# ! [allow (unused_variables , unused_parens , unused_braces , dead_code)] # ! [allow (clippy :: float_cmp)] # ! [allow (clippy :: unreadable_literal , clippy :: type_complexity)] # ! [allow (clippy :: nonminimal_bool , clippy :: double_parens , clippy :: manual_is_multiple_of)] use super :: operands :: PluralOperands ; use super :: PluralRuleType ; use super :: PluralCategory ; use unic_langid :: LanguageIdentifier ; use unic_langid :: subtags ; pub type PluralRule = fn (& PluralOperands) -> PluralCategory ; pub static CLDR_VERSION : & str = "33" ; macro_rules ! langid { ($ lang : expr , $ script : expr , $ region : expr , $ variants : expr) => { { unsafe { LanguageIdentifier :: from_raw_parts_unchecked ($ lang , $ script , $ region , $ variants ,) } } } ; } # [doc = " The cardinal rules of `af`, `asa`, `az`, `bem`, `bez`, `bg`, `brx`, `ce`, `cgg`, `chr`, `ckb`, `dv`, `ee`, `el`, `eo`, `es`, `eu`, `fo`, `fur`, `gsw`, `ha`, `haw`, `hu`, `jgo`, `jmc`, `ka`, `kaj`, `kcg`, `kk`, `kkj`, `kl`, `ks`, `ksb`, `ku`, `ky`, `lb`, `lg`, `mas`, `mgo`, `ml`, `mn`, `nah`, `nb`, `nd`, `ne`, `nn`, `nnh`, `no`, `nr`, `ny`, `nyn`, `om`, `or`, `os`, `pap`, `ps`, `rm`, `rof`, `rwk`, `saq`, `sd`, `sdh`, `seh`, `sn`, `so`, `sq`, `ss`, `ssy`, `st`, `syr`, `ta`, `te`, `teo`, `tig`, `tk`, `tn`, `tr`, `ts`, `ug`, `uz`, `ve`, `vo`, `vun`, `wae`, `xh`, `xog`."] # [doc = ""] # [doc = " - one: `n = 1`"] const fn cardinal_rule_0 (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ak`, `bh`, `guw`, `ln`, `mg`, `nso`, `pa`, `ti`, `wa`."] # [doc = ""] # [doc = " - one: `n = 0..1`"] fn cardinal_rule_1 (po : & PluralOperands) -> PluralCategory { if (((0 ..= 1) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `am`, `as`, `bn`, `fa`, `gu`, `hi`, `kn`, `mr`, `zu`."] # [doc = ""] # [doc = " - one: `i = 0 or n = 1`"] const fn cardinal_rule_2 (po : & PluralOperands) -> PluralCategory { if (po . i == 0) || (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ar`, `ars`."] # [doc = ""] # [doc = " - few: `n % 100 = 3..10`"] # [doc = " - many: `n % 100 = 11..99`"] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] # [doc = " - zero: `n = 0`"] fn cardinal_rule_3 (po : & PluralOperands) -> PluralCategory { if (((3 ..= 10) . contains (& (po . i % 100)) && po . f == 0)) { PluralCategory :: FEW } else if (((11 ..= 99) . contains (& (po . i % 100)) && po . f == 0)) { PluralCategory :: MANY } else if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else if (po . n == 0.0) { PluralCategory :: ZERO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ast`, `ca`, `de`, `en`, `et`, `fi`, `fy`, `gl`, `io`, `it`, `ji`, `nl`, `pt-PT`, `scn`, `sv`, `sw`, `ur`, `yi`."] # [doc = ""] # [doc = " - one: `i = 1 and v = 0`"] const fn cardinal_rule_4 (po : & PluralOperands) -> PluralCategory { if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `be`."] # [doc = ""] # [doc = " - few: `n % 10 = 2..4 and n % 100 != 12..14`"] # [doc = " - many: `n % 10 = 0 or n % 10 = 5..9 or n % 100 = 11..14`"] # [doc = " - one: `n % 10 = 1 and n % 100 != 11`"] fn cardinal_rule_5 (po : & PluralOperands) -> PluralCategory { if (((2 ..= 4) . contains (& (po . i % 10)) && po . f == 0) && (! (12 ..= 14) . contains (& (po . i % 100)) || po . f != 0)) { PluralCategory :: FEW } else if ((po . i % 10 == 0 && po . f == 0)) || (((5 ..= 9) . contains (& (po . i % 10)) && po . f == 0)) || (((11 ..= 14) . contains (& (po . i % 100)) && po . f == 0)) { PluralCategory :: MANY } else if ((po . i % 10 == 1 && po . f == 0) && (po . i % 100 != 11 || po . f != 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `bm`, `bo`, `dz`, `id`, `ig`, `ii`, `in`, `ja`, `jbo`, `jv`, `jw`, `kde`, `kea`, `km`, `ko`, `lkt`, `lo`, `ms`, `my`, `nqo`, `sah`, `ses`, `sg`, `th`, `to`, `und`, `vi`, `wo`, `yo`, `yue`, `zh`."] # [doc = ""] # [doc = " Every number selects `other`."] const fn cardinal_rule_6 (po : & PluralOperands) -> PluralCategory { { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `br`."] # [doc = ""] # [doc = " - few: `n % 10 = 3..4,9 and n % 100 != 10..19,70..79,90..99`"] # [doc = " - many: `n != 0 and n % 1000000 = 0`"] # [doc = " - one: `n % 10 = 1 and n % 100 != 11,71,91`"] # [doc = " - two: `n % 10 = 2 and n % 100 != 12,72,92`"] fn cardinal_rule_7 (po : & PluralOperands) -> PluralCategory { if (((po . i % 10 == 9 && po . f == 0) || ((3 ..= 4) . contains (& (po . i % 10)) && po . f == 0)) && (! (10 ..= 19) . contains (& (po . i % 100)) || po . f != 0) && (! (70 ..= 79) . contains (& (po . i % 100)) || po . f != 0) && (! (90 ..= 99) . contains (& (po . i % 100)) || po . f != 0)) { PluralCategory :: FEW } else if (po . n != 0.0 && (po . i % 1000000 == 0 && po . f == 0)) { PluralCategory :: MANY } else if ((po . i % 10 == 1 && po . f == 0) && (po . i % 100 != 11 || po . f != 0) && (po . i % 100 != 71 || po . f != 0) && (po . i % 100 != 91 || po . f != 0)) { PluralCategory :: ONE } else if ((po . i % 10 == 2 && po . f == 0) && (po . i % 100 != 12 || po . f != 0) && (po . i % 100 != 72 || po . f != 0) && (po . i % 100 != 92 || po . f != 0)) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `bs`, `hr`, `sh`, `sr`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14`"] # [doc = " - one: `v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11`"] fn cardinal_rule_8 (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (2 ..= 4) . contains (& (po . i % 10)) && ! (12 ..= 14) . contains (& (po . i % 100))) || ((2 ..= 4) . contains (& (po . f % 10)) && ! (12 ..= 14) . contains (& (po . f % 100))) { PluralCategory :: FEW } else if (po . v == 0 && po . i % 10 == 1 && po . i % 100 != 11) || (po . f % 10 == 1 && po . f % 100 != 11) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `cs`, `sk`."] # [doc = ""] # [doc = " - few: `i = 2..4 and v = 0`"] # [doc = " - many: `v != 0`"] # [doc = " - one: `i = 1 and v = 0`"] fn cardinal_rule_9 (po : & PluralOperands) -> PluralCategory { if ((2 ..= 4) . contains (& (po . i)) && po . v == 0) { PluralCategory :: FEW } else if (po . v != 0) { PluralCategory :: MANY } else if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `cy`."] # [doc = ""] # [doc = " - few: `n = 3`"] # [doc = " - many: `n = 6`"] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] # [doc = " - zero: `n = 0`"] const fn cardinal_rule_10 (po : & PluralOperands) -> PluralCategory { if (po . n == 3.0) { PluralCategory :: FEW } else if (po . n == 6.0) { PluralCategory :: MANY } else if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else if (po . n == 0.0) { PluralCategory :: ZERO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `da`."] # [doc = ""] # [doc = " - one: `n = 1 or t != 0 and i = 0,1`"] const fn cardinal_rule_11 (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) || (po . t != 0 && (po . i == 0 || po . i == 1)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `dsb`, `hsb`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 100 = 3..4 or f % 100 = 3..4`"] # [doc = " - one: `v = 0 and i % 100 = 1 or f % 100 = 1`"] # [doc = " - two: `v = 0 and i % 100 = 2 or f % 100 = 2`"] fn cardinal_rule_12 (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (3 ..= 4) . contains (& (po . i % 100))) || ((3 ..= 4) . contains (& (po . f % 100))) { PluralCategory :: FEW } else if (po . v == 0 && po . i % 100 == 1) || (po . f % 100 == 1) { PluralCategory :: ONE } else if (po . v == 0 && po . i % 100 == 2) || (po . f % 100 == 2) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ff`, `fr`, `hy`, `kab`."] # [doc = ""] # [doc = " - one: `i = 0,1`"] const fn cardinal_rule_13 (po : & PluralOperands) -> PluralCategory { if ((po . i == 0 || po . i == 1)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `fil`, `tl`."] # [doc = ""] # [doc = " - one: `v = 0 and i = 1,2,3 or v = 0 and i % 10 != 4,6,9 or v != 0 and f % 10 != 4,6,9`"] const fn cardinal_rule_14 (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (po . i == 1 || po . i == 2 || po . i == 3)) || (po . v == 0 && po . i % 10 != 4 && po . i % 10 != 6 && po . i % 10 != 9) || (po . v != 0 && po . f % 10 != 4 && po . f % 10 != 6 && po . f % 10 != 9) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ga`."] # [doc = ""] # [doc = " - few: `n = 3..6`"] # [doc = " - many: `n = 7..10`"] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] fn cardinal_rule_15 (po : & PluralOperands) -> PluralCategory { if (((3 ..= 6) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: FEW } else if (((7 ..= 10) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: MANY } else if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `gd`."] # [doc = ""] # [doc = " - few: `n = 3..10,13..19`"] # [doc = " - one: `n = 1,11`"] # [doc = " - two: `n = 2,12`"] fn cardinal_rule_16 (po : & PluralOperands) -> PluralCategory { if ((((3 ..= 10) . contains (& (po . i)) && po . f == 0) || ((13 ..= 19) . contains (& (po . i)) && po . f == 0))) { PluralCategory :: FEW } else if ((po . n == 1.0 || po . n == 11.0)) { PluralCategory :: ONE } else if ((po . n == 2.0 || po . n == 12.0)) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `gv`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 100 = 0,20,40,60,80`"] # [doc = " - many: `v != 0`"] # [doc = " - one: `v = 0 and i % 10 = 1`"] # [doc = " - two: `v = 0 and i % 10 = 2`"] const fn cardinal_rule_17 (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (po . i % 100 == 0 || po . i % 100 == 20 || po . i % 100 == 40 || po . i % 100 == 60 || po . i % 100 == 80)) { PluralCategory :: FEW } else if (po . v != 0) { PluralCategory :: MANY } else if (po . v == 0 && po . i % 10 == 1) { PluralCategory :: ONE } else if (po . v == 0 && po . i % 10 == 2) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `he`, `iw`."] # [doc = ""] # [doc = " - many: `v = 0 and n != 0..10 and n % 10 = 0`"] # [doc = " - one: `i = 1 and v = 0`"] # [doc = " - two: `i = 2 and v = 0`"] fn cardinal_rule_18 (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (! (0 ..= 10) . contains (& (po . i)) || po . f != 0) && (po . i % 10 == 0 && po . f == 0)) { PluralCategory :: MANY } else if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else if (po . i == 2 && po . v == 0) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `is`."] # [doc = ""] # [doc = " - one: `t = 0 and i % 10 = 1 and i % 100 != 11 or t != 0`"] const fn cardinal_rule_19 (po : & PluralOperands) -> PluralCategory { if (po . t == 0 && po . i % 10 == 1 && po . i % 100 != 11) || (po . t != 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `iu`, `kw`, `naq`, `se`, `sma`, `smi`, `smj`, `smn`, `sms`."] # [doc = ""] # [doc = " - one: `n = 1`"] # [doc = " - two: `n = 2`"] const fn cardinal_rule_20 (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 2.0) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ksh`."] # [doc = ""] # [doc = " - one: `n = 1`"] # [doc = " - zero: `n = 0`"] const fn cardinal_rule_21 (po : & PluralOperands) -> PluralCategory { if (po . n == 1.0) { PluralCategory :: ONE } else if (po . n == 0.0) { PluralCategory :: ZERO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `lag`."] # [doc = ""] # [doc = " - one: `i = 0,1 and n != 0`"] # [doc = " - zero: `n = 0`"] const fn cardinal_rule_22 (po : & PluralOperands) -> PluralCategory { if ((po . i == 0 || po . i == 1) && po . n != 0.0) { PluralCategory :: ONE } else if (po . n == 0.0) { PluralCategory :: ZERO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `lt`."] # [doc = ""] # [doc = " - few: `n % 10 = 2..9 and n % 100 != 11..19`"] # [doc = " - many: `f != 0`"] # [doc = " - one: `n % 10 = 1 and n % 100 != 11..19`"] fn cardinal_rule_23 (po : & PluralOperands) -> PluralCategory { if (((2 ..= 9) . contains (& (po . i % 10)) && po . f == 0) && (! (11 ..= 19) . contains (& (po . i % 100)) || po . f != 0)) { PluralCategory :: FEW } else if (po . f != 0) { PluralCategory :: MANY } else if ((po . i % 10 == 1 && po . f == 0) && (! (11 ..= 19) . contains (& (po . i % 100)) || po . f != 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `lv`, `prg`."] # [doc = ""] # [doc = " - one: `n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 or v != 2 and f % 10 = 1`"] # [doc = " - zero: `n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19`"] fn cardinal_rule_24 (po : & PluralOperands) -> PluralCategory { if ((po . i % 10 == 1 && po . f == 0) && (po . i % 100 != 11 || po . f != 0)) || (po . v == 2 && po . f % 10 == 1 && po . f % 100 != 11) || (po . v != 2 && po . f % 10 == 1) { PluralCategory :: ONE } else if ((po . i % 10 == 0 && po . f == 0)) || (((11 ..= 19) . contains (& (po . i % 100)) && po . f == 0)) || (po . v == 2 && (11 ..= 19) . contains (& (po . f % 100))) { PluralCategory :: ZERO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `mk`."] # [doc = ""] # [doc = " - one: `v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11`"] const fn cardinal_rule_25 (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && po . i % 10 == 1 && po . i % 100 != 11) || (po . f % 10 == 1 && po . f % 100 != 11) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `mo`, `ro`."] # [doc = ""] # [doc = " - few: `v != 0 or n = 0 or n != 1 and n % 100 = 1..19`"] # [doc = " - one: `i = 1 and v = 0`"] fn cardinal_rule_26 (po : & PluralOperands) -> PluralCategory { if (po . v != 0) || (po . n == 0.0) || (po . n != 1.0 && ((1 ..= 19) . contains (& (po . i % 100)) && po . f == 0)) { PluralCategory :: FEW } else if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `mt`."] # [doc = ""] # [doc = " - few: `n = 0 or n % 100 = 2..10`"] # [doc = " - many: `n % 100 = 11..19`"] # [doc = " - one: `n = 1`"] fn cardinal_rule_27 (po : & PluralOperands) -> PluralCategory { if (po . n == 0.0) || (((2 ..= 10) . contains (& (po . i % 100)) && po . f == 0)) { PluralCategory :: FEW } else if (((11 ..= 19) . contains (& (po . i % 100)) && po . f == 0)) { PluralCategory :: MANY } else if (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `pl`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 10 = 2..4 and i % 100 != 12..14`"] # [doc = " - many: `v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 12..14`"] # [doc = " - one: `i = 1 and v = 0`"] fn cardinal_rule_28 (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (2 ..= 4) . contains (& (po . i % 10)) && ! (12 ..= 14) . contains (& (po . i % 100))) { PluralCategory :: FEW } else if (po . v == 0 && po . i != 1 && (0 ..= 1) . contains (& (po . i % 10))) || (po . v == 0 && (5 ..= 9) . contains (& (po . i % 10))) || (po . v == 0 && (12 ..= 14) . contains (& (po . i % 100))) { PluralCategory :: MANY } else if (po . i == 1 && po . v == 0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `pt`."] # [doc = ""] # [doc = " - one: `i = 0..1`"] fn cardinal_rule_29 (po : & PluralOperands) -> PluralCategory { if ((0 ..= 1) . contains (& (po . i))) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `ru`, `uk`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 10 = 2..4 and i % 100 != 12..14`"] # [doc = " - many: `v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14`"] # [doc = " - one: `v = 0 and i % 10 = 1 and i % 100 != 11`"] fn cardinal_rule_30 (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (2 ..= 4) . contains (& (po . i % 10)) && ! (12 ..= 14) . contains (& (po . i % 100))) { PluralCategory :: FEW } else if (po . v == 0 && po . i % 10 == 0) || (po . v == 0 && (5 ..= 9) . contains (& (po . i % 10))) || (po . v == 0 && (11 ..= 14) . contains (& (po . i % 100))) { PluralCategory :: MANY } else if (po . v == 0 && po . i % 10 == 1 && po . i % 100 != 11) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `shi`."] # [doc = ""] # [doc = " - few: `n = 2..10`"] # [doc = " - one: `i = 0 or n = 1`"] fn cardinal_rule_31 (po : & PluralOperands) -> PluralCategory { if (((2 ..= 10) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: FEW } else if (po . i == 0) || (po . n == 1.0) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `si`."] # [doc = ""] # [doc = " - one: `n = 0,1 or i = 0 and f = 1`"] const fn cardinal_rule_32 (po : & PluralOperands) -> PluralCategory { if ((po . n == 0.0 || po . n == 1.0)) || (po . i == 0 && po . f == 1) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `sl`."] # [doc = ""] # [doc = " - few: `v = 0 and i % 100 = 3..4 or v != 0`"] # [doc = " - one: `v = 0 and i % 100 = 1`"] # [doc = " - two: `v = 0 and i % 100 = 2`"] fn cardinal_rule_33 (po : & PluralOperands) -> PluralCategory { if (po . v == 0 && (3 ..= 4) . contains (& (po . i % 100))) || (po . v != 0) { PluralCategory :: FEW } else if (po . v == 0 && po . i % 100 == 1) { PluralCategory :: ONE } else if (po . v == 0 && po . i % 100 == 2) { PluralCategory :: TWO } else { PluralCategory :: OTHER } } # [doc = " The cardinal rules of `tzm`."] # [doc = ""] # [doc = " - one: `n = 0..1 or n = 11..99`"] fn cardinal_rule_34 (po : & PluralOperands) -> PluralCategory { if (((0 ..= 1) . contains (& (po . i)) && po . f == 0)) || (((11 ..= 99) . contains (& (po . i)) && po . f == 0)) { PluralCategory :: ONE } else { PluralCategory :: OTHER } } pub const PRS_CARDINAL : & [(LanguageIdentifier , PluralRule)] = & [(langid ! (subtags :: Language :: from_raw_unchecked (26209u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (27489u64) , None , None , None) , cardinal_rule_1) , (langid ! (subtags :: Language :: from_raw_unchecked (28001u64) , None , None , None) , cardinal_rule_2) , (langid ! (subtags :: Language :: from_raw_unchecked (29281u64) , None , None , None) , cardinal_rule_3) , (langid ! (subtags :: Language :: from_raw_unchecked (7565921u64) , None , None , None) , cardinal_rule_3) , (langid ! (subtags :: Language :: from_raw_unchecked (29537u64) , None , None , None) , cardinal_rule_2) , (langid ! (subtags :: Language :: from_raw_unchecked (6386529u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (7631713u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (31329u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (25954u64) , None , None , None) , cardinal_rule_5) , (langid ! (subtags :: Language :: from_raw_unchecked (7169378u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (8021346u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (26466u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (26722u64) , None , None , None) , cardinal_rule_1) , (langid ! (subtags :: Language :: from_raw_unchecked (28002u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (28258u64) , None , None , None) , cardinal_rule_2) , (langid ! (subtags :: Language :: from_raw_unchecked (28514u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (29282u64) , None , None , None) , cardinal_rule_7) , (langid ! (subtags :: Language :: from_raw_unchecked (7893602u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (29538u64) , None , None , None) , cardinal_rule_8) , (langid ! (subtags :: Language :: from_raw_unchecked (24931u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (25955u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (6776675u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (7497827u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (6450019u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (29539u64) , None , None , None) , cardinal_rule_9) , (langid ! (subtags :: Language :: from_raw_unchecked (31075u64) , None , None , None) , cardinal_rule_10) , (langid ! (subtags :: Language :: from_raw_unchecked (24932u64) , None , None , None) , cardinal_rule_11) , (langid ! (subtags :: Language :: from_raw_unchecked (25956u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (6452068u64) , None , None , None) , cardinal_rule_12) , (langid ! (subtags :: Language :: from_raw_unchecked (30308u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (31332u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (25957u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (27749u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (28261u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (28517u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (29541u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (29797u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (30053u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (24934u64) , None , None , None) , cardinal_rule_2) , (langid ! (subtags :: Language :: from_raw_unchecked (26214u64) , None , None , None) , cardinal_rule_13) , (langid ! (subtags :: Language :: from_raw_unchecked (26982u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (7104870u64) , None , None , None) , cardinal_rule_14) , (langid ! (subtags :: Language :: from_raw_unchecked (28518u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (29286u64) , None , None , None) , cardinal_rule_13) , (langid ! (subtags :: Language :: from_raw_unchecked (7501158u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (31078u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (24935u64) , None , None , None) , cardinal_rule_15) , (langid ! (subtags :: Language :: from_raw_unchecked (25703u64) , None , None , None) , cardinal_rule_16) , (langid ! (subtags :: Language :: from_raw_unchecked (27751u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (7828327u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (30055u64) , None , None , None) , cardinal_rule_2) , (langid ! (subtags :: Language :: from_raw_unchecked (7828839u64) , None , None , None) , cardinal_rule_1) , (langid ! (subtags :: Language :: from_raw_unchecked (30311u64) , None , None , None) , cardinal_rule_17) , (langid ! (subtags :: Language :: from_raw_unchecked (24936u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (7823720u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (25960u64) , None , None , None) , cardinal_rule_18) , (langid ! (subtags :: Language :: from_raw_unchecked (26984u64) , None , None , None) , cardinal_rule_2) , (langid ! (subtags :: Language :: from_raw_unchecked (29288u64) , None , None , None) , cardinal_rule_8) , (langid ! (subtags :: Language :: from_raw_unchecked (6452072u64) , None , None , None) , cardinal_rule_12) , (langid ! (subtags :: Language :: from_raw_unchecked (30056u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (31080u64) , None , None , None) , cardinal_rule_13) , (langid ! (subtags :: Language :: from_raw_unchecked (25705u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (26473u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (26985u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (28265u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (28521u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (29545u64) , None , None , None) , cardinal_rule_19) , (langid ! (subtags :: Language :: from_raw_unchecked (29801u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (30057u64) , None , None , None) , cardinal_rule_20) , (langid ! (subtags :: Language :: from_raw_unchecked (30569u64) , None , None , None) , cardinal_rule_18) , (langid ! (subtags :: Language :: from_raw_unchecked (24938u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (7299690u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (7300970u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (26986u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (6516074u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (30314u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (30570u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (24939u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (6447467u64) , None , None , None) , cardinal_rule_13) , (langid ! (subtags :: Language :: from_raw_unchecked (6971755u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (6775659u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (6644843u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (6382955u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (27499u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (6974315u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (27755u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (28011u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (28267u64) , None , None , None) , cardinal_rule_2) , (langid ! (subtags :: Language :: from_raw_unchecked (28523u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (29547u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (6452075u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (6845291u64) , None , None , None) , cardinal_rule_21) , (langid ! (subtags :: Language :: from_raw_unchecked (30059u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (30571u64) , None , None , None) , cardinal_rule_20) , (langid ! (subtags :: Language :: from_raw_unchecked (31083u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (6775148u64) , None , None , None) , cardinal_rule_22) , (langid ! (subtags :: Language :: from_raw_unchecked (25196u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (26476u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (7629676u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (28268u64) , None , None , None) , cardinal_rule_1) , (langid ! (subtags :: Language :: from_raw_unchecked (28524u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (29804u64) , None , None , None) , cardinal_rule_23) , (langid ! (subtags :: Language :: from_raw_unchecked (30316u64) , None , None , None) , cardinal_rule_24) , (langid ! (subtags :: Language :: from_raw_unchecked (7561581u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (26477u64) , None , None , None) , cardinal_rule_1) , (langid ! (subtags :: Language :: from_raw_unchecked (7300973u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (27501u64) , None , None , None) , cardinal_rule_25) , (langid ! (subtags :: Language :: from_raw_unchecked (27757u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (28269u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (28525u64) , None , None , None) , cardinal_rule_26) , (langid ! (subtags :: Language :: from_raw_unchecked (29293u64) , None , None , None) , cardinal_rule_2) , (langid ! (subtags :: Language :: from_raw_unchecked (29549u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (29805u64) , None , None , None) , cardinal_rule_27) , (langid ! (subtags :: Language :: from_raw_unchecked (31085u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (6840686u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (7430510u64) , None , None , None) , cardinal_rule_20) , (langid ! (subtags :: Language :: from_raw_unchecked (25198u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (25710u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (25966u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (27758u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (28270u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (6844014u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (28526u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (7303534u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (29294u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (7304046u64) , None , None , None) , cardinal_rule_1) , (langid ! (subtags :: Language :: from_raw_unchecked (31086u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (7240046u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (28015u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (29295u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (29551u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (24944u64) , None , None , None) , cardinal_rule_1) , (langid ! (subtags :: Language :: from_raw_unchecked (7364976u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (27760u64) , None , None , None) , cardinal_rule_28) , (langid ! (subtags :: Language :: from_raw_unchecked (6779504u64) , None , None , None) , cardinal_rule_24) , (langid ! (subtags :: Language :: from_raw_unchecked (29552u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (29808u64) , None , None , None) , cardinal_rule_29) , (langid ! (subtags :: Language :: from_raw_unchecked (29808u64) , None , Some (subtags :: Region :: from_raw_unchecked (21584u32)) , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (28018u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (28530u64) , None , None , None) , cardinal_rule_26) , (langid ! (subtags :: Language :: from_raw_unchecked (6713202u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (30066u64) , None , None , None) , cardinal_rule_30) , (langid ! (subtags :: Language :: from_raw_unchecked (7042930u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (6840691u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (7430515u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (7234419u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (25715u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (6841459u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (25971u64) , None , None , None) , cardinal_rule_20) , (langid ! (subtags :: Language :: from_raw_unchecked (6841715u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (7562611u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (26483u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (26739u64) , None , None , None) , cardinal_rule_8) , (langid ! (subtags :: Language :: from_raw_unchecked (6908019u64) , None , None , None) , cardinal_rule_31) , (langid ! (subtags :: Language :: from_raw_unchecked (26995u64) , None , None , None) , cardinal_rule_32) , (langid ! (subtags :: Language :: from_raw_unchecked (27507u64) , None , None , None) , cardinal_rule_9) , (langid ! (subtags :: Language :: from_raw_unchecked (27763u64) , None , None , None) , cardinal_rule_33) , (langid ! (subtags :: Language :: from_raw_unchecked (6385011u64) , None , None , None) , cardinal_rule_20) , (langid ! (subtags :: Language :: from_raw_unchecked (6909299u64) , None , None , None) , cardinal_rule_20) , (langid ! (subtags :: Language :: from_raw_unchecked (6974835u64) , None , None , None) , cardinal_rule_20) , (langid ! (subtags :: Language :: from_raw_unchecked (7236979u64) , None , None , None) , cardinal_rule_20) , (langid ! (subtags :: Language :: from_raw_unchecked (7564659u64) , None , None , None) , cardinal_rule_20) , (langid ! (subtags :: Language :: from_raw_unchecked (28275u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (28531u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (29043u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (29299u64) , None , None , None) , cardinal_rule_8) , (langid ! (subtags :: Language :: from_raw_unchecked (29555u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (7959411u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (29811u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (30323u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (30579u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (7502195u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (24948u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (25972u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (7300468u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (26740u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (26996u64) , None , None , None) , cardinal_rule_1) , (langid ! (subtags :: Language :: from_raw_unchecked (6777204u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (27508u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (27764u64) , None , None , None) , cardinal_rule_14) , (langid ! (subtags :: Language :: from_raw_unchecked (28276u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (28532u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (29300u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (29556u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (7174772u64) , None , None , None) , cardinal_rule_34) , (langid ! (subtags :: Language :: from_raw_unchecked (26485u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (27509u64) , None , None , None) , cardinal_rule_30) , (langid ! (subtags :: Language :: from_raw_unchecked (6581877u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (29301u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (31349u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (25974u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (26998u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (28534u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (7239030u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (24951u64) , None , None , None) , cardinal_rule_1) , (langid ! (subtags :: Language :: from_raw_unchecked (6644087u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (28535u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (26744u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (6778744u64) , None , None , None) , cardinal_rule_0) , (langid ! (subtags :: Language :: from_raw_unchecked (27001u64) , None , None , None) , cardinal_rule_4) , (langid ! (subtags :: Language :: from_raw_unchecked (28537u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (6649209u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (26746u64) , None , None , None) , cardinal_rule_6) , (langid ! (subtags :: Language :: from_raw_unchecked (30074u64) , None , None , None) , cardinal_rule_2)] ; pub const PRS_CARDINAL_CATEGORIES : & [(LanguageIdentifier , & [PluralCategory])] = & [(langid ! (subtags :: Language :: from_raw_unchecked (26209u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27489u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28001u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29281u64) , None , None , None) , & [PluralCategory :: ZERO , PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7565921u64) , None , None , None) , & [PluralCategory :: ZERO , PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29537u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6386529u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7631713u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31329u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25954u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7169378u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (8021346u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26466u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26722u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28002u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28258u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28514u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29282u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7893602u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29538u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24931u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25955u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6776675u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7497827u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6450019u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29539u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31075u64) , None , None , None) , & [PluralCategory :: ZERO , PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24932u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25956u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6452068u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30308u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31332u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25957u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27749u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28261u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28517u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29541u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29797u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30053u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24934u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26214u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26982u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7104870u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28518u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29286u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7501158u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31078u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24935u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25703u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27751u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7828327u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30055u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7828839u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30311u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24936u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7823720u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25960u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26984u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29288u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6452072u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30056u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31080u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25705u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26473u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26985u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28265u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28521u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29545u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29801u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30057u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30569u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24938u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7299690u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7300970u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26986u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6516074u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30314u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30570u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24939u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6447467u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6971755u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6775659u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6644843u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6382955u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27499u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6974315u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27755u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28011u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28267u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28523u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29547u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6452075u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6845291u64) , None , None , None) , & [PluralCategory :: ZERO , PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30059u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30571u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31083u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6775148u64) , None , None , None) , & [PluralCategory :: ZERO , PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25196u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26476u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7629676u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28268u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28524u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29804u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30316u64) , None , None , None) , & [PluralCategory :: ZERO , PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7561581u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26477u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7300973u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27501u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27757u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28269u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28525u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29293u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29549u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29805u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31085u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6840686u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7430510u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25198u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25710u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25966u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27758u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28270u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6844014u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28526u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7303534u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29294u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7304046u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31086u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7240046u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28015u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29295u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29551u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24944u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7364976u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27760u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6779504u64) , None , None , None) , & [PluralCategory :: ZERO , PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29552u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29808u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29808u64) , None , Some (subtags :: Region :: from_raw_unchecked (21584u32)) , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28018u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28530u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6713202u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30066u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7042930u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6840691u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7430515u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7234419u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25715u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6841459u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25971u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6841715u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7562611u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26483u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26739u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6908019u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26995u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27507u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27763u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6385011u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6909299u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6974835u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7236979u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7564659u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: TWO , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28275u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28531u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29043u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29299u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29555u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7959411u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29811u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30323u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30579u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7502195u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24948u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25972u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7300468u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26740u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26996u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6777204u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27508u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27764u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28276u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28532u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29300u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29556u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7174772u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26485u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27509u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: FEW , PluralCategory :: MANY , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6581877u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (29301u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (31349u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (25974u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26998u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28534u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (7239030u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (24951u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6644087u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28535u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26744u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6778744u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (27001u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (28537u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (6649209u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (26746u64) , None , None , None) , & [PluralCategory :: OTHER]) , (langid ! (subtags :: Language :: from_raw_unchecked (30074u64) , None , None , None) , & [PluralCategory :: ONE , PluralCategory :: OTHER])] ; # [doc = r" The number of categories of each locale, at the index of the locale in the rule table."] pub const PRS_CARDINAL_CATEGORY_COUNTS : & [u8] = & [2 , 2 , 2 , 6 , 6 , 2 , 2 , 2 , 2 , 4 , 2 , 2 , 2 , 2 , 1 , 2 , 1 , 5 , 2 , 3 , 2 , 2 , 2 , 2 , 2 , 4 , 6 , 2 , 2 , 4 , 2 , 1 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 5 , 4 , 2 , 2 , 2 , 2 , 5 , 2 , 2 , 4 , 2 , 3 , 4 , 2 , 2 , 1 , 1 , 1 , 1 , 2 , 2 , 2 , 3 , 4 , 1 , 1 , 2 , 2 , 2 , 1 , 1 , 2 , 2 , 2 , 2 , 1 , 1 , 2 , 2 , 2 , 1 , 2 , 1 , 2 , 2 , 3 , 2 , 3 , 2 , 3 , 2 , 2 , 1 , 2 , 1 , 4 , 3 , 2 , 2 , 2 , 2 , 2 , 2 , 3 , 2 , 1 , 4 , 1 , 2 , 3 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 1 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 4 , 3 , 2 , 2 , 2 , 2 , 3 , 2 , 4 , 2 , 1 , 2 , 2 , 2 , 2 , 3 , 2 , 1 , 1 , 3 , 3 , 2 , 4 , 4 , 3 , 3 , 3 , 3 , 3 , 2 , 2 , 2 , 3 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 2 , 1 , 2 , 2 , 2 , 2 , 2 , 1 , 2 , 2 , 2 , 2 , 4 , 1 , 2 , 2 , 2 , 1 , 2 , 2 , 2 , 2 , 1 , 2 , 2 , 2 , 1 , 1 , 1 , 2] ; # [doc = r" The letters of the operands the rules of each locale read, at the index of the locale in the rule table."] pub const PRS_CARDINAL_OPERANDS : & [& str] = & ["n" , "n" , "ni" , "n" , "n" , "ni" , "n" , "iv" , "n" , "n" , "n" , "n" , "n" , "n" , "" , "ni" , "" , "n" , "n" , "ivf" , "iv" , "n" , "n" , "n" , "n" , "iv" , "n" , "nit" , "iv" , "ivf" , "n" , "" , "n" , "n" , "iv" , "n" , "n" , "iv" , "n" , "ni" , "i" , "iv" , "ivf" , "n" , "i" , "n" , "iv" , "n" , "n" , "iv" , "n" , "ni" , "n" , "iv" , "n" , "n" , "niv" , "ni" , "ivf" , "ivf" , "n" , "i" , "" , "" , "" , "" , "iv" , "it" , "iv" , "n" , "niv" , "" , "" , "n" , "iv" , "n" , "" , "" , "n" , "i" , "n" , "n" , "" , "" , "n" , "n" , "n" , "" , "ni" , "" , "n" , "n" , "n" , "n" , "n" , "n" , "ni" , "n" , "n" , "" , "n" , "" , "nf" , "nvf" , "n" , "n" , "n" , "ivf" , "n" , "n" , "niv" , "ni" , "" , "n" , "" , "n" , "n" , "n" , "n" , "n" , "iv" , "n" , "n" , "n" , "" , "n" , "n" , "n" , "n" , "n" , "n" , "n" , "n" , "n" , "iv" , "nvf" , "n" , "i" , "iv" , "n" , "niv" , "n" , "iv" , "n" , "" , "n" , "iv" , "n" , "n" , "n" , "n" , "" , "" , "ivf" , "ni" , "nif" , "iv" , "iv" , "n" , "n" , "n" , "n" , "n" , "n" , "n" , "n" , "ivf" , "n" , "n" , "n" , "iv" , "iv" , "n" , "n" , "n" , "n" , "" , "n" , "n" , "n" , "ivf" , "n" , "" , "n" , "n" , "n" , "n" , "iv" , "" , "iv" , "n" , "n" , "" , "n" , "n" , "n" , "n" , "" , "n" , "n" , "iv" , "" , "" , "" , "ni"] ; pub const CARDINAL_LOCALE_COUNT : usize = 205 ; pub fn select_cardinal (lang : & LanguageIdentifier) -> Option < PluralRule > { PRS_CARDINAL . binary_search_by (| (l , _) | l . cmp (lang)) . ok () . map (| idx | PRS_CARDINAL [idx] . 1) } pub fn cardinal_map () -> std :: collections :: HashMap < LanguageIdentifier , PluralRule > { static MAP : std :: sync :: OnceLock < std :: collections :: HashMap < LanguageIdentifier , PluralRule >> = std :: sync :: OnceLock :: new () ; MAP . get_or_init (|| PRS_CARDINAL . iter () . cloned () . collect ()) . clone () } pub const PRS_CARDINAL_SOURCES : & [(LanguageIdentifier , & [(PluralCategory , & str)])] = & [(langid ! (subtags :: Language :: from_raw_unchecked (26209u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (27489u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 0..1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28001u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 0 or n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29281u64) , None , None , None) , & [(PluralCategory :: FEW , "n % 100 = 3..10") , (PluralCategory :: MANY , "n % 100 = 11..99") , (PluralCategory :: ONE , "n = 1") , (PluralCategory :: TWO , "n = 2") , (PluralCategory :: ZERO , "n = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7565921u64) , None , None , None) , & [(PluralCategory :: FEW , "n % 100 = 3..10") , (PluralCategory :: MANY , "n % 100 = 11..99") , (PluralCategory :: ONE , "n = 1") , (PluralCategory :: TWO , "n = 2") , (PluralCategory :: ZERO , "n = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29537u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 0 or n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6386529u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7631713u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (31329u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (25954u64) , None , None , None) , & [(PluralCategory :: FEW , "n % 10 = 2..4 and n % 100 != 12..14") , (PluralCategory :: MANY , "n % 10 = 0 or n % 10 = 5..9 or n % 100 = 11..14") , (PluralCategory :: ONE , "n % 10 = 1 and n % 100 != 11")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7169378u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (8021346u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (26466u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (26722u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 0..1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28002u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (28258u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 0 or n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28514u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (29282u64) , None , None , None) , & [(PluralCategory :: FEW , "n % 10 = 3..4,9 and n % 100 != 10..19,70..79,90..99") , (PluralCategory :: MANY , "n != 0 and n % 1000000 = 0") , (PluralCategory :: ONE , "n % 10 = 1 and n % 100 != 11,71,91") , (PluralCategory :: TWO , "n % 10 = 2 and n % 100 != 12,72,92")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7893602u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29538u64) , None , None , None) , & [(PluralCategory :: FEW , "v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14") , (PluralCategory :: ONE , "v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11")]) , (langid ! (subtags :: Language :: from_raw_unchecked (24931u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (25955u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6776675u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7497827u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6450019u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29539u64) , None , None , None) , & [(PluralCategory :: FEW , "i = 2..4 and v = 0") , (PluralCategory :: MANY , "v != 0") , (PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (31075u64) , None , None , None) , & [(PluralCategory :: FEW , "n = 3") , (PluralCategory :: MANY , "n = 6") , (PluralCategory :: ONE , "n = 1") , (PluralCategory :: TWO , "n = 2") , (PluralCategory :: ZERO , "n = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (24932u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1 or t != 0 and i = 0,1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (25956u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6452068u64) , None , None , None) , & [(PluralCategory :: FEW , "v = 0 and i % 100 = 3..4 or f % 100 = 3..4") , (PluralCategory :: ONE , "v = 0 and i % 100 = 1 or f % 100 = 1") , (PluralCategory :: TWO , "v = 0 and i % 100 = 2 or f % 100 = 2")]) , (langid ! (subtags :: Language :: from_raw_unchecked (30308u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (31332u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (25957u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (27749u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28261u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28517u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29541u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29797u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (30053u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (24934u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 0 or n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (26214u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 0,1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (26982u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7104870u64) , None , None , None) , & [(PluralCategory :: ONE , "v = 0 and i = 1,2,3 or v = 0 and i % 10 != 4,6,9 or v != 0 and f % 10 != 4,6,9")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28518u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29286u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 0,1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7501158u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (31078u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (24935u64) , None , None , None) , & [(PluralCategory :: FEW , "n = 3..6") , (PluralCategory :: MANY , "n = 7..10") , (PluralCategory :: ONE , "n = 1") , (PluralCategory :: TWO , "n = 2")]) , (langid ! (subtags :: Language :: from_raw_unchecked (25703u64) , None , None , None) , & [(PluralCategory :: FEW , "n = 3..10,13..19") , (PluralCategory :: ONE , "n = 1,11") , (PluralCategory :: TWO , "n = 2,12")]) , (langid ! (subtags :: Language :: from_raw_unchecked (27751u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7828327u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (30055u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 0 or n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7828839u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 0..1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (30311u64) , None , None , None) , & [(PluralCategory :: FEW , "v = 0 and i % 100 = 0,20,40,60,80") , (PluralCategory :: MANY , "v != 0") , (PluralCategory :: ONE , "v = 0 and i % 10 = 1") , (PluralCategory :: TWO , "v = 0 and i % 10 = 2")]) , (langid ! (subtags :: Language :: from_raw_unchecked (24936u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7823720u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (25960u64) , None , None , None) , & [(PluralCategory :: MANY , "v = 0 and n != 0..10 and n % 10 = 0") , (PluralCategory :: ONE , "i = 1 and v = 0") , (PluralCategory :: TWO , "i = 2 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (26984u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 0 or n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29288u64) , None , None , None) , & [(PluralCategory :: FEW , "v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14") , (PluralCategory :: ONE , "v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6452072u64) , None , None , None) , & [(PluralCategory :: FEW , "v = 0 and i % 100 = 3..4 or f % 100 = 3..4") , (PluralCategory :: ONE , "v = 0 and i % 100 = 1 or f % 100 = 1") , (PluralCategory :: TWO , "v = 0 and i % 100 = 2 or f % 100 = 2")]) , (langid ! (subtags :: Language :: from_raw_unchecked (30056u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (31080u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 0,1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (25705u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (26473u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (26985u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (28265u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (28521u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29545u64) , None , None , None) , & [(PluralCategory :: ONE , "t = 0 and i % 10 = 1 and i % 100 != 11 or t != 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29801u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (30057u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1") , (PluralCategory :: TWO , "n = 2")]) , (langid ! (subtags :: Language :: from_raw_unchecked (30569u64) , None , None , None) , & [(PluralCategory :: MANY , "v = 0 and n != 0..10 and n % 10 = 0") , (PluralCategory :: ONE , "i = 1 and v = 0") , (PluralCategory :: TWO , "i = 2 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (24938u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (7299690u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (7300970u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (26986u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6516074u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (30314u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (30570u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (24939u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6447467u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 0,1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6971755u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6775659u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6644843u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (6382955u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (27499u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6974315u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (27755u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28011u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (28267u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 0 or n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28523u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (29547u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6452075u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6845291u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1") , (PluralCategory :: ZERO , "n = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (30059u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (30571u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1") , (PluralCategory :: TWO , "n = 2")]) , (langid ! (subtags :: Language :: from_raw_unchecked (31083u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6775148u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 0,1 and n != 0") , (PluralCategory :: ZERO , "n = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (25196u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (26476u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7629676u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (28268u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 0..1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28524u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (29804u64) , None , None , None) , & [(PluralCategory :: FEW , "n % 10 = 2..9 and n % 100 != 11..19") , (PluralCategory :: MANY , "f != 0") , (PluralCategory :: ONE , "n % 10 = 1 and n % 100 != 11..19")]) , (langid ! (subtags :: Language :: from_raw_unchecked (30316u64) , None , None , None) , & [(PluralCategory :: ONE , "n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 or v != 2 and f % 10 = 1") , (PluralCategory :: ZERO , "n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7561581u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (26477u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 0..1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7300973u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (27501u64) , None , None , None) , & [(PluralCategory :: ONE , "v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11")]) , (langid ! (subtags :: Language :: from_raw_unchecked (27757u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28269u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28525u64) , None , None , None) , & [(PluralCategory :: FEW , "v != 0 or n = 0 or n != 1 and n % 100 = 1..19") , (PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29293u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 0 or n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29549u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (29805u64) , None , None , None) , & [(PluralCategory :: FEW , "n = 0 or n % 100 = 2..10") , (PluralCategory :: MANY , "n % 100 = 11..19") , (PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (31085u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (6840686u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7430510u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1") , (PluralCategory :: TWO , "n = 2")]) , (langid ! (subtags :: Language :: from_raw_unchecked (25198u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (25710u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (25966u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (27758u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28270u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6844014u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28526u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7303534u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (29294u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7304046u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 0..1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (31086u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7240046u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28015u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29295u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29551u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (24944u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 0..1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7364976u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (27760u64) , None , None , None) , & [(PluralCategory :: FEW , "v = 0 and i % 10 = 2..4 and i % 100 != 12..14") , (PluralCategory :: MANY , "v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 12..14") , (PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6779504u64) , None , None , None) , & [(PluralCategory :: ONE , "n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 or v != 2 and f % 10 = 1") , (PluralCategory :: ZERO , "n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29552u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29808u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 0..1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29808u64) , None , Some (subtags :: Region :: from_raw_unchecked (21584u32)) , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28018u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28530u64) , None , None , None) , & [(PluralCategory :: FEW , "v != 0 or n = 0 or n != 1 and n % 100 = 1..19") , (PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6713202u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (30066u64) , None , None , None) , & [(PluralCategory :: FEW , "v = 0 and i % 10 = 2..4 and i % 100 != 12..14") , (PluralCategory :: MANY , "v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14") , (PluralCategory :: ONE , "v = 0 and i % 10 = 1 and i % 100 != 11")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7042930u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6840691u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (7430515u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7234419u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (25715u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6841459u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (25971u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1") , (PluralCategory :: TWO , "n = 2")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6841715u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7562611u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (26483u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (26739u64) , None , None , None) , & [(PluralCategory :: FEW , "v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14") , (PluralCategory :: ONE , "v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6908019u64) , None , None , None) , & [(PluralCategory :: FEW , "n = 2..10") , (PluralCategory :: ONE , "i = 0 or n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (26995u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 0,1 or i = 0 and f = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (27507u64) , None , None , None) , & [(PluralCategory :: FEW , "i = 2..4 and v = 0") , (PluralCategory :: MANY , "v != 0") , (PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (27763u64) , None , None , None) , & [(PluralCategory :: FEW , "v = 0 and i % 100 = 3..4 or v != 0") , (PluralCategory :: ONE , "v = 0 and i % 100 = 1") , (PluralCategory :: TWO , "v = 0 and i % 100 = 2")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6385011u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1") , (PluralCategory :: TWO , "n = 2")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6909299u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1") , (PluralCategory :: TWO , "n = 2")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6974835u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1") , (PluralCategory :: TWO , "n = 2")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7236979u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1") , (PluralCategory :: TWO , "n = 2")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7564659u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1") , (PluralCategory :: TWO , "n = 2")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28275u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28531u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29043u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29299u64) , None , None , None) , & [(PluralCategory :: FEW , "v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14") , (PluralCategory :: ONE , "v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29555u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7959411u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29811u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (30323u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (30579u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7502195u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (24948u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (25972u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7300468u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (26740u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (26996u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 0..1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6777204u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (27508u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (27764u64) , None , None , None) , & [(PluralCategory :: ONE , "v = 0 and i = 1,2,3 or v = 0 and i % 10 != 4,6,9 or v != 0 and f % 10 != 4,6,9")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28276u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28532u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (29300u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (29556u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7174772u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 0..1 or n = 11..99")]) , (langid ! (subtags :: Language :: from_raw_unchecked (26485u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (27509u64) , None , None , None) , & [(PluralCategory :: FEW , "v = 0 and i % 10 = 2..4 and i % 100 != 12..14") , (PluralCategory :: MANY , "v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14") , (PluralCategory :: ONE , "v = 0 and i % 10 = 1 and i % 100 != 11")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6581877u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (29301u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (31349u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (25974u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (26998u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (28534u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (7239030u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (24951u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 0..1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6644087u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28535u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (26744u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (6778744u64) , None , None , None) , & [(PluralCategory :: ONE , "n = 1")]) , (langid ! (subtags :: Language :: from_raw_unchecked (27001u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 1 and v = 0")]) , (langid ! (subtags :: Language :: from_raw_unchecked (28537u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (6649209u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (26746u64) , None , None , None) , & []) , (langid ! (subtags :: Language :: from_raw_unchecked (30074u64) , None , None , None) , & [(PluralCategory :: ONE , "i = 0 or n = 1")])] ; pub fn select (lang : & LanguageIdentifier , po : & PluralOperands , ty : PluralRuleType ,) -> PluralCategory { # [allow (unreachable_patterns)] let select_rule : fn (& LanguageIdentifier) -> Option < PluralRule > = match ty { PluralRuleType :: CARDINAL => select_cardinal , _ => return PluralCategory :: OTHER , } ; let rule = select_rule (lang) . or_else (|| select_rule (& LanguageIdentifier :: from_parts (lang . language , None , None , & []))) . or_else (|| select_rule (& langid ! (subtags :: Language :: from_raw_unchecked (6581877u64) , None , None , None))) ; match rule { Some (rule) => rule (po) , None => PluralCategory :: OTHER , } } pub fn supported_locales (ty : PluralRuleType ,) -> impl Iterator < Item = & 'static LanguageIdentifier > { # [allow (unreachable_patterns)] let table : & 'static [(LanguageIdentifier , PluralRule)] = match ty { PluralRuleType :: CARDINAL => PRS_CARDINAL , _ => & [] , } ; table . iter () . map (| (lang , _) | lang) } pub fn rules (ty : PluralRuleType ,) -> impl Iterator < Item = (& 'static LanguageIdentifier , PluralRule) > { # [allow (unreachable_patterns)] let table : & 'static [(LanguageIdentifier , PluralRule)] = match ty { PluralRuleType :: CARDINAL => PRS_CARDINAL , _ => & [] , } ; table . iter () . map (| (lang , rule) | (lang , * rule)) } pub fn find (ty : PluralRuleType , lang : & LanguageIdentifier) -> Option < PluralRule > { # [allow (unreachable_patterns)] let select_rule : fn (& LanguageIdentifier) -> Option < PluralRule > = match ty { PluralRuleType :: CARDINAL => select_cardinal , _ => return None , } ; select_rule (lang) } pub fn category_count (lang : & LanguageIdentifier , ty : PluralRuleType) -> usize { # [allow (unreachable_patterns)] let (table , counts) : (& [(LanguageIdentifier , PluralRule)] , & [u8]) = match ty { PluralRuleType :: CARDINAL => (PRS_CARDINAL , PRS_CARDINAL_CATEGORY_COUNTS) , _ => return 1 , } ; let index = | lang : & LanguageIdentifier | table . binary_search_by (| (l , _) | l . cmp (lang)) . ok () ; let idx = index (lang) . or_else (|| index (& LanguageIdentifier :: from_parts (lang . language , None , None , & []))) . or_else (|| index (& langid ! (subtags :: Language :: from_raw_unchecked (6581877u64) , None , None , None))) ; idx . map_or (1 , | idx | counts [idx] as usize) } pub fn rule_source (lang : & LanguageIdentifier , ty : PluralRuleType , category : PluralCategory ,) -> Option < & 'static str > { # [allow (unreachable_patterns)] let table = match ty { PluralRuleType :: CARDINAL => PRS_CARDINAL_SOURCES , _ => return None , } ; let idx = table . binary_search_by_key (& lang , | (l , _) | l) . ok () ? ; table [idx] . 1 . iter () . find (| (c , _) | * c == category) . map (| (_ , source) | * source) } # [cfg (test)] mod langid_tests { use super :: * ; # [test] fn cardinal_langids () { for (lang , _) in PRS_CARDINAL . iter () { let source = std :: string :: ToString :: to_string (lang) ; if source == "und" { continue ; } assert_eq ! (source . parse :: < LanguageIdentifier > () . ok () . as_ref () , Some (lang) , "`{}`" , source) ; } } }
\ No newline at end of file
@@ -0,0 +1 @@
+# ! [doc = r" Plural operands in compliance with [CLDR Plural Rules](https://unicode.org/reports/tr35/tr35-numbers.html#Operands)."] # ! [allow (clippy :: unnecessary_cast , clippy :: cast_lossless)] use std :: convert :: TryFrom ; use std :: fmt ; use std :: str :: FromStr ; # [doc = r" The plural operands of the absolute value of a number."] # [derive (Debug , Clone , PartialEq)] pub struct PluralOperands { # [doc = r" Absolute value of input"] pub n : f64 , # [doc = r" Integer value of the absolute value of input"] pub i : u64 , # [doc = r" Number of visible fraction digits with trailing zeros"] pub v : usize , # [doc = r" Number of visible fraction digits without trailing zeros"] pub w : usize , # [doc = r" Visible fraction digits with trailing zeros"] pub f : u64 , # [doc = r" Visible fraction digits without trailing zeros"] pub t : u64 , } impl < 'a > TryFrom < & 'a str > for PluralOperands { type Error = & 'static str ; fn try_from (input : & 'a str) -> Result < Self , Self :: Error > { let abs_str = input . strip_prefix (['-' , '+']) . unwrap_or (input) ; if abs_str . starts_with (['-' , '+']) { return Err ("Incorrect number passed!") ; } let absolute_value = f64 :: from_str (abs_str) . map_err (| _ | "Incorrect number passed!") ? ; let Some ((int_str , dec_str)) = abs_str . split_once ('.') else { let i = u64 :: from_str (abs_str) . unwrap_or (absolute_value as u64) ; return Ok (PluralOperands { n : i as f64 , i , v : 0 , w : 0 , f : 0 , t : 0 }) ; } ; let i = u64 :: from_str (int_str) . map_err (| _ | "Could not convert string to integer!") ? ; let backtrace = dec_str . trim_end_matches ('0') ; Ok (PluralOperands { n : if dec_str . is_empty () { i as f64 } else { absolute_value } , i , v : dec_str . len () , w : backtrace . len () , f : u64 :: from_str (dec_str) . map_err (| _ | "Could not convert string to integer!") ? , t : u64 :: from_str (backtrace) . unwrap_or (0) , }) } } # [doc = r#" Parses the operands of a decimal string, such as `"-1234.560"`, keeping its visible fraction digits."#] impl FromStr for PluralOperands { type Err = & 'static str ; fn from_str (input : & str) -> Result < Self , Self :: Err > { PluralOperands :: try_from (input) } } # [doc = r#" Writes the decimal string of the operands, such as `"1.50"`, which `from_str` parses back."#] impl fmt :: Display for PluralOperands { fn fmt (& self , f : & mut fmt :: Formatter < '_ >) -> fmt :: Result { write ! (f , "{}" , self . i) ? ; if self . v > 0 { write ! (f , ".{:0width$}" , self . f , width = self . v) ? ; } Ok (()) } } impl From < u8 > for PluralOperands { fn from (input : u8) -> Self { PluralOperands { n : input as f64 , i : input as u64 , v : 0 , w : 0 , f : 0 , t : 0 } } } impl From < u16 > for PluralOperands { fn from (input : u16) -> Self { PluralOperands { n : input as f64 , i : input as u64 , v : 0 , w : 0 , f : 0 , t : 0 } } } impl From < u32 > for PluralOperands { fn from (input : u32) -> Self { PluralOperands { n : input as f64 , i : input as u64 , v : 0 , w : 0 , f : 0 , t : 0 } } } impl From < u64 > for PluralOperands { fn from (input : u64) -> Self { PluralOperands { n : input as f64 , i : input as u64 , v : 0 , w : 0 , f : 0 , t : 0 } } } impl From < usize > for PluralOperands { fn from (input : usize) -> Self { PluralOperands { n : input as f64 , i : input as u64 , v : 0 , w : 0 , f : 0 , t : 0 } } } impl From < i8 > for PluralOperands { fn from (input : i8) -> Self { PluralOperands :: from (input . unsigned_abs () as u64) } } impl From < i16 > for PluralOperands { fn from (input : i16) -> Self { PluralOperands :: from (input . unsigned_abs () as u64) } } impl From < i32 > for PluralOperands { fn from (input : i32) -> Self { PluralOperands :: from (input . unsigned_abs () as u64) } } impl From < i64 > for PluralOperands { fn from (input : i64) -> Self { PluralOperands :: from (input . unsigned_abs () as u64) } } impl From < isize > for PluralOperands { fn from (input : isize) -> Self { PluralOperands :: from (input . unsigned_abs () as u64) } } # [doc = r" The operands of a float are those of its shortest decimal representation."] impl From < f32 > for PluralOperands { fn from (input : f32) -> Self { let abs = input . abs () ; if ! abs . is_finite () || abs . fract () == 0.0 { return PluralOperands { n : abs as f64 , i : abs as u64 , v : 0 , w : 0 , f : 0 , t : 0 } ; } PluralOperands :: try_from (abs . to_string () . as_str ()) . expect ("A finite float displays as a decimal number") } } # [doc = r" The operands of a float are those of its shortest decimal representation."] impl From < f64 > for PluralOperands { fn from (input : f64) -> Self { let abs = input . abs () ; if ! abs . is_finite () || abs . fract () == 0.0 { return PluralOperands { n : abs as f64 , i : abs as u64 , v : 0 , w : 0 , f : 0 , t : 0 } ; } PluralOperands :: try_from (abs . to_string () . as_str ()) . expect ("A finite float displays as a decimal number") } } impl TryFrom < String > for PluralOperands { type Error = & 'static str ; fn try_from (input : String) -> Result < Self , Self :: Error > { PluralOperands :: try_from (input . as_str ()) } }
\ No newline at end of file
@@ -0,0 +1,103 @@
+#![cfg(feature = "icu")]
+
+use make_pluralrules::{GenerateError, generate_rs, icu_to_cldr_json, parse_dataset};
+use std::fs;
+use std::process::Command;
+
+fn read_file(path: &str) -> String {
+    fs::read_to_string(path).expect("Could not read the fixture")
+}
+
+#[test]
+fn icu_rules_match_json() {
+    let icu = read_file("./tests/fixtures/cldr_pluralrules_icu_test.txt");
+    let json = read_file("./tests/fixtures/cldr_pluralrules_samples_test.json");
+
+    let converted = icu_to_cldr_json(&icu).expect("The ICU rules are valid");
+    let icu_dataset = parse_dataset(std::slice::from_ref(&converted)).expect("Parsing succeeded");
+    let json_dataset = parse_dataset(std::slice::from_ref(&json)).expect("Parsing succeeded");
+    assert_eq!(icu_dataset.len(), 3);
+    assert_eq!(icu_dataset, json_dataset);
+    // ICU's text files usually start with a byte order mark.
+    assert_eq!(
+        icu_to_cldr_json(&format!("\u{FEFF}{}", icu)).as_ref().ok(),
+        Some(&converted)
+    );
+    assert_eq!(
+        generate_rs(&[converted]).expect("Generation succeeded"),
+        generate_rs(&[json]).expect("Generation succeeded")
+    );
+}
+
+#[test]
+fn icu_input_format() {
+    let generate = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+            .args(args)
+            .args(["-o", "-"])
+            .output()
+            .expect("The generator runs");
+        assert!(output.status.success(), "{:?}", output);
+        output.stdout
+    };
+
+    let json = generate(&["-i", "./tests/fixtures/cldr_pluralrules_samples_test.json"]);
+    // `*.txt` inputs are detected as ICU text.
+    let icu = generate(&["-i", "./tests/fixtures/cldr_pluralrules_icu_test.txt"]);
+    assert_eq!(icu, json);
+
+    // Other inputs are read as ICU text with `--format icu`.
+    let stdin = Command::new(env!("CARGO_BIN_EXE_make_pluralrules"))
+        .args(["--format", "icu", "-o", "-"])
+        .stdin(fs::File::open("./tests/fixtures/cldr_pluralrules_icu_test.txt").unwrap())
+        .output()
+        .expect("The generator runs");
+    assert!(stdin.status.success());
+    assert_eq!(stdin.stdout, json);
+}
+
+#[test]
+fn invalid_icu_rules() {
+    let convert = |body: &str| icu_to_cldr_json(&format!("plurals{{Version{{\"33\"}}{}}}", body));
+
+    assert!(matches!(
+        convert("cardinal{xx{\"one: n = 1; several: n = 2\"}}"),
+        Err(GenerateError::UnknownRuleName { rule_name, .. }) if rule_name == "pluralRule-count-several"
+    ));
+    assert!(matches!(
+        convert("cardinal{xx{\"one: n = \"}}"),
+        Err(GenerateError::InvalidRule { rule_name, .. }) if rule_name == "pluralRule-count-one"
+    ));
+    for (body, message) in [
+        (
+            "cardinal{xx{\"n = 1\"}}",
+            "invalid ICU plural rules: the rule `n = 1` of `xx` has no category keyword",
+        ),
+        (
+            "cardinal{xx{\"one: n = 1\"}",
+            "invalid ICU plural rules: expected `}` at line 1",
+        ),
+        (
+            "cardinal{xx{\"one: n = 1}}",
+            "invalid ICU plural rules: unterminated string at line 1",
+        ),
+        (
+            "cardinal{xx{cardinal{}}}",
+            "invalid ICU plural rules: the rules of `xx` are not a string",
+        ),
+    ] {
+        assert_eq!(convert(body).unwrap_err().to_string(), message, "{}", body);
+    }
+    assert_eq!(
+        icu_to_cldr_json("plurals{cardinal{xx{\"other:\"}}}")
+            .unwrap_err()
+            .to_string(),
+        "invalid ICU plural rules: missing `Version`"
+    );
+    assert_eq!(
+        icu_to_cldr_json("plurals{}\nordinals{}")
+            .unwrap_err()
+            .to_string(),
+        "invalid ICU plural rules: the input is not a single table"
+    );
+}
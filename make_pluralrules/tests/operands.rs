@@ -0,0 +1,80 @@
+//! The standalone `PluralOperands` type of `--emit-operands`, compiled on its own.
+
+#[rustfmt::skip]
+#[path = "fixtures/operands.rs"]
+mod operands;
+
+use intl_pluralrules::operands::PluralOperands as RuntimeOperands;
+use make_pluralrules::generate_operands_rs;
+use operands::PluralOperands;
+use std::convert::TryFrom;
+
+fn as_tuple(po: &PluralOperands) -> (f64, u64, usize, usize, u64, u64) {
+    (po.n, po.i, po.v, po.w, po.f, po.t)
+}
+
+#[test]
+fn operands_fixture() {
+    let fixture = std::fs::read_to_string("./tests/fixtures/operands.rs")
+        .expect("Could not read the fixture");
+    assert_eq!(generate_operands_rs(), fixture.trim_end());
+}
+
+#[test]
+fn standalone_operands_match_runtime() {
+    for input in [
+        "0",
+        "1",
+        "-1",
+        "+2",
+        "1.0",
+        "1.50",
+        "-1234.560",
+        "0.05",
+        "18446744073709551615",
+        "",
+        "1.",
+        "--1",
+        "one",
+    ] {
+        let standalone = input.parse::<PluralOperands>();
+        let runtime = input.parse::<RuntimeOperands>();
+        assert_eq!(
+            standalone.as_ref().map(as_tuple),
+            runtime.as_ref().map(RuntimeOperands::as_tuple),
+            "`{}`",
+            input
+        );
+        assert_eq!(
+            standalone.map(|po| po.to_string()),
+            runtime.map(|po| po.to_string()),
+            "`{}`",
+            input
+        );
+    }
+
+    assert_eq!(
+        as_tuple(&PluralOperands::from(-2_i32)),
+        RuntimeOperands::from(-2_i32).as_tuple()
+    );
+    assert_eq!(
+        as_tuple(&PluralOperands::from(u64::MAX)),
+        RuntimeOperands::from(u64::MAX).as_tuple()
+    );
+    for float in [2.5, 0.1 + 0.2, -1.5, 3.0, f64::NAN, f64::INFINITY] {
+        assert_eq!(
+            format!("{:?}", as_tuple(&PluralOperands::from(float))),
+            format!("{:?}", RuntimeOperands::from(float).as_tuple()),
+            "`{}`",
+            float
+        );
+    }
+    assert_eq!(
+        as_tuple(&PluralOperands::from(1.5_f32)),
+        RuntimeOperands::from(1.5_f32).as_tuple()
+    );
+    assert_eq!(
+        PluralOperands::try_from("2.50".to_string()).map(|po| as_tuple(&po)),
+        Ok((2.5, 2, 2, 1, 50, 5))
+    );
+}
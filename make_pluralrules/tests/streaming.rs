@@ -0,0 +1,85 @@
+//! Peak memory use of the streaming JSON parser path, measured with a counting global allocator.
+//!
+//! These tests live in their own binary, as the allocator counts the allocations of every test of the binary.
+
+use make_pluralrules::{GenerateOptions, generate_rs_from_readers, generate_rs_with};
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs;
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            PEAK.fetch_max(allocated, Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the result of `f`, along with the peak number of bytes it allocated on top of those already allocated.
+fn peak_allocated<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let baseline = ALLOCATED.load(Ordering::SeqCst);
+    PEAK.store(baseline, Ordering::SeqCst);
+    let result = f();
+    (result, PEAK.load(Ordering::SeqCst) - baseline)
+}
+
+/// Writes the CLDR 33 cardinals, along with a supplemental section of several megabytes of unrelated data.
+fn write_large_input() -> PathBuf {
+    let cardinals = fs::read_to_string("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+        .expect("Could not read the cardinals fixture");
+    let filler: Vec<String> = (0..200_000)
+        .map(|i| format!("unrelated supplemental data #{:06}", i))
+        .collect();
+    let filler = serde_json::to_string(&filler).unwrap();
+    let large = cardinals.replacen(
+        "\"plurals-type-cardinal\"",
+        &format!("\"weekData\": {},\n    \"plurals-type-cardinal\"", filler),
+        1,
+    );
+    assert!(large.len() > 4_000_000);
+
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("streaming_large_input.json");
+    fs::write(&path, large).expect("Could not write the large input");
+    path
+}
+
+#[test]
+fn streaming_peak_memory() {
+    let path = write_large_input();
+    let options = GenerateOptions::default();
+
+    let (from_string, string_peak) = peak_allocated(|| {
+        let body = fs::read_to_string(&path).unwrap();
+        generate_rs_with(&[body], &options).unwrap()
+    });
+    let (from_reader, reader_peak) = peak_allocated(|| {
+        generate_rs_from_readers([File::open(&path).unwrap()], &options).unwrap()
+    });
+
+    assert_eq!(from_reader, from_string);
+    assert!(
+        reader_peak * 2 < string_peak,
+        "streaming peak of {} bytes is not below half of the {} bytes of the string path",
+        reader_peak,
+        string_peak
+    );
+}
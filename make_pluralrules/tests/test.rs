@@ -1,8 +1,92 @@
-use make_pluralrules::generate_rs;
+use make_pluralrules::{
+    Emit, GenerateError, GenerateOptions, Input, RuleChangeKind, contains_plural_rules,
+    diff_datasets, expand_inputs, generate_all, generate_rs, generate_rs_from_readers,
+    generate_rs_if_changed, generate_rs_with, generate_versioned_rs, parse_dataset, pretty_print,
+    size_report, unmatched_locales,
+};
 
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
 use std::fs::File;
 use std::io;
 use std::io::Read;
+use std::path::PathBuf;
+
+use cldr_pluralrules_parser::PluralRuleParseError;
+use cldr_pluralrules_parser::ast::Operand;
+use intl_pluralrules::operands::PluralOperands;
+use intl_pluralrules::test_util::assert_selects;
+use intl_pluralrules::{PluralCategory, PluralRuleType, operands};
+use unic_langid::LanguageIdentifier;
+
+#[rustfmt::skip]
+#[path = "fixtures/cldr_pluralrules_33.rs"]
+mod cldr_33;
+
+#[rustfmt::skip]
+#[path = "fixtures/cldr_pluralrules_33_exported.rs"]
+mod cldr_33_exported;
+
+#[rustfmt::skip]
+#[path = "fixtures/cldr_pluralrules_33_match.rs"]
+mod cldr_33_match;
+
+#[rustfmt::skip]
+#[path = "fixtures/cldr_pluralrules_33_phf.rs"]
+mod cldr_33_phf;
+
+#[rustfmt::skip]
+#[path = "fixtures/cldr_pluralrules_33_minimal_pairs.rs"]
+mod cldr_33_minimal_pairs;
+
+#[rustfmt::skip]
+#[path = "fixtures/cldr_pluralrules_33_sources.rs"]
+mod cldr_33_sources;
+
+#[rustfmt::skip]
+#[path = "fixtures/cldr_pluralrules_samples_test.rs"]
+mod samples_test;
+
+#[rustfmt::skip]
+#[path = "fixtures/cldr_pluralrules_within_test_dyn.rs"]
+mod within_test_dyn;
+
+#[rustfmt::skip]
+#[path = "fixtures/cldr_pluralrules_modules_test.rs"]
+mod modules_test;
+
+#[rustfmt::skip]
+#[path = "fixtures/cldr_pluralrules_overrides_test.rs"]
+mod overrides_test;
+
+#[rustfmt::skip]
+#[path = "fixtures/cldr_pluralrules_versions_test.rs"]
+mod versions_test;
+
+// Without an accessor, the `e` operand is generated as `0`, which makes `e = 0` read `0 == 0`.
+#[rustfmt::skip]
+#[allow(clippy::eq_op)]
+#[path = "fixtures/cldr_pluralrules_compact_test.rs"]
+mod compact_test;
+
+#[rustfmt::skip]
+#[path = "fixtures/cldr_pluralrules_within_test_custom_operands.rs"]
+mod within_test_custom_operands;
+
+/// An operands type exposing the operands through methods, targeted by `within_test_custom_operands`.
+mod custom_operands {
+    pub struct Operands(pub f64);
+
+    impl Operands {
+        pub fn value(&self) -> f64 {
+            self.0.abs()
+        }
+
+        pub fn integer_part(&self) -> u64 {
+            self.0.abs().trunc() as u64
+        }
+    }
+}
 
 fn read_file(path: &str) -> Result<String, io::Error> {
     let mut f = File::open(path)?;
@@ -12,79 +96,1837 @@ fn read_file(path: &str) -> Result<String, io::Error> {
 }
 
 #[test]
-fn full_cldr_test() {
+fn full_cldr_test() {
+    let cardinal_json = read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+        .expect("Could not read input json");
+    let ordinal_json = read_file("./tests/fixtures/cldr_pluralrules_ordinals_33.json")
+        .expect("Could not read input json");
+    let output_rs =
+        read_file("./tests/fixtures/cldr_pluralrules_33.rs").expect("Could not read output rs");
+
+    let output = generate_rs(&[cardinal_json, ordinal_json]).expect("Generation succeeded");
+
+    assert_eq!(output_rs, output);
+}
+
+#[test]
+fn locale_count_test() {
+    assert_eq!(cldr_33::CARDINAL_LOCALE_COUNT, cldr_33::PRS_CARDINAL.len());
+    assert_eq!(cldr_33::ORDINAL_LOCALE_COUNT, cldr_33::PRS_ORDINAL.len());
+    // Including the root rules, listed as `und`.
+    assert_eq!(cldr_33::CARDINAL_LOCALE_COUNT, 205);
+    assert_eq!(cldr_33::ORDINAL_LOCALE_COUNT, 93);
+    assert_eq!(cldr_33::CLDR_VERSION, "33");
+
+    assert_eq!(within_test_dyn::CARDINAL_LOCALE_COUNT, 1);
+}
+
+#[test]
+fn supported_locales_test() {
+    let cardinal: Vec<_> = cldr_33::supported_locales(PluralRuleType::CARDINAL).collect();
+    let ordinal: Vec<_> = cldr_33::supported_locales(PluralRuleType::ORDINAL).collect();
+    assert_eq!(cardinal.len(), cldr_33::CARDINAL_LOCALE_COUNT);
+    assert_eq!(ordinal.len(), cldr_33::ORDINAL_LOCALE_COUNT);
+
+    let en: LanguageIdentifier = "en".parse().expect("Parsing failed.");
+    assert!(cardinal.contains(&&en));
+    assert!(ordinal.contains(&&en));
+
+    // Without ordinal rules, no locale is supported for them.
+    assert_eq!(
+        within_test_dyn::supported_locales(PluralRuleType::CARDINAL).count(),
+        1
+    );
+    assert_eq!(
+        within_test_dyn::supported_locales(PluralRuleType::ORDINAL).count(),
+        0
+    );
+}
+
+#[test]
+fn rules_and_find_test() {
+    assert_eq!(
+        cldr_33::rules(PluralRuleType::CARDINAL).count(),
+        cldr_33::CARDINAL_LOCALE_COUNT
+    );
+    assert_eq!(
+        cldr_33::rules(PluralRuleType::ORDINAL).count(),
+        cldr_33::ORDINAL_LOCALE_COUNT
+    );
+    assert!(
+        cldr_33::rules(PluralRuleType::CARDINAL)
+            .map(|(lang, _)| lang)
+            .eq(cldr_33::supported_locales(PluralRuleType::CARDINAL))
+    );
+
+    // `find` only matches the locale exactly, unlike `select`.
+    let lang = |lang: &str| -> LanguageIdentifier { lang.parse().expect("Parsing failed.") };
+    let one = PluralOperands::from(1_u8);
+    let rule = cldr_33::find(PluralRuleType::CARDINAL, &lang("en")).expect("`en` has rules");
+    assert_eq!(rule(&one), PluralCategory::ONE);
+    let (_, listed) = cldr_33::rules(PluralRuleType::CARDINAL)
+        .find(|(l, _)| **l == lang("en"))
+        .expect("`en` is listed");
+    assert_eq!(listed(&one), PluralCategory::ONE);
+    assert!(cldr_33::find(PluralRuleType::CARDINAL, &lang("en-US")).is_none());
+    assert!(cldr_33::find(PluralRuleType::CARDINAL, &lang("xx")).is_none());
+    assert!(within_test_dyn::find(PluralRuleType::ORDINAL, &lang("en")).is_none());
+}
+
+#[test]
+fn category_count_test() {
+    let lang = |s: &str| s.parse::<LanguageIdentifier>().unwrap();
+
+    assert_eq!(
+        cldr_33::category_count(&lang("ja"), PluralRuleType::CARDINAL),
+        1
+    );
+    assert_eq!(
+        cldr_33::category_count(&lang("ar"), PluralRuleType::CARDINAL),
+        6
+    );
+    assert_eq!(
+        cldr_33::category_count(&lang("en"), PluralRuleType::ORDINAL),
+        4
+    );
+    // Locales fall back to their language, then have the `OTHER` category only.
+    assert_eq!(
+        cldr_33::category_count(&lang("ar-EG"), PluralRuleType::CARDINAL),
+        6
+    );
+    assert_eq!(
+        cldr_33::category_count(&lang("xx"), PluralRuleType::CARDINAL),
+        1
+    );
+
+    for (prt, table, categories, counts) in [
+        (
+            PluralRuleType::CARDINAL,
+            cldr_33::PRS_CARDINAL,
+            cldr_33::PRS_CARDINAL_CATEGORIES,
+            cldr_33::PRS_CARDINAL_CATEGORY_COUNTS,
+        ),
+        (
+            PluralRuleType::ORDINAL,
+            cldr_33::PRS_ORDINAL,
+            cldr_33::PRS_ORDINAL_CATEGORIES,
+            cldr_33::PRS_ORDINAL_CATEGORY_COUNTS,
+        ),
+    ] {
+        assert_eq!(table.len(), counts.len());
+        for ((lang, _), (categories_lang, categories)) in table.iter().zip(categories) {
+            assert_eq!(lang, categories_lang);
+            assert_eq!(cldr_33::category_count(lang, prt), categories.len());
+        }
+    }
+}
+
+#[test]
+fn shared_rules_test() {
+    let output_rs =
+        read_file("./tests/fixtures/cldr_pluralrules_33.rs").expect("Could not read output rs");
+
+    // The 204 cardinal and 92 ordinal locales of CLDR 33 share 35 and 21 distinct rules,
+    // which shrinks the unformatted output from 106591 bytes with one closure per locale to 95702 bytes.
+    // The doc comments naming the locales and sources of each shared rule bring it to 105867 bytes,
+    // the tests of the language identifiers and the tables of the operands each rule reads to 108797 bytes,
+    // the `rules` and `find` functions to 109520 bytes, and the category counts to 111790 bytes.
+    assert_eq!(output_rs.matches("fn cardinal_rule_").count(), 35);
+    assert_eq!(output_rs.matches("fn ordinal_rule_").count(), 21);
+    assert!(output_rs.len() < 113_000);
+
+    // The per-locale selectors are generated from the same rules without sharing them.
+    assert_eq!(
+        cldr_33::PRS_CARDINAL.len(),
+        cldr_33_exported::PRS_CARDINAL.len()
+    );
+    let samples = [
+        "0", "1", "2", "3", "5", "11", "12", "22", "101", "1000000", "0.5", "1.0", "1.5", "2.30",
+    ];
+    for ((lang, shared), (exported_lang, selector)) in cldr_33::PRS_CARDINAL
+        .iter()
+        .zip(cldr_33_exported::PRS_CARDINAL)
+    {
+        assert_eq!(lang, exported_lang);
+        for sample in samples {
+            let po = PluralOperands::try_from(sample).expect("Valid operands");
+            assert_eq!(
+                shared(&po),
+                selector(&po),
+                "{} selects differently for {}",
+                lang,
+                sample
+            );
+        }
+    }
+}
+
+#[test]
+fn exclude_root_test() {
+    let inputs = [
+        "./tests/fixtures/cldr_pluralrules_cardinals_33.json",
+        "./tests/fixtures/cldr_pluralrules_ordinals_33.json",
+    ]
+    .map(|path| read_file(path).expect("Could not read input json"));
+    // The root rules are listed under an explicit `und` language, which parsing leaves empty.
+    let is_root = |lang: &LanguageIdentifier| lang.language.as_str() == "und";
+    // The raw value of the `und` language subtag, which the tables and the fallbacks refer to.
+    let raw_und = "from_raw_unchecked (6581877u64)";
+
+    // The root rules are included by default, and the fallbacks end with them.
+    assert!(cldr_33::PRS_CARDINAL.iter().any(|(lang, _)| is_root(lang)));
+    assert!(cldr_33::PRS_ORDINAL.iter().any(|(lang, _)| is_root(lang)));
+    let included = generate_rs(&inputs).expect("Generation succeeded");
+    assert!(included.contains(raw_und));
+
+    let options = GenerateOptions {
+        exclude_root: true,
+        ..Default::default()
+    };
+    let excluded = generate_rs_with(&inputs, &options).expect("Generation succeeded");
+    if let Err(err) = syn::parse_file(&excluded) {
+        panic!("The output is not valid Rust: {}", err);
+    }
+    assert!(!excluded.contains(raw_und));
+    for (prt, table) in [
+        ("CARDINAL", cldr_33::PRS_CARDINAL),
+        ("ORDINAL", cldr_33::PRS_ORDINAL),
+    ] {
+        let count = |count: usize| format!("pub const {}_LOCALE_COUNT : usize = {} ;", prt, count);
+        assert!(included.contains(&count(table.len())));
+        assert!(excluded.contains(&count(table.len() - 1)));
+    }
+    // The fallbacks stop at the language alone.
+    assert!(excluded.contains(
+        "select_rule (& LanguageIdentifier :: from_parts (lang . language , None , None , & []))) ;"
+    ));
+}
+
+#[test]
+fn minimal_pairs_test() {
+    let inputs = [
+        "./tests/fixtures/cldr_pluralrules_cardinals_33.json",
+        "./tests/fixtures/cldr_pluralrules_ordinals_33.json",
+        "./tests/fixtures/cldr_pluralrules_unsampled_test.json",
+    ]
+    .map(|path| read_file(path).expect("Could not read input json"));
+    let output_rs = read_file("./tests/fixtures/cldr_pluralrules_33_minimal_pairs.rs")
+        .expect("Could not read output rs");
+
+    let options = GenerateOptions {
+        minimal_pairs: true,
+        ..Default::default()
+    };
+    let output = generate_rs_with(&inputs, &options).expect("Generation succeeded");
+
+    assert_eq!(output_rs, output);
+
+    let lang = |s: &str| s.parse::<LanguageIdentifier>().unwrap();
+    let pairs = |lang: &LanguageIdentifier, prt| {
+        cldr_33_minimal_pairs::minimal_pairs(lang, prt).collect::<Vec<_>>()
+    };
+    let po = |s: &str| PluralOperands::try_from(s).expect("Valid operands");
+
+    let en = pairs(&lang("en"), PluralRuleType::CARDINAL);
+    assert_eq!(
+        en.iter().map(|(category, _)| *category).collect::<Vec<_>>(),
+        [PluralCategory::ONE, PluralCategory::OTHER]
+    );
+    assert_eq!(en[0].1, po("1"));
+    // Locales fall back to their language, then to the root rules.
+    assert_eq!(pairs(&lang("en-US"), PluralRuleType::CARDINAL), en);
+    assert_eq!(
+        pairs(&lang("xx"), PluralRuleType::CARDINAL),
+        [(PluralCategory::OTHER, po("0"))]
+    );
+
+    // Missing or misfiled samples are replaced by the first integer or decimal selecting the category.
+    assert_eq!(
+        pairs(&lang("zz"), PluralRuleType::CARDINAL),
+        [
+            (PluralCategory::ONE, po("1")),
+            (PluralCategory::FEW, po("3")),
+            (PluralCategory::MANY, po("0.0")),
+            (PluralCategory::OTHER, po("0")),
+        ]
+    );
+
+    // Every category of every locale has an example selecting it.
+    for (prt, table, categories) in [
+        (
+            PluralRuleType::CARDINAL,
+            cldr_33_minimal_pairs::PRS_CARDINAL,
+            cldr_33_minimal_pairs::PRS_CARDINAL_CATEGORIES,
+        ),
+        (
+            PluralRuleType::ORDINAL,
+            cldr_33_minimal_pairs::PRS_ORDINAL,
+            cldr_33_minimal_pairs::PRS_ORDINAL_CATEGORIES,
+        ),
+    ] {
+        for ((lang, rule), (_, categories)) in table.iter().zip(categories) {
+            let pairs = pairs(lang, prt);
+            assert_eq!(
+                pairs
+                    .iter()
+                    .map(|(category, _)| *category)
+                    .collect::<Vec<_>>(),
+                *categories,
+                "{}",
+                lang
+            );
+            for (category, po) in pairs {
+                assert_eq!(rule(&po), category, "{} {:?}", lang, po);
+            }
+        }
+    }
+}
+
+#[test]
+fn match_dispatch_test() {
+    let inputs = [
+        "./tests/fixtures/cldr_pluralrules_cardinals_33.json",
+        "./tests/fixtures/cldr_pluralrules_ordinals_33.json",
+    ]
+    .map(|path| read_file(path).expect("Could not read input json"));
+    let output_rs = read_file("./tests/fixtures/cldr_pluralrules_33_match.rs")
+        .expect("Could not read output rs");
+
+    let options = GenerateOptions {
+        match_dispatch: true,
+        ..Default::default()
+    };
+    let output = generate_rs_with(&inputs, &options).expect("Generation succeeded");
+
+    assert_eq!(output_rs, output);
+
+    let samples = [
+        "0", "1", "2", "3", "4", "5", "6", "7", "11", "12", "21", "22", "23", "101", "1000000",
+        "0.5", "1.0", "1.5", "2.30",
+    ];
+    for (table, match_fn) in [
+        (
+            cldr_33_match::PRS_CARDINAL,
+            cldr_33_match::match_cardinal as fn(&_, &_) -> _,
+        ),
+        (cldr_33_match::PRS_ORDINAL, cldr_33_match::match_ordinal),
+    ] {
+        for (lang, rule) in table {
+            for sample in samples {
+                let po = PluralOperands::try_from(sample).expect("Valid operands");
+                assert_eq!(
+                    Some(rule(&po)),
+                    match_fn(lang, &po),
+                    "{} selects differently for {}",
+                    lang,
+                    sample
+                );
+            }
+        }
+    }
+
+    // Like `find`, the functions don't fall back to other locales.
+    let po = PluralOperands::from(1_u64);
+    let lang = |s: &str| s.parse::<LanguageIdentifier>().unwrap();
+    assert_eq!(
+        cldr_33_match::match_cardinal(&lang("pt-PT"), &po),
+        Some(PluralCategory::ONE)
+    );
+    assert_eq!(cldr_33_match::match_cardinal(&lang("en-US"), &po), None);
+    assert_eq!(cldr_33_match::match_cardinal(&lang("en-fonipa"), &po), None);
+}
+
+#[test]
+fn select_test() {
+    assert!(
+        cldr_33::PRS_CARDINAL
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0)
+    );
+    assert!(
+        cldr_33::PRS_ORDINAL
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0)
+    );
+
+    for (lang, n, expected) in [
+        ("en", 1, PluralCategory::ONE),
+        ("pl", 3, PluralCategory::FEW),
+        ("pl", 12, PluralCategory::MANY),
+        ("ar", 0, PluralCategory::ZERO),
+        ("zh", 1, PluralCategory::OTHER),
+    ] {
+        let langid: LanguageIdentifier = lang.parse().expect("Parsing failed.");
+        let rule = cldr_33::select_cardinal(&langid).expect("Locale is present");
+        assert_eq!(rule(&PluralOperands::from(n as u64)), expected);
+    }
+
+    let langid: LanguageIdentifier = "en".parse().expect("Parsing failed.");
+    let rule = cldr_33::select_ordinal(&langid).expect("Locale is present");
+    assert_eq!(rule(&PluralOperands::from(2_u64)), PluralCategory::TWO);
+
+    let langid: LanguageIdentifier = "xx".parse().expect("Parsing failed.");
+    assert!(cldr_33::select_cardinal(&langid).is_none());
+}
+
+#[test]
+fn generated_select_test() {
+    for (lang, n, ty, expected) in [
+        ("en", "1", PluralRuleType::CARDINAL, PluralCategory::ONE),
+        ("en", "1.0", PluralRuleType::CARDINAL, PluralCategory::OTHER),
+        ("en", "2", PluralRuleType::ORDINAL, PluralCategory::TWO),
+        ("pl", "3", PluralRuleType::CARDINAL, PluralCategory::FEW),
+        ("cy", "3", PluralRuleType::ORDINAL, PluralCategory::FEW),
+        // Locales without rules of their own fall back to those of their language.
+        ("en-US", "1", PluralRuleType::CARDINAL, PluralCategory::ONE),
+        (
+            "en-Latn-US",
+            "2",
+            PluralRuleType::ORDINAL,
+            PluralCategory::TWO,
+        ),
+        ("pt-BR", "0", PluralRuleType::CARDINAL, PluralCategory::ONE),
+        // Other locales fall back to the root rules, which only list `OTHER`.
+        ("xx", "1", PluralRuleType::CARDINAL, PluralCategory::OTHER),
+    ] {
+        let langid: LanguageIdentifier = lang.parse().expect("Parsing failed.");
+        let po = PluralOperands::try_from(n).expect("Parsing succeeded");
+        assert_eq!(
+            cldr_33::select(&langid, &po, ty),
+            expected,
+            "{} {}",
+            lang,
+            n
+        );
+    }
+
+    assert!(!cldr_33::supported_locales(PluralRuleType::CARDINAL).any(|l| *l == "en-US"));
+    assert!(cldr_33::supported_locales(PluralRuleType::CARDINAL).any(|l| *l == "und"));
+
+    // The root rules apply to any language without rules of its own.
+    let po = PluralOperands::from(1_u64);
+    for lang in ["und", "xx", "xx-Latn-XY"] {
+        let langid: LanguageIdentifier = lang.parse().expect("Parsing failed.");
+        assert_eq!(
+            within_test_dyn::select(&langid, &po, PluralRuleType::CARDINAL),
+            PluralCategory::ONE,
+            "{}",
+            lang
+        );
+    }
+}
+
+#[test]
+fn map_test() {
+    let map = cldr_33::cardinal_map();
+    assert_eq!(map.len(), cldr_33::PRS_CARDINAL.len());
+    assert_eq!(cldr_33::ordinal_map().len(), cldr_33::PRS_ORDINAL.len());
+
+    let langid: LanguageIdentifier = "pl".parse().expect("Parsing failed.");
+    let (_, rule) = cldr_33::PRS_CARDINAL
+        .iter()
+        .find(|(l, _)| *l == langid)
+        .expect("Locale is present");
+    for n in [1, 3, 12, 22] {
+        let po = PluralOperands::from(n as u64);
+        assert_eq!(map[&langid](&po), rule(&po));
+    }
+
+    // The map is a working copy, which can be extended without affecting the tables.
+    let mut map = within_test_dyn::cardinal_map();
+    let langid: LanguageIdentifier = "xx".parse().expect("Parsing failed.");
+    map.insert(langid.clone(), &|_| PluralCategory::MANY);
+    assert_eq!(
+        map[&langid](&PluralOperands::from(1_u64)),
+        PluralCategory::MANY
+    );
+    assert_eq!(within_test_dyn::cardinal_map().len(), 1);
+}
+
+#[test]
+fn if_changed_test() {
+    let cardinal_json = read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+        .expect("Could not read input json");
+    let ordinal_json = read_file("./tests/fixtures/cldr_pluralrules_ordinals_33.json")
+        .expect("Could not read input json");
+    let out_path = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("if_changed_test.rs");
+    let _ = std::fs::remove_file(&out_path);
+
+    let inputs = [cardinal_json.clone()];
+    assert!(generate_rs_if_changed(&inputs, &out_path).expect("Generation succeeded"));
+    assert_eq!(
+        generate_rs(&inputs).expect("Generation succeeded"),
+        read_file(out_path.to_str().unwrap()).expect("Could not read output rs")
+    );
+
+    // Identical inputs don't regenerate the output.
+    assert!(!generate_rs_if_changed(&inputs, &out_path).expect("Generation succeeded"));
+
+    let inputs = [cardinal_json, ordinal_json];
+    assert!(generate_rs_if_changed(&inputs, &out_path).expect("Generation succeeded"));
+    assert_eq!(
+        generate_rs(&inputs).expect("Generation succeeded"),
+        read_file(out_path.to_str().unwrap()).expect("Could not read output rs")
+    );
+}
+
+#[test]
+fn within_test() {
+    let input_json = read_file("./tests/fixtures/cldr_pluralrules_within_test.json")
+        .expect("Could not read input json");
+    let output_rs = read_file("./tests/fixtures/cldr_pluralrules_within_test.rs")
+        .expect("Could not read output rs");
+
+    let output = generate_rs(&[input_json]).expect("Generation succeeded");
+
+    assert_eq!(output_rs, output);
+}
+
+#[test]
+fn within_fractions_test() {
+    // `n within 2..10 and i not within 8..9` selects `few`, while `n % 10 within 1..2` selects `two`.
+    let undefined: LanguageIdentifier = "und".parse().expect("Parsing failed.");
+    let select = |number: &str| {
+        let po = PluralOperands::try_from(number).expect("Valid operands");
+        within_test_dyn::select(&undefined, &po, PluralRuleType::CARDINAL)
+    };
+
+    assert_eq!(select("2.5"), PluralCategory::FEW);
+    assert_eq!(select("7"), PluralCategory::FEW);
+    assert_eq!(select("8.5"), PluralCategory::OTHER);
+    assert_eq!(select("1"), PluralCategory::ONE);
+    assert_eq!(select("1.5"), PluralCategory::TWO);
+    assert_eq!(select("11.5"), PluralCategory::TWO);
+    assert_eq!(select("12.5"), PluralCategory::OTHER);
+}
+
+#[test]
+fn dyn_rules_test() {
+    let input_json = read_file("./tests/fixtures/cldr_pluralrules_within_test.json")
+        .expect("Could not read input json");
+    let output_rs = read_file("./tests/fixtures/cldr_pluralrules_within_test_dyn.rs")
+        .expect("Could not read output rs");
+
+    let options = GenerateOptions {
+        dyn_rules: true,
+        ..Default::default()
+    };
+    let output = generate_rs_with(&[input_json], &options).expect("Generation succeeded");
+
+    assert_eq!(output_rs, output);
+}
+
+#[test]
+fn custom_operands_test() {
+    let input_json = read_file("./tests/fixtures/cldr_pluralrules_within_test.json")
+        .expect("Could not read input json");
+    let output_rs = read_file("./tests/fixtures/cldr_pluralrules_within_test_custom_operands.rs")
+        .expect("Could not read output rs");
+
+    let options = GenerateOptions {
+        operands_path: Some("crate::custom_operands::Operands".to_owned()),
+        operand_accessors: [
+            ('n', "value()".to_owned()),
+            ('i', "integer_part()".to_owned()),
+        ]
+        .into(),
+        ..Default::default()
+    };
+    let output = generate_rs_with(&[input_json], &options).expect("Generation succeeded");
+
+    assert_eq!(output_rs, output);
+
+    let (_, rule) = &within_test_custom_operands::PRS_CARDINAL[0];
+    assert_eq!(rule(&custom_operands::Operands(1.0)), PluralCategory::ONE);
+    assert_eq!(rule(&custom_operands::Operands(11.0)), PluralCategory::TWO);
+    assert_eq!(rule(&custom_operands::Operands(3.0)), PluralCategory::FEW);
+    assert_eq!(rule(&custom_operands::Operands(8.0)), PluralCategory::OTHER);
+}
+
+#[test]
+fn invalid_operands_options_test() {
+    let input_json = read_file("./tests/fixtures/cldr_pluralrules_within_test.json")
+        .expect("Could not read input json");
+
+    let options = GenerateOptions {
+        operands_path: Some("crate::not a path".to_owned()),
+        ..Default::default()
+    };
+    assert!(matches!(
+        generate_rs_with(std::slice::from_ref(&input_json), &options),
+        Err(GenerateError::InvalidOperandsPath(_))
+    ));
+
+    let options = GenerateOptions {
+        operand_accessors: [('x', "value()".to_owned())].into(),
+        ..Default::default()
+    };
+    assert!(matches!(
+        generate_rs_with(&[input_json], &options),
+        Err(GenerateError::InvalidOperandAccessor(_))
+    ));
+}
+
+#[test]
+fn runtime_path_test() {
+    let input_json = read_file("./tests/fixtures/cldr_pluralrules_within_test.json")
+        .expect("Could not read input json");
+
+    let options = GenerateOptions {
+        runtime_path: Some("intl_pluralrules".to_owned()),
+        ..Default::default()
+    };
+    let output = generate_rs_with(std::slice::from_ref(&input_json), &options)
+        .expect("Generation succeeded");
+    assert!(output.contains(
+        "use intl_pluralrules :: operands :: PluralOperands ; \
+         use intl_pluralrules :: PluralRuleType ; \
+         use intl_pluralrules :: PluralCategory ;"
+    ));
+    assert!(!output.contains("use super :: PluralCategory"));
+
+    // The operands path still takes precedence for the operands type.
+    let options = GenerateOptions {
+        runtime_path: Some("crate::plurals".to_owned()),
+        operands_path: Some("crate::numbers::Operands".to_owned()),
+        ..Default::default()
+    };
+    let output = generate_rs_with(std::slice::from_ref(&input_json), &options)
+        .expect("Generation succeeded");
+    assert!(output.contains(
+        "use crate :: numbers :: Operands as PluralOperands ; \
+         use crate :: plurals :: PluralRuleType ; \
+         use crate :: plurals :: PluralCategory ;"
+    ));
+
+    let options = GenerateOptions {
+        runtime_path: Some("::intl_pluralrules".to_owned()),
+        ..Default::default()
+    };
+    assert!(matches!(
+        generate_rs_with(&[input_json], &options),
+        Err(GenerateError::InvalidRuntimePath(_))
+    ));
+}
+
+#[test]
+fn compact_exponent_test() {
+    let input_json = String::from(
+        r#"{
+  "supplemental": {
+    "version": {
+      "_unicodeVersion": "10.0.0",
+      "_cldrVersion": "0"
+    },
+    "plurals-type-cardinal": {
+      "xx": {
+        "pluralRule-count-one": "i = 0,1 @integer 0, 1",
+        "pluralRule-count-many": "e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or c != 0..5 @integer 1000000, 1c6 @decimal 1.0000001c6, 1.1c6",
+        "pluralRule-count-other": " @integer 2~17, 100, 1000, 10000 @decimal 1.1c3"
+      }
+    }
+  }
+}"#,
+    );
+
+    let output = generate_rs(std::slice::from_ref(&input_json)).expect("Generation succeeded");
+    assert!(output.contains("(0 == 0 && po . i != 0"));
+    assert!(output.contains("! (0 ..= 5) . contains (& (0))"));
+
+    let options = GenerateOptions {
+        operand_accessors: [('c', "exponent".to_owned())].into(),
+        ..Default::default()
+    };
+    let output = generate_rs_with(std::slice::from_ref(&input_json), &options)
+        .expect("Generation succeeded");
+    assert!(output.contains("(po . exponent == 0 && po . i != 0"));
+    assert!(output.contains("! (0 ..= 5) . contains (& (po . exponent))"));
+
+    // The compact samples select their category with the exponent, and are embedded as written.
+    let options = GenerateOptions {
+        strict_samples: true,
+        embed_samples: true,
+        ..Default::default()
+    };
+    let output = generate_rs_with(&[input_json], &options).expect("Generation succeeded");
+    assert!(output.contains(r#"["1000000" , "1c6" , "1.0000001c6" , "1.1c6"]"#));
+}
+
+#[test]
+fn variant_test() {
+    let input_json = String::from(
+        r#"{
+  "supplemental": {
+    "version": {
+      "_unicodeVersion": "10.0.0",
+      "_cldrVersion": "0"
+    },
+    "plurals-type-cardinal": {
+      "ca-ES-valencia": {
+        "pluralRule-count-one": "i = 1 and v = 0 @integer 1",
+        "pluralRule-count-other": " @integer 0, 2~16"
+      }
+    }
+  }
+}"#,
+    );
+
+    let output = generate_rs(&[input_json]).expect("Generation succeeded");
+    assert!(output.contains(
+        "Some (subtags :: Region :: from_raw_unchecked (21317u32)) , \
+         Some (Box :: new ([subtags :: Variant :: from_raw_unchecked (7019250820032782710u64)])))"
+    ));
+    assert!(
+        output.contains("($ lang : expr , $ script : expr , $ region : expr , $ variants : expr)")
+    );
+}
+
+#[test]
+fn export_selectors_test() {
+    let input_json = read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+        .expect("Could not read input json");
+    let output_rs = read_file("./tests/fixtures/cldr_pluralrules_33_exported.rs")
+        .expect("Could not read output rs");
+
+    let options = GenerateOptions {
+        export_selectors: true,
+        ..Default::default()
+    };
+    let output = generate_rs_with(&[input_json], &options).expect("Generation succeeded");
+
+    assert_eq!(output_rs, output);
+
+    // Each locale has a function of its own, named in stack traces and profiles, which its entry of the
+    // table refers to rather than to a closure.
+    for (lang, _) in cldr_33_exported::PRS_CARDINAL {
+        let name = format!(
+            "select_{}_cardinal",
+            lang.to_string().to_lowercase().replace('-', "_")
+        );
+        assert!(
+            output.contains(&format!("fn {} (po : & PluralOperands)", name)),
+            "{}",
+            name
+        );
+        assert!(output.contains(&format!(" , {})", name)), "{}", name);
+    }
+    assert!(!output.contains("| po |"));
+
+    use cldr_33_exported::{select_en_cardinal, select_pt_pt_cardinal};
+
+    assert_eq!(
+        select_en_cardinal(&PluralOperands::from(1_u64)),
+        PluralCategory::ONE
+    );
+    assert_eq!(
+        select_en_cardinal(&PluralOperands::from(2_u64)),
+        PluralCategory::OTHER
+    );
+    assert_eq!(
+        select_pt_pt_cardinal(&PluralOperands::from(1_u64)),
+        PluralCategory::ONE
+    );
+    assert_selects(select_en_cardinal, "1.0", PluralCategory::OTHER);
+    assert_selects(select_pt_pt_cardinal, "1.5", PluralCategory::OTHER);
+
+    let langid: LanguageIdentifier = "en".parse().expect("Parsing failed.");
+    let (_, rule) = cldr_33_exported::PRS_CARDINAL
+        .iter()
+        .find(|(l, _)| *l == langid)
+        .expect("Locale is present");
+    for n in [0_u64, 1, 2, 11] {
+        let po = PluralOperands::from(n);
+        assert_eq!(rule(&po), select_en_cardinal(&po));
+    }
+}
+
+#[test]
+fn emit_all_test() {
+    let cardinal_json = read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+        .expect("Could not read input json");
+    let ordinal_json = read_file("./tests/fixtures/cldr_pluralrules_ordinals_33.json")
+        .expect("Could not read input json");
+    let output_rs =
+        read_file("./tests/fixtures/cldr_pluralrules_33.rs").expect("Could not read output rs");
+
+    let outputs = generate_all(
+        &[cardinal_json, ordinal_json],
+        &[Emit::Rust, Emit::AstJson],
+        &GenerateOptions::default(),
+    )
+    .expect("Generation succeeded");
+    assert_eq!(outputs.len(), 2);
+    assert_eq!(output_rs, outputs[0]);
+
+    let ast: serde_json::Value = serde_json::from_str(&outputs[1]).expect("Parsing succeeded");
+    assert_eq!(ast["cldrVersion"], "33");
+    assert_eq!(ast["cardinal"]["en"]["one"]["source"], "i = 1 and v = 0");
+    assert_eq!(
+        ast["cardinal"]["en"]["one"]["condition"][0][1],
+        serde_json::json!({ "operand": "v", "operator": "=", "ranges": [0] })
+    );
+
+    // Both outputs describe the same locales and categories.
+    for (type_name, categories_table) in [
+        ("cardinal", cldr_33::PRS_CARDINAL_CATEGORIES),
+        ("ordinal", cldr_33::PRS_ORDINAL_CATEGORIES),
+    ] {
+        let locales = ast[type_name].as_object().expect("Locales are an object");
+        assert_eq!(locales.len(), categories_table.len());
+        for (lang, categories) in categories_table {
+            let rules = locales[&lang.to_string()]
+                .as_object()
+                .expect("Categories are an object");
+            let mut names: Vec<_> = categories
+                .iter()
+                .filter(|cat| **cat != PluralCategory::OTHER)
+                .map(|cat| format!("{:?}", cat).to_lowercase())
+                .collect();
+            names.sort();
+            let json_names: Vec<_> = rules.keys().cloned().collect();
+            assert_eq!(names, json_names, "categories of {}", lang);
+        }
+    }
+}
+
+#[test]
+fn operands_table_test() {
+    let operands = |table: &[(LanguageIdentifier, _)], operands: &[&'static str], lang: &str| {
+        assert_eq!(table.len(), operands.len());
+        let lang: LanguageIdentifier = lang.parse().expect("Parsing succeeded");
+        let idx = table
+            .binary_search_by(|(l, _)| l.cmp(&lang))
+            .expect("The locale has rules");
+        operands[idx]
+    };
+    let cardinal = |lang| operands(cldr_33::PRS_CARDINAL, cldr_33::PRS_CARDINAL_OPERANDS, lang);
+    let ordinal = |lang| operands(cldr_33::PRS_ORDINAL, cldr_33::PRS_ORDINAL_OPERANDS, lang);
+
+    assert_eq!(cardinal("en"), "iv");
+    assert_eq!(cardinal("fr"), "i");
+    assert_eq!(cardinal("lv"), "nvf");
+    assert_eq!(cardinal("ja"), "");
+    assert_eq!(ordinal("en"), "n");
+    assert_eq!(ordinal("pl"), "");
+}
+
+#[test]
+fn manifest_test() {
+    let inputs = [
+        read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+            .expect("Could not read input json"),
+        read_file("./tests/fixtures/cldr_pluralrules_ordinals_33.json")
+            .expect("Could not read input json"),
+    ];
+    let outputs = generate_all(&inputs, &[Emit::Manifest], &GenerateOptions::default())
+        .expect("Generation succeeded");
+
+    let manifest: serde_json::Value = serde_json::from_str(&outputs[0]).expect("Parsing succeeded");
+    assert_eq!(manifest["cldrVersion"], cldr_33::CLDR_VERSION);
+    for (type_name, table) in [
+        ("cardinal", cldr_33::PRS_CARDINAL),
+        ("ordinal", cldr_33::PRS_ORDINAL),
+    ] {
+        let expected: Vec<_> = table.iter().map(|(lang, _)| lang.to_string()).collect();
+        assert_eq!(
+            manifest[type_name],
+            serde_json::json!(expected),
+            "{}",
+            type_name
+        );
+    }
+}
+
+#[test]
+fn pretty_print_test() {
+    let cardinal_json = read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+        .expect("Could not read input json");
+    let ordinal_json = read_file("./tests/fixtures/cldr_pluralrules_ordinals_33.json")
+        .expect("Could not read input json");
+    let inputs = [cardinal_json, ordinal_json];
+    // The tokens of the code, ignoring whether punctuation is joint and how a string is written, such as
+    // a doc comment's.
+    fn flatten(stream: proc_macro2::TokenStream, tokens: &mut Vec<String>) {
+        for tt in stream {
+            match tt {
+                proc_macro2::TokenTree::Group(group) => {
+                    tokens.push(format!("{:?}", group.delimiter()));
+                    flatten(group.stream(), tokens);
+                    tokens.push("end".to_owned());
+                }
+                proc_macro2::TokenTree::Literal(literal) => {
+                    tokens.push(match syn::parse_str::<syn::LitStr>(&literal.to_string()) {
+                        Ok(string) => format!("{:?}", string.value()),
+                        Err(_) => literal.to_string(),
+                    })
+                }
+                tt => tokens.push(tt.to_string()),
+            }
+        }
+    }
+    let tokens = |code: &str| {
+        let mut tokens = Vec::new();
+        flatten(code.parse().expect("Tokenizing succeeded"), &mut tokens);
+        tokens
+    };
+
+    let all_options = [
+        GenerateOptions::default(),
+        GenerateOptions {
+            dyn_rules: true,
+            export_selectors: true,
+            embed_sources: true,
+            ..Default::default()
+        },
+    ];
+    for options in &all_options {
+        let output = generate_rs_with(&inputs, options).expect("Generation succeeded");
+        let pretty = pretty_print(&output);
+
+        // The same inputs always yield the same bytes, which only differ in layout from the output.
+        let again =
+            pretty_print(&generate_rs_with(&inputs, options).expect("Generation succeeded"));
+        assert_eq!(pretty.as_bytes(), again.as_bytes());
+        assert_eq!(tokens(&output), tokens(&pretty));
+        assert_eq!(pretty_print(&pretty), pretty);
+
+        // Every function and module starts its own line, and only comments and string literals run past
+        // the width rustfmt keeps to.
+        for line in pretty.lines() {
+            let code = code_of(line);
+            assert!(code.chars().count() <= 100, "`{}` is too wide", line);
+            for keyword in ["fn ", "mod "] {
+                for (at, _) in code.match_indices(keyword) {
+                    let before = code[..at].trim();
+                    assert!(
+                        matches!(before, "" | "pub" | "const" | "pub const"),
+                        "`{}` doesn't start the line `{}`",
+                        keyword,
+                        line
+                    );
+                }
+            }
+        }
+        for glued in ["let(", "for(", "if!", "::< ", "#[doc"] {
+            assert!(!pretty.contains(glued), "`{}` in the output", glued);
+        }
+    }
+
+    let pretty = pretty_print(&generate_rs(&inputs).expect("Generation succeeded"));
+    let lines: Vec<_> = pretty.lines().collect();
+    assert_eq!(
+        lines[0],
+        "#![allow(unused_variables, unused_parens, unused_braces, dead_code)]"
+    );
+    assert!(lines.contains(&"pub static CLDR_VERSION: &str = \"33\";"));
+    assert!(lines.contains(&"pub const PRS_CARDINAL: &[(LanguageIdentifier, PluralRule)] = &["));
+    assert!(lines.windows(4).any(|entry| entry
+        == [
+            "    (",
+            "        langid!(subtags::Language::from_raw_unchecked(28261u64), None, None, None),",
+            "        &[PluralCategory::ONE, PluralCategory::OTHER]",
+            "    ),",
+        ]));
+    assert!(lines.windows(5).any(|rule| rule
+        == [
+            "fn cardinal_rule_1(po: &PluralOperands) -> PluralCategory {",
+            "    if (((0..=1).contains(&(po.i)) && po.f == 0)) {",
+            "        PluralCategory::ONE",
+            "    } else {",
+            "        PluralCategory::OTHER",
+        ]));
+}
+
+/// Returns a line of code without its comment or the contents of its string literals.
+fn code_of(line: &str) -> String {
+    let line = line.trim_start();
+    if line.starts_with("//") {
+        return String::new();
+    }
+    let mut code = String::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in line.chars() {
+        if c == '"' && !escaped {
+            in_string = !in_string;
+        }
+        if !in_string || c == '"' && !escaped {
+            code.push(c);
+        }
+        escaped = in_string && !escaped && c == '\\';
+    }
+    code
+}
+
+#[test]
+fn pretty_print_parses_test() {
+    let cardinal_json = read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+        .expect("Could not read input json");
+    let ordinal_json = read_file("./tests/fixtures/cldr_pluralrules_ordinals_33.json")
+        .expect("Could not read input json");
+    let inputs = [cardinal_json, ordinal_json];
+
+    let all_options = [
+        GenerateOptions::default(),
+        GenerateOptions {
+            dyn_rules: true,
+            export_selectors: true,
+            embed_sources: true,
+            embed_samples: true,
+            phf_lookup: true,
+            type_modules: true,
+            ..Default::default()
+        },
+        GenerateOptions {
+            module: Some("v33".to_owned()),
+            sample_tests: true,
+            ..Default::default()
+        },
+    ];
+    for options in &all_options {
+        let output = generate_rs_with(&inputs, options).expect("Generation succeeded");
+        let pretty = pretty_print(&output);
+        if let Err(err) = syn::parse_file(&pretty) {
+            panic!("The formatted output is not valid Rust: {}", err);
+        }
+    }
+}
+
+#[test]
+fn committed_rules_are_pretty_printed_test() {
+    // `cargo regenerate-data` in `intl_pluralrules` writes these rules with the built-in printer.
+    let inputs = [
+        read_file("../intl_pluralrules/cldr_data/cldr_pluralrules_cardinals.json")
+            .expect("Could not read input json"),
+        read_file("../intl_pluralrules/cldr_data/cldr_pluralrules_ordinals.json")
+            .expect("Could not read input json"),
+    ];
+    let output = generate_rs(&inputs).expect("Generation succeeded");
+    let committed = fs::read_to_string("../intl_pluralrules/src/rules.rs")
+        .expect("Could not read the committed rules");
+    assert!(
+        pretty_print(&output) == committed,
+        "intl_pluralrules/src/rules.rs is out of date, run `cargo regenerate-data` in intl_pluralrules"
+    );
+}
+
+#[test]
+fn embed_sources_test() {
+    let input_json = read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+        .expect("Could not read input json");
+    let output_rs = read_file("./tests/fixtures/cldr_pluralrules_33_sources.rs")
+        .expect("Could not read output rs");
+
+    let options = GenerateOptions {
+        embed_sources: true,
+        ..Default::default()
+    };
+    let output = generate_rs_with(&[input_json], &options).expect("Generation succeeded");
+
+    assert_eq!(output_rs, output);
+
+    use cldr_33_sources::rule_source;
+
+    let en: LanguageIdentifier = "en".parse().expect("Parsing failed.");
+    let pl: LanguageIdentifier = "pl".parse().expect("Parsing failed.");
+    let xx: LanguageIdentifier = "xx".parse().expect("Parsing failed.");
+    assert_eq!(
+        rule_source(&en, PluralRuleType::CARDINAL, PluralCategory::ONE),
+        Some("i = 1 and v = 0")
+    );
+    assert_eq!(
+        rule_source(&pl, PluralRuleType::CARDINAL, PluralCategory::FEW),
+        Some("v = 0 and i % 10 = 2..4 and i % 100 != 12..14")
+    );
+    assert_eq!(
+        rule_source(&en, PluralRuleType::CARDINAL, PluralCategory::OTHER),
+        None
+    );
+    assert_eq!(
+        rule_source(&en, PluralRuleType::ORDINAL, PluralCategory::ONE),
+        None
+    );
+    assert_eq!(
+        rule_source(&xx, PluralRuleType::CARDINAL, PluralCategory::ONE),
+        None
+    );
+}
+
+#[test]
+fn embed_samples_test() {
+    let input_json = read_file("./tests/fixtures/cldr_pluralrules_samples_test.json")
+        .expect("Could not read input json");
+    let output_rs = read_file("./tests/fixtures/cldr_pluralrules_samples_test.rs")
+        .expect("Could not read output rs");
+
+    let options = GenerateOptions {
+        embed_samples: true,
+        sample_tests: true,
+        ..Default::default()
+    };
+    let output = generate_rs_with(&[input_json], &options).expect("Generation succeeded");
+
+    assert_eq!(output_rs, output);
+
+    use samples_test::samples_for;
+
+    let en: LanguageIdentifier = "en".parse().expect("Parsing failed.");
+    let pl: LanguageIdentifier = "pl".parse().expect("Parsing failed.");
+    let xx: LanguageIdentifier = "xx".parse().expect("Parsing failed.");
+    assert_eq!(
+        samples_for(&en, PluralRuleType::CARDINAL, PluralCategory::ONE),
+        Some(&["1"][..])
+    );
+    assert_eq!(
+        samples_for(&pl, PluralRuleType::CARDINAL, PluralCategory::FEW).map(|s| &s[..5]),
+        Some(&["2", "3", "4", "22", "23"][..])
+    );
+    let other = samples_for(&pl, PluralRuleType::CARDINAL, PluralCategory::OTHER)
+        .expect("Samples are present");
+    assert_eq!(&other[..3], ["0.0", "0.1", "0.2"]);
+    assert_eq!(other.last(), Some(&"1000000.0"));
+    assert_eq!(
+        samples_for(&en, PluralRuleType::CARDINAL, PluralCategory::FEW),
+        None
+    );
+    assert_eq!(
+        samples_for(&en, PluralRuleType::ORDINAL, PluralCategory::ONE),
+        None
+    );
+    assert_eq!(
+        samples_for(&xx, PluralRuleType::CARDINAL, PluralCategory::ONE),
+        None
+    );
+
+    // Every sample value selects the category it is listed for.
+    for (lang, rule) in samples_test::PRS_CARDINAL {
+        for category in [
+            PluralCategory::ONE,
+            PluralCategory::FEW,
+            PluralCategory::MANY,
+            PluralCategory::OTHER,
+        ] {
+            for sample in samples_for(lang, PluralRuleType::CARDINAL, category).unwrap_or_default()
+            {
+                let po = PluralOperands::try_from(*sample).expect("Valid operands");
+                assert_eq!(
+                    rule(&po),
+                    category,
+                    "{} selects differently for {}",
+                    lang,
+                    sample
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn dyn_rules_registry_test() {
+    use within_test_dyn::{CLDR_VERSION, PRS_CARDINAL, PluralRule};
+
+    assert_eq!(CLDR_VERSION, "0");
+
+    fn lookup(registry: &[(LanguageIdentifier, PluralRule)], langid: &str) -> Option<PluralRule> {
+        registry
+            .iter()
+            .find(|(l, _)| *l == langid)
+            .map(|(_, rule)| *rule)
+    }
+
+    let registered: PluralRule = Box::leak(Box::new(|po: &PluralOperands| {
+        if po.i == 7 {
+            PluralCategory::ONE
+        } else {
+            PluralCategory::OTHER
+        }
+    }));
+
+    let mut registry = PRS_CARDINAL.to_vec();
+    registry.push(("xx".parse().expect("Parsing failed."), registered));
+
+    let compiled = lookup(&registry, "und").expect("Compiled rule found");
+    assert_eq!(compiled(&PluralOperands::from(1_u64)), PluralCategory::ONE);
+
+    let registered = lookup(&registry, "xx").expect("Registered rule found");
+    assert_eq!(
+        registered(&PluralOperands::from(7_u64)),
+        PluralCategory::ONE
+    );
+    assert_eq!(
+        registered(&PluralOperands::from(1_u64)),
+        PluralCategory::OTHER
+    );
+}
+
+#[test]
+fn bad_type_test() {
+    let text = String::from(
+        r#"{
+  "supplemental": {
+    "version": {
+      "_number": "$Revision: 13898 $",
+      "_unicodeVersion": "10.0.0",
+      "_cldrVersion": "0"
+    },
+    "plurals-type-cardinals": {
+      "test": {
+        "pluralRule-count-one": "n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000",
+        "pluralRule-count-other": " @integer 0, 2~16, 100, 1000, 10000, 100000, 1000000, … @decimal 0.0~0.9, 1.1~1.6, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …"
+      }
+    }
+  }
+}"#,
+    );
+
+    assert!(matches!(
+        generate_rs(&[text]),
+        Err(GenerateError::UnknownPluralType(name)) if name == "cardinals"
+    ));
+}
+
+#[test]
+fn same_data_cardinal_test() {
+    let cardinal_json = read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+        .expect("Could not read input json");
+    let copy_json = cardinal_json.clone();
+
+    assert_eq!(
+        generate_rs(&[cardinal_json.clone(), copy_json]).expect("Generation succeeded"),
+        generate_rs(&[cardinal_json]).expect("Generation succeeded")
+    );
+}
+
+#[test]
+fn same_data_ordinal_test() {
+    let ordinal_json = read_file("./tests/fixtures/cldr_pluralrules_ordinals_33.json")
+        .expect("Could not read input json");
+    let copy_json = ordinal_json.clone();
+
+    assert_eq!(
+        generate_rs(&[ordinal_json.clone(), copy_json]).expect("Generation succeeded"),
+        generate_rs(&[ordinal_json]).expect("Generation succeeded")
+    );
+}
+
+#[test]
+fn override_input_test() {
+    let cardinal_json = read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+        .expect("Could not read input json");
+    let ordinal_json = read_file("./tests/fixtures/cldr_pluralrules_ordinals_33.json")
+        .expect("Could not read input json");
+    let override_json = String::from(
+        r#"{
+  "supplemental": {
+    "version": {
+      "_unicodeVersion": "10.0.0",
+      "_cldrVersion": "33"
+    },
+    "plurals-type-cardinal": {
+      "en": {
+        "pluralRule-count-one": "n = 1",
+        "pluralRule-count-few": "n = 2..4",
+        "pluralRule-count-other": ""
+      },
+      "xx": {
+        "pluralRule-count-one": "n = 0"
+      }
+    }
+  }
+}"#,
+    );
+    let base =
+        parse_dataset(&[cardinal_json.clone(), ordinal_json.clone()]).expect("Parsing succeeded");
+    let layered =
+        parse_dataset(&[cardinal_json, ordinal_json, override_json]).expect("Parsing succeeded");
+
+    let en: LanguageIdentifier = "en".parse().unwrap();
+    let categories = |dataset: &make_pluralrules::Dataset, lang, prt| -> Vec<PluralCategory> {
+        dataset[lang][&prt].iter().map(|(cat, _)| *cat).collect()
+    };
+    // The override replaces all the cardinal rules of `en`, but not its ordinal rules.
+    assert_eq!(
+        categories(&layered, &en, PluralRuleType::CARDINAL),
+        [
+            PluralCategory::FEW,
+            PluralCategory::ONE,
+            PluralCategory::OTHER
+        ]
+    );
+    assert_eq!(
+        layered[&en][&PluralRuleType::CARDINAL][1]
+            .1
+            .condition
+            .to_string(),
+        "n = 1"
+    );
+    assert_eq!(
+        layered[&en][&PluralRuleType::ORDINAL],
+        base[&en][&PluralRuleType::ORDINAL]
+    );
+    // Other locales are kept, and new ones are added.
+    let fr: LanguageIdentifier = "fr".parse().unwrap();
+    assert_eq!(layered[&fr], base[&fr]);
+    assert!(layered.contains_key(&"xx".parse::<LanguageIdentifier>().unwrap()));
+    assert_eq!(layered.len(), base.len() + 1);
+}
+
+#[test]
+fn byte_order_mark_test() {
+    // The fixture starts with a byte order mark, ends with blank lines, and pads a rule with spaces.
+    let path = "./tests/fixtures/cldr_pluralrules_bom_test.json";
+    let input_json = read_file(path).expect("File was not found");
+    assert!(input_json.starts_with('\u{FEFF}'));
+    assert!(contains_plural_rules(&input_json));
+
+    let expected = generate_rs(&[
+        read_file("./tests/fixtures/cldr_pluralrules_modules_test.json")
+            .expect("File was not found"),
+    ])
+    .expect("Generation succeeded");
+    assert_eq!(
+        generate_rs(&[input_json]).expect("Generation succeeded"),
+        expected
+    );
+    let file = File::open(path).expect("File was not found");
+    assert_eq!(
+        generate_rs_from_readers([file], &GenerateOptions::default())
+            .expect("Generation succeeded"),
+        expected
+    );
+}
+
+#[test]
+fn size_report_test() {
+    let input_json = read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+        .expect("File was not found");
+    let report = size_report(&parse_dataset(&[input_json]).expect("The rules are valid"));
+    let size_of = |lang: &str| {
+        report
+            .0
+            .iter()
+            .find(|size| size.lang == lang)
+            .expect("The locale is reported")
+    };
+
+    // Japanese only has `other`, which costs no condition.
+    let ja = size_of("ja");
+    assert_eq!(ja.relations, 0);
+    assert!(ja.operands.is_empty());
+    assert_eq!(ja.tokens, 0);
+
+    // Arabic has all six categories.
+    let ar = size_of("ar");
+    assert_eq!(ar.relations, 5);
+    assert_eq!(ar.operands, BTreeSet::from([Operand::N]));
+    assert!(ar.tokens > 50);
+
+    let table = report.to_string();
+    assert!(table.starts_with("locale      relations   tokens      operands\n"));
+    assert!(table.contains("\nja          0           0           \n"));
+    assert!(table.contains(&format!("\nar          5           {:<12}n\n", ar.tokens)));
+}
+
+#[test]
+fn parse_dataset_test() {
+    let cardinal_json = String::from(
+        r#"{
+  "supplemental": {
+    "version": {
+      "_unicodeVersion": "10.0.0",
+      "_cldrVersion": "0"
+    },
+    "plurals-type-cardinal": {
+      "xx": {
+        "pluralRule-count-one": "i = 1 and v = 0 @integer 1",
+        "pluralRule-count-other": " @integer 0, 2~16"
+      },
+      "yy": {
+        "pluralRule-count-other": " @integer 0~15"
+      }
+    }
+  }
+}"#,
+    );
+    let ordinal_json = String::from(
+        r#"{
+  "supplemental": {
+    "version": {
+      "_unicodeVersion": "10.0.0",
+      "_cldrVersion": "0"
+    },
+    "plurals-type-ordinal": {
+      "xx": {
+        "pluralRule-count-few": "n % 10 = 3 @integer 3, 13",
+        "pluralRule-count-other": " @integer 0~2, 4~12"
+      }
+    }
+  }
+}"#,
+    );
+
+    let dataset = parse_dataset(&[cardinal_json, ordinal_json]).expect("Parsing succeeded");
+
+    let xx: LanguageIdentifier = "xx".parse().expect("Parsing failed.");
+    let yy: LanguageIdentifier = "yy".parse().expect("Parsing failed.");
+    assert_eq!(dataset.keys().collect::<Vec<_>>(), vec![&xx, &yy]);
+    assert_eq!(
+        dataset[&xx].keys().collect::<Vec<_>>(),
+        vec![&PluralRuleType::ORDINAL, &PluralRuleType::CARDINAL]
+    );
+    assert_eq!(
+        dataset[&yy].keys().collect::<Vec<_>>(),
+        vec![&PluralRuleType::CARDINAL]
+    );
+
+    let cardinal = &dataset[&xx][&PluralRuleType::CARDINAL];
+    assert_eq!(
+        cardinal
+            .iter()
+            .map(|(category, _)| *category)
+            .collect::<Vec<_>>(),
+        vec![PluralCategory::ONE, PluralCategory::OTHER]
+    );
+    assert_eq!(
+        cardinal[0].1,
+        cldr_pluralrules_parser::parse("i = 1 and v = 0 @integer 1").expect("Parsing succeeded")
+    );
+    assert!(cardinal[1].1.condition.0.is_empty());
+    assert!(cardinal[1].1.samples.is_some());
+    assert_eq!(
+        dataset[&xx][&PluralRuleType::ORDINAL][0].0,
+        PluralCategory::FEW
+    );
+}
+
+#[test]
+fn invalid_rule_test() {
+    let input_json = String::from(
+        r#"{
+  "supplemental": {
+    "version": {
+      "_unicodeVersion": "10.0.0",
+      "_cldrVersion": "0"
+    },
+    "plurals-type-cardinal": {
+      "xx": {
+        "pluralRule-count-one": "i = 1 and @integer 1",
+        "pluralRule-count-other": " @integer 0, 2~16"
+      }
+    }
+  }
+}"#,
+    );
+
+    let err = generate_rs(&[input_json]).expect_err("Generation failed");
+    assert_eq!(
+        err.to_string(),
+        "invalid `pluralRule-count-one` rule of `xx`: expected a relation at offset 9"
+    );
+    assert!(matches!(
+        err,
+        GenerateError::InvalidRule {
+            error: PluralRuleParseError::EmptyRelation { offset: 9 },
+            ..
+        }
+    ));
+}
+
+#[test]
+fn duplicate_category_test() {
+    let merged_json = String::from(
+        r#"{
+  "supplemental": {
+    "version": {
+      "_unicodeVersion": "10.0.0",
+      "_cldrVersion": "0"
+    },
+    "plurals-type-cardinal": {
+      "xx": {
+        "pluralRule-count-one": "i = 1 @integer 1",
+        "pluralRule-count-one": "i = 2 @integer 2",
+        "pluralRule-count-other": " @integer 0, 3~16"
+      }
+    }
+  }
+}"#,
+    );
+    let inputs = [merged_json];
+
+    match generate_rs(&inputs) {
+        Err(GenerateError::DuplicateCategory { locale, category }) => {
+            assert_eq!(locale, "xx");
+            assert_eq!(category, PluralCategory::ONE);
+        }
+        other => panic!("Expected a duplicate category error, got {:?}", other),
+    }
+
+    let options = GenerateOptions {
+        merge_duplicate_categories: true,
+        ..Default::default()
+    };
+    let outputs = generate_all(&inputs, &[Emit::AstJson], &options).expect("Generation succeeded");
+    let ast: serde_json::Value = serde_json::from_str(&outputs[0]).expect("Parsing succeeded");
+    assert_eq!(ast["cardinal"]["xx"]["one"]["source"], "i = 1 or i = 2");
+    assert_eq!(
+        ast["cardinal"]["xx"]["one"]["condition"]
+            .as_array()
+            .map(Vec::len),
+        Some(2)
+    );
+}
+
+#[test]
+fn strict_samples_test() {
     let cardinal_json = read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
         .expect("Could not read input json");
     let ordinal_json = read_file("./tests/fixtures/cldr_pluralrules_ordinals_33.json")
         .expect("Could not read input json");
-    let output_rs =
-        read_file("./tests/fixtures/cldr_pluralrules_33.rs").expect("Could not read output rs");
+    let options = GenerateOptions {
+        strict_samples: true,
+        ..Default::default()
+    };
 
-    let output = generate_rs(&[cardinal_json, ordinal_json]);
+    // CLDR's own samples are consistent with its rules.
+    assert_eq!(
+        generate_rs_with(&[cardinal_json, ordinal_json], &options).expect("Generation succeeded"),
+        read_file("./tests/fixtures/cldr_pluralrules_33.rs").expect("Could not read output rs")
+    );
 
-    assert_eq!(output_rs, output);
+    // Huge samples are handled without being expanded in full, and those which can't be evaluated are
+    // reported rather than skipped.
+    let huge_json = String::from(
+        r#"{
+  "supplemental": {
+    "version": {
+      "_unicodeVersion": "10.0.0",
+      "_cldrVersion": "0"
+    },
+    "plurals-type-cardinal": {
+      "xx": {
+        "pluralRule-count-other": " @integer 0~18446744073709551615, 1c4000000000"
+      }
+    }
+  }
+}"#,
+    );
+    let options_with_samples = GenerateOptions {
+        embed_samples: true,
+        sample_tests: true,
+        ..Default::default()
+    };
+    assert!(generate_rs_with(std::slice::from_ref(&huge_json), &options_with_samples).is_ok());
+    match generate_rs_with(&[huge_json], &options) {
+        Err(GenerateError::UnevaluableSample { locale, sample }) => {
+            assert_eq!(locale, "xx");
+            assert_eq!(sample, "1c4000000000");
+        }
+        _ => panic!("Generation should fail on an unevaluable sample"),
+    }
+
+    let corrupted_json = String::from(
+        r#"{
+  "supplemental": {
+    "version": {
+      "_unicodeVersion": "10.0.0",
+      "_cldrVersion": "0"
+    },
+    "plurals-type-cardinal": {
+      "xx": {
+        "pluralRule-count-one": "n = 2 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000",
+        "pluralRule-count-other": " @integer 0, 2~16, 100, 1000, 10000, 100000, 1000000, … @decimal 0.0~0.9, 1.1~1.6, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …"
+      }
+    }
+  }
+}"#,
+    );
+
+    let inputs = [corrupted_json];
+    assert!(generate_rs(&inputs).is_ok());
+    match generate_rs_with(&inputs, &options) {
+        Err(GenerateError::SampleMismatch {
+            locale,
+            sample,
+            expected,
+            actual,
+        }) => {
+            assert_eq!(locale, "xx");
+            assert_eq!(sample, "1");
+            assert_eq!(expected, PluralCategory::ONE);
+            assert_eq!(actual, PluralCategory::OTHER);
+        }
+        _ => panic!("Generation should fail on a mis-selecting sample"),
+    }
 }
 
 #[test]
-fn within_test() {
-    let input_json = read_file("./tests/fixtures/cldr_pluralrules_within_test.json")
+fn invalid_json_test() {
+    let input = |section: &str| {
+        format!(
+            r#"{{ "supplemental": {{ "version": {{ "_unicodeVersion": "10.0.0", "_cldrVersion": "33" }}, "plurals-type-cardinal": {} }} }}"#,
+            section
+        )
+    };
+
+    for json in [
+        String::from(r#"{ "supplemental": "#),
+        String::from(r#"{ "supplemental": { "plurals-type-cardinal": {} } }"#),
+        input("[]"),
+    ] {
+        assert!(
+            matches!(
+                generate_rs(std::slice::from_ref(&json)),
+                Err(GenerateError::InvalidJson { input: 0, .. })
+            ),
+            "{}",
+            json
+        );
+    }
+
+    match generate_rs(&[input(r#"{ "x_y": { "pluralRule-count-other": "" } }"#)]) {
+        Err(GenerateError::InvalidLocale(locale)) => assert_eq!(locale, "x_y"),
+        _ => panic!("Generation should fail on an invalid locale"),
+    }
+    // Parsing leaves the language of `und-US` empty, which the generated tables can't hold.
+    match generate_rs(&[input(r#"{ "und-US": { "pluralRule-count-other": "" } }"#)]) {
+        Err(GenerateError::InvalidLocale(locale)) => assert_eq!(locale, "und-US"),
+        _ => panic!("Generation should fail on a locale without a language"),
+    }
+    match generate_rs(&[input(
+        r#"{ "xx": { "pluralRule-count-several": "n = 2", "pluralRule-count-other": "" } }"#,
+    )]) {
+        Err(GenerateError::UnknownRuleName { locale, rule_name }) => {
+            assert_eq!(locale, "xx");
+            assert_eq!(rule_name, "pluralRule-count-several");
+        }
+        _ => panic!("Generation should fail on an unknown rule name"),
+    }
+    // Invalid samples fail as soon as they are parsed.
+    match parse_dataset(&[input(
+        r#"{ "xx": { "pluralRule-count-one": "n = 1 @integer 1~", "pluralRule-count-other": "" } }"#,
+    )]) {
+        Err(GenerateError::InvalidRule {
+            locale, rule_name, ..
+        }) => {
+            assert_eq!(locale, "xx");
+            assert_eq!(rule_name, "pluralRule-count-one");
+        }
+        _ => panic!("Parsing should fail on invalid samples"),
+    }
+}
+
+#[test]
+fn no_rules_test() {
+    let cardinal_json = read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
         .expect("Could not read input json");
-    let output_rs = read_file("./tests/fixtures/cldr_pluralrules_within_test.rs")
-        .expect("Could not read output rs");
+    let unrelated_json = String::from(
+        r#"{
+  "supplemental": {
+    "version": {
+      "_unicodeVersion": "10.0.0",
+      "_cldrVersion": "33"
+    },
+    "currencyData": {}
+  }
+}"#,
+    );
 
-    let output = generate_rs(&[input_json]);
+    assert!(matches!(
+        generate_rs(&[cardinal_json, unrelated_json]),
+        Err(GenerateError::NoRulesFound(1))
+    ));
+    assert!(matches!(
+        generate_rs(&[String::from(r#"{ "name": "not CLDR" }"#)]),
+        Err(GenerateError::NoRulesFound(0))
+    ));
+}
 
-    assert_eq!(output_rs, output);
+#[test]
+fn expand_inputs_test() {
+    let input = |path: &str, expanded| Input {
+        path: PathBuf::from(path),
+        expanded,
+    };
+    let expand = |entries: &[&str]| {
+        expand_inputs(&entries.iter().map(|e| e.to_string()).collect::<Vec<_>>())
+            .expect("Expanding the inputs succeeded")
+    };
+
+    assert_eq!(
+        expand(&["./tests/fixtures/inputs"]),
+        [
+            input("./tests/fixtures/inputs/cardinals.json", true),
+            input("./tests/fixtures/inputs/currencies.json", true),
+            input("./tests/fixtures/inputs/nested/ordinals.json", true),
+        ]
+    );
+    assert_eq!(
+        expand(&["./tests/fixtures/inputs/*.json"]),
+        [
+            input("./tests/fixtures/inputs/cardinals.json", true),
+            input("./tests/fixtures/inputs/currencies.json", true),
+        ]
+    );
+    assert_eq!(
+        expand(&["./tests/fixtures/inputs/**/?rdinals.*"]),
+        [input("./tests/fixtures/inputs/nested/ordinals.json", true)]
+    );
+
+    // Files reached twice are kept once, at their first position, as listed.
+    assert_eq!(
+        expand(&[
+            "-",
+            "./tests/fixtures/inputs/*s.json",
+            "./tests/fixtures/inputs/../inputs/cardinals.json",
+            "./tests/fixtures/inputs",
+        ]),
+        [
+            input("-", false),
+            input("./tests/fixtures/inputs/cardinals.json", false),
+            input("./tests/fixtures/inputs/currencies.json", true),
+            input("./tests/fixtures/inputs/nested/ordinals.json", true),
+        ]
+    );
+
+    let contents = |path: &str| read_file(path).expect("Could not read input json");
+    assert!(contains_plural_rules(&contents(
+        "./tests/fixtures/inputs/cardinals.json"
+    )));
+    assert!(!contains_plural_rules(&contents(
+        "./tests/fixtures/inputs/currencies.json"
+    )));
+    assert!(!contains_plural_rules("not JSON"));
 }
 
 #[test]
-#[should_panic]
-fn bad_type_test() {
-    let text = String::from(
+fn no_std_test() {
+    let cardinal_json = String::from(
         r#"{
   "supplemental": {
     "version": {
-      "_number": "$Revision: 13898 $",
       "_unicodeVersion": "10.0.0",
-      "_cldrVersion": "0"
+      "_cldrVersion": "33"
     },
-    "plurals-type-cardinals": {
-      "test": {
-        "pluralRule-count-one": "n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000",
-        "pluralRule-count-other": " @integer 0, 2~16, 100, 1000, 10000, 100000, 1000000, … @decimal 0.0~0.9, 1.1~1.6, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …"
+    "plurals-type-cardinal": {
+      "xx": {
+        "pluralRule-count-one": "i = 1 and v = 0 @integer 1",
+        "pluralRule-count-other": " @integer 0, 2~16"
+      },
+      "xx-Latn-XY": {
+        "pluralRule-count-other": " @integer 0~15"
       }
     }
   }
 }"#,
     );
+    let options = GenerateOptions {
+        embed_sources: true,
+        embed_samples: true,
+        no_std: true,
+        ..Default::default()
+    };
+    let output = generate_rs_with(&[cardinal_json], &options).expect("Generation succeeded");
+    assert!(!output.contains("std ::"));
+    assert!(!output.contains("cardinal_map"));
+
+    // Build the output in a `#![no_std]` crate.
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("no_std_test");
+    fs::create_dir_all(dir.join("src")).expect("Creating the crate succeeded");
+    fs::write(
+        dir.join("Cargo.toml"),
+        r#"[package]
+name = "no_std_test"
+version = "0.0.0"
+edition = "2024"
+
+[dependencies]
+unic-langid = "0.9.6"
+
+[workspace]
+"#,
+    )
+    .expect("Writing the manifest succeeded");
+    fs::write(
+        dir.join("src/lib.rs"),
+        r#"#![no_std]
+
+pub mod operands {
+    pub struct PluralOperands {
+        pub n: f64,
+        pub i: u64,
+        pub v: usize,
+        pub w: usize,
+        pub f: u64,
+        pub t: u64,
+    }
+}
 
-    generate_rs(&[text]);
+#[derive(PartialEq)]
+pub enum PluralCategory {
+    ZERO,
+    ONE,
+    TWO,
+    FEW,
+    MANY,
+    OTHER,
 }
 
-#[test]
-#[should_panic]
-fn same_data_cardinal_test() {
-    let cardinal_json = read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
-        .expect("Could not read input json");
-    let copy_json = read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
-        .expect("Could not read input json");
+pub enum PluralRuleType {
+    CARDINAL,
+}
 
-    generate_rs(&[cardinal_json, copy_json]);
+pub mod rules;
+"#,
+    )
+    .expect("Writing the crate succeeded");
+    fs::write(dir.join("src/rules.rs"), output).expect("Writing the rules succeeded");
+
+    let build = std::process::Command::new(std::env::var("CARGO").unwrap_or("cargo".to_owned()))
+        .args(["build", "--offline", "--quiet", "--manifest-path"])
+        .arg(dir.join("Cargo.toml"))
+        .env("CARGO_TARGET_DIR", dir.join("target"))
+        .output()
+        .expect("Cargo runs");
+    assert!(
+        build.status.success(),
+        "{}",
+        String::from_utf8_lossy(&build.stderr)
+    );
 }
 
 #[test]
-#[should_panic]
-fn same_data_ordinal_test() {
-    let cardinal_json = read_file("./tests/fixtures/cldr_pluralrules_ordinals_33.json")
-        .expect("Could not read input json");
-    let copy_json = read_file("./tests/fixtures/cldr_pluralrules_ordinals_33.json")
-        .expect("Could not read input json");
+fn version_test() {
+    let cardinal_json = String::from(
+        r#"{
+  "supplemental": {
+    "version": {
+      "_number": "$Revision: 13898 $",
+      "_unicodeVersion": "10.0.0",
+      "_cldrVersion": "33.0.1"
+    },
+    "plurals-type-cardinal": {
+      "xx": {
+        "pluralRule-count-one": "n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000",
+        "pluralRule-count-other": " @integer 0, 2~16, 100, 1000, 10000, 100000, 1000000, … @decimal 0.0~0.9, 1.1~1.6, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …"
+      }
+    }
+  }
+}"#,
+    );
+
+    let output = generate_rs(std::slice::from_ref(&cardinal_json)).expect("Generation succeeded");
+    assert!(output.contains(r#"pub static CLDR_VERSION : & str = "33.0.1" ;"#));
 
-    generate_rs(&[cardinal_json, copy_json]);
+    for version in ["33.", "v33", ""] {
+        let cardinal_json = cardinal_json.replace("33.0.1", version);
+        assert!(matches!(
+            generate_rs(&[cardinal_json]),
+            Err(GenerateError::InvalidCldrVersion(v)) if v == version
+        ));
+    }
 }
 
 #[test]
-#[should_panic]
 fn different_version_test() {
     let cardinal_json = String::from(
         r#"{
@@ -94,8 +1936,8 @@ fn different_version_test() {
       "_unicodeVersion": "10.0.0",
       "_cldrVersion": "0"
     },
-    "plurals-type-cardinals": {
-      "test": {
+    "plurals-type-cardinal": {
+      "xx": {
         "pluralRule-count-one": "n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000",
         "pluralRule-count-other": " @integer 0, 2~16, 100, 1000, 10000, 100000, 1000000, … @decimal 0.0~0.9, 1.1~1.6, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …"
       }
@@ -111,8 +1953,8 @@ fn different_version_test() {
       "_unicodeVersion": "10.0.0",
       "_cldrVersion": "1"
     },
-    "plurals-type-cardinals": {
-      "test": {
+    "plurals-type-cardinal": {
+      "xx": {
         "pluralRule-count-one": "n = 1 @integer 1 @decimal 1.0, 1.00, 1.000, 1.0000",
         "pluralRule-count-other": " @integer 0, 2~16, 100, 1000, 10000, 100000, 1000000, … @decimal 0.0~0.9, 1.1~1.6, 10.0, 100.0, 1000.0, 10000.0, 100000.0, 1000000.0, …"
       }
@@ -121,5 +1963,649 @@ fn different_version_test() {
 }"#,
     );
 
-    generate_rs(&[cardinal_json, ordinal_json]);
+    let result = generate_rs(&[cardinal_json, ordinal_json]);
+    assert!(
+        matches!(
+            &result,
+            Err(GenerateError::VersionMismatch { input: 1, expected, found })
+                if expected == "0" && found == "1"
+        ),
+        "{:?}",
+        result
+    );
+    assert!(matches!(generate_rs(&[]), Err(GenerateError::NoInputs)));
+    assert!(matches!(
+        generate_versioned_rs(&[], &GenerateOptions::default()),
+        Err(GenerateError::NoInputs)
+    ));
+}
+
+#[test]
+fn overridden_version_test() {
+    let resource = |ty: &str, version: &str| {
+        format!(
+            r#"{{
+  "supplemental": {{
+    "version": {{
+      "_number": "$Revision: 13898 $",
+      "_unicodeVersion": "10.0.0",
+      "_cldrVersion": "{version}"
+    }},
+    "plurals-type-{ty}": {{
+      "xx": {{
+        "pluralRule-count-one": "n = 1 @integer 1",
+        "pluralRule-count-other": " @integer 0, 2~16"
+      }}
+    }}
+  }}
+}}"#
+        )
+    };
+    let options = GenerateOptions {
+        cldr_version: Some("2".to_owned()),
+        ..GenerateOptions::default()
+    };
+
+    // Inputs of different versions can be merged under the overridden one.
+    let code = generate_rs_with(
+        &[resource("cardinal", "0"), resource("ordinal", "1")],
+        &options,
+    )
+    .expect("Generation succeeded");
+    assert!(code.contains(r#"CLDR_VERSION : & str = "2""#), "{}", code);
+}
+
+#[test]
+fn only_locales_test() {
+    let cardinal_json = fs::read_to_string("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+        .expect("Could not read cardinals json");
+    let only = vec!["pt".parse().unwrap(), "zz".parse().unwrap()];
+    let options = GenerateOptions {
+        only: only.clone(),
+        ..GenerateOptions::default()
+    };
+
+    // A language-only entry selects the locales of that language with other subtags.
+    let code = generate_rs_with(std::slice::from_ref(&cardinal_json), &options)
+        .expect("Generation succeeded");
+    assert!(
+        code.contains("CARDINAL_LOCALE_COUNT : usize = 2"),
+        "{}",
+        code
+    );
+
+    let dataset = parse_dataset(&[cardinal_json]).expect("Parsing succeeded");
+    let zz: LanguageIdentifier = "zz".parse().unwrap();
+    assert_eq!(unmatched_locales(&dataset, &only), [&zz]);
+}
+
+#[test]
+fn const_selector_test() {
+    const ONE: PluralOperands = PluralOperands {
+        n: 1.0,
+        i: 1,
+        v: 0,
+        w: 0,
+        f: 0,
+        t: 0,
+    };
+    const TWO: PluralOperands = PluralOperands {
+        n: 2.0,
+        i: 2,
+        ..ONE
+    };
+    const EN: PluralCategory = cldr_33_exported::select_en_cardinal(&ONE);
+    const CY: PluralCategory = cldr_33_exported::select_cy_cardinal(&TWO);
+    assert_eq!(EN, PluralCategory::ONE);
+    assert_eq!(CY, PluralCategory::TWO);
+
+    // Rules checking ranges with `RangeInclusive::contains`, which is not a `const fn`, stay regular functions.
+    let code = read_file("./tests/fixtures/cldr_pluralrules_33_exported.rs")
+        .expect("Could not read output rs");
+    assert!(code.contains("pub const fn select_en_cardinal"));
+    assert!(code.contains("pub fn select_ru_cardinal"));
+
+    // Custom operand accessors may not be const either.
+    let code = read_file("./tests/fixtures/cldr_pluralrules_within_test_custom_operands.rs")
+        .expect("Could not read output rs");
+    assert!(!code.contains("const fn"));
+}
+
+#[test]
+fn rule_doc_test() {
+    let inputs = [
+        read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+            .expect("Could not read input json"),
+    ];
+    let doc_of = |code: &str, name: &str| -> Vec<String> {
+        let file = syn::parse_file(code).expect("The generated code parses");
+        let rule = file
+            .items
+            .into_iter()
+            .find_map(|item| match item {
+                syn::Item::Fn(rule) if rule.sig.ident == name => Some(rule),
+                _ => None,
+            })
+            .expect("The rule is generated");
+        rule.attrs
+            .iter()
+            .filter_map(|attr| match &attr.meta {
+                syn::Meta::NameValue(doc) if doc.path.is_ident("doc") => {
+                    let syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(line),
+                        ..
+                    }) = &doc.value
+                    else {
+                        panic!("The doc comment is a string");
+                    };
+                    Some(line.value())
+                }
+                _ => None,
+            })
+            .collect()
+    };
+
+    let options = GenerateOptions {
+        export_selectors: true,
+        ..Default::default()
+    };
+    let code = generate_rs_with(&inputs, &options).expect("Generation succeeded");
+    assert_eq!(
+        doc_of(&code, "select_lv_cardinal"),
+        [
+            " The cardinal rules of `lv`.",
+            "",
+            " - one: `n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 or v != 2 and f % 10 = 1`",
+            " - zero: `n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19`",
+        ]
+    );
+
+    // A shared rule names every locale using it.
+    let code = generate_rs(&inputs).expect("Generation succeeded");
+    let doc = doc_of(&code, "cardinal_rule_0");
+    assert!(doc[0].starts_with(" The cardinal rules of `af`, `asa`, `az`, "));
+    assert!(doc[0].ends_with(", `xh`, `xog`."));
+    assert_eq!(doc[2..], [" - one: `n = 1`"]);
+}
+
+#[test]
+fn single_other_arm_test() {
+    let inputs = [
+        read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+            .expect("Could not read input json"),
+        read_file("./tests/fixtures/cldr_pluralrules_ordinals_33.json")
+            .expect("Could not read input json"),
+    ];
+    let code = generate_rs(&inputs).expect("Generation succeeded");
+    let file = syn::parse_file(&code).expect("The generated code parses");
+
+    let mut rules = 0;
+    for item in file.items {
+        let syn::Item::Fn(rule) = item else {
+            continue;
+        };
+        if !rule.sig.ident.to_string().contains("_rule_") {
+            continue;
+        }
+        rules += 1;
+        let body = quote::quote!(#rule).to_string();
+        assert_eq!(
+            body.matches("PluralCategory :: OTHER").count(),
+            1,
+            "{}",
+            body
+        );
+    }
+    assert!(rules > 0);
+}
+
+#[test]
+fn diff_datasets_test() {
+    let dataset = |path| {
+        parse_dataset(&[read_file(path).expect("Could not read input json")])
+            .expect("Parsing succeeded")
+    };
+    let old = dataset("./tests/fixtures/diff/old.json");
+    let new = dataset("./tests/fixtures/diff/new.json");
+
+    // Only `fr` changed, while the samples of its `other` rule changing alone is not reported.
+    let fr: LanguageIdentifier = "fr".parse().unwrap();
+    let changes = diff_datasets(&old, &new);
+    assert!(
+        changes.iter().all(|change| change.locale == fr),
+        "{:?}",
+        changes
+    );
+    assert_eq!(
+        changes
+            .iter()
+            .map(|change| (change.category, &change.kind))
+            .collect::<Vec<_>>(),
+        [
+            (
+                PluralCategory::ONE,
+                &RuleChangeKind::Changed {
+                    old: "i = 0,1".to_owned(),
+                    new: "i = 0..1".to_owned()
+                }
+            ),
+            (
+                PluralCategory::MANY,
+                &RuleChangeKind::Added(
+                    "e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5".to_owned()
+                )
+            ),
+        ]
+    );
+
+    assert!(diff_datasets(&old, &old).is_empty());
+    assert!(diff_datasets(&new, &old).iter().any(|change| change.kind
+        == RuleChangeKind::Removed(
+            "e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5".to_owned()
+        )));
+
+    // Conditions written differently but normalizing to the same rule are not reported.
+    let dataset_of = |one: &str| {
+        let json = format!(
+            r#"{{ "supplemental": {{
+                "version": {{ "_unicodeVersion": "10.0.0", "_cldrVersion": "33" }},
+                "plurals-type-cardinal": {{ "xx": {{ "pluralRule-count-one": "{}" }} }}
+            }} }}"#,
+            one
+        );
+        parse_dataset(&[json]).expect("Parsing succeeded")
+    };
+    let old = dataset_of("v = 0 and i is 1..1 @integer 1");
+    assert!(diff_datasets(&old, &dataset_of("i = 1 and v = 0")).is_empty());
+    assert_eq!(
+        diff_datasets(&old, &dataset_of("i = 1 and v = 1"))[0].kind,
+        RuleChangeKind::Changed {
+            old: "v = 0 and i = 1..1".to_owned(),
+            new: "i = 1 and v = 1".to_owned()
+        }
+    );
+}
+
+#[test]
+fn sample_tests_test() {
+    let inputs = [
+        read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+            .expect("Could not read input json"),
+        read_file("./tests/fixtures/cldr_pluralrules_ordinals_33.json")
+            .expect("Could not read input json"),
+    ];
+    let options = GenerateOptions {
+        sample_tests: true,
+        ..Default::default()
+    };
+    let code = generate_rs_with(&inputs, &options).expect("Generation succeeded");
+    let file = syn::parse_file(&code).expect("The generated code parses");
+
+    // Without `embed_samples`, the sample values are only listed in the test module.
+    let item_names = |items: &[syn::Item]| -> Vec<String> {
+        items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Const(item) => Some(item.ident.to_string()),
+                syn::Item::Fn(item) => Some(item.sig.ident.to_string()),
+                _ => None,
+            })
+            .collect()
+    };
+    assert!(!item_names(&file.items).contains(&"PRS_CARDINAL_SAMPLES".to_owned()));
+    let tests = file
+        .items
+        .iter()
+        .find_map(|item| match item {
+            syn::Item::Mod(item) if item.ident == "sample_tests" => item.content.as_ref(),
+            _ => None,
+        })
+        .expect("The test module is generated");
+    assert_eq!(
+        item_names(&tests.1),
+        [
+            "PRS_CARDINAL_SAMPLES",
+            "cardinal_samples",
+            "PRS_ORDINAL_SAMPLES",
+            "ordinal_samples"
+        ]
+    );
+}
+
+#[test]
+fn phf_lookup_test() {
+    let inputs = [
+        read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+            .expect("Could not read input json"),
+        read_file("./tests/fixtures/cldr_pluralrules_ordinals_33.json")
+            .expect("Could not read input json"),
+    ];
+    let options = GenerateOptions {
+        phf_lookup: true,
+        ..Default::default()
+    };
+    let code = generate_rs_with(&inputs, &options).expect("Generation succeeded");
+    let file = syn::parse_file(&code).expect("The generated code parses");
+
+    for (map_name, ty) in [
+        ("PRS_CARDINAL_PHF", PluralRuleType::CARDINAL),
+        ("PRS_ORDINAL_PHF", PluralRuleType::ORDINAL),
+    ] {
+        let map = file
+            .items
+            .iter()
+            .find_map(|item| match item {
+                syn::Item::Static(item) if item.ident == map_name => Some(item),
+                _ => None,
+            })
+            .expect("The map is generated");
+        let syn::Expr::Macro(phf_map) = &*map.expr else {
+            panic!("The map is not built by a macro");
+        };
+        let path = &phf_map.mac.path;
+        assert_eq!(quote::quote!(#path).to_string(), "phf :: phf_map");
+
+        // The map is keyed by the canonical string of every locale of the table.
+        let keys: Vec<String> = phf_map
+            .mac
+            .tokens
+            .clone()
+            .into_iter()
+            .filter_map(|tt| match tt {
+                proc_macro2::TokenTree::Literal(lit) => {
+                    Some(lit.to_string().trim_matches('"').to_owned())
+                }
+                _ => None,
+            })
+            .collect();
+        let locales: Vec<String> = cldr_33::supported_locales(ty)
+            .map(|lang| lang.to_string())
+            .collect();
+        assert_eq!(keys, locales);
+    }
+
+    // The selectors query the maps instead of binary searching the tables.
+    let select_cardinal = file
+        .items
+        .iter()
+        .find_map(|item| match item {
+            syn::Item::Fn(item) if item.sig.ident == "select_cardinal" => Some(item),
+            _ => None,
+        })
+        .expect("The selector is generated");
+    let body = quote::quote!(#select_cardinal).to_string();
+    assert!(body.contains("PRS_CARDINAL_PHF . get"), "{}", body);
+    assert!(!body.contains("binary_search"), "{}", body);
+}
+
+#[test]
+fn phf_fixture_test() {
+    // The compiled maps find the same rule as the binary search for every locale.
+    let operands: Vec<PluralOperands> = (0..=200)
+        .map(PluralOperands::from)
+        .chain(["0.0", "1.5", "2.10", "1000000"].map(|n| n.parse().expect("Valid operands")))
+        .collect();
+    for (ty, table) in [
+        (PluralRuleType::CARDINAL, cldr_33::PRS_CARDINAL),
+        (PluralRuleType::ORDINAL, cldr_33::PRS_ORDINAL),
+    ] {
+        for (lang, rule) in table {
+            let phf_rule = cldr_33_phf::find(ty, lang).expect("The locale is in the map");
+            for po in &operands {
+                assert_eq!(phf_rule(po), rule(po), "{} {:?} {:?}", lang, ty, po);
+            }
+        }
+    }
+
+    let unknown: LanguageIdentifier = "xx".parse().expect("Parsing failed.");
+    assert!(cldr_33_phf::select_cardinal(&unknown).is_none());
+    let en_us: LanguageIdentifier = "en-US".parse().expect("Parsing failed.");
+    let one = PluralOperands::from(1_u64);
+    assert_eq!(
+        cldr_33_phf::select(&en_us, &one, PluralRuleType::CARDINAL),
+        PluralCategory::ONE
+    );
+}
+
+#[test]
+fn type_modules_test() {
+    let input_json = read_file("./tests/fixtures/cldr_pluralrules_modules_test.json")
+        .expect("Could not read input json");
+    let output_rs = read_file("./tests/fixtures/cldr_pluralrules_modules_test.rs")
+        .expect("Could not read output rs");
+
+    let options = GenerateOptions {
+        type_modules: true,
+        ..Default::default()
+    };
+    let output = generate_rs_with(&[input_json], &options).expect("Generation succeeded");
+
+    assert_eq!(output_rs, output);
+
+    let en: LanguageIdentifier = "en".parse().expect("Parsing failed.");
+    let pl: LanguageIdentifier = "pl".parse().expect("Parsing failed.");
+
+    // Each module has its own tables and selector.
+    assert_eq!(modules_test::cardinal::CARDINAL_LOCALE_COUNT, 2);
+    assert_eq!(modules_test::ordinal::ORDINAL_LOCALE_COUNT, 2);
+    let rule = modules_test::cardinal::select_cardinal(&pl).expect("pl has cardinal rules");
+    assert_eq!(rule(&PluralOperands::from(3_u64)), PluralCategory::FEW);
+    let rule = modules_test::ordinal::select_ordinal(&en).expect("en has ordinal rules");
+    assert_eq!(rule(&PluralOperands::from(22_u64)), PluralCategory::TWO);
+
+    // The items are re-exported, so the file offers the same API as without modules.
+    assert_eq!(modules_test::PRS_CARDINAL.len(), 2);
+    let po = PluralOperands::from(1_u64);
+    assert_eq!(
+        modules_test::select(&en, &po, PluralRuleType::CARDINAL),
+        PluralCategory::ONE
+    );
+    assert_eq!(
+        modules_test::select(&en, &po, PluralRuleType::ORDINAL),
+        PluralCategory::ONE
+    );
+    assert_eq!(
+        modules_test::supported_locales(PluralRuleType::ORDINAL).count(),
+        2
+    );
+}
+
+#[test]
+fn rule_overrides_test() {
+    let json = read_file("./tests/fixtures/cldr_pluralrules_modules_test.json")
+        .expect("Could not read input json");
+    let qaa: LanguageIdentifier = "qaa".parse().expect("Parsing failed.");
+    let pl: LanguageIdentifier = "pl".parse().expect("Parsing failed.");
+    let overrides = |rules: &str| {
+        BTreeMap::from([
+            ((qaa.clone(), PluralRuleType::CARDINAL), rules.to_owned()),
+            (
+                (pl.clone(), PluralRuleType::ORDINAL),
+                "one: n = 1; other: @integer 0, 2~16".to_owned(),
+            ),
+        ])
+    };
+    let options = GenerateOptions {
+        rule_overrides: overrides("one: n = 1..3; other: @integer 0, 4~16"),
+        ..Default::default()
+    };
+    let generated =
+        generate_rs_with(std::slice::from_ref(&json), &options).expect("Generation succeeded");
+    let fixture = read_file("./tests/fixtures/cldr_pluralrules_overrides_test.rs")
+        .expect("Could not read the fixture");
+    assert_eq!(generated, fixture.trim_end());
+
+    // The constructed locale is added to the table, and the overridden one keeps its place.
+    assert_eq!(overrides_test::CARDINAL_LOCALE_COUNT, 3);
+    assert_eq!(overrides_test::ORDINAL_LOCALE_COUNT, 2);
+    for (value, category) in [
+        (0_u64, PluralCategory::OTHER),
+        (2, PluralCategory::ONE),
+        (3, PluralCategory::ONE),
+        (4, PluralCategory::OTHER),
+    ] {
+        assert_eq!(
+            overrides_test::select(&qaa, &value.into(), PluralRuleType::CARDINAL),
+            category
+        );
+    }
+    // The override replaces the CLDR rules of `pl`, which only select `other`.
+    assert_eq!(
+        overrides_test::select(&pl, &1_u64.into(), PluralRuleType::ORDINAL),
+        PluralCategory::ONE
+    );
+    assert_eq!(
+        overrides_test::select(&pl, &3_u64.into(), PluralRuleType::CARDINAL),
+        PluralCategory::FEW
+    );
+
+    let error = |rules| {
+        let options = GenerateOptions {
+            rule_overrides: overrides(rules),
+            ..Default::default()
+        };
+        generate_rs_with(std::slice::from_ref(&json), &options)
+            .expect_err("Generation failed")
+            .to_string()
+    };
+    assert_eq!(
+        error("n = 1..3"),
+        "the rule `n = 1..3` of `qaa` has no category keyword"
+    );
+    assert_eq!(
+        error("several: n = 1"),
+        "unknown rule name `pluralRule-count-several` of `qaa`"
+    );
+    assert!(error("one: n = 3..1").starts_with("invalid `pluralRule-count-one` rule of `qaa`: "));
+}
+
+#[test]
+fn option_combinations_test() {
+    let cardinal_json = read_file("./tests/fixtures/cldr_pluralrules_cardinals_33.json")
+        .expect("Could not read input json");
+    let ordinal_json = read_file("./tests/fixtures/cldr_pluralrules_ordinals_33.json")
+        .expect("Could not read input json");
+    let inputs = [cardinal_json, ordinal_json];
+
+    // `generate_rs` is `generate_rs_with` with the default options.
+    assert_eq!(
+        generate_rs(&inputs).expect("Generation succeeded"),
+        generate_rs_with(&inputs, &GenerateOptions::default()).expect("Generation succeeded")
+    );
+
+    // A version override, a locale filter, `no_std`, the `match` lookup and the examples.
+    let options = GenerateOptions {
+        cldr_version: Some("46.1".to_owned()),
+        only: vec!["en".parse().unwrap(), "pl".parse().unwrap()],
+        no_std: true,
+        match_dispatch: true,
+        minimal_pairs: true,
+        ..Default::default()
+    };
+    let output = generate_rs_with(&inputs, &options).expect("Generation succeeded");
+    assert!(output.contains("CLDR_VERSION : & str = \"46.1\""));
+    assert!(output.contains("CARDINAL_LOCALE_COUNT : usize = 2"));
+    assert!(output.contains("ORDINAL_LOCALE_COUNT : usize = 2"));
+    assert!(output.contains("pub fn match_cardinal"));
+    assert!(output.contains("pub fn minimal_pairs"));
+    assert!(output.contains("(\"pl\" , None , None , 0) =>"));
+    assert!(!output.contains("(\"fr\" , None , None , 0)"));
+    assert!(!output.contains("std ::"));
+
+    // Trait object rules, in a module per type, along with the embedded samples and their tests.
+    let options = GenerateOptions {
+        dyn_rules: true,
+        type_modules: true,
+        embed_samples: true,
+        sample_tests: true,
+        match_dispatch: true,
+        ..Default::default()
+    };
+    let output = generate_rs_with(&inputs, &options).expect("Generation succeeded");
+    if let Err(err) = syn::parse_file(&output) {
+        panic!("The output is not valid Rust: {}", err);
+    }
+    assert!(output.contains("pub type PluralRule = & 'static (dyn Fn"));
+    assert!(output.contains("pub mod ordinal { use super :: * ;"));
+    assert!(output.contains("pub fn samples_for"));
+    assert!(output.contains("pub fn match_ordinal"));
+}
+
+#[test]
+fn compact_integer_test() {
+    let input = read_file("./tests/fixtures/cldr_pluralrules_compact_test.json")
+        .expect("Could not read input json");
+    let generated = generate_rs(&[input]).expect("Generation succeeded");
+    let fixture = read_file("./tests/fixtures/cldr_pluralrules_compact_test.rs")
+        .expect("Could not read the fixture");
+    assert_eq!(generated, fixture.trim_end());
+
+    // Integers have no compact exponent, so the `e = 0` of the French rules holds for them.
+    let fr: LanguageIdentifier = "fr".parse().expect("Parsing failed.");
+    for (value, category) in [
+        (0, PluralCategory::ONE),
+        (1, PluralCategory::ONE),
+        (2, PluralCategory::OTHER),
+        (1000000, PluralCategory::MANY),
+    ] {
+        assert_eq!(
+            compact_test::select(
+                &fr,
+                &PluralOperands::from_integer(value),
+                PluralRuleType::CARDINAL
+            ),
+            category,
+            "{}",
+            value
+        );
+    }
+}
+
+#[test]
+fn versioned_modules_test() {
+    let inputs = [
+        read_file("./tests/fixtures/cldr_pluralrules_versions_44.json")
+            .expect("Could not read input json"),
+        read_file("./tests/fixtures/cldr_pluralrules_modules_test.json")
+            .expect("Could not read input json"),
+    ];
+    let generated =
+        generate_versioned_rs(&inputs, &GenerateOptions::default()).expect("Generation succeeded");
+    let fixture = read_file("./tests/fixtures/cldr_pluralrules_versions_test.rs")
+        .expect("Could not read the fixture");
+    assert_eq!(generated, fixture.trim_end());
+
+    // Each version has its own tables, in a module named after it.
+    assert_eq!(versions_test::v33::CLDR_VERSION, "33");
+    assert_eq!(versions_test::v44::CLDR_VERSION, "44");
+    let fr: LanguageIdentifier = "fr".parse().expect("Parsing failed.");
+    let pl: LanguageIdentifier = "pl".parse().expect("Parsing failed.");
+    assert_eq!(
+        versions_test::v44::select(&fr, &0_u64.into(), PluralRuleType::CARDINAL),
+        PluralCategory::ONE
+    );
+    assert_eq!(
+        versions_test::v33::select(&fr, &0_u64.into(), PluralRuleType::CARDINAL),
+        PluralCategory::OTHER
+    );
+    assert_eq!(
+        versions_test::v33::select(&pl, &3_u64.into(), PluralRuleType::CARDINAL),
+        PluralCategory::FEW
+    );
+    assert_eq!(
+        versions_test::v44::supported_locales(PluralRuleType::ORDINAL).count(),
+        0
+    );
+    assert_eq!(versions_test::v33::ORDINAL_LOCALE_COUNT, 2);
+
+    let options = GenerateOptions {
+        module: Some("v-33".to_owned()),
+        ..Default::default()
+    };
+    assert_eq!(
+        generate_rs_with(&inputs[1..], &options)
+            .expect_err("Generation failed")
+            .to_string(),
+        "invalid module name `v-33`"
+    );
 }